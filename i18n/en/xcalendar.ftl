@@ -9,16 +9,94 @@ menu-file = File
 menu-edit = Edit
 menu-view = View
 menu-new-event = New Event...
+menu-quick-add-event = Quick Add Event...
+menu-new-event-on-picked-dates = New Event on Picked Dates...
 menu-new-calendar = New Calendar...
+menu-calendar-palette = Calendar Colors
+menu-palette-pastel = Pastel
+menu-palette-vivid = Vivid
+menu-palette-colorblind-safe = Colorblind-Safe
 menu-import-ical = Import iCal...
+menu-import-clipboard = Import from Clipboard
 menu-export-ical = Export iCal...
+menu-export-range = Export Date Range...
+menu-export-selected = Export Selected Event...
+menu-sync-all = Sync All Now
+menu-export-view-image = Export View as Image...
+menu-view-image-scale = Image Resolution
+menu-view-image-scale-1x = Standard (1x)
+menu-view-image-scale-2x = Double (2x, pixel-doubled on standard displays)
+menu-make-recurring = Make Recurring...
+menu-toggle-all-day = Switch All-Day / Timed
+menu-skip-occurrence = Skip This Occurrence
+menu-copy-event-link = Copy Event Link
+menu-confirm-event-moves = Confirm Event Moves
+menu-drag-threshold = Drag Distance Before Moving
+menu-drag-threshold-off = Move Immediately
+menu-drag-threshold-pixels = { $pixels } px
+menu-event-click = Single Click on Event
+menu-event-click-select = Selects Event
+menu-event-click-edit = Opens Editor
+menu-time-snap = Snap Selections To
+menu-time-snap-minutes = { $minutes } Minutes
+menu-new-event-rounding = Round New Event Start To
+menu-new-event-rounding-enabled = Round Up From Now
+menu-merge-duplicates = Merge Duplicate Events...
+menu-copy-week-forward = Copy Week to Next Week...
+menu-edit-day-note = Note for Selected Day...
 menu-settings = Settings...
 menu-today = Jump to Today
+menu-return-to-selected = Return to Selected Day
 menu-day-view = Day View
 menu-week-view = Week View
 menu-month-view = Month View
 menu-year-view = Year View
 menu-show-week-numbers = Show Week Numbers
+menu-highlight-current-week = Highlight Current Week
+menu-rolling-week = Week Starts on Selected Day
+menu-focus-mode = Focus on Selected Calendar
+menu-show-legend = Show Calendar Legend
+menu-month-stats = Month Statistics...
+menu-show-event-count-badges = Show Event Count Badges
+menu-dim-past-events = Dim Past Events
+menu-half-hour-lines = Half-Hour Gridlines
+menu-highlight-free-days = Highlight Free Days
+menu-week-scroll-start = Week View Opens At
+menu-week-scroll-current-time = Current Time
+menu-week-scroll-working-hours = Start of Working Hours
+menu-week-scroll-top = Top of Day
+menu-weekend-days = Weekend
+menu-weekend-system = System Default
+menu-weekend-sat-sun = Saturday and Sunday
+menu-weekend-fri-sat = Friday and Saturday
+menu-weekend-fri = Friday Only
+menu-weekend-sat = Saturday Only
+menu-weekend-sun = Sunday Only
+menu-compact-sensitivity = Compact Month Cells
+menu-compact-sensitivity-low = Only When Small
+menu-compact-sensitivity-normal = Default
+menu-compact-sensitivity-high = Sooner
+menu-today-highlight = Today Color
+menu-selected-highlight = Selected Day Color
+highlight-accent = Accent Color
+highlight-blue = Blue
+highlight-green = Green
+highlight-orange = Orange
+highlight-red = Red
+highlight-purple = Purple
+menu-event-overlap = Overlapping Events
+menu-event-overlap-side-by-side = Side by Side
+menu-event-overlap-cascade = Cascade
+menu-event-sort = Sort Events Within a Day
+menu-event-sort-start-time = By Start Time
+menu-event-sort-all-day-first = All-Day First
+menu-event-sort-calendar = By Calendar
+menu-event-sort-priority = By Priority
+menu-startup-view = Open At Startup
+menu-startup-view-last-used = Last Used View
+menu-next-day-after = Open Day View on Tomorrow
+menu-next-day-after-never = Never
+menu-next-day-after-hour = After { $hour }:00
 menu-about = About Sol Calendar
 menu-quit = Quit
 
@@ -28,17 +106,33 @@ view-month = Month
 view-week = Week
 view-day = Day
 
+# Empty states
+empty-state-month = No events this month — click a day to add one
+empty-state-week = No events this week — drag across the grid to add one
+empty-state-day = No events on this day — drag across the grid to add one
+
 # Navigation
 nav-today = Today
+nav-now = Now
+nav-previous-month = Previous month
+nav-next-month = Next month
+nav-previous-year = Previous year
+nav-next-year = Next year
 nav-previous = Previous
 nav-next = Next
 
+# Upcoming event badge
+upcoming-in-minutes = in {$minutes} min
+upcoming-in-hours = in {$hours} h
+upcoming-in-hours-minutes = in {$hours} h {$minutes} min
+
 # Sidebar
 sidebar-calendars = Calendars
 sidebar-mini-calendar = Mini Calendar
 
 # Week number
 week-abbr = Wk
+week-view-week-number = { week-abbr } { $week }
 
 # Calendar actions
 calendar-add = Add Calendar
@@ -47,6 +141,11 @@ calendar-export = Export Calendar
 calendar-delete = Delete Calendar
 calendar-select = Select Calendar
 calendar-toggle = Toggle visibility
+calendar-show = Show { $name }
+checkbox-checked = Checked
+checkbox-unchecked = Not checked
+calendar-change-color = Change calendar color
+calendar-color-option = Color { $color }
 
 # Days of week (abbreviated)
 day-sun = Sun
@@ -82,26 +181,38 @@ month-december = December
 
 # Events
 event-new = New Event
+event-new-on-dates = {$count ->
+    [one] New Event on 1 Date
+    *[other] New Event on {$count} Dates
+}
 event-edit = Edit Event
 event-delete = Delete Event
 event-title = Title
 event-title-placeholder = Event title
+event-no-title = (No title)
 event-location = Location
 event-location-placeholder = Add location
 event-all-day = All day
+all-day-more = +{ $count }
 event-starts = Starts
 event-ends = Ends
 event-datetime-section = Date & Time
 event-travel-time = Travel Time
 event-repeat = Repeat
+event-repeat-preview = Next occurrences
+event-priority = Priority
 event-schedule-section = Schedule
 event-calendar = Calendar
+event-calendar-read-only = This calendar is read-only
+event-unsaved-changes = Discard your unsaved changes?
 event-no-calendar = No calendar
 event-invitees = Invitees
 event-invitee-placeholder = Add email address
 event-alert = Alert
+event-alert-empty = No reminders
 event-attachments = Attachments
 event-no-attachments = No attachments
+event-attachments-drop-hint = Drop files here to attach them
 event-url = URL
 event-url-placeholder = Add URL
 event-notes = Notes
@@ -128,6 +239,12 @@ repeat-monthly = Monthly
 repeat-yearly = Yearly
 repeat-custom = Custom
 
+# Priority Options
+priority-none = None
+priority-low = Low
+priority-medium = Medium
+priority-high = High
+
 # Alert Options
 alert-none = None
 alert-at-time = At time
@@ -140,6 +257,7 @@ alert-2hours = 2 hours before
 alert-1day = 1 day before
 alert-2days = 2 days before
 alert-1week = 1 week before
+alert-time-to-leave = When it's time to leave
 
 # Buttons
 button-save = Save
@@ -151,6 +269,9 @@ button-apply = Apply
 button-import = Import
 button-ok = OK
 button-revert = Revert
+button-merge = Merge
+button-create-anyway = Create Anyway
+button-export = Export
 
 # Dialog - Import
 dialog-import-title = Import Calendar Events
@@ -168,16 +289,88 @@ dialog-import-result-title = Import Complete
 import-success = Import completed successfully!
 import-failed = Import failed
 import-imported = Imported
+import-updated = Updated
 import-skipped = Skipped (duplicates)
 import-failed-count = Failed
 error = Error
 
+# Dialog - Merge Duplicates
+dialog-merge-duplicates-title = Merge Duplicate Events
+merge-duplicates-none = No duplicate events were found.
+merge-duplicates-found = {$count ->
+    [one] 1 duplicate event will be merged
+    *[other] {$count} duplicate events will be merged
+}
+merge-duplicates-warning = The most complete copy of each event is kept. This cannot be undone.
+
+# Dialog - Month Statistics
+dialog-month-stats-title = Statistics for {$month}
+month-stats-none = There are no events in this month.
+month-stats-events = Events
+month-stats-hours = Scheduled time
+month-stats-hours-value = {$hours} h
+month-stats-busiest = Busiest day
+month-stats-busiest-value = {$date} ({$count ->
+    [one] 1 event
+   *[other] {$count} events
+})
+month-stats-by-calendar = By calendar
+month-stats-calendar-value = {$count ->
+    [one] 1 event
+   *[other] {$count} events
+} · {$hours} h
+
+# Dialog - Copy Week
+dialog-copy-week-title = Copy Week to Next Week
+copy-week-none = There are no events in this week that need copying.
+copy-week-count = {$count ->
+    [one] 1 event will be copied to next week
+    *[other] {$count} events will be copied to next week
+}
+copy-week-note = Recurring events and events already copied are skipped.
+button-copy = Copy
+
+# Dialog - Export Range
+dialog-export-range-title = Export Date Range
+export-range-dates = Dates
+export-range-calendars = Calendars
+export-range-invalid = Enter two dates as YYYY-MM-DD, the first on or before the second
+
+# Dialog - Command Palette
+dialog-command-palette-title = Quick Add Event
+command-palette-placeholder = e.g. Lunch with Sam tomorrow 12:30-13:30
+command-palette-all-day = All day
+
+# Dialog - Day Note
+dialog-day-note-title = Note for {$date}
+day-note-placeholder = Write a note for this day
+day-note-hint = Day notes aren't events and are not exported. Clear the text to remove the note.
+
 # Dialog - Calendar (New/Edit)
 dialog-new-calendar-title = New Calendar
 dialog-edit-calendar-title = Edit Calendar
 dialog-calendar-name = Name
 dialog-calendar-name-placeholder = Calendar name
+dialog-calendar-group = Group
+dialog-calendar-group-placeholder = None (e.g. Work)
 dialog-calendar-color = Color
+dialog-calendar-event-duration = New event length
+calendar-duration-default = Default
+calendar-duration-minutes = { $minutes } min
+calendar-duration-hours = { $hours ->
+    [one] 1 hour
+   *[other] { $hours } hours
+}
+dialog-calendar-sync-interval = Sync interval
+calendar-sync-manual = Manual only
+calendar-sync-never = Never
+calendar-sync-now = At the next check
+calendar-sync-schedule = Last synced: { $last } · Next sync: { $next }
+dialog-calendar-holiday-overlay = Holiday overlay
+dialog-calendar-holiday-overlay-hint = All-day events shade their days as holidays instead of showing as events. Use this for an imported holiday calendar.
+dialog-calendar-auto-export = Auto-export to path
+dialog-calendar-auto-export-placeholder = /home/user/Public/calendar.ics
+dialog-calendar-auto-export-hint = The calendar is written to this .ics file whenever its events change. Leave empty to turn it off.
 
 # Dialog - Delete Calendar
 dialog-delete-calendar-title = Delete Calendar
@@ -220,3 +413,78 @@ subscribe-dialog-use-existing = Use existing calendar
 subscribe-dialog-create-new = Create new calendar
 subscribe-dialog-new-calendar-placeholder = New calendar name
 subscribe-dialog-subscribe = Subscribe
+
+# Sync conflict dialog
+dialog-sync-conflict-title = Sync Conflict
+sync-conflict-message = This event was changed both on this device and on the server.
+sync-conflict-local = On this device
+sync-conflict-remote = On the server
+button-keep-local = Keep Mine
+button-keep-remote = Keep Server
+button-keep-both = Keep Both
+button-keep-editing = Keep Editing
+button-discard = Discard
+
+# Toast notifications
+event-moved = Event moved
+button-undo = Undo
+toast-calendar-exported = Calendar exported
+toast-view-image-saved = View saved as image
+toast-export-failed = Export failed
+toast-auto-export-failed = Auto-export failed
+toast-range-exported = {$count ->
+    [one] 1 event exported
+    *[other] {$count} events exported
+}
+toast-invalid-link = This link could not be opened
+toast-event-not-found = This event could not be found
+toast-event-title-required = Add a title to save the event
+toast-occurrence-skipped = Occurrence skipped
+toast-event-link-copied = Event link copied
+toast-day-note-failed = The note could not be saved
+toast-palette-applied = Calendar colors updated
+toast-event-now-all-day = Event is now all-day
+toast-event-now-timed = Event is now timed
+toast-event-created = Event created
+toast-quick-event-duplicate = An identical event already exists
+toast-events-created = {$count ->
+    [one] 1 event created
+    *[other] {$count} events created
+}
+toast-no-picked-dates = Ctrl-click days in the month view to pick dates first
+toast-week-copied = {$count ->
+    [one] 1 event copied to next week
+    *[other] {$count} events copied to next week
+}
+toast-sync-complete = Calendars synced
+toast-sync-failed = { $count ->
+    [one] 1 calendar failed to sync
+   *[other] { $count } calendars failed to sync
+}
+toast-calendar-read-only = This calendar is read-only. Subscribed calendars can't be edited yet
+toast-calendar-unreadable = The downloaded calendar could not be read
+toast-import-invalid = This file is not a valid calendar
+toast-import-empty = No events found in this file
+toast-clipboard-not-calendar = The clipboard doesn't contain calendar data
+import-source-clipboard = Clipboard: { $name }
+toast-import-failed = The event could not be imported
+toast-no-calendars = No calendar available
+toast-import-cancelled = { $count ->
+    [one] Import cancelled, 1 event undone
+   *[other] Import cancelled, { $count } events undone
+}
+toast-import-reverted = { $count ->
+    [one] Import reverted, 1 event undone
+   *[other] Import reverted, { $count } events undone
+}
+
+# Make recurring dialog
+dialog-convert-recurring-title = Make Recurring
+convert-recurring-message = Repeat this event starting from its current date:
+button-make-recurring = Make Recurring
+
+# Make timed dialog
+dialog-make-timed-title = Make Timed
+make-timed-message = Start time on the event's first day:
+make-timed-invalid = Enter a time as HH:MM
+button-make-timed = Make Timed
@@ -1,13 +1,14 @@
 use crate::cache::CalendarCache;
-use crate::calendars::CalendarManager;
+use crate::calendars::{CalendarManager, SyncConflict};
 use crate::components;
 use crate::dialogs::ActiveDialog;
+use crate::event_cache::{EventCache, EventsByDate};
 use crate::fl;
 use crate::locale::LocalePreferences;
 use crate::menu_action::MenuAction;
 use crate::message::Message;
-use crate::models::{CalendarState, WeekState, DayState, YearState};
-use crate::selection::{SelectionState, EventDragState};
+use crate::models::{AutoExportQueue, CalendarState, WeekState, DayState, YearState, MoveUndo, ToastQueue};
+use crate::selection::{edge_scroll_delta, SelectionState, EventDragState};
 use crate::settings::AppSettings;
 use crate::views::{self, CalendarView};
 use chrono::{Datelike, NaiveDate};
@@ -18,8 +19,8 @@ use cosmic::widget::calendar::CalendarModel;
 use cosmic::widget::{about, menu, text_editor};
 use cosmic::widget::menu::Action as _; // Import trait for .message() method
 use cosmic::{Application, Element};
-use log::info;
-use std::collections::HashMap;
+use log::{debug, info, warn};
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
 // Use different APP_ID for development builds to avoid conflicts with installed Flatpak
@@ -53,6 +54,7 @@ impl cosmic::app::CosmicFlags for AppFlags {
 pub enum EventDialogField {
     Title,
     Location,
+    Invitee,
     Url,
 }
 
@@ -86,16 +88,16 @@ pub struct EventDialogState {
     pub travel_time: crate::caldav::TravelTime,
     /// Repeat/recurrence settings
     pub repeat: crate::caldav::RepeatFrequency,
+    /// iCalendar priority (1 highest to 9 lowest, None when undefined)
+    pub priority: Option<u8>,
     /// Selected calendar ID for the event
     pub calendar_id: String,
     /// Invitees (email addresses)
     pub invitees: Vec<String>,
     /// New invitee being typed (input buffer)
     pub invitee_input: String,
-    /// Alert/reminder settings
-    pub alert: crate::caldav::AlertTime,
-    /// Second alert (optional)
-    pub alert_second: Option<crate::caldav::AlertTime>,
+    /// Reminders, in the order they were added
+    pub alerts: Vec<crate::caldav::AlertTime>,
     /// File attachments (paths or URLs)
     pub attachments: Vec<String>,
     /// URL associated with the event
@@ -116,6 +118,12 @@ pub struct EventDialogState {
     pub start_time_picker_open: bool,
     /// Whether the end time picker is open
     pub end_time_picker_open: bool,
+    /// Dates to create a copy of a new event on (empty for a single event)
+    pub bulk_dates: Vec<chrono::NaiveDate>,
+    /// Whether any field was changed since the dialog opened
+    pub dirty: bool,
+    /// Whether closing is waiting for the user to discard or keep the unsaved changes
+    pub confirm_discard: bool,
 }
 
 /// Main application state
@@ -125,6 +133,9 @@ pub struct CosmicCalendar {
     /// The anchor date shared by all views - when you select a date or switch views,
     /// all views sync to show the period containing this date
     pub selected_date: NaiveDate,
+    /// Date whose period is shown while peeking ahead or back (None = showing selected_date)
+    /// Peeking moves the views without moving selected_date
+    pub peek_date: Option<NaiveDate>,
     pub calendar_manager: CalendarManager,
     pub show_sidebar: bool,
     /// Track previous condensed state to detect changes and sync sidebar
@@ -146,23 +157,55 @@ pub struct CosmicCalendar {
     pub cached_month_events: std::collections::HashMap<chrono::NaiveDate, Vec<crate::components::DisplayEvent>>,
     /// Cached events for current week view, grouped by date
     pub cached_week_events: std::collections::HashMap<chrono::NaiveDate, Vec<crate::components::DisplayEvent>>,
+    /// Whether the cached events came from the startup cache and still need reloading
+    pub events_from_cache: bool,
+    /// Whether the displayed events changed since the startup cache was last written
+    pub event_cache_dirty: bool,
     /// Color of the selected calendar (cached for quick event input)
     pub selected_calendar_color: String,
+    /// Whether the toolbar's selected calendar picker is open
+    pub calendar_picker_open: bool,
+    /// Year shown in the toolbar's month/year picker, None when it's closed
+    pub month_year_picker_year: Option<i32>,
+    /// Show only the selected calendar's events (not persisted)
+    pub focus_mode: bool,
+    /// Show the floating calendar color legend (not persisted)
+    pub show_legend: bool,
     /// Centralized dialog state - only one dialog can be open at a time
     pub active_dialog: ActiveDialog,
     /// Drag selection state for multi-day event creation
     pub selection_state: SelectionState,
     /// Event drag state for moving events to new dates
     pub event_drag_state: EventDragState,
+    /// Time slot last clicked without dragging in the week/day view (Enter creates an event there)
+    pub clicked_time_slot: Option<(NaiveDate, chrono::NaiveTime)>,
+    /// Keyboard modifiers currently held (Ctrl turns selections into date picks)
+    pub keyboard_modifiers: keyboard::Modifiers,
     /// Currently selected event UID (for viewing/editing/deleting) - uses unique_id format (calendar_id:uid)
     pub selected_event_uid: Option<String>,
     /// Cached unique_id (calendar_id:uid) of the event being dragged (computed from event_drag_state)
     pub dragging_event_unique_id: Option<String>,
     /// Current scroll position for week view - continuously tracked via on_scroll callback
     pub week_view_scroll_opt: Option<cosmic::iced::widget::scrollable::AbsoluteOffset>,
+    /// Visible bounds of the week/day timeline in window coordinates (for drag auto-scroll)
+    pub week_view_bounds: Option<cosmic::iced::Rectangle>,
     /// Saved scroll position to restore after quick event closes
     /// Captured when quick event starts, used to restore when it ends (prevents focus-induced jump)
     pub week_view_scroll_restore: Option<cosmic::iced::widget::scrollable::AbsoluteOffset>,
+    /// Sync conflicts waiting to be shown after the current one is resolved
+    pub pending_sync_conflicts: Vec<SyncConflict>,
+    /// Whether server events are being fetched for a sync (no new sync starts meanwhile)
+    pub sync_in_progress: bool,
+    /// Last drag move that can still be undone (only when "Confirm event moves" is on)
+    pub pending_move_undo: Option<MoveUndo>,
+    /// Transient notifications shown at the bottom of the calendar view
+    pub toasts: ToastQueue,
+    /// Free-text notes attached to dates (not events), keyed by date
+    pub day_notes: BTreeMap<NaiveDate, String>,
+    /// Holiday names from holiday overlay calendars, keyed by date (month grid and week)
+    pub holidays: HashMap<NaiveDate, String>,
+    /// Calendars waiting to be rewritten to their auto-export .ics files
+    pub auto_exports: AutoExportQueue,
 
     // Legacy field - kept because text_editor::Content doesn't implement Clone
     /// Event dialog state (for Create/Edit) - None when dialog is closed
@@ -185,6 +228,9 @@ impl CosmicCalendar {
         // Initialize calendar manager with default calendars
         let calendar_manager = CalendarManager::with_defaults();
 
+        // Day notes are few, so they are all kept in memory
+        let day_notes = calendar_manager.day_notes();
+
         // Select the first calendar by default for new events
         let selected_calendar_id = calendar_manager
             .sources()
@@ -206,8 +252,13 @@ impl CosmicCalendar {
             .links([(fl!("about-repository"), "https://github.com/xarbit/sol"),
                 (fl!("about-support"), "https://github.com/xarbit/sol/issues")]);
 
-        // Detect system locale preferences
-        let locale = LocalePreferences::detect_from_system();
+        // Detect system locale preferences, applying weekend and format overrides from settings
+        let locale = LocalePreferences::detect_from_system()
+            .with_weekend_days(settings.weekend_days.days())
+            .with_custom_formats(
+                settings.custom_date_format.as_deref(),
+                settings.custom_time_format.as_deref(),
+            );
 
         // Initialize keyboard shortcuts from centralized module
         let key_binds = crate::keyboard::init_key_binds();
@@ -222,25 +273,53 @@ impl CosmicCalendar {
             .map(|c| c.info().color.clone())
             .unwrap_or_else(|| "#3B82F6".to_string());
 
-        // Cache events for current month
-        let cached_month_events = calendar_manager.get_display_events_for_month(year, month);
+        // Create week state
+        let week_state = if settings.rolling_week {
+            WeekState::rolling(today, locale.first_day_of_week, &locale)
+        } else {
+            WeekState::current_with_first_day(locale.first_day_of_week, &locale)
+        };
+
+        // Cache events for the current month and week, from the startup cache when it's
+        // still valid (the real events are loaded right after the first frame)
+        let (cached_month_events, cached_week_events, events_from_cache) =
+            match EventCache::load((year, month), &week_state.days) {
+                Some((month_events, week_events)) => (month_events, week_events, true),
+                None => (
+                    calendar_manager.get_display_events_for_month(year, month),
+                    calendar_manager.get_display_events_for_week(&week_state.days),
+                    false,
+                ),
+            };
+
+        let (holiday_start, holiday_end) = Self::holiday_range(year, month, &week_state.days);
+        let holidays = calendar_manager.holidays_between(holiday_start, holiday_end);
+
+        // Auto-export files were written by earlier runs; only changes from now on rewrite them
+        let mut auto_exports = AutoExportQueue::default();
+        for source in calendar_manager.sources() {
+            if let Some(path) = source.info().auto_export_path.as_deref() {
+                auto_exports.track(&source.info().id, path);
+            }
+        }
 
-        // Create week state and cache week events
-        let week_state = WeekState::current_with_first_day(locale.first_day_of_week, &locale);
-        let cached_week_events = calendar_manager.get_display_events_for_week(&week_state.days);
+        // Evening planning: past the cutoff the day view opens on tomorrow
+        let current_view = settings.startup_view.resolve(settings.last_view);
+        let startup_date = settings.startup_date(current_view, chrono::Local::now().naive_local());
 
         #[allow(deprecated)]
         CosmicCalendar {
             core,
-            current_view: CalendarView::Month,
-            selected_date: today,
+            current_view,
+            selected_date: startup_date,
+            peek_date: None,
             calendar_manager,
             show_sidebar: true,
             last_condensed: false, // Will be synced on first render
             show_search: false,
             cache,
             week_state,
-            day_state: DayState::current(&locale),
+            day_state: DayState::new(startup_date, &locale),
             year_state: YearState::current(),
             mini_calendar_state,
             locale,
@@ -250,22 +329,50 @@ impl CosmicCalendar {
             selected_calendar_id,
             cached_month_events,
             cached_week_events,
+            events_from_cache,
+            event_cache_dirty: !events_from_cache,
             selected_calendar_color,
+            calendar_picker_open: false,
+            month_year_picker_year: None,
+            focus_mode: false,
+            show_legend: false,
             active_dialog: ActiveDialog::None,
             selection_state: SelectionState::new(),
             event_drag_state: EventDragState::new(),
+            clicked_time_slot: None,
+            keyboard_modifiers: keyboard::Modifiers::empty(),
             selected_event_uid: None,
             dragging_event_unique_id: None,
             week_view_scroll_opt: None,
+            week_view_bounds: None,
             week_view_scroll_restore: None,
+            pending_sync_conflicts: Vec::new(),
+            sync_in_progress: false,
+            pending_move_undo: None,
+            toasts: ToastQueue::default(),
+            day_notes,
+            holidays,
+            auto_exports,
             // Legacy field - kept because text_editor::Content doesn't implement Clone
             event_dialog: None,
         }
     }
 
     /// Sync all views to show the period containing the selected_date
+    /// This also ends peeking, so it is the single action to return to the selected day
     pub fn sync_views_to_selected_date(&mut self) {
-        let date = self.selected_date;
+        self.peek_date = None;
+        self.sync_views_to_date(self.selected_date);
+    }
+
+    /// Show the period containing the given date without changing selected_date
+    pub fn peek_at(&mut self, date: NaiveDate) {
+        self.peek_date = Some(date);
+        self.sync_views_to_date(date);
+    }
+
+    /// Sync all views to show the period containing the given date
+    fn sync_views_to_date(&mut self, date: NaiveDate) {
         let year = date.year();
         let month = date.month();
 
@@ -273,8 +380,14 @@ impl CosmicCalendar {
         self.cache.set_current(year, month);
         self.cache.precache_surrounding(1, 2);
 
-        // Update week view
-        self.week_state = WeekState::new(date, self.locale.first_day_of_week, &self.locale);
+        // Update week view - a rolling week keeps its anchor while the date stays inside it
+        self.week_state = if !self.settings.rolling_week {
+            WeekState::new(date, self.locale.first_day_of_week, &self.locale)
+        } else if self.week_state.rolling && self.week_state.days.contains(&date) {
+            WeekState::rolling(self.week_state.days[0], self.locale.first_day_of_week, &self.locale)
+        } else {
+            WeekState::rolling(date, self.locale.first_day_of_week, &self.locale)
+        };
 
         // Update day view
         self.day_state = DayState::new(date, &self.locale);
@@ -282,7 +395,7 @@ impl CosmicCalendar {
         // Update year view
         self.year_state = YearState::new(year);
 
-        // Sync mini calendar to show the month containing selected_date
+        // Sync mini calendar to show the month containing the date
         self.mini_calendar_state = CalendarState::new(year, month);
 
         // Refresh cached events for the new month
@@ -299,6 +412,167 @@ impl CosmicCalendar {
         // Refresh week events
         self.cached_week_events = self.calendar_manager
             .get_display_events_for_week(&self.week_state.days);
+
+        // Sort once here so every view lists a day's events in the same order
+        let order = self.settings.event_sort_order;
+        self.calendar_manager.sort_display_events(&mut self.cached_month_events, order);
+        self.calendar_manager.sort_display_events(&mut self.cached_week_events, order);
+        self.events_from_cache = false;
+        self.event_cache_dirty = true;
+
+        let (holiday_start, holiday_end) = Self::holiday_range(cache_state.year, cache_state.month, &self.week_state.days);
+        self.holidays = self.calendar_manager.holidays_between(holiday_start, holiday_end);
+
+        self.apply_focus_filter();
+        self.queue_auto_exports();
+    }
+
+    /// Queue an export for auto-exported calendars whose events were written (or
+    /// whose export path changed) since the last refresh; the files are written
+    /// once edits settle
+    fn queue_auto_exports(&mut self) {
+        let now = std::time::Instant::now();
+        let changed = self.calendar_manager.take_changed_calendars();
+        let mut auto_exported = Vec::new();
+        for source in self.calendar_manager.sources() {
+            let info = source.info();
+            let Some(path) = info.auto_export_path.as_deref() else {
+                continue;
+            };
+            auto_exported.push(info.id.clone());
+            if self.auto_exports.observe(&info.id, path, changed.contains(&info.id), now) {
+                debug!("Queued auto-export of calendar '{}'", info.id);
+            }
+        }
+        self.auto_exports.retain(&auto_exported);
+    }
+
+    /// Dates holidays are looked up for: the month grid, including adjacent
+    /// month days, and the displayed week
+    fn holiday_range(year: i32, month: u32, week_days: &[NaiveDate]) -> (NaiveDate, NaiveDate) {
+        let first_of_month = NaiveDate::from_ymd_opt(year, month, 1).unwrap_or_default();
+        let grid_start = first_of_month - chrono::Duration::days(6);
+        let grid_end = first_of_month + chrono::Duration::days(44);
+        (
+            week_days.first().map_or(grid_start, |&day| day.min(grid_start)),
+            week_days.last().map_or(grid_end, |&day| day.max(grid_end)),
+        )
+    }
+
+    /// Load the events of the period shown from the startup cache in the background,
+    /// with a connection of its own so the first frames aren't held up
+    fn reload_events_in_background(&self) -> Task<Message> {
+        let cache_state = self.cache.current_state();
+        let (year, month) = (cache_state.year, cache_state.month);
+        let week_days = self.week_state.days.clone();
+        let order = self.settings.event_sort_order;
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    let manager = CalendarManager::with_defaults();
+                    let mut month_events = manager.get_display_events_for_month(year, month);
+                    let mut week_events = manager.get_display_events_for_week(&week_days);
+                    manager.sort_display_events(&mut month_events, order);
+                    manager.sort_display_events(&mut week_events, order);
+                    (month_events, week_events)
+                })
+                .await
+            },
+            |result| match result {
+                Ok((month_events, week_events)) => cosmic::Action::App(Message::CachedEventsReloaded(month_events, week_events)),
+                Err(e) => {
+                    warn!("CosmicCalendar: Background event reload failed: {}", e);
+                    cosmic::Action::App(Message::ReloadCachedEvents)
+                }
+            },
+        )
+    }
+
+    /// Replace the events shown from the startup cache with the reloaded ones,
+    /// unless something refreshed them in the meantime
+    pub fn apply_reloaded_events(
+        &mut self,
+        month_events: EventsByDate,
+        week_events: EventsByDate,
+    ) -> Task<Message> {
+        if !self.events_from_cache {
+            debug!("CosmicCalendar: Events were refreshed during the reload, dropping it");
+            return Task::none();
+        }
+        self.cached_month_events = month_events;
+        self.cached_week_events = week_events;
+        self.events_from_cache = false;
+        self.event_cache_dirty = true;
+        self.apply_focus_filter();
+        self.save_event_cache()
+    }
+
+    /// Write the cached events for the next startup in the background, when they
+    /// changed and the current period is displayed. Called on the timer tick so
+    /// quick successive refreshes only write once; a cache left stale on exit
+    /// no longer matches the database and is ignored at startup.
+    pub fn save_event_cache(&mut self) -> Task<Message> {
+        // Focus mode drops other calendars' events, so the cache waits until it's off
+        if !self.event_cache_dirty || self.focus_mode {
+            return Task::none();
+        }
+        self.event_cache_dirty = false;
+
+        let cache_state = self.cache.current_state();
+        let month = (cache_state.year, cache_state.month);
+        let today = chrono::Local::now().date_naive();
+        if month != (today.year(), today.month()) || !self.week_state.days.contains(&today) {
+            return Task::none();
+        }
+
+        let cache = EventCache::new(month, &self.week_state.days, &self.cached_month_events, &self.cached_week_events);
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || cache.write())
+                    .await
+                    .unwrap_or_else(|e| Err(std::io::Error::other(e)))
+            },
+            |result| {
+                if let Err(e) = result {
+                    warn!("CosmicCalendar: Failed to save event cache: {}", e);
+                }
+                cosmic::Action::App(Message::None)
+            },
+        )
+    }
+
+    /// In focus mode, drop cached events that aren't in the selected calendar
+    fn apply_focus_filter(&mut self) {
+        let Some(calendar_id) = self.selected_calendar_id.as_deref().filter(|_| self.focus_mode) else {
+            return;
+        };
+        for events in self.cached_month_events.values_mut().chain(self.cached_week_events.values_mut()) {
+            events.retain(|event| event.calendar_id == calendar_id);
+        }
+    }
+
+    /// Build the toolbar picker for the calendar new events are added to
+    /// Read-only calendars are left out since events can't be created in them
+    fn calendar_picker(&self) -> components::CalendarPicker {
+        let calendars = self
+            .calendar_manager
+            .sources()
+            .iter()
+            .map(|source| source.info())
+            .filter(|info| !info.read_only)
+            .map(|info| components::ToolbarCalendar {
+                id: info.id.clone(),
+                name: info.name.clone(),
+                color: info.color.clone(),
+            })
+            .collect();
+
+        components::CalendarPicker {
+            calendars,
+            selected_id: self.selected_calendar_id.clone(),
+            selected_color: self.selected_calendar_color.clone(),
+            open: self.calendar_picker_open,
+        }
     }
 
     /// Update the selected calendar color cache
@@ -320,6 +594,65 @@ impl CosmicCalendar {
     pub fn navigate_to_today(&mut self) {
         let today = chrono::Local::now().date_naive();
         self.set_selected_date(today);
+
+        // A rolling week always starts today after jumping to it
+        if self.settings.rolling_week && self.week_state.days[0] != today {
+            self.week_state = WeekState::rolling(today, self.locale.first_day_of_week, &self.locale);
+            self.refresh_cached_events();
+        }
+    }
+
+    /// Whether a large import is running; it can still be cancelled and undone,
+    /// so background syncs and exports wait until it has finished
+    pub fn is_importing(&self) -> bool {
+        matches!(self.active_dialog, ActiveDialog::ImportProgress { .. })
+    }
+
+    /// Check whether the displayed period already contains the current date
+    /// Used to hide the floating "now" button when it would be a no-op
+    pub fn is_showing_now(&self) -> bool {
+        let today = chrono::Local::now().date_naive();
+        match self.current_view {
+            CalendarView::Year => self.year_state.year == today.year(),
+            CalendarView::Month => {
+                let state = self.cache.current_state();
+                state.year == today.year() && state.month == today.month()
+            }
+            CalendarView::Week => self.week_state.days.contains(&today),
+            CalendarView::Day => self.day_state.date == today,
+        }
+    }
+
+    /// Whether the displayed day, week or month has no events (the year view never counts as empty)
+    pub fn is_displayed_period_empty(&self) -> bool {
+        let has_events = |date: &NaiveDate| {
+            self.cached_week_events.get(date).is_some_and(|events| !events.is_empty())
+        };
+        match self.current_view {
+            CalendarView::Year => false,
+            CalendarView::Month => {
+                // The month map also holds the adjacent-month days shown in the grid
+                let state = self.cache.current_state();
+                !self.cached_month_events.iter().any(|(date, events)| {
+                    date.year() == state.year && date.month() == state.month && !events.is_empty()
+                })
+            }
+            CalendarView::Week => !self.week_state.days.iter().any(has_events),
+            CalendarView::Day => !has_events(&self.day_state.date),
+        }
+    }
+
+    /// How far to auto-scroll the timeline for an event dragged near its edge (0 when not)
+    pub fn drag_edge_scroll_delta(&self) -> f32 {
+        if !self.event_drag_state.is_active || !matches!(self.current_view, CalendarView::Day | CalendarView::Week) {
+            return 0.0;
+        }
+        match (self.event_drag_state.cursor_position(), self.week_view_bounds) {
+            (Some((x, y)), Some(bounds)) if x >= bounds.x && x <= bounds.x + bounds.width => {
+                edge_scroll_delta(y, bounds.y, bounds.height)
+            }
+            _ => 0.0,
+        }
     }
 
     /// Navigate to the previous period based on current view
@@ -361,6 +694,8 @@ impl CosmicCalendar {
             selected_day,
             &self.active_dialog,
             self.selected_calendar_id.as_ref(),
+            &self.settings.collapsed_calendar_groups,
+            self.locale.numerals,
         )
     }
 
@@ -380,6 +715,14 @@ impl CosmicCalendar {
             event_drag_active: self.event_drag_state.is_active,
             dragging_event_uid: self.dragging_event_unique_id.as_deref(),
             drag_target_date: self.event_drag_state.target_date(),
+            compact_thresholds: self.settings.compact_sensitivity.thresholds(),
+            show_event_count: self.settings.show_event_count_badges,
+            dim_past_events: self.settings.dim_past_events,
+            today_highlight: self.settings.today_highlight,
+            selected_highlight: self.settings.selected_highlight,
+            highlight_free_days: self.settings.highlight_free_days,
+            day_notes: &self.day_notes,
+            holidays: &self.holidays,
         };
 
         let week_events = views::WeekViewEvents {
@@ -388,9 +731,17 @@ impl CosmicCalendar {
             selection: &self.selection_state,
             active_dialog: &self.active_dialog,
             calendar_color: &self.selected_calendar_color,
+            all_day_collapsed: self.settings.collapse_all_day_section,
+            dim_past_events: self.settings.dim_past_events,
+            overlap_layout: self.settings.event_overlap_layout,
+            time_snap: self.settings.time_snap,
+            half_hour_lines: self.settings.half_hour_lines,
+            day_notes: &self.day_notes,
+            holidays: &self.holidays,
+            show_week_number: self.settings.show_week_numbers,
         };
 
-        views::render_main_content(
+        let content = views::render_main_content(
             &self.cache,
             &self.week_state,
             &self.day_state,
@@ -399,9 +750,54 @@ impl CosmicCalendar {
             self.current_view,
             Some(self.selected_date),
             self.settings.show_week_numbers,
+            self.settings.highlight_current_week,
             Some(month_events),
             Some(week_events),
-        )
+            self.calendar_picker(),
+            self.month_year_picker_year,
+        );
+
+        // Empty-state hint, hidden while a dialog or quick event input is open
+        let content = if self.is_displayed_period_empty() && !self.active_dialog.is_open() {
+            let hint = match self.current_view {
+                CalendarView::Month => fl!("empty-state-month"),
+                CalendarView::Week => fl!("empty-state-week"),
+                CalendarView::Day | CalendarView::Year => fl!("empty-state-day"),
+            };
+            cosmic::iced::widget::stack![content, components::render_empty_state(hint)].into()
+        } else {
+            content
+        };
+
+        // Floating "now" button only when the displayed period doesn't include today
+        let content = if self.is_showing_now() {
+            content
+        } else {
+            cosmic::iced::widget::stack![content, components::render_now_button()].into()
+        };
+
+        // Legend of the visible calendars' colors
+        let content = if self.show_legend {
+            let entries = self.calendar_manager
+                .sources()
+                .iter()
+                .filter(|c| c.is_enabled())
+                .map(|c| components::LegendEntry {
+                    name: c.info().name.clone(),
+                    color: c.info().color.clone(),
+                })
+                .collect();
+            cosmic::iced::widget::stack![content, components::render_calendar_legend(entries)].into()
+        } else {
+            content
+        };
+
+        // Toast notifications (including the undo offer after a drag move)
+        if self.toasts.is_empty() {
+            content
+        } else {
+            cosmic::iced::widget::stack![content, components::render_toasts(&self.toasts)].into()
+        }
     }
 }
 
@@ -426,9 +822,16 @@ impl Application for CosmicCalendar {
     }
 
     fn init(core: Core, flags: Self::Flags) -> (Self, cosmic::app::Task<Self::Message>) {
-        let app = Self::initialize_app(core);
+        let mut app = Self::initialize_app(core);
         info!("CosmicCalendar: Application initialized with view {:?}", app.current_view);
 
+        // Events shown from the startup cache are replaced with fresh ones loaded in the background
+        let reload = if app.events_from_cache {
+            app.reload_events_in_background()
+        } else {
+            app.save_event_cache()
+        };
+
         // Handle file arguments if provided
         if !flags.files_to_open.is_empty() {
             info!("CosmicCalendar: {} file(s) to open on startup", flags.files_to_open.len());
@@ -436,7 +839,8 @@ impl Application for CosmicCalendar {
             // (Only one dialog can be open at a time)
             if let Some(file_path) = flags.files_to_open.first() {
                 info!("CosmicCalendar: Triggering import for {:?}", file_path);
-                return (app, cosmic::app::Task::done(cosmic::Action::App(Message::ImportFile(file_path.clone()))));
+                let import = cosmic::app::Task::done(cosmic::Action::App(Message::ImportFile(file_path.clone())));
+                return (app, cosmic::app::Task::batch([reload, import]));
             }
         }
 
@@ -446,15 +850,31 @@ impl Application for CosmicCalendar {
             // Process the first URL
             if let Some(url) = flags.urls_to_open.first() {
                 info!("CosmicCalendar: Processing URL: {}", url);
-                return (app, cosmic::app::Task::done(cosmic::Action::App(Message::ProcessUrl(url.clone()))));
+                let process = cosmic::app::Task::done(cosmic::Action::App(Message::ProcessUrl(url.clone())));
+                return (app, cosmic::app::Task::batch([reload, process]));
             }
         }
 
-        (app, cosmic::app::Task::none())
+        // Timeline views scroll to their configured start position when opened
+        if matches!(app.current_view, CalendarView::Day | CalendarView::Week) {
+            let view = app.current_view;
+            let scroll = cosmic::app::Task::done(cosmic::Action::App(Message::ChangeView(view)));
+            return (app, cosmic::app::Task::batch([reload, scroll]));
+        }
+
+        (app, reload)
     }
 
     fn header_start(&self) -> Vec<Element<'_, Self::Message>> {
-        components::render_header_start(&self.core, &self.key_binds, self.show_sidebar, self.settings.show_week_numbers)
+        components::render_header_start(
+            &self.core,
+            &self.key_binds,
+            self.show_sidebar,
+            &self.settings,
+            self.focus_mode,
+            self.show_legend,
+            self.selected_event_uid.is_some(),
+        )
     }
 
     fn header_end(&self) -> Vec<Element<'_, Self::Message>> {
@@ -524,11 +944,19 @@ impl Application for CosmicCalendar {
                     }
                     None
                 }
+                // Track held modifiers so Ctrl-click can pick dates
+                cosmic::iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                    Some(Message::ModifiersChanged(modifiers))
+                }
                 // Handle window resize to sync sidebar with condensed state
                 // The actual condensed state is checked in update handler
                 cosmic::iced::Event::Window(cosmic::iced::window::Event::Resized { .. }) => {
                     Some(Message::WindowResized)
                 }
+                // Files dropped from the file manager (attached in the event dialog)
+                cosmic::iced::Event::Window(cosmic::iced::window::Event::FileDropped(path)) => {
+                    Some(Message::FileDropped(path))
+                }
                 // Track mouse position for drag preview
                 // Always emit cursor move events - the handler will check if drag is active
                 cosmic::iced::Event::Mouse(cosmic::iced::mouse::Event::CursorMoved { position }) => {
@@ -542,7 +970,92 @@ impl Application for CosmicCalendar {
         let timer_sub = cosmic::iced::time::every(std::time::Duration::from_secs(30))
             .map(|_| Message::TimeTick);
 
-        Subscription::batch([event_sub, timer_sub])
+        // Faster tick while toasts are shown so they dismiss on time
+        let toast_sub = if self.toasts.is_empty() {
+            Subscription::none()
+        } else {
+            cosmic::iced::time::every(std::time::Duration::from_secs(1))
+                .map(|_| Message::TimeTick)
+        };
+
+        // Check for calendars due a background sync once a minute, when any has an interval
+        let sync_sub = if self.calendar_manager.sources().iter().any(|c| c.info().sync_interval_minutes.is_some()) {
+            cosmic::iced::time::every(std::time::Duration::from_secs(60))
+                .map(|_| Message::SyncDue)
+        } else {
+            Subscription::none()
+        };
+
+        // Check once a second whether queued auto-exports are due
+        let auto_export_sub = if self.auto_exports.is_waiting() {
+            cosmic::iced::time::every(std::time::Duration::from_secs(1))
+                .map(|_| Message::AutoExportDue)
+        } else {
+            Subscription::none()
+        };
+
+        // Scroll the timeline while an event is dragged near its top or bottom edge
+        let drag_scroll_sub = if self.drag_edge_scroll_delta() != 0.0 {
+            cosmic::iced::time::every(std::time::Duration::from_millis(30))
+                .map(|_| Message::DragAutoScrollTick)
+        } else {
+            Subscription::none()
+        };
+
+        // Number keys switch views directly, Enter starts an event at the clicked time slot
+        // and Home/End jump the timeline; only with no dialog open and no text input focused
+        #[allow(deprecated)]
+        let view_keys_sub = if self.active_dialog.is_open() || self.event_dialog.is_some() {
+            Subscription::none()
+        } else {
+            cosmic::iced::event::listen_with(|event, status, _window_id| match event {
+                cosmic::iced::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                    if modifiers.is_empty() && status == cosmic::iced::event::Status::Ignored =>
+                {
+                    if let Some(view) = crate::keyboard::view_for_number_key(&key) {
+                        return Some(Message::ChangeView(view));
+                    }
+                    if let Some(action) = crate::keyboard::timeline_jump_for_key(&key) {
+                        return Some(action.message());
+                    }
+                    (key == keyboard::Key::Named(keyboard::key::Named::Enter))
+                        .then_some(Message::QuickEventAtClickedTime)
+                }
+                _ => None,
+            })
+        };
+
+        // Tab / Shift+Tab move through the event dialog's controls, only while it is open
+        #[allow(deprecated)]
+        let event_dialog_tab_sub = if self.event_dialog.is_some() {
+            cosmic::iced::event::listen_with(|event, _status, _window_id| match event {
+                cosmic::iced::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                    if key == keyboard::Key::Named(keyboard::key::Named::Tab)
+                        && !modifiers.control()
+                        && !modifiers.alt() =>
+                {
+                    Some(if modifiers.shift() {
+                        Message::EventDialogFocusPrevious
+                    } else {
+                        Message::EventDialogFocusNext
+                    })
+                }
+                _ => None,
+            })
+        } else {
+            Subscription::none()
+        };
+
+        Subscription::batch([
+            event_sub,
+            view_keys_sub,
+            timer_sub,
+            toast_sub,
+            sync_sub,
+            auto_export_sub,
+            drag_scroll_sub,
+            event_dialog_tab_sub,
+        ])
     }
 
     #[cfg(feature = "single-instance")]
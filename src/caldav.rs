@@ -1,6 +1,7 @@
 use icalendar::{Calendar, Component, Event, EventLike};
 use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::error::Error;
 
 /// Repeat frequency for recurring events
@@ -16,6 +17,63 @@ pub enum RepeatFrequency {
     Custom(String), // For custom RRULE strings
 }
 
+impl RepeatFrequency {
+    /// Parse an iCalendar RRULE value into a frequency and the UNTIL date
+    /// Rules the app can't represent (BYDAY, COUNT, other intervals...) are kept as Custom
+    pub fn from_rrule(rule: &str) -> (Self, Option<chrono::NaiveDate>) {
+        let mut freq = None;
+        let mut interval = 1;
+        let mut until = None;
+        let mut unsupported = false;
+
+        for part in rule.trim().split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part.split_once('=').unwrap_or((part, ""));
+            match key.trim().to_ascii_uppercase().as_str() {
+                "FREQ" => freq = Some(value.trim().to_ascii_uppercase()),
+                "INTERVAL" => match value.trim().parse::<u32>() {
+                    Ok(n) => interval = n,
+                    Err(_) => unsupported = true,
+                },
+                // UNTIL is a date or a date-time; only the date part matters here
+                "UNTIL" => until = value.get(..8).and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y%m%d").ok()),
+                "WKST" => {}
+                _ => unsupported = true,
+            }
+        }
+
+        let frequency = match (freq.as_deref(), interval, unsupported) {
+            (Some("DAILY"), 1, false) => RepeatFrequency::Daily,
+            (Some("WEEKLY"), 1, false) => RepeatFrequency::Weekly,
+            (Some("WEEKLY"), 2, false) => RepeatFrequency::Biweekly,
+            (Some("MONTHLY"), 1, false) => RepeatFrequency::Monthly,
+            (Some("YEARLY"), 1, false) => RepeatFrequency::Yearly,
+            _ => RepeatFrequency::Custom(rule.trim().to_string()),
+        };
+        (frequency, until)
+    }
+
+    /// iCalendar RRULE value for this frequency, ending on `until` if given
+    /// (None for events that don't repeat). UNTIL is a date for all-day
+    /// events and the end of that day in UTC otherwise.
+    pub fn to_rrule(&self, until: Option<chrono::NaiveDate>, all_day: bool) -> Option<String> {
+        let rule = match self {
+            RepeatFrequency::Never => return None,
+            RepeatFrequency::Daily => "FREQ=DAILY".to_string(),
+            RepeatFrequency::Weekly => "FREQ=WEEKLY".to_string(),
+            RepeatFrequency::Biweekly => "FREQ=WEEKLY;INTERVAL=2".to_string(),
+            RepeatFrequency::Monthly => "FREQ=MONTHLY".to_string(),
+            RepeatFrequency::Yearly => "FREQ=YEARLY".to_string(),
+            // Custom rules are written back as imported (including any UNTIL)
+            RepeatFrequency::Custom(rule) => return Some(rule.clone()),
+        };
+        Some(match until {
+            Some(date) if all_day => format!("{};UNTIL={}", rule, date.format("%Y%m%d")),
+            Some(date) => format!("{};UNTIL={}T235959Z", rule, date.format("%Y%m%d")),
+            None => rule,
+        })
+    }
+}
+
 /// Alert timing before an event
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AlertTime {
@@ -31,6 +89,7 @@ pub enum AlertTime {
     TwoDays,
     OneWeek,
     Custom(i32), // Custom minutes before
+    TimeToLeave, // Travel time before the start ("leave by")
 }
 
 impl Default for AlertTime {
@@ -39,6 +98,91 @@ impl Default for AlertTime {
     }
 }
 
+impl AlertTime {
+    /// Minutes before the event start the alert fires (None when there is no alert)
+    /// A time-to-leave alert fires the event's travel time early.
+    pub fn minutes_before(&self, travel_time: &TravelTime) -> Option<i64> {
+        match self {
+            AlertTime::None => None,
+            AlertTime::AtTime => Some(0),
+            AlertTime::FiveMinutes => Some(5),
+            AlertTime::TenMinutes => Some(10),
+            AlertTime::FifteenMinutes => Some(15),
+            AlertTime::ThirtyMinutes => Some(30),
+            AlertTime::OneHour => Some(60),
+            AlertTime::TwoHours => Some(120),
+            AlertTime::OneDay => Some(24 * 60),
+            AlertTime::TwoDays => Some(2 * 24 * 60),
+            AlertTime::OneWeek => Some(7 * 24 * 60),
+            AlertTime::Custom(mins) => Some(i64::from(*mins)),
+            AlertTime::TimeToLeave => Some(travel_time.minutes()),
+        }
+    }
+
+    /// The preset matching a number of minutes before the start, Custom otherwise
+    pub fn from_minutes(minutes: i64) -> Self {
+        match minutes {
+            0 => AlertTime::AtTime,
+            5 => AlertTime::FiveMinutes,
+            10 => AlertTime::TenMinutes,
+            15 => AlertTime::FifteenMinutes,
+            30 => AlertTime::ThirtyMinutes,
+            60 => AlertTime::OneHour,
+            120 => AlertTime::TwoHours,
+            1440 => AlertTime::OneDay,
+            2880 => AlertTime::TwoDays,
+            10080 => AlertTime::OneWeek,
+            other => AlertTime::Custom(i32::try_from(other).unwrap_or(i32::MAX)),
+        }
+    }
+
+    /// Parse a TRIGGER duration relative to the start (e.g. "-PT15M", "-P1D", "PT0S")
+    /// Triggers after the start and absolute date-times are not reminders the app can show.
+    pub fn from_ical_trigger(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let (negative, rest) = match value.as_bytes().first()? {
+            b'-' => (true, &value[1..]),
+            b'+' => (false, &value[1..]),
+            _ => (false, value),
+        };
+        let rest = rest.strip_prefix('P')?;
+
+        let mut seconds: i64 = 0;
+        let mut number = String::new();
+        let mut in_time = false;
+        for c in rest.chars() {
+            match c {
+                'T' if !in_time && number.is_empty() => in_time = true,
+                '0'..='9' => number.push(c),
+                unit => {
+                    let n: i64 = number.parse().ok()?;
+                    number.clear();
+                    seconds += n * match (unit, in_time) {
+                        ('W', false) => 7 * 24 * 3600,
+                        ('D', false) => 24 * 3600,
+                        ('H', true) => 3600,
+                        ('M', true) => 60,
+                        ('S', true) => 1,
+                        _ => return None,
+                    };
+                }
+            }
+        }
+        if !number.is_empty() || (seconds > 0 && !negative) {
+            return None;
+        }
+        Some(Self::from_minutes(seconds / 60))
+    }
+
+    /// TRIGGER value for a VALARM (None when there is no alert)
+    pub fn ical_trigger(&self, travel_time: &TravelTime) -> Option<String> {
+        self.minutes_before(travel_time).map(|mins| match mins {
+            0 => "PT0S".to_string(),
+            mins => format!("-PT{}M", mins),
+        })
+    }
+}
+
 /// Travel time duration options
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum TravelTime {
@@ -55,6 +199,173 @@ pub enum TravelTime {
     Custom(i32), // Custom minutes
 }
 
+impl TravelTime {
+    /// Travel time in minutes (0 when none is set)
+    pub fn minutes(&self) -> i64 {
+        match self {
+            TravelTime::None => 0,
+            TravelTime::FiveMinutes => 5,
+            TravelTime::TenMinutes => 10,
+            TravelTime::FifteenMinutes => 15,
+            TravelTime::ThirtyMinutes => 30,
+            TravelTime::FortyFiveMinutes => 45,
+            TravelTime::OneHour => 60,
+            TravelTime::OneHourThirty => 90,
+            TravelTime::TwoHours => 120,
+            TravelTime::Custom(mins) => i64::from(*mins),
+        }
+    }
+}
+
+/// Action of a VALARM reminder component
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlarmAction {
+    Display,
+    Audio,
+    Email,
+    Other(String), // Any other (e.g. X-) action
+}
+
+impl AlarmAction {
+    /// Parse an iCalendar ACTION value
+    pub fn from_ical(value: &str) -> Self {
+        match value.trim().to_ascii_uppercase().as_str() {
+            "DISPLAY" => AlarmAction::Display,
+            "AUDIO" => AlarmAction::Audio,
+            "EMAIL" => AlarmAction::Email,
+            other => AlarmAction::Other(other.to_string()),
+        }
+    }
+}
+
+/// How an event shows the owner's availability, from iCalendar STATUS and TRANSP
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ShowAs {
+    #[default]
+    Busy,
+    Tentative,
+    Free,
+}
+
+impl ShowAs {
+    /// Derive from STATUS and TRANSP values - a transparent event is free even when tentative
+    pub fn from_ical(status: Option<&str>, transp: Option<&str>) -> Self {
+        if transp.is_some_and(|t| t.trim().eq_ignore_ascii_case("TRANSPARENT")) {
+            ShowAs::Free
+        } else if status.is_some_and(|s| s.trim().eq_ignore_ascii_case("TENTATIVE")) {
+            ShowAs::Tentative
+        } else {
+            ShowAs::Busy
+        }
+    }
+}
+
+/// Importance of an event, grouped from the iCalendar PRIORITY value (1 highest, 9 lowest)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriorityLevel {
+    None,
+    High,
+    Medium,
+    Low,
+}
+
+impl PriorityLevel {
+    /// Group a PRIORITY value as RFC 5545 suggests: 1-4 high, 5 medium, 6-9 low
+    pub fn from_priority(priority: Option<u8>) -> Self {
+        match priority {
+            Some(1..=4) => PriorityLevel::High,
+            Some(5) => PriorityLevel::Medium,
+            Some(6..=9) => PriorityLevel::Low,
+            _ => PriorityLevel::None,
+        }
+    }
+
+    /// PRIORITY value stored for this level
+    pub fn to_priority(self) -> Option<u8> {
+        match self {
+            PriorityLevel::None => None,
+            PriorityLevel::High => Some(1),
+            PriorityLevel::Medium => Some(5),
+            PriorityLevel::Low => Some(9),
+        }
+    }
+}
+
+/// Parse an iCalendar PRIORITY value - 0 means undefined
+pub fn priority_from_ical(value: Option<&str>) -> Option<u8> {
+    value
+        .and_then(|v| v.trim().parse::<u8>().ok())
+        .filter(|p| (1..=9).contains(p))
+}
+
+/// A VALARM component kept as imported so reminders survive a round trip.
+/// Only DISPLAY alarms are meaningful to the app; the rest are carried along for export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventAlarm {
+    /// ACTION of the alarm
+    pub action: AlarmAction,
+    /// TRIGGER value (e.g. "-PT15M")
+    pub trigger: String,
+    /// All unfolded content lines between BEGIN:VALARM and END:VALARM
+    pub lines: Vec<String>,
+}
+
+impl EventAlarm {
+    /// The reminder a DISPLAY alarm triggered before the start stands for
+    pub fn as_alert(&self) -> Option<AlertTime> {
+        if self.action != AlarmAction::Display {
+            return None;
+        }
+        // Triggers relative to the end can't be expressed as a reminder
+        let related_to_end = self.lines.iter().any(|line| {
+            line.split_once(':').is_some_and(|(key, _)| {
+                let mut parts = key.split(';');
+                parts.next().is_some_and(|name| name.eq_ignore_ascii_case("TRIGGER"))
+                    && parts.any(|param| param.eq_ignore_ascii_case("RELATED=END"))
+            })
+        });
+        if related_to_end {
+            return None;
+        }
+        AlertTime::from_ical_trigger(&self.trigger)
+    }
+}
+
+/// One occurrence of a recurring event changed on its own (an iCalendar RECURRENCE-ID override)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OccurrenceOverride {
+    pub summary: String,
+    pub location: Option<String>,
+    pub all_day: bool,
+    pub start: chrono::DateTime<chrono::Utc>,
+    pub end: chrono::DateTime<chrono::Utc>,
+    pub notes: Option<String>,
+}
+
+impl OccurrenceOverride {
+    /// Take the overridable fields from an imported override VEVENT
+    pub fn from_event(event: &CalendarEvent) -> Self {
+        OccurrenceOverride {
+            summary: event.summary.clone(),
+            location: event.location.clone(),
+            all_day: event.all_day,
+            start: event.start,
+            end: event.end,
+            notes: event.notes.clone(),
+        }
+    }
+
+    /// Replace the generated occurrence's fields with the overridden ones
+    pub fn apply(&self, occurrence: &mut CalendarEvent) {
+        occurrence.summary = self.summary.clone();
+        occurrence.location = self.location.clone();
+        occurrence.all_day = self.all_day;
+        occurrence.start = self.start;
+        occurrence.end = self.end;
+        occurrence.notes = self.notes.clone();
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CalendarEvent {
     /// Unique identifier for the event
@@ -80,16 +391,30 @@ pub struct CalendarEvent {
     pub exception_dates: Vec<chrono::NaiveDate>,
     /// Invitees (email addresses)
     pub invitees: Vec<String>,
-    /// Alert/reminder settings
-    pub alert: AlertTime,
-    /// Second alert (optional)
-    pub alert_second: Option<AlertTime>,
+    /// Reminders, each exported as a VALARM (never contains AlertTime::None)
+    #[serde(default)]
+    pub alerts: Vec<AlertTime>,
     /// File attachments (paths or URLs)
     pub attachments: Vec<String>,
     /// URL associated with the event
     pub url: Option<String>,
     /// Notes/description
     pub notes: Option<String>,
+    /// Availability shown for this event (busy, tentative or free)
+    #[serde(default)]
+    pub show_as: ShowAs,
+    /// iCalendar PRIORITY (1 highest to 9 lowest), None when undefined
+    #[serde(default)]
+    pub priority: Option<u8>,
+    /// Imported VALARM components, re-emitted on export
+    #[serde(default)]
+    pub alarms: Vec<EventAlarm>,
+    /// Modified occurrences of a recurring event, keyed by the date they replace (RECURRENCE-ID)
+    #[serde(default)]
+    pub overrides: BTreeMap<chrono::NaiveDate, OccurrenceOverride>,
+    /// Manual position among a day's all-day events (None sorts chronologically)
+    #[serde(default)]
+    pub display_order: Option<u32>,
 }
 
 // CalDAV client for future use
@@ -263,14 +588,93 @@ mod tests {
             repeat_until: None,
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::FifteenMinutes,
-            alert_second: None,
+            alerts: vec![AlertTime::FifteenMinutes],
             attachments: vec![],
             url: None,
             notes: Some("A test event".to_string()),
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         };
 
         // Note: This test would fail without a real CalDAV server
         // In production, you'd use mock servers or integration tests
     }
+
+    #[test]
+    fn test_show_as_from_ical() {
+        assert_eq!(ShowAs::from_ical(None, None), ShowAs::Busy);
+        assert_eq!(ShowAs::from_ical(Some("CONFIRMED"), Some("OPAQUE")), ShowAs::Busy);
+        assert_eq!(ShowAs::from_ical(Some("tentative"), None), ShowAs::Tentative);
+        assert_eq!(ShowAs::from_ical(Some("TENTATIVE"), Some("TRANSPARENT")), ShowAs::Free);
+    }
+
+    #[test]
+    fn test_repeat_from_rrule() {
+        assert_eq!(RepeatFrequency::from_rrule("FREQ=WEEKLY"), (RepeatFrequency::Weekly, None));
+        assert_eq!(
+            RepeatFrequency::from_rrule("FREQ=WEEKLY;INTERVAL=2;UNTIL=20260301T000000Z"),
+            (RepeatFrequency::Biweekly, chrono::NaiveDate::from_ymd_opt(2026, 3, 1))
+        );
+        assert_eq!(
+            RepeatFrequency::from_rrule("FREQ=WEEKLY;BYDAY=MO,WE"),
+            (RepeatFrequency::Custom("FREQ=WEEKLY;BYDAY=MO,WE".to_string()), None)
+        );
+    }
+
+    #[test]
+    fn test_repeat_to_rrule() {
+        let until = chrono::NaiveDate::from_ymd_opt(2026, 3, 1);
+        assert_eq!(RepeatFrequency::Never.to_rrule(until, false), None);
+        assert_eq!(RepeatFrequency::Monthly.to_rrule(None, false).as_deref(), Some("FREQ=MONTHLY"));
+        assert_eq!(
+            RepeatFrequency::Biweekly.to_rrule(until, false).as_deref(),
+            Some("FREQ=WEEKLY;INTERVAL=2;UNTIL=20260301T235959Z")
+        );
+        assert_eq!(RepeatFrequency::Yearly.to_rrule(until, true).as_deref(), Some("FREQ=YEARLY;UNTIL=20260301"));
+
+        // Written rules read back as the same frequency and end date
+        for repeat in [RepeatFrequency::Daily, RepeatFrequency::Weekly, RepeatFrequency::Biweekly] {
+            let rule = repeat.to_rrule(until, false).unwrap();
+            assert_eq!(RepeatFrequency::from_rrule(&rule), (repeat, until));
+        }
+    }
+
+    #[test]
+    fn test_priority_from_ical() {
+        assert_eq!(priority_from_ical(Some("1")), Some(1));
+        assert_eq!(priority_from_ical(Some(" 9 ")), Some(9));
+        assert_eq!(priority_from_ical(Some("0")), None);
+        assert_eq!(priority_from_ical(Some("high")), None);
+        assert_eq!(priority_from_ical(None), None);
+        assert_eq!(PriorityLevel::from_priority(Some(3)), PriorityLevel::High);
+        assert_eq!(PriorityLevel::from_priority(Some(5)), PriorityLevel::Medium);
+        assert_eq!(PriorityLevel::from_priority(Some(7)), PriorityLevel::Low);
+    }
+
+    #[test]
+    fn test_time_to_leave_alert_trigger() {
+        assert_eq!(AlertTime::TimeToLeave.minutes_before(&TravelTime::FortyFiveMinutes), Some(45));
+        // Without travel time there is nothing to subtract
+        assert_eq!(AlertTime::TimeToLeave.minutes_before(&TravelTime::None), Some(0));
+        assert_eq!(AlertTime::FifteenMinutes.minutes_before(&TravelTime::OneHour), Some(15));
+        assert_eq!(AlertTime::None.minutes_before(&TravelTime::OneHour), None);
+    }
+
+    #[test]
+    fn test_alert_from_ical_trigger() {
+        assert_eq!(AlertTime::from_ical_trigger("-PT15M"), Some(AlertTime::FifteenMinutes));
+        assert_eq!(AlertTime::from_ical_trigger("-P1D"), Some(AlertTime::OneDay));
+        assert_eq!(AlertTime::from_ical_trigger("-P1W"), Some(AlertTime::OneWeek));
+        assert_eq!(AlertTime::from_ical_trigger("-PT1H30M"), Some(AlertTime::Custom(90)));
+        assert_eq!(AlertTime::from_ical_trigger("PT0S"), Some(AlertTime::AtTime));
+        // After the start, or not a duration
+        assert_eq!(AlertTime::from_ical_trigger("PT15M"), None);
+        assert_eq!(AlertTime::from_ical_trigger("20260302T090000Z"), None);
+
+        let trigger = AlertTime::TimeToLeave.ical_trigger(&TravelTime::FortyFiveMinutes).unwrap();
+        assert_eq!(AlertTime::from_ical_trigger(&trigger), Some(AlertTime::Custom(45)));
+    }
 }
@@ -5,6 +5,7 @@
 #![allow(dead_code)] // Prepared for future CalDAV sync feature
 
 use super::calendar_source::{CalendarInfo, CalendarSource, CalendarType};
+use super::reconcile::{reconcile_events, SyncConflict};
 use crate::caldav::{CalDavClient, CalendarEvent};
 use std::error::Error;
 
@@ -14,6 +15,10 @@ pub struct CalDavCalendar {
     info: CalendarInfo,
     client: CalDavClient,
     cached_events: Vec<CalendarEvent>,
+    /// Remote events as of the last successful sync (base for conflict detection)
+    synced_events: Vec<CalendarEvent>,
+    /// Conflicts found during the last sync, awaiting user resolution
+    pending_conflicts: Vec<SyncConflict>,
 }
 
 impl CalDavCalendar {
@@ -32,6 +37,8 @@ impl CalDavCalendar {
             info,
             client,
             cached_events: Vec::new(),
+            synced_events: Vec::new(),
+            pending_conflicts: Vec::new(),
         })
     }
 
@@ -59,6 +66,8 @@ impl CalDavCalendar {
             info,
             client,
             cached_events: Vec::new(),
+            synced_events: Vec::new(),
+            pending_conflicts: Vec::new(),
         })
     }
 
@@ -163,11 +172,27 @@ impl CalendarSource for CalDavCalendar {
 
     fn sync(&mut self) -> Result<(), Box<dyn Error>> {
         // Fetch fresh events from CalDAV server
-        let events = self.client.fetch_events()?;
-        self.cached_events = events;
+        let remote = self.client.fetch_events()?;
+        self.apply_remote_events(remote);
         Ok(())
     }
 
+    fn remote_client(&self) -> Option<CalDavClient> {
+        Some(self.client.clone())
+    }
+
+    fn apply_remote_events(&mut self, remote: Vec<CalendarEvent>) {
+        // Reconcile instead of overwriting so events edited on both sides are surfaced
+        let outcome = reconcile_events(&self.info.id, &self.synced_events, &self.cached_events, &remote);
+        self.cached_events = outcome.merged;
+        self.pending_conflicts.extend(outcome.conflicts);
+        self.synced_events = remote;
+    }
+
+    fn take_sync_conflicts(&mut self) -> Vec<SyncConflict> {
+        std::mem::take(&mut self.pending_conflicts)
+    }
+
     fn supports_read(&self) -> bool {
         true
     }
@@ -1,4 +1,6 @@
-use crate::caldav::CalendarEvent;
+use super::reconcile::SyncConflict;
+use crate::caldav::{CalDavClient, CalendarEvent};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt::Debug;
@@ -28,6 +30,9 @@ impl CalendarType {
     }
 }
 
+/// Length of new timed events when a calendar doesn't set its own
+pub const DEFAULT_EVENT_DURATION_MINUTES: i64 = 60;
+
 /// Metadata about a calendar source
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalendarInfo {
@@ -43,6 +48,27 @@ pub struct CalendarInfo {
     pub description: Option<String>,
     /// Whether the calendar is currently enabled/visible
     pub enabled: bool,
+    /// Sidebar group this calendar is listed under (e.g. "Work", "Subscribed")
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Whether events in this calendar can't be edited (subscriptions, until sync can write back)
+    #[serde(default)]
+    pub read_only: bool,
+    /// Length of new timed events in minutes (None uses the app default of one hour)
+    #[serde(default)]
+    pub default_duration_minutes: Option<u32>,
+    /// Minutes between background syncs (None syncs only on demand)
+    #[serde(default)]
+    pub sync_interval_minutes: Option<u32>,
+    /// When the calendar last synced successfully
+    #[serde(default)]
+    pub last_synced: Option<DateTime<Utc>>,
+    /// Whether all-day events shade day cells as holidays instead of showing as events
+    #[serde(default)]
+    pub holiday_overlay: bool,
+    /// .ics file the calendar is re-exported to whenever its events change
+    #[serde(default)]
+    pub auto_export_path: Option<String>,
 }
 
 impl CalendarInfo {
@@ -54,9 +80,35 @@ impl CalendarInfo {
             color: Self::default_color_for_type(calendar_type),
             description: None,
             enabled: true,
+            group: None,
+            read_only: false,
+            default_duration_minutes: None,
+            sync_interval_minutes: None,
+            last_synced: None,
+            holiday_overlay: false,
+            auto_export_path: None,
         }
     }
 
+    /// Length of new timed events created in this calendar
+    pub fn default_event_duration(&self) -> chrono::Duration {
+        chrono::Duration::minutes(
+            self.default_duration_minutes
+                .map(i64::from)
+                .unwrap_or(DEFAULT_EVENT_DURATION_MINUTES),
+        )
+    }
+
+    /// Whether a background sync is due (never for calendars synced only on demand)
+    /// A calendar that never synced is due straight away
+    pub fn is_sync_due(&self, now: DateTime<Utc>) -> bool {
+        let Some(minutes) = self.sync_interval_minutes else {
+            return false;
+        };
+        self.last_synced
+            .is_none_or(|last| now >= last + chrono::Duration::minutes(i64::from(minutes)))
+    }
+
     fn default_color_for_type(calendar_type: CalendarType) -> String {
         match calendar_type {
             CalendarType::Local => "#3B82F6".to_string(),      // blue
@@ -104,6 +156,21 @@ pub trait CalendarSource: Debug + Send {
     /// For local calendars, this might just save to disk
     fn sync(&mut self) -> Result<(), Box<dyn Error>>;
 
+    /// Client for fetching this calendar's server events off the UI thread
+    /// Local calendars have no server, so the default returns None
+    fn remote_client(&self) -> Option<CalDavClient> {
+        None
+    }
+
+    /// Merge server events fetched with `remote_client` into the calendar
+    fn apply_remote_events(&mut self, _remote: Vec<CalendarEvent>) {}
+
+    /// Take conflicts detected by the last sync (events changed both locally and remotely)
+    /// Local calendars never conflict, so the default returns nothing
+    fn take_sync_conflicts(&mut self) -> Vec<SyncConflict> {
+        Vec::new()
+    }
+
     /// Check if this calendar supports read operations
     #[allow(dead_code)] // Part of trait API for future use
     fn supports_read(&self) -> bool {
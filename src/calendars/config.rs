@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Configuration for a calendar source
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +11,27 @@ pub struct CalendarConfig {
     pub color: String,
     pub enabled: bool,
     pub calendar_type: String,
+    /// Sidebar group name, if any
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Length of new timed events in minutes, if customised
+    #[serde(default)]
+    pub default_duration_minutes: Option<u32>,
+    /// Minutes between background syncs, if enabled
+    #[serde(default)]
+    pub sync_interval_minutes: Option<u32>,
+    /// When the calendar last synced successfully
+    #[serde(default)]
+    pub last_synced: Option<chrono::DateTime<chrono::Utc>>,
+    /// Whether the calendar is shown as a holiday overlay
+    #[serde(default)]
+    pub holiday_overlay: bool,
+    /// .ics file the calendar is kept exported to, if any
+    #[serde(default)]
+    pub auto_export_path: Option<String>,
+    /// Whether events can't be edited (subscriptions)
+    #[serde(default)]
+    pub read_only: bool,
 }
 
 /// Manager configuration that stores all calendar settings
@@ -22,13 +43,16 @@ pub struct CalendarManagerConfig {
 impl CalendarManagerConfig {
     /// Load configuration from disk
     pub fn load() -> Result<Self, io::Error> {
-        let path = Self::config_path();
+        Self::load_from(&Self::config_path())
+    }
 
+    /// Load configuration from the given file
+    pub fn load_from(path: &Path) -> Result<Self, io::Error> {
         if !path.exists() {
             return Ok(Self::default());
         }
 
-        let contents = fs::read_to_string(&path)?;
+        let contents = fs::read_to_string(path)?;
         let config: CalendarManagerConfig = serde_json::from_str(&contents)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
         Ok(config)
@@ -36,8 +60,11 @@ impl CalendarManagerConfig {
 
     /// Save configuration to disk
     pub fn save(&self) -> Result<(), io::Error> {
-        let path = Self::config_path();
+        self.save_to(&Self::config_path())
+    }
 
+    /// Save configuration to the given file
+    pub fn save_to(&self, path: &Path) -> Result<(), io::Error> {
         // Ensure parent directory exists
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
@@ -45,12 +72,12 @@ impl CalendarManagerConfig {
 
         let json = serde_json::to_string_pretty(self)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        fs::write(&path, json)?;
+        fs::write(path, json)?;
         Ok(())
     }
 
     /// Get the configuration file path
-    fn config_path() -> PathBuf {
+    pub fn config_path() -> PathBuf {
         let mut path = dirs::config_local_dir().unwrap_or_else(|| PathBuf::from("."));
         path.push("sol-calendar");
         path.push("calendars.json");
@@ -81,3 +108,45 @@ impl CalendarManagerConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_round_trip() {
+        let path = std::env::temp_dir().join("sol_test_calendars.json");
+        let _ = fs::remove_file(&path);
+
+        let mut config = CalendarManagerConfig::default();
+        config.update_calendar(CalendarConfig {
+            id: "holidays".to_string(),
+            name: "Holidays".to_string(),
+            color: "#EF4444".to_string(),
+            enabled: true,
+            calendar_type: "Local".to_string(),
+            group: Some("Subscribed".to_string()),
+            default_duration_minutes: None,
+            sync_interval_minutes: Some(60),
+            last_synced: None,
+            holiday_overlay: true,
+            auto_export_path: None,
+            read_only: true,
+        });
+        config.save_to(&path).unwrap();
+
+        let loaded = CalendarManagerConfig::load_from(&path).unwrap();
+        let calendar = loaded.get_calendar("holidays").unwrap();
+        assert!(calendar.read_only);
+        assert!(calendar.holiday_overlay);
+        assert_eq!(calendar.group.as_deref(), Some("Subscribed"));
+        assert_eq!(calendar.sync_interval_minutes, Some(60));
+
+        // Configs written before the field existed load as editable
+        fs::write(&path, r#"{"calendars":[{"id":"work","name":"Work","color":"#8B5CF6","enabled":true,"calendar_type":"Local"}]}"#).unwrap();
+        let old = CalendarManagerConfig::load_from(&path).unwrap();
+        assert!(!old.get_calendar("work").unwrap().read_only);
+
+        let _ = fs::remove_file(&path);
+    }
+}
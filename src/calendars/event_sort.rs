@@ -0,0 +1,134 @@
+//! Ordering of the events shown within a day.
+//!
+//! The display event cache is sorted once when it is built, so the month
+//! cells, the all-day section and other day lists all show the same order.
+//! Layout code (overlap columns, spanning slots) may still reorder events
+//! for placement.
+
+use std::cmp::Ordering;
+
+use crate::components::DisplayEvent;
+use crate::settings::EventSortOrder;
+
+/// Sort one day's events by the configured order
+///
+/// `calendar_order` lists calendar IDs in sidebar order, for sorting by calendar.
+/// All-day events given a manual position by dragging come first, in that order.
+/// Ties fall back to start time and then title, so the order is stable across refreshes.
+pub fn sort_day_events(events: &mut [DisplayEvent], order: EventSortOrder, calendar_order: &[&str]) {
+    let calendar_rank = |event: &DisplayEvent| {
+        calendar_order
+            .iter()
+            .position(|id| *id == event.calendar_id)
+            .unwrap_or(calendar_order.len())
+    };
+    // iCalendar priority runs from 1 (highest) to 9; undefined sorts last
+    let priority_rank = |event: &DisplayEvent| event.priority.filter(|p| *p > 0).unwrap_or(u8::MAX);
+    // Events without a manual position keep the configured order after the ordered ones
+    let manual_rank = |event: &DisplayEvent| {
+        event.display_order.filter(|_| event.all_day).map_or((true, 0), |order| (false, order))
+    };
+
+    events.sort_by(|a, b| {
+        let primary = match order {
+            EventSortOrder::StartTime => Ordering::Equal,
+            EventSortOrder::AllDayFirst => b.all_day.cmp(&a.all_day),
+            EventSortOrder::Calendar => calendar_rank(a).cmp(&calendar_rank(b)),
+            EventSortOrder::Priority => priority_rank(a).cmp(&priority_rank(b)),
+        };
+        manual_rank(a)
+            .cmp(&manual_rank(b))
+            .then(primary)
+            .then_with(|| by_start(a, b))
+            .then_with(|| a.summary.cmp(&b.summary))
+    });
+}
+
+/// Chronological order; all-day events count as starting at midnight,
+/// with longer spans (started earlier) first
+fn by_start(a: &DisplayEvent, b: &DisplayEvent) -> Ordering {
+    a.start_time
+        .unwrap_or_default()
+        .cmp(&b.start_time.unwrap_or_default())
+        .then_with(|| a.span_start.cmp(&b.span_start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::ShowAs;
+    use chrono::NaiveTime;
+
+    fn event(uid: &str, calendar_id: &str, time: Option<(u32, u32)>, priority: Option<u8>) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: calendar_id.to_string(),
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            color: "#3B82F6".to_string(),
+            all_day: time.is_none(),
+            start_time: time.and_then(|(h, m)| NaiveTime::from_hms_opt(h, m, 0)),
+            end_time: time.and_then(|(h, m)| NaiveTime::from_hms_opt(h + 1, m, 0)),
+            show_as: ShowAs::Busy,
+            priority,
+            location: None,
+            notes_preview: None,
+            display_order: None,
+            span_start: None,
+            span_end: None,
+        }
+    }
+
+    fn mixed_day() -> Vec<DisplayEvent> {
+        vec![
+            event("lunch", "personal", Some((12, 0)), None),
+            event("holiday", "personal", None, None),
+            event("early", "work", Some((0, 0)), Some(5)),
+            event("review", "work", Some((9, 30)), Some(1)),
+            event("deadline", "work", None, Some(1)),
+        ]
+    }
+
+    fn uids(events: &[DisplayEvent]) -> Vec<&str> {
+        events.iter().map(|e| e.uid.as_str()).collect()
+    }
+
+    #[test]
+    fn test_sort_day_events_by_each_order() {
+        let calendars = ["work", "personal"];
+
+        let mut events = mixed_day();
+        sort_day_events(&mut events, EventSortOrder::StartTime, &calendars);
+        // All-day events count as midnight, so the 00:00 event sits among them
+        assert_eq!(uids(&events), ["deadline", "early", "holiday", "review", "lunch"]);
+
+        sort_day_events(&mut events, EventSortOrder::AllDayFirst, &calendars);
+        assert_eq!(uids(&events), ["deadline", "holiday", "early", "review", "lunch"]);
+
+        sort_day_events(&mut events, EventSortOrder::Calendar, &calendars);
+        assert_eq!(uids(&events), ["deadline", "early", "review", "holiday", "lunch"]);
+
+        sort_day_events(&mut events, EventSortOrder::Priority, &calendars);
+        assert_eq!(uids(&events), ["deadline", "review", "early", "holiday", "lunch"]);
+    }
+
+    #[test]
+    fn test_sort_day_events_manual_all_day_order() {
+        let calendars = ["work", "personal"];
+        let mut events = mixed_day();
+        for event in events.iter_mut() {
+            event.display_order = match event.uid.as_str() {
+                "holiday" => Some(0),
+                "deadline" => Some(1),
+                // Manual positions only apply to all-day events
+                "lunch" => Some(0),
+                _ => None,
+            };
+        }
+
+        sort_day_events(&mut events, EventSortOrder::StartTime, &calendars);
+        assert_eq!(uids(&events), ["holiday", "deadline", "early", "review", "lunch"]);
+
+        sort_day_events(&mut events, EventSortOrder::Priority, &calendars);
+        assert_eq!(uids(&events), ["holiday", "deadline", "review", "early", "lunch"]);
+    }
+}
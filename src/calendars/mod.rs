@@ -1,18 +1,23 @@
 mod calendar_source;
 mod caldav_calendar;
 mod config;
+mod event_sort;
 mod local_calendar;
+mod reconcile;
 
-pub use calendar_source::{CalendarSource, CalendarType};
+pub use calendar_source::{CalendarInfo, CalendarSource, CalendarType, DEFAULT_EVENT_DURATION_MINUTES};
+pub use reconcile::SyncConflict;
 pub use config::{CalendarConfig, CalendarManagerConfig};
+pub use event_sort::sort_day_events;
 pub use local_calendar::LocalCalendar;
 
-use crate::caldav::{CalendarEvent, RepeatFrequency};
-use crate::components::DisplayEvent;
+use crate::caldav::{CalendarEvent, RepeatFrequency, ShowAs, TravelTime};
+use crate::components::{end_of_day, DisplayEvent};
 use crate::database::Database;
+use crate::settings::EventSortOrder;
 use chrono::{Datelike, Timelike, NaiveDate, Duration, Months};
 use log::{debug, info};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::error::Error;
 use std::sync::{Arc, Mutex};
 
@@ -31,11 +36,14 @@ impl CalendarManager {
 
         // Open or create the database
         let db = Database::open().expect("Failed to open database");
-        let db = Arc::new(Mutex::new(db));
+        Self::with_database(db)
+    }
 
+    /// Create a CalendarManager without calendars on an already opened database
+    pub fn with_database(db: Database) -> Self {
         CalendarManager {
             sources: Vec::new(),
-            db,
+            db: Arc::new(Mutex::new(db)),
         }
     }
 
@@ -81,6 +89,13 @@ impl CalendarManager {
                 // Apply saved settings
                 calendar.info_mut().color = cal_config.color.clone();
                 calendar.info_mut().enabled = cal_config.enabled;
+                calendar.info_mut().group = cal_config.group.clone();
+                calendar.info_mut().default_duration_minutes = cal_config.default_duration_minutes;
+                calendar.info_mut().sync_interval_minutes = cal_config.sync_interval_minutes;
+                calendar.info_mut().last_synced = cal_config.last_synced;
+                calendar.info_mut().holiday_overlay = cal_config.holiday_overlay;
+                calendar.info_mut().auto_export_path = cal_config.auto_export_path.clone();
+                calendar.info_mut().read_only = cal_config.read_only;
                 manager.add_source(Box::new(calendar));
             }
         }
@@ -132,6 +147,40 @@ impl CalendarManager {
         self.db.clone()
     }
 
+    /// Start a batch of writes committed together, much faster than one commit per write
+    /// Only local calendars write through the database; remote writes are not covered
+    pub fn begin_batch(&self) -> Result<(), Box<dyn Error>> {
+        let db = self.db.lock().map_err(|_| "Database lock poisoned")?;
+        db.begin_transaction()
+    }
+
+    /// Commit the open batch of writes
+    pub fn commit_batch(&self) -> Result<(), Box<dyn Error>> {
+        let db = self.db.lock().map_err(|_| "Database lock poisoned")?;
+        if db.in_transaction() {
+            db.commit_transaction()?;
+        }
+        Ok(())
+    }
+
+    /// Load the notes attached to dates (not to events)
+    pub fn day_notes(&self) -> BTreeMap<NaiveDate, String> {
+        let Ok(db) = self.db.lock() else {
+            log::error!("CalendarManager: Database lock poisoned, no day notes loaded");
+            return BTreeMap::new();
+        };
+        db.get_day_notes().unwrap_or_else(|e| {
+            log::error!("CalendarManager: Failed to load day notes: {}", e);
+            BTreeMap::new()
+        })
+    }
+
+    /// Save the note for a date (an empty note removes it)
+    pub fn set_day_note(&self, date: NaiveDate, text: &str) -> Result<(), Box<dyn Error>> {
+        let db = self.db.lock().map_err(|_| "Database lock poisoned")?;
+        db.set_day_note(date, text)
+    }
+
     /// Add a calendar source to the manager
     pub fn add_source(&mut self, source: Box<dyn CalendarSource>) {
         self.sources.push(source);
@@ -153,6 +202,13 @@ impl CalendarManager {
         &self.sources
     }
 
+    /// Check whether a calendar is read-only (unknown calendars are treated as writable)
+    pub fn is_read_only(&self, calendar_id: &str) -> bool {
+        self.sources
+            .iter()
+            .any(|c| c.info().id == calendar_id && c.info().read_only)
+    }
+
     /// Get a mutable reference to all sources
     pub fn sources_mut(&mut self) -> &mut [Box<dyn CalendarSource>] {
         &mut self.sources
@@ -195,16 +251,18 @@ impl CalendarManager {
 
     /// Expand a recurring event into multiple occurrences within a date range
     /// Returns a vector of (occurrence_date, event) tuples
+    /// The occurrence date is the series date, which differs from the start of a moved occurrence
     /// Skips exception dates (dates where the recurring event was deleted for a single occurrence)
-    fn expand_recurring_event(
+    pub(crate) fn expand_recurring_event(
         event: &CalendarEvent,
         range_start: NaiveDate,
         range_end: NaiveDate,
     ) -> Vec<(NaiveDate, CalendarEvent)> {
         // Non-recurring events return a single occurrence
+        // Multi-day events that started before the range still overlap it
         if matches!(event.repeat, RepeatFrequency::Never) {
             let event_date = event.start.date_naive();
-            if event_date >= range_start && event_date <= range_end {
+            if event.end.date_naive() >= range_start && event_date <= range_end {
                 return vec![(event_date, event.clone())];
             } else {
                 return vec![];
@@ -233,19 +291,28 @@ impl CalendarManager {
         while current_date <= recurrence_end && current_date <= range_end && iteration_count < max_iterations {
             iteration_count += 1;
 
-            // Only add if within the visible range AND not an exception date
-            if current_date >= range_start && !event.exception_dates.contains(&current_date) {
+            // Skip exception dates; the rest are added when they land within the visible range
+            if !event.exception_dates.contains(&current_date) {
                 // Create a clone of the event with adjusted dates
                 let duration = event.end - event.start;
                 let mut occurrence = event.clone();
                 occurrence.start = current_date.and_time(event.start.time()).and_utc();
                 occurrence.end = occurrence.start + duration;
 
+                // A modified occurrence (RECURRENCE-ID) replaces the generated one and may have moved day
+                if let Some(change) = event.overrides.get(&current_date) {
+                    change.apply(&mut occurrence);
+                }
+
                 // Generate unique UID for each occurrence by appending the date
                 // This ensures deduplication logic in views doesn't skip occurrences
+                // The original date is kept for moved occurrences so skipping or deleting still targets them
                 occurrence.uid = format!("{}_{}", event.uid, current_date.format("%Y%m%d"));
 
-                occurrences.push((current_date, occurrence));
+                let shown_date = occurrence.start.date_naive();
+                if shown_date >= range_start && shown_date <= range_end {
+                    occurrences.push((current_date, occurrence));
+                }
             }
 
             // Advance to next occurrence based on repeat frequency
@@ -274,6 +341,51 @@ impl CalendarManager {
         occurrences
     }
 
+    /// First `count` occurrence dates of a recurrence starting on `start`
+    /// Used to preview a rule in the event dialog before it's saved
+    pub fn preview_occurrences(start: NaiveDate, repeat: &RepeatFrequency, count: usize) -> Vec<NaiveDate> {
+        // Longest gap between two occurrences, to size the expansion range
+        let step_days = match repeat {
+            RepeatFrequency::Daily => 1,
+            RepeatFrequency::Weekly => 7,
+            RepeatFrequency::Biweekly => 14,
+            RepeatFrequency::Monthly => 31,
+            RepeatFrequency::Yearly => 366,
+            RepeatFrequency::Never | RepeatFrequency::Custom(_) => return Vec::new(),
+        };
+
+        let start_time = start.and_time(chrono::NaiveTime::MIN).and_utc();
+        let template = CalendarEvent {
+            uid: String::new(),
+            summary: String::new(),
+            location: None,
+            all_day: true,
+            start: start_time,
+            end: start_time,
+            travel_time: TravelTime::None,
+            repeat: repeat.clone(),
+            repeat_until: None,
+            exception_dates: vec![],
+            invitees: vec![],
+            alerts: vec![],
+            attachments: vec![],
+            url: None,
+            notes: None,
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
+        };
+
+        let range_end = start + Duration::days(step_days * count as i64);
+        Self::expand_recurring_event(&template, start, range_end)
+            .into_iter()
+            .map(|(date, _)| date)
+            .take(count)
+            .collect()
+    }
+
     /// Get events for a specific month grouped by date, with calendar colors.
     /// Includes events from adjacent months that would be visible in the month view.
     /// Returns a HashMap where key is NaiveDate and value is Vec of DisplayEvents.
@@ -297,7 +409,8 @@ impl CalendarManager {
         let range_end = first_of_month + chrono::Duration::days(days_in_month + 13);
 
         for source in &self.sources {
-            if !source.is_enabled() {
+            // Holiday overlays shade day cells instead of listing events
+            if !source.is_enabled() || source.info().holiday_overlay {
                 continue;
             }
 
@@ -327,6 +440,11 @@ impl CalendarManager {
                                         all_day: true,
                                         start_time: None,
                                         end_time: None,
+                                        show_as: occurrence_event.show_as,
+                                        priority: occurrence_event.priority,
+                                        location: occurrence_event.location.clone(),
+                                        notes_preview: DisplayEvent::preview_notes(occurrence_event.notes.as_deref()),
+                                        display_order: occurrence_event.display_order,
                                         span_start: Some(event_start),
                                         span_end: Some(event_end),
                                     };
@@ -366,6 +484,11 @@ impl CalendarManager {
                                     all_day: occurrence_event.all_day,
                                     start_time,
                                     end_time,
+                                    show_as: occurrence_event.show_as,
+                                    priority: occurrence_event.priority,
+                                    location: occurrence_event.location.clone(),
+                                    notes_preview: DisplayEvent::preview_notes(occurrence_event.notes.as_deref()),
+                                    display_order: occurrence_event.display_order,
                                     span_start: None,
                                     span_end: None,
                                 };
@@ -383,6 +506,82 @@ impl CalendarManager {
         events_by_date
     }
 
+    /// Sort the events of each day in the given order, ranking calendars by sidebar position
+    pub fn sort_display_events(&self, events_by_date: &mut HashMap<NaiveDate, Vec<DisplayEvent>>, order: EventSortOrder) {
+        let calendar_order: Vec<&str> = self.sources.iter().map(|s| s.info().id.as_str()).collect();
+        for events in events_by_date.values_mut() {
+            sort_day_events(events, order, &calendar_order);
+        }
+    }
+
+    /// Holiday names by date from the enabled holiday overlay calendars.
+    /// Every day covered by an all-day event is a holiday; several holidays on
+    /// the same day are joined into one label.
+    pub fn holidays_between(&self, range_start: NaiveDate, range_end: NaiveDate) -> HashMap<NaiveDate, String> {
+        let mut names: BTreeMap<NaiveDate, Vec<String>> = BTreeMap::new();
+
+        for source in &self.sources {
+            if !source.is_enabled() || !source.info().holiday_overlay {
+                continue;
+            }
+            let Ok(events) = source.fetch_events() else {
+                continue;
+            };
+
+            for event in events.iter().filter(|e| e.all_day) {
+                for (_, occurrence) in Self::expand_recurring_event(event, range_start, range_end) {
+                    let mut day = occurrence.start.date_naive().max(range_start);
+                    let last = occurrence.end.date_naive().min(range_end);
+                    while day <= last {
+                        let day_names = names.entry(day).or_default();
+                        if !day_names.contains(&occurrence.summary) {
+                            day_names.push(occurrence.summary.clone());
+                        }
+                        match day.succ_opt() {
+                            Some(next) => day = next,
+                            None => break,
+                        }
+                    }
+                }
+            }
+        }
+
+        debug!("CalendarManager: {} holidays between {} and {}", names.len(), range_start, range_end);
+        names.into_iter().map(|(day, day_names)| (day, day_names.join(", "))).collect()
+    }
+
+    /// Split a timed event into per-day (date, start, end) segments for the time grid.
+    /// The first segment starts at the event start, middle segments cover the whole
+    /// day and the last one ends at the event end. An event ending exactly at
+    /// midnight finishes at the end of the previous day.
+    fn timed_day_segments(event: &CalendarEvent) -> Vec<(NaiveDate, chrono::NaiveTime, chrono::NaiveTime)> {
+        let start_time = chrono::NaiveTime::from_hms_opt(event.start.hour(), event.start.minute(), 0)
+            .unwrap_or_default();
+        let mut end_time = chrono::NaiveTime::from_hms_opt(event.end.hour(), event.end.minute(), 0)
+            .unwrap_or_default();
+        let first_day = event.start.date_naive();
+        let mut last_day = event.end.date_naive();
+
+        if end_time == chrono::NaiveTime::MIN && last_day > first_day {
+            last_day = last_day.pred_opt().unwrap_or(last_day);
+            end_time = end_of_day();
+        }
+
+        let mut segments = Vec::new();
+        let mut day = first_day;
+        while day <= last_day {
+            let segment_start = if day == first_day { start_time } else { chrono::NaiveTime::MIN };
+            let segment_end = if day == last_day { end_time } else { end_of_day() };
+            segments.push((day, segment_start, segment_end));
+            match day.succ_opt() {
+                Some(next) => day = next,
+                None => break,
+            }
+        }
+
+        segments
+    }
+
     /// Get events for a specific week grouped by date, with calendar colors.
     /// Returns a HashMap where key is NaiveDate and value is Vec of DisplayEvents.
     pub fn get_display_events_for_week(&self, week_days: &[chrono::NaiveDate]) -> HashMap<chrono::NaiveDate, Vec<DisplayEvent>> {
@@ -398,7 +597,8 @@ impl CalendarManager {
         let range_end = week_days[week_days.len() - 1];
 
         for source in &self.sources {
-            if !source.is_enabled() {
+            // Holiday overlays shade day cells instead of listing events
+            if !source.is_enabled() || source.info().holiday_overlay {
                 continue;
             }
 
@@ -427,6 +627,11 @@ impl CalendarManager {
                                         all_day: true,
                                         start_time: None,
                                         end_time: None,
+                                        show_as: occurrence_event.show_as,
+                                        priority: occurrence_event.priority,
+                                        location: occurrence_event.location.clone(),
+                                        notes_preview: DisplayEvent::preview_notes(occurrence_event.notes.as_deref()),
+                                        display_order: occurrence_event.display_order,
                                         span_start: Some(event_start),
                                         span_end: Some(event_end),
                                     };
@@ -437,6 +642,38 @@ impl CalendarManager {
                                 }
                                 current = current.succ_opt().unwrap_or(current);
                             }
+                        } else if !occurrence_event.all_day && event_end > event_start {
+                            // Multi-day timed event: one connected segment per day column
+                            let segments = Self::timed_day_segments(&occurrence_event);
+                            let span = match (segments.first(), segments.last()) {
+                                (Some(first), Some(last)) if first.0 != last.0 => Some((first.0, last.0)),
+                                _ => None,
+                            };
+                            for (day, start_time, end_time) in segments {
+                                if day < range_start || day > range_end {
+                                    continue;
+                                }
+                                let display_event = DisplayEvent {
+                                    calendar_id: source.info().id.clone(),
+                                    uid: occurrence_event.uid.clone(),
+                                    summary: occurrence_event.summary.clone(),
+                                    color: calendar_color.clone(),
+                                    all_day: false,
+                                    start_time: Some(start_time),
+                                    end_time: Some(end_time),
+                                    show_as: occurrence_event.show_as,
+                                    priority: occurrence_event.priority,
+                                    location: occurrence_event.location.clone(),
+                                    notes_preview: DisplayEvent::preview_notes(occurrence_event.notes.as_deref()),
+                                    display_order: occurrence_event.display_order,
+                                    span_start: span.map(|(first, _)| first),
+                                    span_end: span.map(|(_, last)| last),
+                                };
+                                events_by_date
+                                    .entry(day)
+                                    .or_default()
+                                    .push(display_event);
+                            }
                         } else {
                             // Single-day event: only add to start date
                             if event_start >= range_start && event_start <= range_end {
@@ -466,6 +703,11 @@ impl CalendarManager {
                                     all_day: occurrence_event.all_day,
                                     start_time,
                                     end_time,
+                                    show_as: occurrence_event.show_as,
+                                    priority: occurrence_event.priority,
+                                    location: occurrence_event.location.clone(),
+                                    notes_preview: DisplayEvent::preview_notes(occurrence_event.notes.as_deref()),
+                                    display_order: occurrence_event.display_order,
                                     span_start: None,
                                     span_end: None,
                                 };
@@ -483,6 +725,15 @@ impl CalendarManager {
         events_by_date
     }
 
+    /// Calendars whose events were written since the last call, used to notice
+    /// which auto-exported calendars need writing out again
+    pub fn take_changed_calendars(&self) -> HashSet<String> {
+        self.db
+            .lock()
+            .map(|db| db.take_changed_calendars())
+            .unwrap_or_default()
+    }
+
     /// Sync all calendar sources
     #[allow(dead_code)] // Reserved for future CalDAV sync
     pub fn sync_all(&mut self) -> Result<(), Box<dyn Error>> {
@@ -495,7 +746,8 @@ impl CalendarManager {
     }
 
     /// Save calendar configuration to config file (not database)
-    /// Each calendar's current state (color, enabled, name) is saved
+    /// Each calendar's current state (color, enabled, name, group, event duration, sync schedule,
+    /// holiday overlay, auto-export path) is saved
     pub fn save_config(&self) -> Result<(), Box<dyn Error>> {
         let mut config = CalendarManagerConfig::load().unwrap_or_default();
 
@@ -507,6 +759,13 @@ impl CalendarManager {
                 color: info.color.clone(),
                 enabled: info.enabled,
                 calendar_type: format!("{:?}", info.calendar_type),
+                group: info.group.clone(),
+                default_duration_minutes: info.default_duration_minutes,
+                sync_interval_minutes: info.sync_interval_minutes,
+                last_synced: info.last_synced,
+                holiday_overlay: info.holiday_overlay,
+                auto_export_path: info.auto_export_path.clone(),
+                read_only: info.read_only,
             });
         }
 
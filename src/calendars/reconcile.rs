@@ -0,0 +1,188 @@
+//! Three-way reconciliation of local and remote events for two-way sync.
+//!
+//! Compares the local cache and the freshly fetched remote events against the
+//! snapshot taken at the last successful sync (the "base"). Changes made on only
+//! one side are applied automatically; events changed on both sides are
+//! surfaced as [`SyncConflict`]s instead of being silently overwritten.
+
+use crate::caldav::CalendarEvent;
+use std::collections::{BTreeSet, HashMap};
+
+/// An event that was modified both locally and remotely since the last sync
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncConflict {
+    /// Calendar the conflicting event belongs to
+    pub calendar_id: String,
+    /// The locally modified version
+    pub local: CalendarEvent,
+    /// The remotely modified version
+    pub remote: CalendarEvent,
+}
+
+/// Result of reconciling local and remote event sets
+#[derive(Debug, Default)]
+pub struct ReconcileOutcome {
+    /// Merged event set; conflicting events keep their local version until resolved
+    pub merged: Vec<CalendarEvent>,
+    /// Events changed on both sides that need a user decision
+    pub conflicts: Vec<SyncConflict>,
+}
+
+/// Reconcile local and remote events against the last synced base snapshot
+pub fn reconcile_events(
+    calendar_id: &str,
+    base: &[CalendarEvent],
+    local: &[CalendarEvent],
+    remote: &[CalendarEvent],
+) -> ReconcileOutcome {
+    let base_by_uid: HashMap<&str, &CalendarEvent> = base.iter().map(|e| (e.uid.as_str(), e)).collect();
+    let local_by_uid: HashMap<&str, &CalendarEvent> = local.iter().map(|e| (e.uid.as_str(), e)).collect();
+    let remote_by_uid: HashMap<&str, &CalendarEvent> = remote.iter().map(|e| (e.uid.as_str(), e)).collect();
+
+    // Sorted UID set keeps the merged output deterministic
+    let uids: BTreeSet<&str> = base_by_uid
+        .keys()
+        .chain(local_by_uid.keys())
+        .chain(remote_by_uid.keys())
+        .copied()
+        .collect();
+
+    let mut outcome = ReconcileOutcome::default();
+
+    for uid in uids {
+        let base = base_by_uid.get(uid).copied();
+        let local = local_by_uid.get(uid).copied();
+        let remote = remote_by_uid.get(uid).copied();
+
+        match (base, local, remote) {
+            // Present everywhere: whichever side changed wins, both changed is a conflict
+            (Some(b), Some(l), Some(r)) => {
+                if l == b || l == r {
+                    outcome.merged.push(r.clone());
+                } else if r == b {
+                    outcome.merged.push(l.clone());
+                } else {
+                    outcome.merged.push(l.clone());
+                    outcome.conflicts.push(SyncConflict {
+                        calendar_id: calendar_id.to_string(),
+                        local: l.clone(),
+                        remote: r.clone(),
+                    });
+                }
+            }
+            // Created on both sides since the last sync with the same UID
+            (None, Some(l), Some(r)) => {
+                outcome.merged.push(l.clone());
+                if l != r {
+                    outcome.conflicts.push(SyncConflict {
+                        calendar_id: calendar_id.to_string(),
+                        local: l.clone(),
+                        remote: r.clone(),
+                    });
+                }
+            }
+            // Deleted locally: keep the deletion unless the remote copy was edited
+            (Some(b), None, Some(r)) => {
+                if r != b {
+                    outcome.merged.push(r.clone());
+                }
+            }
+            // Deleted remotely: keep the deletion unless the local copy was edited
+            (Some(b), Some(l), None) => {
+                if l != b {
+                    outcome.merged.push(l.clone());
+                }
+            }
+            // New on one side only
+            (None, Some(l), None) => outcome.merged.push(l.clone()),
+            (None, None, Some(r)) => outcome.merged.push(r.clone()),
+            // Deleted on both sides (or nowhere)
+            (_, None, None) => {}
+        }
+    }
+
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::{RepeatFrequency, ShowAs, TravelTime};
+    use chrono::Utc;
+    use std::collections::BTreeMap;
+
+    fn event(uid: &str, summary: &str) -> CalendarEvent {
+        let start = Utc::now();
+        CalendarEvent {
+            uid: uid.to_string(),
+            summary: summary.to_string(),
+            location: None,
+            all_day: false,
+            start,
+            end: start + chrono::Duration::hours(1),
+            travel_time: TravelTime::None,
+            repeat: RepeatFrequency::Never,
+            repeat_until: None,
+            exception_dates: vec![],
+            invitees: vec![],
+            alerts: vec![],
+            attachments: vec![],
+            url: None,
+            notes: None,
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
+        }
+    }
+
+    #[test]
+    fn test_one_sided_changes_apply_without_conflict() {
+        let base = vec![event("a", "A"), event("b", "B")];
+        let mut local = base.clone();
+        local[0].summary = "A local".to_string();
+        let mut remote = base.clone();
+        remote[1].summary = "B remote".to_string();
+
+        let outcome = reconcile_events("cal", &base, &local, &remote);
+
+        assert!(outcome.conflicts.is_empty());
+        assert_eq!(outcome.merged.len(), 2);
+        assert_eq!(outcome.merged[0].summary, "A local");
+        assert_eq!(outcome.merged[1].summary, "B remote");
+    }
+
+    #[test]
+    fn test_both_sides_changed_is_conflict() {
+        let base = vec![event("a", "A")];
+        let mut local = base.clone();
+        local[0].summary = "A local".to_string();
+        let mut remote = base.clone();
+        remote[0].summary = "A remote".to_string();
+
+        let outcome = reconcile_events("cal", &base, &local, &remote);
+
+        assert_eq!(outcome.conflicts.len(), 1);
+        assert_eq!(outcome.conflicts[0].calendar_id, "cal");
+        assert_eq!(outcome.conflicts[0].local.summary, "A local");
+        assert_eq!(outcome.conflicts[0].remote.summary, "A remote");
+        // Local version is kept until the user resolves the conflict
+        assert_eq!(outcome.merged[0].summary, "A local");
+    }
+
+    #[test]
+    fn test_deletions_propagate_unless_edited() {
+        let base = vec![event("a", "A"), event("b", "B")];
+        // "a" deleted locally and untouched remotely, "b" deleted remotely but edited locally
+        let mut local = vec![base[1].clone()];
+        local[0].summary = "B local".to_string();
+        let remote = vec![base[0].clone()];
+
+        let outcome = reconcile_events("cal", &base, &local, &remote);
+
+        assert!(outcome.conflicts.is_empty());
+        assert_eq!(outcome.merged.len(), 1);
+        assert_eq!(outcome.merged[0].summary, "B local");
+    }
+}
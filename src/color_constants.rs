@@ -6,11 +6,19 @@ pub const COLOR_DEFAULT_GRAY: Color = Color::from_rgb(107.0/255.0, 114.0/255.0,
 pub const COLOR_BORDER_LIGHT: Color = Color::from_rgba(0.0, 0.0, 0.0, 0.2);
 pub const COLOR_BORDER_SELECTED: Color = Color::from_rgb(0.0, 0.0, 0.0);
 pub const COLOR_DAY_CELL_BORDER: Color = Color::from_rgba(0.5, 0.5, 0.5, 0.2);
+pub const COLOR_HALF_HOUR_LINE: Color = Color::from_rgba(0.5, 0.5, 0.5, 0.1); // Fainter than hour lines
 pub const COLOR_WEEKEND_BACKGROUND: Color = Color::from_rgba(0.5, 0.5, 0.5, 0.05); // Subtle gray tint
+pub const COLOR_HOLIDAY_BACKGROUND: Color = Color::from_rgba(1.0, 149.0/255.0, 0.0, 0.08); // Subtle warm tint
 
 /// Blue color for "today" indicator circle - consistent across all themes
 pub const COLOR_TODAY_BLUE: Color = Color::from_rgb(0.0, 122.0/255.0, 255.0/255.0); // #007AFF
 
+/// Colors offered for the today and selected day highlights
+pub const COLOR_HIGHLIGHT_GREEN: Color = Color::from_rgb(52.0/255.0, 199.0/255.0, 89.0/255.0); // #34C759
+pub const COLOR_HIGHLIGHT_ORANGE: Color = Color::from_rgb(1.0, 149.0/255.0, 0.0); // #FF9500
+pub const COLOR_HIGHLIGHT_RED: Color = Color::from_rgb(1.0, 59.0/255.0, 48.0/255.0); // #FF3B30
+pub const COLOR_HIGHLIGHT_PURPLE: Color = Color::from_rgb(175.0/255.0, 82.0/255.0, 222.0/255.0); // #AF52DE
+
 /// Red color for the current time indicator line in week/day views
 pub const COLOR_CURRENT_TIME: Color = Color::from_rgb(1.0, 59.0/255.0, 48.0/255.0); // #FF3B30
 
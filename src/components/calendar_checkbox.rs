@@ -0,0 +1,67 @@
+//! Checkbox drawn in a calendar's own color
+//!
+//! Used wherever calendars are toggled on or off so the box matches the color
+//! of the calendar's event chips. It is a button rather than a native checkbox,
+//! so screen readers get its name and checked state from the accessibility
+//! name and description.
+
+use cosmic::iced::{alignment, Alignment, Border, Color, Length};
+use cosmic::widget::{button, container, row};
+use cosmic::{widget, Element};
+
+use crate::components::color_picker::parse_color_safe;
+use crate::fl;
+use crate::message::Message;
+use crate::ui_constants::{BORDER_RADIUS_VALUE, BORDER_WIDTH_HIGHLIGHT, SPACING_SMALL};
+
+/// Size of the check box
+const CHECKBOX_SIZE: f32 = 18.0;
+
+/// Render a checkbox filled with the calendar color when checked and outlined
+/// with it otherwise, optionally followed by a label that toggles it too
+/// `name` is read out by screen readers (the label, when there is one)
+pub fn render_calendar_checkbox<'a>(
+    name: String,
+    label: Option<String>,
+    color: &str,
+    is_checked: bool,
+    on_toggle: Message,
+) -> Element<'a, Message> {
+    let color = parse_color_safe(color);
+
+    let check_box = container(
+        widget::text(if is_checked { "✓" } else { "" }).size(12),
+    )
+    .width(Length::Fixed(CHECKBOX_SIZE))
+    .height(Length::Fixed(CHECKBOX_SIZE))
+    .align_x(alignment::Horizontal::Center)
+    .align_y(alignment::Vertical::Center)
+    .style(move |_theme: &cosmic::Theme| container::Style {
+        background: is_checked.then(|| color.into()),
+        text_color: Some(Color::WHITE),
+        border: Border {
+            color,
+            width: BORDER_WIDTH_HIGHLIGHT,
+            radius: BORDER_RADIUS_VALUE.into(),
+        },
+        ..Default::default()
+    });
+
+    let content: Element<'a, Message> = match label {
+        Some(label) => row()
+            .spacing(SPACING_SMALL)
+            .align_y(Alignment::Center)
+            .push(check_box)
+            .push(widget::text(label))
+            .into(),
+        None => check_box.into(),
+    };
+
+    button::custom(content)
+        .on_press(on_toggle)
+        .padding(0)
+        .class(cosmic::theme::Button::Text)
+        .name(name)
+        .description(if is_checked { fl!("checkbox-checked") } else { fl!("checkbox-unchecked") })
+        .into()
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Local, Utc};
 use cosmic::iced::Length;
 use cosmic::widget::{button, column, container, dialog, row, text_input};
 use cosmic::{widget, Element};
@@ -12,27 +13,160 @@ use crate::ui_constants::{
     COLOR_BUTTON_SIZE_SMALL, COLOR_DEFAULT_GRAY, SPACING_COLOR_GRID,
 };
 
+/// Text input ID for the calendar dialog's group field
+pub fn calendar_dialog_group_input_id() -> text_input::Id {
+    text_input::Id::new("calendar_dialog_group")
+}
+
+/// Event durations offered in the calendar dialog, in minutes
+const DURATION_OPTIONS: [Option<u32>; 5] = [None, Some(15), Some(30), Some(60), Some(120)];
+
+/// Label for a default event duration option
+fn duration_label(minutes: Option<u32>) -> String {
+    match minutes {
+        None => fl!("calendar-duration-default"),
+        Some(m) if m % 60 == 0 => fl!("calendar-duration-hours", hours = (m / 60)),
+        Some(m) => fl!("calendar-duration-minutes", minutes = m),
+    }
+}
+
+/// Background sync intervals offered in the calendar edit dialog, in minutes
+const SYNC_INTERVAL_OPTIONS: [Option<u32>; 5] = [None, Some(15), Some(60), Some(240), Some(1440)];
+
+/// Label for a background sync interval option
+fn sync_interval_label(minutes: Option<u32>) -> String {
+    match minutes {
+        None => fl!("calendar-sync-manual"),
+        Some(m) if m % 60 == 0 => fl!("calendar-duration-hours", hours = (m / 60)),
+        Some(m) => fl!("calendar-duration-minutes", minutes = m),
+    }
+}
+
+/// Last and next sync times shown under the sync interval choice
+fn sync_schedule_text(sync_interval: Option<u32>, last_synced: Option<DateTime<Utc>>) -> String {
+    let format_time = |time: DateTime<Utc>| time.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string();
+    let last = last_synced.map_or_else(|| fl!("calendar-sync-never"), format_time);
+    // A calendar that never synced is synced at the next check
+    let next = match sync_interval {
+        None => fl!("calendar-sync-manual"),
+        Some(minutes) => last_synced
+            .map(|last| format_time(last + chrono::Duration::minutes(i64::from(minutes))))
+            .unwrap_or_else(|| fl!("calendar-sync-now")),
+    };
+    fl!("calendar-sync-schedule", last = last, next = next)
+}
+
 /// Render the calendar dialog (Create or Edit mode) using COSMIC dialog widget
 /// Takes the active dialog state which should be CalendarCreate or CalendarEdit variant
 pub fn render_calendar_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
     // Extract data from active_dialog
-    let (is_edit_mode, name, current_color) = match active_dialog {
-        ActiveDialog::CalendarCreate { name, color } => (false, name.as_str(), color.as_str()),
-        ActiveDialog::CalendarEdit { name, color, .. } => (true, name.as_str(), color.as_str()),
+    // Sync schedule, holiday overlay and auto-export are only edited for existing calendars
+    let (is_edit_mode, name, current_color, group, default_duration, sync_schedule, holiday_overlay, auto_export_path) = match active_dialog {
+        ActiveDialog::CalendarCreate { name, color, group, default_duration } => {
+            (false, name.as_str(), color.as_str(), group.as_str(), *default_duration, None, None, None)
+        }
+        ActiveDialog::CalendarEdit { name, color, group, default_duration, sync_interval, last_synced, holiday_overlay, auto_export_path, .. } => {
+            (true, name.as_str(), color.as_str(), group.as_str(), *default_duration, Some((*sync_interval, *last_synced)), Some(*holiday_overlay), Some(auto_export_path.as_str()))
+        }
         _ => return widget::text("").into(), // Should not happen
     };
 
     // Name input field with label
+    // A new calendar still needs its color picked, so Enter moves on instead of creating it
     let name_control = column()
         .spacing(8)
         .push(widget::text(fl!("dialog-calendar-name")))
         .push(
             text_input(fl!("dialog-calendar-name-placeholder"), name)
                 .on_input(Message::CalendarDialogNameChanged)
-                .on_submit(|_| Message::ConfirmCalendarDialog)
+                .on_submit(move |_| if is_edit_mode {
+                    Message::ConfirmCalendarDialog
+                } else {
+                    Message::CalendarDialogFocusGroup
+                })
                 .width(Length::Fill),
         );
 
+    // Group input field with label (blank keeps the calendar ungrouped)
+    // When creating, only the Create button submits
+    let mut group_input = text_input(fl!("dialog-calendar-group-placeholder"), group)
+        .id(calendar_dialog_group_input_id())
+        .on_input(Message::CalendarDialogGroupChanged)
+        .width(Length::Fill);
+    if is_edit_mode {
+        group_input = group_input.on_submit(|_| Message::ConfirmCalendarDialog);
+    }
+    let group_control = column()
+        .spacing(8)
+        .push(widget::text(fl!("dialog-calendar-group")))
+        .push(group_input);
+
+    // Default length of new events (None follows the app default)
+    let mut duration_buttons = row().spacing(4);
+    for option in DURATION_OPTIONS {
+        duration_buttons = duration_buttons.push(
+            button::custom(widget::text(duration_label(option)).size(12))
+                .on_press(Message::CalendarDialogDurationChanged(option))
+                .padding([4, 8])
+                .class(if default_duration == option {
+                    cosmic::theme::Button::Suggested
+                } else {
+                    cosmic::theme::Button::Standard
+                }),
+        );
+    }
+    let duration_control = column()
+        .spacing(8)
+        .push(widget::text(fl!("dialog-calendar-event-duration")))
+        .push(duration_buttons);
+
+    // Background sync interval with the last and next sync times
+    let sync_control = sync_schedule.map(|(sync_interval, last_synced)| {
+        let mut interval_buttons = row().spacing(4);
+        for option in SYNC_INTERVAL_OPTIONS {
+            interval_buttons = interval_buttons.push(
+                button::custom(widget::text(sync_interval_label(option)).size(12))
+                    .on_press(Message::CalendarDialogSyncIntervalChanged(option))
+                    .padding([4, 8])
+                    .class(if sync_interval == option {
+                        cosmic::theme::Button::Suggested
+                    } else {
+                        cosmic::theme::Button::Standard
+                    }),
+            );
+        }
+        column()
+            .spacing(8)
+            .push(widget::text(fl!("dialog-calendar-sync-interval")))
+            .push(interval_buttons)
+            .push(widget::text::caption(sync_schedule_text(sync_interval, last_synced)))
+    });
+
+    // Holiday overlay: all-day events shade day cells instead of showing as events
+    let holiday_control = holiday_overlay.map(|holiday_overlay| {
+        column()
+            .spacing(8)
+            .push(
+                widget::checkbox(fl!("dialog-calendar-holiday-overlay"), holiday_overlay)
+                    .on_toggle(Message::CalendarDialogHolidayOverlayToggled),
+            )
+            .push(widget::text::caption(fl!("dialog-calendar-holiday-overlay-hint")))
+    });
+
+    // Auto-export: the calendar is rewritten to this .ics file whenever it changes
+    let auto_export_control = auto_export_path.map(|auto_export_path| {
+        column()
+            .spacing(8)
+            .push(widget::text(fl!("dialog-calendar-auto-export")))
+            .push(
+                text_input(fl!("dialog-calendar-auto-export-placeholder"), auto_export_path)
+                    .on_input(Message::CalendarDialogAutoExportPathChanged)
+                    .on_submit(|_| Message::ConfirmCalendarDialog)
+                    .width(Length::Fill),
+            )
+            .push(widget::text::caption(fl!("dialog-calendar-auto-export-hint")))
+    });
+
     // Color picker grid using shared color constant
     let mut color_grid = column().spacing(SPACING_COLOR_GRID);
 
@@ -64,6 +198,7 @@ pub fn render_calendar_dialog(active_dialog: &ActiveDialog) -> Element<'_, Messa
                     }),
             )
             .on_press(Message::CalendarDialogColorChanged(hex_owned))
+            .name(fl!("calendar-color-option", color = hex.to_string()))
             .padding(0);
 
             color_row = color_row.push(color_button);
@@ -93,9 +228,21 @@ pub fn render_calendar_dialog(active_dialog: &ActiveDialog) -> Element<'_, Messa
     };
 
     // Use COSMIC's dialog widget with controls
-    dialog()
+    let mut dialog = dialog()
         .title(title)
         .control(name_control)
+        .control(group_control)
+        .control(duration_control);
+    if let Some(sync_control) = sync_control {
+        dialog = dialog.control(sync_control);
+    }
+    if let Some(holiday_control) = holiday_control {
+        dialog = dialog.control(holiday_control);
+    }
+    if let Some(auto_export_control) = auto_export_control {
+        dialog = dialog.control(auto_export_control);
+    }
+    dialog
         .control(color_control)
         .secondary_action(
             button::text(fl!("button-cancel")).on_press(Message::CancelCalendarDialog),
@@ -1,14 +1,17 @@
 use cosmic::iced::Length;
-use cosmic::widget::{button, column, container, menu, row};
+use cosmic::widget::{button, column, container, menu, row, Column};
 use cosmic::{widget, Element};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::calendars::CalendarSource;
-use crate::components::{render_color_indicator, render_quick_color_picker};
+use crate::components::{render_calendar_checkbox, render_color_indicator, render_quick_color_picker};
 use crate::dialogs::ActiveDialog;
 use crate::fl;
 use crate::message::Message;
-use crate::ui_constants::{SPACING_MEDIUM, SPACING_SMALL, PADDING_MEDIUM, FONT_SIZE_BODY, PADDING_COLOR_PICKER_NESTED, COLOR_INDICATOR_SIZE};
+use crate::ui_constants::{
+    SPACING_MEDIUM, SPACING_SMALL, PADDING_MEDIUM, FONT_SIZE_BODY, PADDING_COLOR_PICKER_NESTED,
+    COLOR_INDICATOR_SIZE, ICON_COLLAPSED, ICON_EXPANDED,
+};
 
 /// Context menu actions for calendar items - uses index to avoid Clone/Copy issues with String
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -47,73 +50,134 @@ fn calendar_context_menu(index: usize) -> Option<Vec<menu::Tree<Message>>> {
 }
 
 /// Render the list of calendars with checkboxes, color pickers, and selection
+///
+/// Ungrouped calendars are listed first, followed by one collapsible section per
+/// group (sorted by name) with the number of calendars in it.
 pub fn render_calendar_list<'a>(
     calendars: &'a [Box<dyn CalendarSource>],
     active_dialog: &ActiveDialog,
     selected_calendar_id: Option<&String>,
+    collapsed_groups: &[String],
 ) -> Element<'a, Message> {
     let mut calendar_list = column()
         .spacing(SPACING_MEDIUM)
         .padding(PADDING_MEDIUM)
         .push(widget::text::body(fl!("sidebar-calendars")).size(FONT_SIZE_BODY));
 
+    // Keep the original source index for context menu actions
+    let mut ungrouped: Vec<(usize, &'a dyn CalendarSource)> = Vec::new();
+    let mut groups: BTreeMap<&'a str, Vec<(usize, &'a dyn CalendarSource)>> = BTreeMap::new();
     for (index, calendar) in calendars.iter().enumerate() {
-        let info = calendar.info();
-        let is_enabled = calendar.is_enabled();
-        let is_picker_open = active_dialog.color_picker_calendar_id() == Some(&info.id);
-        let is_selected = selected_calendar_id.map(|id| id == &info.id).unwrap_or(false);
-
-        // Use the color picker component for the indicator
-        let color_indicator = render_color_indicator(
-            info.id.clone(),
-            &info.color,
-            COLOR_INDICATOR_SIZE,
-        );
+        match calendar.info().group.as_deref() {
+            Some(group) => groups.entry(group).or_default().push((index, calendar.as_ref())),
+            None => ungrouped.push((index, calendar.as_ref())),
+        }
+    }
 
-        // Checkbox for visibility toggle
-        let checkbox = widget::checkbox("", is_enabled).on_toggle({
-            let id = info.id.clone();
-            move |_| Message::ToggleCalendar(id.clone())
-        });
-
-        // Calendar name as a clickable button to select it
-        let name_button = button::custom(
-            widget::text(&info.name).width(Length::Fill)
-        )
-        .on_press(Message::SelectCalendar(info.id.clone()))
-        .padding([SPACING_SMALL, SPACING_SMALL])
-        .class(if is_selected {
-            cosmic::theme::Button::Suggested
-        } else {
-            cosmic::theme::Button::Text
-        });
-
-        let calendar_row = row()
-            .spacing(SPACING_SMALL)
-            .align_y(cosmic::iced::Alignment::Center)
-            .push(checkbox)
-            .push(color_indicator)
-            .push(name_button);
-
-        // Wrap in context menu for right-click actions
-        let calendar_row_with_context = widget::context_menu(
-            calendar_row,
-            calendar_context_menu(index),
-        )
-        .on_surface_action(Message::Surface);
-
-        calendar_list = calendar_list.push(calendar_row_with_context);
-
-        // Show inline color picker if this calendar's picker is open
-        if is_picker_open {
-            let color_picker = render_quick_color_picker(info.id.clone(), &info.color);
-
-            calendar_list = calendar_list.push(
-                container(color_picker)
-                    .padding(PADDING_COLOR_PICKER_NESTED)
-            );
+    for (index, calendar) in ungrouped {
+        calendar_list = push_calendar_row(calendar_list, index, calendar, active_dialog, selected_calendar_id);
+    }
+
+    for (group, members) in groups {
+        let is_collapsed = collapsed_groups.iter().any(|g| g == group);
+        calendar_list = calendar_list.push(render_group_header(group, members.len(), is_collapsed));
+
+        if is_collapsed {
+            continue;
+        }
+        for (index, calendar) in members {
+            calendar_list = push_calendar_row(calendar_list, index, calendar, active_dialog, selected_calendar_id);
         }
     }
 
     calendar_list.into()
 }
+
+/// Render a clickable group header that collapses or expands its calendars
+fn render_group_header<'a>(group: &str, count: usize, is_collapsed: bool) -> Element<'a, Message> {
+    let icon_name = if is_collapsed { ICON_COLLAPSED } else { ICON_EXPANDED };
+
+    let header = row()
+        .spacing(SPACING_SMALL)
+        .align_y(cosmic::iced::Alignment::Center)
+        .push(widget::icon::from_name(icon_name).size(16))
+        .push(widget::text::body(group.to_string()).width(Length::Fill))
+        .push(widget::text::caption(count.to_string()));
+
+    button::custom(header)
+        .on_press(Message::ToggleCalendarGroup(group.to_string()))
+        .padding([SPACING_SMALL, SPACING_SMALL])
+        .width(Length::Fill)
+        .class(cosmic::theme::Button::Text)
+        .into()
+}
+
+/// Push a single calendar row (and its inline color picker, if open) onto the list
+fn push_calendar_row<'a>(
+    calendar_list: Column<'a, Message>,
+    index: usize,
+    calendar: &'a dyn CalendarSource,
+    active_dialog: &ActiveDialog,
+    selected_calendar_id: Option<&String>,
+) -> Column<'a, Message> {
+    let info = calendar.info();
+    let is_enabled = calendar.is_enabled();
+    let is_picker_open = active_dialog.color_picker_calendar_id() == Some(&info.id);
+    let is_selected = selected_calendar_id.map(|id| id == &info.id).unwrap_or(false);
+
+    // Use the color picker component for the indicator
+    let color_indicator = render_color_indicator(
+        info.id.clone(),
+        &info.color,
+        COLOR_INDICATOR_SIZE,
+    );
+
+    // Checkbox for visibility toggle, in the calendar's color
+    let checkbox = render_calendar_checkbox(
+        fl!("calendar-show", name = info.name.clone()),
+        None,
+        &info.color,
+        is_enabled,
+        Message::ToggleCalendar(info.id.clone()),
+    );
+
+    // Calendar name as a clickable button to select it
+    let name_button = button::custom(
+        widget::text(&info.name).width(Length::Fill)
+    )
+    .on_press(Message::SelectCalendar(info.id.clone()))
+    .padding([SPACING_SMALL, SPACING_SMALL])
+    .class(if is_selected {
+        cosmic::theme::Button::Suggested
+    } else {
+        cosmic::theme::Button::Text
+    });
+
+    let calendar_row = row()
+        .spacing(SPACING_SMALL)
+        .align_y(cosmic::iced::Alignment::Center)
+        .push(checkbox)
+        .push(color_indicator)
+        .push(name_button);
+
+    // Wrap in context menu for right-click actions
+    let calendar_row_with_context = widget::context_menu(
+        calendar_row,
+        calendar_context_menu(index),
+    )
+    .on_surface_action(Message::Surface);
+
+    let mut calendar_list = calendar_list.push(calendar_row_with_context);
+
+    // Show inline color picker if this calendar's picker is open
+    if is_picker_open {
+        let color_picker = render_quick_color_picker(info.id.clone(), &info.color);
+
+        calendar_list = calendar_list.push(
+            container(color_picker)
+                .padding(PADDING_COLOR_PICKER_NESTED)
+        );
+    }
+
+    calendar_list
+}
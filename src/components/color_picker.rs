@@ -2,6 +2,7 @@ use cosmic::iced::{Color, Length};
 use cosmic::widget::{button, column, container, row};
 use cosmic::{widget, Element};
 
+use crate::fl;
 use crate::message::Message;
 use crate::styles::color_button_style;
 use crate::ui_constants::{
@@ -75,6 +76,7 @@ pub fn render_color_indicator<'a>(
             })
     )
     .on_press(Message::ToggleColorPicker(calendar_id))
+    .name(fl!("calendar-change-color"))
     .padding(0)
     .into()
 }
@@ -102,6 +104,7 @@ pub fn render_color_palette<'a>(calendar_id: String) -> Element<'a, Message> {
                     })
             )
             .on_press(Message::ChangeCalendarColor(calendar_id_clone, hex_owned))
+            .name(fl!("calendar-color-option", color = hex.to_string()))
             .padding(0);
 
             color_row = color_row.push(
@@ -149,6 +152,7 @@ pub fn render_quick_color_picker<'a>(
                     })
             )
             .on_press(Message::ChangeCalendarColor(calendar_id_clone, hex_owned))
+            .name(fl!("calendar-color-option", color = hex.to_string()))
             .padding(0);
 
             color_row = color_row.push(color_button);
@@ -1,50 +1,66 @@
 use chrono::NaiveDate;
 use cosmic::iced::{alignment, Length, Size};
-use cosmic::widget::{column, container, mouse_area, responsive};
+use cosmic::widget::{column, container, mouse_area, responsive, row};
 use cosmic::{widget, Element};
 
 use crate::components::{
     render_compact_events, render_unified_events_with_selection, render_quick_event_input, DisplayEvent,
-    calculate_display_mode, EventDisplayMode,
+    calculate_display_mode, CompactThresholds, EventDisplayMode,
 };
+use crate::components::spacer::fill_spacer;
+use crate::locale::Numerals;
 use crate::message::Message;
+use crate::settings::HighlightColor;
 use crate::styles::{
     today_circle_style, selected_day_style, day_cell_style, adjacent_month_day_style,
     adjacent_month_selected_style, selection_highlight_style, adjacent_month_selection_style,
-    drag_target_style,
+    drag_target_style, event_count_badge_style, free_day_style, holiday_day_style,
 };
-use crate::ui_constants::{PADDING_DAY_CELL, SPACING_SMALL, DAY_HEADER_HEIGHT};
+use crate::ui_constants::{PADDING_DAY_CELL, SPACING_SMALL, DAY_HEADER_HEIGHT, ICON_DAY_NOTE};
 
 /// Size of the circle behind today's day number
 const TODAY_CIRCLE_SIZE: f32 = 32.0;
 
+/// Size of the day note indicator icon
+const DAY_NOTE_ICON_SIZE: u16 = 12;
+
 /// Vertical-only padding for day cells (all-day events need edge-to-edge)
 const PADDING_DAY_CELL_VERTICAL: [u16; 4] = [PADDING_DAY_CELL[0], 0, PADDING_DAY_CELL[2], 0];
 
 /// Apply the appropriate style to a day cell container based on state
 /// Today no longer gets special cell styling - the circle is on the day number
 /// Selected gets a border, drag selection gets highlight, drop target gets accent highlight,
-/// regular cells get weekend background
+/// regular cells get weekend background, or the holiday tint on holidays
 /// Uses vertical-only padding so all-day events can span edge-to-edge
+#[allow(clippy::too_many_arguments)]
 fn apply_day_cell_style<'a>(
     content: impl Into<Element<'a, Message>>,
     is_selected: bool,
     is_in_selection: bool,
     is_drag_target: bool,
     is_weekend: bool,
+    is_holiday: bool,
+    is_free_day: bool,
+    selected_highlight: HighlightColor,
 ) -> container::Container<'a, Message, cosmic::Theme> {
     let base = container(content)
         .padding(PADDING_DAY_CELL_VERTICAL) // Vertical padding only, horizontal handled per-element
         .width(Length::Fill)
         .height(Length::Fill);
+    // Holidays keep a day-off shading under the other states, like weekends
+    let is_weekend = is_weekend || is_holiday;
 
     if is_drag_target {
         // Drop target takes priority - show where the event will land
         base.style(move |theme: &cosmic::Theme| drag_target_style(theme, is_weekend))
     } else if is_selected {
-        base.style(move |theme: &cosmic::Theme| selected_day_style(theme, is_weekend))
+        base.style(move |theme: &cosmic::Theme| selected_day_style(theme, is_weekend, selected_highlight))
     } else if is_in_selection {
         base.style(move |theme: &cosmic::Theme| selection_highlight_style(theme, is_weekend))
+    } else if is_holiday {
+        base.style(|_theme: &cosmic::Theme| holiday_day_style())
+    } else if is_free_day {
+        base.style(move |theme: &cosmic::Theme| free_day_style(theme, is_weekend))
     } else {
         base.style(move |_theme: &cosmic::Theme| day_cell_style(is_weekend))
     }
@@ -55,8 +71,14 @@ pub struct DayCellConfig {
     pub year: i32,
     pub month: u32,
     pub day: u32,
+    /// Digits the day number is written in
+    pub numerals: Numerals,
     pub is_today: bool,
     pub is_selected: bool,
+    /// Color of the circle behind today's day number
+    pub today_highlight: HighlightColor,
+    /// Color of the selected day's border (and the ring around today when both apply)
+    pub selected_highlight: HighlightColor,
     pub is_weekend: bool,
     /// Whether this day is from an adjacent month (shown grayed out)
     pub is_adjacent_month: bool,
@@ -84,6 +106,18 @@ pub struct DayCellConfig {
     pub dragging_event_uid: Option<String>,
     /// Whether this cell is the current drop target
     pub is_drag_target: bool,
+    /// Cell size below which events collapse to compact indicators
+    pub compact_thresholds: CompactThresholds,
+    /// Show a total event count badge when some events are hidden
+    pub show_event_count: bool,
+    /// Dim events that have already ended
+    pub dim_past_events: bool,
+    /// Whether this day is part of a shaded run of free days
+    pub is_free_day: bool,
+    /// Whether a note is attached to this day
+    pub has_day_note: bool,
+    /// Holiday name(s) from holiday overlay calendars, if this day is a holiday
+    pub holiday: Option<String>,
 }
 
 /// Render a day cell with events and optional quick event input
@@ -93,38 +127,13 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
 
     // Use responsive to get actual cell dimensions and adapt display
     let cell_content = responsive(move |size: Size| {
-        let display_mode = calculate_display_mode(size);
+        let display_mode = calculate_display_mode(size, config.compact_thresholds);
 
-        // Day number - with circle background if today (only for current month)
-        let day_number: Element<'static, Message> = if config.is_today && !config.is_adjacent_month {
-            // Today: blue circle behind the day number
-            container(
-                widget::text(config.day.to_string())
-            )
-            .width(Length::Fixed(TODAY_CIRCLE_SIZE))
-            .height(Length::Fixed(TODAY_CIRCLE_SIZE))
-            .center_x(Length::Fixed(TODAY_CIRCLE_SIZE))
-            .center_y(Length::Fixed(TODAY_CIRCLE_SIZE))
-            .style(|theme: &cosmic::Theme| today_circle_style(theme, TODAY_CIRCLE_SIZE))
-            .into()
-        } else {
-            // Regular day number
-            widget::text(config.day.to_string()).into()
-        };
-
-        // Right-align the day number with horizontal padding
-        // Use fixed height to ensure consistent positioning that matches the overlay
-        let header = container(day_number)
-            .width(Length::Fill)
-            .height(Length::Fixed(DAY_HEADER_HEIGHT))
-            .padding([0, PADDING_DAY_CELL[1], 0, PADDING_DAY_CELL[3]]) // horizontal padding for header
-            .align_x(alignment::Horizontal::Right);
-
-        // Build content with day number at top
-        let mut content = column()
-            .spacing(SPACING_SMALL) // More spacing between day number and events
-            .width(Length::Fill)
-            .push(header);
+        // Events go in their own column; the header is added once the hidden count is known
+        let mut events_column = column()
+            .spacing(SPACING_SMALL)
+            .width(Length::Fill);
+        let mut hidden_count = 0;
 
         // Events section - adapts based on display mode
         // Check if we have events OR if there are slots reserved for date events spanning through this day
@@ -136,7 +145,7 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
             if let (Some((ref text, ref color)), EventDisplayMode::Full { .. }) = (&config.quick_event, display_mode) {
                 let quick_event_container = container(render_quick_event_input(text.clone(), color.clone()))
                     .width(Length::Fill);
-                content = content.push(quick_event_container);
+                events_column = events_column.push(quick_event_container);
             }
 
             // Show existing events based on display mode
@@ -156,14 +165,15 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
                         config.week_max_slot,
                     );
 
+                    hidden_count = compact_events.overflow_count;
                     if let Some(compact_element) = compact_events.element {
-                        content = content.push(compact_element);
+                        events_column = events_column.push(compact_element);
                     }
 
                     // Show overflow count as small number if there are hidden events
                     // (only if cell is tall enough)
                     if show_overflow && compact_events.overflow_count > 0 {
-                        content = content.push(
+                        events_column = events_column.push(
                             container(
                                 widget::text(format!("+{}", compact_events.overflow_count))
                                     .size(8)
@@ -182,20 +192,23 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
                         config.selected_event_uid.as_deref(),
                         config.event_drag_active,
                         config.dragging_event_uid.as_deref(),
+                        config.dim_past_events,
                     );
 
+                    hidden_count = unified.overflow_count;
+
                     // Single container for all events (placeholders + timed)
                     // Edge-to-edge width, clip overflow
                     if let Some(events_element) = unified.events {
                         let events_container = container(events_element)
                             .width(Length::Fill)
                             .clip(true);
-                        content = content.push(events_container);
+                        events_column = events_column.push(events_container);
                     }
 
                     // Show "+N more" if there are hidden events (only if cell is tall enough)
                     if show_overflow && unified.overflow_count > 0 {
-                        content = content.push(
+                        events_column = events_column.push(
                             container(
                                 widget::text(format!("+{} more", unified.overflow_count))
                                     .size(10)
@@ -207,15 +220,91 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
             }
         }
 
+        // Day number - with circle background if today (only for current month)
+        let day_number: Element<'static, Message> = if config.is_today && !config.is_adjacent_month {
+            // Today: highlight circle behind the day number, ringed when also selected
+            let today_highlight = config.today_highlight;
+            let selected_ring = config.is_selected.then_some(config.selected_highlight);
+            container(
+                widget::text(config.numerals.format_number(config.day))
+            )
+            .width(Length::Fixed(TODAY_CIRCLE_SIZE))
+            .height(Length::Fixed(TODAY_CIRCLE_SIZE))
+            .center_x(Length::Fixed(TODAY_CIRCLE_SIZE))
+            .center_y(Length::Fixed(TODAY_CIRCLE_SIZE))
+            .style(move |theme: &cosmic::Theme| {
+                today_circle_style(theme, TODAY_CIRCLE_SIZE, today_highlight, selected_ring)
+            })
+            .into()
+        } else {
+            // Regular day number
+            widget::text(config.numerals.format_number(config.day)).into()
+        };
+
+        // Busyness badge: total event count when some events don't fit in the cell
+        let show_count_badge = config.show_event_count && hidden_count > 0;
+        let header_content: Element<'static, Message> = if show_count_badge || config.has_day_note || config.holiday.is_some() {
+            let mut header_row = row()
+                .spacing(SPACING_SMALL)
+                .align_y(alignment::Vertical::Center);
+            if show_count_badge {
+                header_row = header_row.push(
+                    container(widget::text(config.events.len().to_string()).size(9))
+                        .padding([0, 4])
+                        .style(event_count_badge_style),
+                );
+            }
+            // Day note indicator - opens the note editor
+            if let (true, Some(date)) = (config.has_day_note, date) {
+                header_row = header_row.push(
+                    widget::button::icon(widget::icon::from_name(ICON_DAY_NOTE).size(DAY_NOTE_ICON_SIZE))
+                        .padding(0)
+                        .on_press(Message::EditDayNote(date)),
+                );
+            }
+            // Holiday name, clipped to the space left of the day number
+            if let Some(holiday) = &config.holiday {
+                header_row = header_row.push(
+                    widget::text(holiday.clone())
+                        .size(10)
+                        .wrapping(cosmic::iced::widget::text::Wrapping::None),
+                );
+            }
+            header_row
+                .push(fill_spacer())
+                .push(day_number)
+                .into()
+        } else {
+            day_number
+        };
+
+        // Right-align the day number with horizontal padding
+        // Use fixed height to ensure consistent positioning that matches the overlay
+        let header = container(header_content)
+            .width(Length::Fill)
+            .height(Length::Fixed(DAY_HEADER_HEIGHT))
+            .padding([0, PADDING_DAY_CELL[1], 0, PADDING_DAY_CELL[3]]) // horizontal padding for header
+            .align_x(alignment::Horizontal::Right);
+
+        // Build content with day number at top
+        let mut content = column()
+            .spacing(SPACING_SMALL) // More spacing between day number and events
+            .width(Length::Fill)
+            .push(header);
+        if has_events {
+            content = content.push(events_column);
+        }
+
         // Build styled container based on state
         let styled: Element<'static, Message> = if config.is_adjacent_month {
             // Adjacent month: grayed out style, but show selection/highlight if applicable
             if config.is_selected {
+                let selected_highlight = config.selected_highlight;
                 container(content)
                     .padding(PADDING_DAY_CELL_VERTICAL)
                     .width(Length::Fill)
                     .height(Length::Fill)
-                    .style(|theme: &cosmic::Theme| adjacent_month_selected_style(theme))
+                    .style(move |theme: &cosmic::Theme| adjacent_month_selected_style(theme, selected_highlight))
                     .into()
             } else if config.is_in_selection {
                 container(content)
@@ -240,6 +329,9 @@ pub fn render_day_cell_with_events(config: DayCellConfig) -> Element<'static, Me
                 config.is_in_selection,
                 config.is_drag_target,
                 config.is_weekend,
+                config.holiday.is_some(),
+                config.is_free_day,
+                config.selected_highlight,
             ).into()
         };
 
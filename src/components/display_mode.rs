@@ -19,6 +19,34 @@ const PADDING_DAY_CELL_VERTICAL_TOP: f32 = PADDING_DAY_CELL[0] as f32;
 /// Spacing between events (derived from SPACING_TINY)
 const EVENT_SPACING: f32 = SPACING_TINY as f32;
 
+/// Cell size below which events collapse to compact indicators.
+///
+/// Defaults to the layout constants; users can scale it through the
+/// compact sensitivity setting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompactThresholds {
+    /// Cells narrower than this use compact mode
+    pub min_width: f32,
+    /// Cells shorter than this use compact mode
+    pub min_height: f32,
+}
+
+impl CompactThresholds {
+    /// Default thresholds multiplied by `factor` (larger collapses sooner)
+    pub fn scaled(factor: f32) -> Self {
+        Self {
+            min_width: MIN_CELL_WIDTH_FOR_FULL_EVENTS * factor,
+            min_height: MIN_CELL_HEIGHT_FOR_FULL_EVENTS * factor,
+        }
+    }
+}
+
+impl Default for CompactThresholds {
+    fn default() -> Self {
+        Self::scaled(1.0)
+    }
+}
+
 /// Display mode for events based on available cell size.
 ///
 /// This enum determines how events are rendered in day cells and overlays:
@@ -74,12 +102,12 @@ impl EventDisplayMode {
 ///
 /// # Arguments
 /// * `cell_size` - The dimensions of the day cell
+/// * `thresholds` - Cell size below which compact mode is used
 ///
 /// # Returns
 /// The appropriate `EventDisplayMode` for the given cell size
-pub fn calculate_display_mode(cell_size: Size) -> EventDisplayMode {
-    let use_compact = cell_size.height < MIN_CELL_HEIGHT_FOR_FULL_EVENTS
-        || cell_size.width < MIN_CELL_WIDTH_FOR_FULL_EVENTS;
+pub fn calculate_display_mode(cell_size: Size, thresholds: CompactThresholds) -> EventDisplayMode {
+    let use_compact = should_use_compact(cell_size.width, cell_size.height, thresholds);
 
     // Whether to show overflow indicator at all
     let show_overflow = cell_size.height >= MIN_CELL_HEIGHT_FOR_OVERFLOW;
@@ -114,10 +142,11 @@ pub fn calculate_display_mode(cell_size: Size) -> EventDisplayMode {
 /// # Arguments
 /// * `cell_width` - Width of the cell
 /// * `cell_height` - Height of the cell
+/// * `thresholds` - Cell size below which compact mode is used
 ///
 /// # Returns
 /// `true` if compact mode should be used
-pub fn should_use_compact(cell_width: f32, cell_height: f32) -> bool {
-    cell_height < MIN_CELL_HEIGHT_FOR_FULL_EVENTS
-        || cell_width < MIN_CELL_WIDTH_FOR_FULL_EVENTS
+pub fn should_use_compact(cell_width: f32, cell_height: f32, thresholds: CompactThresholds) -> bool {
+    cell_height < thresholds.min_height
+        || cell_width < thresholds.min_width
 }
@@ -0,0 +1,32 @@
+//! Hint shown over a day, week or month view that has no events
+//!
+//! Keeps a new, empty calendar from looking like a blank grid.
+
+use cosmic::iced::{alignment, Length};
+use cosmic::widget::container;
+use cosmic::{widget, Element};
+
+use crate::message::Message;
+use crate::styles::overlay_sidebar_style;
+use crate::ui_constants::{BORDER_RADIUS, PADDING_MEDIUM};
+
+/// Render the empty-state hint centered over the calendar view
+/// The surrounding container fills the view but lets clicks through to the grid
+pub fn render_empty_state(hint: String) -> Element<'static, Message> {
+    let hint = container(widget::text::caption(hint))
+        .padding(PADDING_MEDIUM)
+        .style(|theme: &cosmic::Theme| container::Style {
+            border: cosmic::iced::Border {
+                radius: BORDER_RADIUS.into(),
+                ..Default::default()
+            },
+            ..overlay_sidebar_style(theme)
+        });
+
+    container(hint)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(alignment::Horizontal::Center)
+        .align_y(alignment::Vertical::Center)
+        .into()
+}
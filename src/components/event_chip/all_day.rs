@@ -1,15 +1,18 @@
 //! All-day event chip rendering
 //!
 //! Renders all-day events as colored background bars.
+//! A leading emoji in the summary is shown as a separate icon before the title.
 
 use cosmic::iced::Length;
 use cosmic::iced::widget::text::Wrapping;
-use cosmic::widget::container;
+use cosmic::widget::{container, row};
 use cosmic::{widget, Element};
 
+use crate::caldav::ShowAs;
 use crate::message::Message;
-use crate::ui_constants::{BORDER_RADIUS, BORDER_WIDTH_HIGHLIGHT};
+use crate::ui_constants::{BORDER_RADIUS, BORDER_WIDTH_HIGHLIGHT, SPACING_XXS};
 
+use super::emoji::split_leading_emoji;
 use super::types::{ChipOpacity, ChipSelectionState, SpanPosition, span_border_radius, span_padding};
 
 /// Render an all-day event chip with colored background bar.
@@ -18,20 +21,30 @@ use super::types::{ChipOpacity, ChipSelectionState, SpanPosition, span_border_ra
 /// * `summary` - Event title to display
 /// * `color` - Event calendar color
 /// * `span_position` - Position within a multi-day span (affects border radius)
+/// * `show_as` - Event availability (tentative is lighter and outlined, free is hollow)
 /// * `selection` - Optional selection state for interactive chips; None for simple display
 pub fn render_all_day_chip(
     summary: String,
     color: cosmic::iced::Color,
     span_position: SpanPosition,
+    show_as: ShowAs,
     selection: Option<ChipSelectionState>,
 ) -> Element<'static, Message> {
     let border_radius = span_border_radius(span_position, BORDER_RADIUS[0]);
     let padding = span_padding(span_position);
 
-    let content: Element<'static, Message> = widget::text(summary)
-        .size(11)
-        .wrapping(Wrapping::None)
-        .into();
+    let content: Element<'static, Message> = match split_leading_emoji(&summary) {
+        Some((emoji, rest)) => row()
+            .spacing(SPACING_XXS)
+            .align_y(cosmic::iced::Alignment::Center)
+            .push(widget::text(emoji.to_string()).size(11).wrapping(Wrapping::None))
+            .push(widget::text(rest.to_string()).size(11).wrapping(Wrapping::None))
+            .into(),
+        None => widget::text(summary)
+            .size(11)
+            .wrapping(Wrapping::None)
+            .into(),
+    };
 
     // Calculate opacity based on selection state
     let opacity = selection.map_or(
@@ -39,6 +52,7 @@ pub fn render_all_day_chip(
         |s| ChipOpacity::from_state(s.is_selected, s.is_being_dragged)
     );
     let is_selected = selection.map_or(false, |s| s.is_selected);
+    let (fill, outline) = ChipOpacity::show_as_style(show_as);
 
     container(content)
         .padding(padding)
@@ -47,11 +61,11 @@ pub fn render_all_day_chip(
         .style(move |_theme: &cosmic::Theme| {
             container::Style {
                 background: Some(cosmic::iced::Background::Color(
-                    color.scale_alpha(opacity.background)
+                    color.scale_alpha(opacity.background * fill)
                 )),
                 border: cosmic::iced::Border {
-                    color: if is_selected { color } else { cosmic::iced::Color::TRANSPARENT },
-                    width: if is_selected { BORDER_WIDTH_HIGHLIGHT } else { 0.0 },
+                    color: if is_selected || outline > 0.0 { color } else { cosmic::iced::Color::TRANSPARENT },
+                    width: if is_selected { BORDER_WIDTH_HIGHLIGHT } else { outline },
                     radius: border_radius.into(),
                 },
                 text_color: Some(color.scale_alpha(opacity.text)),
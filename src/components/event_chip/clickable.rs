@@ -31,9 +31,9 @@ pub fn render_event_chip(event: DisplayEvent, current_date: NaiveDate) -> Elemen
     if event.all_day {
         // Calculate span position for multi-day events
         let span_position = event.span_position_for_date(current_date);
-        render_all_day_chip(event.summary, color, span_position, None)
+        render_all_day_chip(event.display_summary(), color, span_position, event.show_as, None)
     } else {
-        render_timed_event_chip(event.summary, event.start_time, color, None, is_past)
+        render_timed_event_chip(event.display_summary(), event.start_time, color, event.show_as, None, is_past)
     }
 }
 
@@ -72,30 +72,32 @@ fn is_event_past(event: &DisplayEvent, current_date: NaiveDate) -> bool {
 /// * `is_selected` - Whether this event is currently selected
 /// * `is_drag_active` - Whether any event drag is currently active
 /// * `is_being_dragged` - Whether this specific event is currently being dragged (for dimming)
+/// * `dim_past` - Whether events that have already ended are dimmed
 pub fn render_clickable_event_chip(
     event: DisplayEvent,
     current_date: NaiveDate,
     is_selected: bool,
     is_drag_active: bool,
     is_being_dragged: bool,
+    dim_past: bool,
 ) -> Element<'static, Message> {
     let calendar_id = event.calendar_id.clone();
     let uid = event.uid.clone();
     let color = parse_hex_color(&event.color).unwrap_or(COLOR_DEFAULT_GRAY);
     // Clone summary and color_hex for the drag preview message (before they're moved into chip)
-    let drag_summary = event.summary.clone();
+    let drag_summary = event.title();
     let drag_color = event.color.clone();
 
     // Check if this event is in the past
-    let is_past = is_event_past(&event, current_date);
+    let is_past = dim_past && is_event_past(&event, current_date);
 
     let selection = Some(ChipSelectionState::new(is_selected, is_being_dragged));
 
     let chip = if event.all_day {
         let span_position = event.span_position_for_date(current_date);
-        render_all_day_chip(event.summary, color, span_position, selection)
+        render_all_day_chip(event.display_summary(), color, span_position, event.show_as, selection)
     } else {
-        render_timed_event_chip(event.summary, event.start_time, color, selection, is_past)
+        render_timed_event_chip(event.display_summary(), event.start_time, color, event.show_as, selection, is_past)
     };
 
     // Wrap with mouse area for click/drag handling
@@ -68,12 +68,10 @@ pub fn render_compact_events(
     week_max_slot: Option<usize>,
 ) -> CompactEventsResult {
     // Separate all-day and timed events
-    let (all_day_events, mut timed_events): (Vec<_>, Vec<_>) =
+    // Timed events keep the day's configured sort order from the event cache
+    let (all_day_events, timed_events): (Vec<_>, Vec<_>) =
         events.into_iter().partition(|e| e.all_day);
 
-    // Sort timed events by start time
-    timed_events.sort_by(|a, b| a.start_time.cmp(&b.start_time));
-
     // Calculate total slots from week_max_slot
     let total_slots = week_max_slot.map(|m| m + 1).unwrap_or(0);
     let total_events = all_day_events.len() + timed_events.len();
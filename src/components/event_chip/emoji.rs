@@ -0,0 +1,120 @@
+//! Leading emoji detection for event summaries
+//!
+//! Events whose summary starts with an emoji (e.g. "🎂 Birthday") show that
+//! emoji as the chip's leading icon and the remaining text as the title.
+
+/// Split a leading emoji off a summary, returning (emoji, remaining title)
+/// Returns None if the summary doesn't start with an emoji or has no text after it
+pub fn split_leading_emoji(summary: &str) -> Option<(&str, &str)> {
+    let mut chars = summary.char_indices().peekable();
+    let (_, first) = chars.next()?;
+    if !is_emoji_base(first) {
+        return None;
+    }
+
+    // Symbols shown as text by default (✓, ★) are only emoji with a following U+FE0F
+    let has_variation_selector = chars.peek().is_some_and(|&(_, c)| c == '\u{FE0F}');
+    if !has_emoji_presentation(first) && !has_variation_selector {
+        return None;
+    }
+
+    // Regional indicators only form a flag in pairs
+    if is_regional_indicator(first) {
+        match chars.next() {
+            Some((_, c)) if is_regional_indicator(c) => {}
+            _ => return None,
+        }
+    }
+
+    // Consume modifiers and ZWJ sequences belonging to the same grapheme
+    let mut end = summary.len();
+    while let Some(&(idx, c)) = chars.peek() {
+        if is_emoji_modifier(c) {
+            chars.next();
+        } else if c == '\u{200D}' {
+            chars.next();
+            // The joined character is part of the sequence
+            if chars.next().is_none() {
+                break;
+            }
+        } else {
+            end = idx;
+            break;
+        }
+    }
+
+    let emoji = &summary[..end];
+    let rest = summary[end..].trim_start();
+    if rest.is_empty() {
+        return None;
+    }
+    Some((emoji, rest))
+}
+
+/// Characters that can start an emoji grapheme
+fn is_emoji_base(c: char) -> bool {
+    matches!(c as u32,
+        0x1F300..=0x1F5FF   // Misc symbols and pictographs
+        | 0x1F600..=0x1F64F // Emoticons
+        | 0x1F680..=0x1F6FF // Transport and map
+        | 0x1F900..=0x1F9FF // Supplemental symbols and pictographs
+        | 0x1FA70..=0x1FAFF // Symbols and pictographs extended-A
+        | 0x2600..=0x26FF   // Misc symbols
+        | 0x2700..=0x27BF   // Dingbats
+        | 0x1F1E6..=0x1F1FF // Regional indicators (flags)
+    )
+}
+
+/// Emoji base characters shown as emoji by default (Unicode Emoji_Presentation)
+/// Pictographs from U+1F000 up are nearly all shown as emoji, so they all count;
+/// in the misc symbols and dingbats blocks only these are, the rest needs U+FE0F
+fn has_emoji_presentation(c: char) -> bool {
+    matches!(c as u32,
+        0x1F000..=0x10FFFF
+        | 0x2614..=0x2615 | 0x2648..=0x2653 | 0x267F | 0x2693 | 0x26A1
+        | 0x26AA..=0x26AB | 0x26BD..=0x26BE | 0x26C4..=0x26C5 | 0x26CE | 0x26D4
+        | 0x26EA | 0x26F2..=0x26F3 | 0x26F5 | 0x26FA | 0x26FD
+        | 0x2705 | 0x270A..=0x270B | 0x2728 | 0x274C | 0x274E | 0x2753..=0x2755
+        | 0x2757 | 0x2795..=0x2797 | 0x27B0 | 0x27BF
+    )
+}
+
+/// Regional indicator symbols, combined in pairs to form flags
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c as u32, 0x1F1E6..=0x1F1FF)
+}
+
+/// Characters that modify the preceding emoji (variation selector, skin tones, keycap)
+fn is_emoji_modifier(c: char) -> bool {
+    matches!(c as u32, 0xFE0F | 0x1F3FB..=0x1F3FF | 0x20E3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_simple_emoji() {
+        assert_eq!(split_leading_emoji("🎂 Birthday"), Some(("🎂", "Birthday")));
+        assert_eq!(split_leading_emoji("☕Coffee"), Some(("☕", "Coffee")));
+    }
+
+    #[test]
+    fn test_split_emoji_sequences() {
+        assert_eq!(split_leading_emoji("❤️ Date night"), Some(("❤️", "Date night")));
+        assert_eq!(split_leading_emoji("👍🏽 Review"), Some(("👍🏽", "Review")));
+        assert_eq!(split_leading_emoji("👩‍💻 Hackathon"), Some(("👩‍💻", "Hackathon")));
+        assert_eq!(split_leading_emoji("🇩🇪 Holiday"), Some(("🇩🇪", "Holiday")));
+    }
+
+    #[test]
+    fn test_no_split() {
+        assert_eq!(split_leading_emoji("Meeting 🎉"), None);
+        // Text-style symbols aren't emoji without U+FE0F
+        assert_eq!(split_leading_emoji("✓ Done"), None);
+        assert_eq!(split_leading_emoji("★ Favorite"), None);
+        assert_eq!(split_leading_emoji("★\u{FE0F} Favorite"), Some(("★\u{FE0F}", "Favorite")));
+        assert_eq!(split_leading_emoji("🎉"), None);
+        assert_eq!(split_leading_emoji(""), None);
+    }
+}
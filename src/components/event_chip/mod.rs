@@ -6,23 +6,28 @@
 //! - `timed`: Timed event chip rendering
 //! - `clickable`: Clickable event chip wrapper
 //! - `quick_event`: Quick event input fields
+//! - `rename`: Inline rename input for event titles
 //! - `unified`: Unified events column rendering
 //! - `compact`: Compact events rendering
+//! - `emoji`: Leading emoji detection for chip icons
 
 mod all_day;
 mod clickable;
 mod compact;
+mod emoji;
 mod quick_event;
+mod rename;
 mod timed;
 mod types;
 mod unified;
 
 // Re-export public types (only what's actually used externally)
-pub use types::{ChipOpacity, DisplayEvent, span_border_radius_from_flags};
+pub use types::{ChipOpacity, DisplayEvent, SpanPosition, end_of_day, event_title, span_border_radius_from_flags, timed_span_border_radius};
 
 // Re-export rendering functions (only what's actually used externally)
 pub use compact::render_compact_events;
 pub use quick_event::{
     quick_event_input_id, render_quick_event_input, render_spanning_quick_event_input,
 };
+pub use rename::{inline_rename_input_id, render_inline_rename_input};
 pub use unified::render_unified_events_with_selection;
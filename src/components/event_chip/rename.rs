@@ -0,0 +1,27 @@
+//! Inline rename input rendering
+//!
+//! Text input that replaces an event chip's label while its title is being
+//! edited in place.
+
+use cosmic::iced::Length;
+use cosmic::iced_widget::text_input;
+use cosmic::Element;
+
+use crate::message::Message;
+
+/// ID for the inline rename text input - used for auto-focus
+pub fn inline_rename_input_id() -> text_input::Id {
+    text_input::Id::new("inline_rename_input")
+}
+
+/// Render the inline rename input shown in place of a chip label
+pub fn render_inline_rename_input(text: String) -> Element<'static, Message> {
+    text_input("", &text)
+        .id(inline_rename_input_id())
+        .on_input(Message::InlineRenameChanged)
+        .on_submit(Message::CommitInlineRename)
+        .size(10)
+        .padding([0, 2])
+        .width(Length::Fill)
+        .into()
+}
@@ -1,6 +1,7 @@
 //! Timed event chip rendering
 //!
 //! Renders timed events with colored dot + time + name.
+//! A leading emoji in the summary replaces the dot as the chip's icon.
 
 use chrono::NaiveTime;
 use cosmic::iced::Length;
@@ -8,9 +9,11 @@ use cosmic::iced::widget::text::Wrapping;
 use cosmic::widget::{container, row};
 use cosmic::{widget, Element};
 
+use crate::caldav::ShowAs;
 use crate::message::Message;
 use crate::ui_constants::{SPACING_XXS, BORDER_RADIUS, BORDER_WIDTH_HIGHLIGHT};
 
+use super::emoji::split_leading_emoji;
 use super::types::{ChipOpacity, ChipSelectionState};
 
 /// Size of the colored dot for timed events
@@ -22,12 +25,14 @@ const TIMED_EVENT_DOT_SIZE: f32 = 8.0;
 /// * `summary` - Event title to display
 /// * `start_time` - Optional start time to display before the title
 /// * `color` - Event calendar color
+/// * `show_as` - Event availability (tentative dot is lighter, free dot is a hollow ring)
 /// * `selection` - Optional selection state for interactive chips; None for simple display
 /// * `is_past` - Whether this event is in the past (for dimming)
 pub fn render_timed_event_chip(
     summary: String,
     start_time: Option<NaiveTime>,
     color: cosmic::iced::Color,
+    show_as: ShowAs,
     selection: Option<ChipSelectionState>,
     is_past: bool,
 ) -> Element<'static, Message> {
@@ -38,6 +43,13 @@ pub fn render_timed_event_chip(
     // Apply past dimming factor to dot
     let base_dot_opacity = ChipOpacity::dot_opacity(is_being_dragged);
     let dot_opacity = if is_past { base_dot_opacity * 0.5 } else { base_dot_opacity };
+    let (dot_fill, dot_outline) = ChipOpacity::show_as_style(show_as);
+
+    // Leading emoji replaces the colored dot as the chip icon
+    let (emoji, summary) = match split_leading_emoji(&summary) {
+        Some((emoji, rest)) => (Some(emoji.to_string()), rest.to_string()),
+        None => (None, summary),
+    };
 
     // Colored dot
     let dot = container(widget::text(""))
@@ -45,10 +57,10 @@ pub fn render_timed_event_chip(
         .height(Length::Fixed(TIMED_EVENT_DOT_SIZE))
         .style(move |_theme: &cosmic::Theme| {
             container::Style {
-                background: Some(cosmic::iced::Background::Color(color.scale_alpha(dot_opacity))),
+                background: Some(cosmic::iced::Background::Color(color.scale_alpha(dot_opacity * dot_fill))),
                 border: cosmic::iced::Border {
-                    color: cosmic::iced::Color::TRANSPARENT,
-                    width: 0.0,
+                    color: color.scale_alpha(dot_opacity),
+                    width: dot_outline,
                     radius: (TIMED_EVENT_DOT_SIZE / 2.0).into(), // Circular
                 },
                 ..Default::default()
@@ -69,12 +81,20 @@ pub fn render_timed_event_chip(
     // Text opacity for past events
     let text_alpha = if is_past { 0.5 } else { 1.0 };
 
+    let icon: Element<'static, Message> = match emoji {
+        Some(emoji) => widget::text(emoji)
+            .size(11)
+            .wrapping(Wrapping::None)
+            .into(),
+        None => dot.into(),
+    };
+
     // Wrap in container with clip to truncate long text
     container(
         row()
             .spacing(SPACING_XXS)
             .align_y(cosmic::iced::Alignment::Center)
-            .push(dot)
+            .push(icon)
             .push(text)
     )
     .width(Length::Fill)
@@ -3,6 +3,19 @@
 //! Core types used across event chip rendering.
 
 use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use crate::caldav::{PriorityLevel, ShowAs};
+use crate::fl;
+use crate::ui_constants::BORDER_WIDTH_NORMAL;
+
+use super::emoji::split_leading_emoji;
+
+/// Marker put in front of the title of high-priority events
+const HIGH_PRIORITY_MARKER: &str = "!";
+
+/// Maximum number of characters of the notes kept for previews
+const NOTES_PREVIEW_CHARS: usize = 120;
 
 /// Position within a multi-day event span
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,6 +54,35 @@ pub fn span_border_radius(span_position: SpanPosition, radius: f32) -> [f32; 4]
     }
 }
 
+/// Calculate border radius for a timed event segment in the week/day time grid.
+/// Segments stack vertically, so the top corners belong to the first day and the
+/// bottom corners to the last day.
+/// Returns [top-left, top-right, bottom-right, bottom-left] radii.
+pub fn timed_span_border_radius(span_position: SpanPosition, radius: f32) -> [f32; 4] {
+    match span_position {
+        SpanPosition::Single => [radius, radius, radius, radius],
+        SpanPosition::First => [radius, radius, 0.0, 0.0],
+        SpanPosition::Middle => [0.0, 0.0, 0.0, 0.0],
+        SpanPosition::Last => [0.0, 0.0, radius, radius],
+    }
+}
+
+/// Last representable time of a day, used as the end of timed segments that
+/// continue past midnight
+pub fn end_of_day() -> NaiveTime {
+    NaiveTime::from_hms_opt(23, 59, 59).unwrap_or_default()
+}
+
+/// Title to show for an event, with a placeholder for events without one
+/// (imported events may have an empty summary)
+pub fn event_title(summary: &str) -> String {
+    if summary.trim().is_empty() {
+        fl!("event-no-title")
+    } else {
+        summary.to_string()
+    }
+}
+
 /// Calculate border radius from start/end boolean flags.
 /// Convenience function that combines from_start_end and span_border_radius.
 pub fn span_border_radius_from_flags(is_start: bool, is_end: bool, radius: f32) -> [f32; 4] {
@@ -120,6 +162,17 @@ impl ChipOpacity {
         let bg = if is_past { base_bg * PAST_EVENT_DIM_FACTOR } else { base_bg };
         (bg, border)
     }
+
+    /// Get the fill multiplier and outline width for an event's availability.
+    /// Returns (background_multiplier, outline_width) tuple.
+    /// Tentative events get a lighter fill with an outline, free events are hollow.
+    pub fn show_as_style(show_as: ShowAs) -> (f32, f32) {
+        match show_as {
+            ShowAs::Busy => (1.0, 0.0),
+            ShowAs::Tentative => (0.5, BORDER_WIDTH_NORMAL),
+            ShowAs::Free => (0.0, BORDER_WIDTH_NORMAL),
+        }
+    }
 }
 
 /// Selection and drag state for event chips.
@@ -141,7 +194,7 @@ impl ChipSelectionState {
 }
 
 /// Event with associated calendar color for display
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DisplayEvent {
     pub calendar_id: String, // Calendar ID for unique identification
     pub uid: String,
@@ -150,6 +203,18 @@ pub struct DisplayEvent {
     pub all_day: bool,      // Whether this is an all-day event
     pub start_time: Option<NaiveTime>, // Start time for timed events
     pub end_time: Option<NaiveTime>,   // End time for timed events (for week/day view positioning)
+    pub show_as: ShowAs,    // Busy/tentative/free availability (affects chip fill)
+    #[serde(default)]
+    pub priority: Option<u8>, // iCalendar PRIORITY (high priority gets a title marker)
+    /// Event location (shown in the hover tooltip)
+    #[serde(default)]
+    pub location: Option<String>,
+    /// Start of the event notes (shown in the hover tooltip)
+    #[serde(default)]
+    pub notes_preview: Option<String>,
+    /// Manual position among the day's all-day events (None sorts by the configured order)
+    #[serde(default)]
+    pub display_order: Option<u32>,
     /// Start date of the event span (for multi-day events)
     pub span_start: Option<NaiveDate>,
     /// End date of the event span (for multi-day events)
@@ -157,12 +222,43 @@ pub struct DisplayEvent {
 }
 
 impl DisplayEvent {
+    /// Shorten event notes to a single-line preview
+    pub fn preview_notes(notes: Option<&str>) -> Option<String> {
+        let text = notes?.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            return None;
+        }
+        if text.chars().count() <= NOTES_PREVIEW_CHARS {
+            return Some(text);
+        }
+        let cut: String = text.chars().take(NOTES_PREVIEW_CHARS).collect();
+        Some(format!("{}…", cut.trim_end()))
+    }
+
     /// Get a unique identifier for this event that includes the calendar
     /// This ensures events with the same UID from different calendars are treated as distinct
     pub fn unique_id(&self) -> String {
         format!("{}:{}", self.calendar_id, self.uid)
     }
 
+    /// Event title, or the placeholder for untitled events
+    pub fn title(&self) -> String {
+        event_title(&self.summary)
+    }
+
+    /// Summary as shown on chips, with a marker for high-priority events
+    /// The marker goes after a leading emoji so the emoji still becomes the chip icon
+    pub fn display_summary(&self) -> String {
+        let title = self.title();
+        if PriorityLevel::from_priority(self.priority) != PriorityLevel::High {
+            return title;
+        }
+        match split_leading_emoji(&title) {
+            Some((emoji, rest)) => format!("{} {} {}", emoji, HIGH_PRIORITY_MARKER, rest),
+            None => format!("{} {}", HIGH_PRIORITY_MARKER, title),
+        }
+    }
+
     /// Check if this is a multi-day all-day event
     pub fn is_multi_day(&self) -> bool {
         self.all_day
@@ -171,6 +267,14 @@ impl DisplayEvent {
             && self.span_start != self.span_end
     }
 
+    /// Check if this is a timed event spanning several days
+    pub fn is_timed_span(&self) -> bool {
+        !self.all_day
+            && self.span_start.is_some()
+            && self.span_end.is_some()
+            && self.span_start != self.span_end
+    }
+
     /// Get the span position for a given date within this event
     pub fn span_position_for_date(&self, date: NaiveDate) -> SpanPosition {
         match (self.span_start, self.span_end) {
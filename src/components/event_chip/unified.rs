@@ -51,7 +51,7 @@ pub fn render_unified_events(
 ) -> UnifiedEventsResult {
     // Use empty set for day_occupied_slots - this legacy function doesn't do Tetris-style rendering
     let empty_slots = std::collections::HashSet::new();
-    render_unified_events_with_selection(events, max_visible, current_date, week_max_slot, &empty_slots, None, false, None)
+    render_unified_events_with_selection(events, max_visible, current_date, week_max_slot, &empty_slots, None, false, None, true)
 }
 
 /// Render events as a unified column with selection support.
@@ -66,6 +66,8 @@ pub fn render_unified_events(
 /// * `day_occupied_slots` - Slots occupied by date events on THIS specific day
 /// * `selected_event_uid` - UID of the currently selected event (if any)
 /// * `dragging_event_uid` - UID of the event currently being dragged (if any)
+/// * `dim_past` - Whether events that have already ended are dimmed
+#[allow(clippy::too_many_arguments)]
 pub fn render_unified_events_with_selection(
     events: Vec<DisplayEvent>,
     max_visible: usize,
@@ -75,14 +77,13 @@ pub fn render_unified_events_with_selection(
     selected_event_uid: Option<&str>,
     is_drag_active: bool,
     dragging_event_uid: Option<&str>,
+    dim_past: bool,
 ) -> UnifiedEventsResult {
     // Separate all-day and timed events
-    let (all_day_events, mut timed_events): (Vec<_>, Vec<_>) =
+    // Timed events keep the day's configured sort order from the event cache
+    let (all_day_events, timed_events): (Vec<_>, Vec<_>) =
         events.into_iter().partition(|e| e.all_day);
 
-    // Sort timed events by start time
-    timed_events.sort_by(|a, b| a.start_time.cmp(&b.start_time));
-
     // Calculate total slots from week_max_slot
     // The overlay renders slots 0..=max_slot
     let total_slots = week_max_slot.map(|m| m + 1).unwrap_or(0);
@@ -115,7 +116,7 @@ pub fn render_unified_events_with_selection(
                 let event_unique_id = event.unique_id();
                 let is_selected = selected_event_uid.map_or(false, |uid| uid == event_unique_id);
                 let is_being_dragged = dragging_event_uid.map_or(false, |uid| uid == event_unique_id);
-                col = col.push(render_clickable_event_chip(event, current_date, is_selected, is_drag_active, is_being_dragged));
+                col = col.push(render_clickable_event_chip(event, current_date, is_selected, is_drag_active, is_being_dragged, dim_past));
             } else {
                 // No more timed events - render placeholder to maintain slot alignment
                 col = col.push(render_empty_slot_placeholder());
@@ -132,7 +133,7 @@ pub fn render_unified_events_with_selection(
         let event_unique_id = event.unique_id();
         let is_selected = selected_event_uid.map_or(false, |uid| uid == event_unique_id);
         let is_being_dragged = dragging_event_uid.map_or(false, |uid| uid == event_unique_id);
-        col = col.push(render_clickable_event_chip(event, current_date, is_selected, is_drag_active, is_being_dragged));
+        col = col.push(render_clickable_event_chip(event, current_date, is_selected, is_drag_active, is_being_dragged, dim_past));
         shown += 1;
     }
 
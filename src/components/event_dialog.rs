@@ -6,14 +6,19 @@ use cosmic::iced::widget::stack;
 use cosmic::iced::Length;
 use cosmic::widget::{button, calendar, column, container, mouse_area, popover, row, scrollable, settings, text, text_editor, toggler};
 use cosmic::widget::editable_input;
+use cosmic::iced_widget::text_input;
 use cosmic::{widget, Element};
 
 use crate::app::{EventDialogField, EventDialogState};
-use crate::caldav::{AlertTime, RepeatFrequency, TravelTime};
-use crate::calendars::CalendarSource;
+use crate::caldav::{AlertTime, PriorityLevel, RepeatFrequency, TravelTime};
+use crate::calendars::{CalendarManager, CalendarSource};
+use crate::components::markdown::render_markdown;
 use crate::fl;
 use crate::message::Message;
 
+/// Number of upcoming occurrences listed under the repeat options
+const RECURRENCE_PREVIEW_COUNT: usize = 5;
+
 /// Helper to format TravelTime for display
 fn travel_time_label(tt: &TravelTime) -> String {
     match tt {
@@ -31,7 +36,7 @@ fn travel_time_label(tt: &TravelTime) -> String {
 }
 
 /// Helper to format RepeatFrequency for display
-fn repeat_label(repeat: &RepeatFrequency) -> String {
+pub fn repeat_label(repeat: &RepeatFrequency) -> String {
     match repeat {
         RepeatFrequency::Never => fl!("repeat-never"),
         RepeatFrequency::Daily => fl!("repeat-daily"),
@@ -43,6 +48,16 @@ fn repeat_label(repeat: &RepeatFrequency) -> String {
     }
 }
 
+/// Helper to format PriorityLevel for display
+fn priority_label(level: PriorityLevel) -> String {
+    match level {
+        PriorityLevel::None => fl!("priority-none"),
+        PriorityLevel::High => fl!("priority-high"),
+        PriorityLevel::Medium => fl!("priority-medium"),
+        PriorityLevel::Low => fl!("priority-low"),
+    }
+}
+
 /// Helper to format AlertTime for display
 fn alert_label(alert: &AlertTime) -> String {
     match alert {
@@ -58,6 +73,17 @@ fn alert_label(alert: &AlertTime) -> String {
         AlertTime::TwoDays => fl!("alert-2days"),
         AlertTime::OneWeek => fl!("alert-1week"),
         AlertTime::Custom(mins) => format!("{} min before", mins),
+        AlertTime::TimeToLeave => fl!("alert-time-to-leave"),
+    }
+}
+
+/// ID for an event dialog text input - used for Tab navigation and auto-focus
+pub fn event_dialog_input_id(field: EventDialogField) -> text_input::Id {
+    match field {
+        EventDialogField::Title => text_input::Id::new("event_dialog_title"),
+        EventDialogField::Location => text_input::Id::new("event_dialog_location"),
+        EventDialogField::Invitee => text_input::Id::new("event_dialog_invitee"),
+        EventDialogField::Url => text_input::Id::new("event_dialog_url"),
     }
 }
 
@@ -71,6 +97,8 @@ pub fn render_event_dialog<'a>(
     // === Dialog Title ===
     let dialog_title = if is_edit_mode {
         fl!("event-edit")
+    } else if !state.bulk_dates.is_empty() {
+        fl!("event-new-on-dates", count = (state.bulk_dates.len() as i64))
     } else {
         fl!("event-new")
     };
@@ -87,7 +115,9 @@ pub fn render_event_dialog<'a>(
         is_editing(EventDialogField::Title),
         |editing| Message::EventDialogToggleEdit(EventDialogField::Title, editing),
     )
+    .id(event_dialog_input_id(EventDialogField::Title))
     .on_input(Message::EventDialogTitleChanged)
+    .on_submit(|_| Message::ConfirmEventDialog)
     .width(Length::Fill);
 
     // === Location Input using editable_input ===
@@ -97,6 +127,7 @@ pub fn render_event_dialog<'a>(
         is_editing(EventDialogField::Location),
         |editing| Message::EventDialogToggleEdit(EventDialogField::Location, editing),
     )
+    .id(event_dialog_input_id(EventDialogField::Location))
     .on_input(Message::EventDialogLocationChanged)
     .width(Length::Fill);
 
@@ -329,7 +360,32 @@ pub fn render_event_dialog<'a>(
         );
     }
 
-    let schedule_section = settings::section()
+    // === Priority Section ===
+    // Imported values keep their exact number until another level is picked
+    let current_priority = PriorityLevel::from_priority(state.priority);
+    let priority_options = [
+        PriorityLevel::None,
+        PriorityLevel::Low,
+        PriorityLevel::Medium,
+        PriorityLevel::High,
+    ];
+
+    let mut priority_buttons = row().spacing(4);
+    for level in priority_options {
+        let is_selected = current_priority == level;
+        priority_buttons = priority_buttons.push(
+            button::custom(text(priority_label(level)).size(12))
+                .on_press(Message::EventDialogPriorityChanged(level.to_priority()))
+                .padding([4, 8])
+                .class(if is_selected {
+                    cosmic::theme::Button::Suggested
+                } else {
+                    cosmic::theme::Button::Standard
+                }),
+        );
+    }
+
+    let mut schedule_section = settings::section()
         .title(fl!("event-schedule-section"))
         .add(
             settings::item::builder(fl!("event-travel-time"))
@@ -338,7 +394,29 @@ pub fn render_event_dialog<'a>(
         .add(
             settings::item::builder(fl!("event-repeat"))
                 .control(repeat_buttons),
+        )
+        .add(
+            settings::item::builder(fl!("event-priority"))
+                .control(priority_buttons),
+        );
+
+    // Preview the next occurrences so the rule can be checked before saving
+    let occurrences = CalendarManager::preview_occurrences(
+        state.start_date,
+        &state.repeat,
+        RECURRENCE_PREVIEW_COUNT,
+    );
+    if !occurrences.is_empty() {
+        let preview = occurrences
+            .iter()
+            .map(|date| date.format("%a, %b %-d, %Y").to_string())
+            .collect::<Vec<_>>()
+            .join(" · ");
+        schedule_section = schedule_section.add(
+            settings::item::builder(fl!("event-repeat-preview"))
+                .control(text::caption(preview)),
         );
+    }
 
     // === Calendar Section ===
     let mut calendar_section = settings::section()
@@ -382,33 +460,56 @@ pub fn render_event_dialog<'a>(
     }
 
     // === Alert Section ===
-    let alert_options = [
-        AlertTime::None,
+    // Current reminders as removable chips
+    let mut alert_chips = row().spacing(4);
+    for (index, alert) in state.alerts.iter().enumerate() {
+        alert_chips = alert_chips.push(
+            button::custom(
+                row()
+                    .spacing(4)
+                    .push(text(alert_label(alert)).size(12))
+                    .push(text("×").size(12)),
+            )
+            .on_press(Message::EventDialogRemoveAlert(index))
+            .padding([2, 6])
+            .class(cosmic::theme::Button::Standard),
+        );
+    }
+
+    let mut alert_options = vec![
         AlertTime::FifteenMinutes,
         AlertTime::ThirtyMinutes,
         AlertTime::OneHour,
         AlertTime::OneDay,
     ];
+    // Events with a location can alert when it's time to leave (travel time early)
+    if !state.location.trim().is_empty() {
+        alert_options.push(AlertTime::TimeToLeave);
+    }
 
+    // Presets not yet on the event, each adding a reminder
     let mut alert_buttons = row().spacing(4);
-    for opt in alert_options.iter() {
-        let is_selected = &state.alert == opt;
-        let opt_clone = opt.clone();
+    for opt in alert_options.into_iter().filter(|opt| !state.alerts.contains(opt)) {
         alert_buttons = alert_buttons.push(
-            button::custom(text(alert_label(opt)).size(11))
-                .on_press(Message::EventDialogAlertChanged(opt_clone))
+            button::custom(text(format!("+ {}", alert_label(&opt))).size(11))
+                .on_press(Message::EventDialogAddAlert(opt))
                 .padding([4, 6])
-                .class(if is_selected {
-                    cosmic::theme::Button::Suggested
-                } else {
-                    cosmic::theme::Button::Standard
-                }),
+                .class(cosmic::theme::Button::Standard),
         );
     }
 
+    let alerts_content = column()
+        .spacing(4)
+        .push(if state.alerts.is_empty() {
+            Element::from(text::caption(fl!("event-alert-empty")))
+        } else {
+            alert_chips.into()
+        })
+        .push(alert_buttons);
+
     let alert_section = settings::section()
         .title(fl!("event-alert"))
-        .add(settings::item::builder(fl!("event-alert")).control(alert_buttons));
+        .add(settings::item::builder(fl!("event-alert")).control(alerts_content));
 
     // === Invitees Section ===
     let mut invitee_chips = row().spacing(4);
@@ -432,6 +533,7 @@ pub fn render_event_dialog<'a>(
         true, // Always editable for input
         |_| Message::EventDialogAddInvitee, // Toggle acts as submit
     )
+    .id(event_dialog_input_id(EventDialogField::Invitee))
     .on_input(Message::EventDialogInviteeInputChanged)
     .on_submit(|_| Message::EventDialogAddInvitee)
     .width(Length::Fill);
@@ -452,14 +554,51 @@ pub fn render_event_dialog<'a>(
         is_editing(EventDialogField::Url),
         |editing| Message::EventDialogToggleEdit(EventDialogField::Url, editing),
     )
+    .id(event_dialog_input_id(EventDialogField::Url))
     .on_input(Message::EventDialogUrlChanged)
     .width(Length::Fill);
 
-    // Notes uses text_editor for multi-line input
-    let notes_editor = text_editor(&state.notes_content)
-        .placeholder(fl!("event-notes-placeholder"))
-        .on_action(Message::EventDialogNotesAction)
-        .height(Length::Fixed(100.0));
+    // Attachments are added by dropping files onto the window while the dialog is open
+    let attachments_content: Element<'a, Message> = if state.attachments.is_empty() {
+        text::caption(fl!("event-attachments-drop-hint")).into()
+    } else {
+        let mut attachment_chips = column().spacing(4);
+        for (index, attachment) in state.attachments.iter().enumerate() {
+            let name = std::path::Path::new(attachment)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or(attachment);
+            attachment_chips = attachment_chips.push(
+                button::custom(
+                    row()
+                        .spacing(4)
+                        .push(text(name.to_string()).size(12))
+                        .push(text("×").size(12)),
+                )
+                .on_press(Message::EventDialogRemoveAttachment(index))
+                .padding([2, 6])
+                .class(cosmic::theme::Button::Standard),
+            );
+        }
+        attachment_chips.into()
+    };
+
+    // Events in read-only calendars (subscriptions) can't be saved
+    let is_read_only = calendars
+        .iter()
+        .any(|c| c.info().id == state.calendar_id && c.info().read_only);
+
+    // Notes uses text_editor for multi-line input; read-only events show formatted notes
+    let notes_text = state.notes_content.text();
+    let notes_editor: Element<'_, Message> = if is_read_only && !notes_text.trim().is_empty() {
+        render_markdown(&notes_text)
+    } else {
+        text_editor(&state.notes_content)
+            .placeholder(fl!("event-notes-placeholder"))
+            .on_action(Message::EventDialogNotesAction)
+            .height(Length::Fixed(100.0))
+            .into()
+    };
 
     let additional_section = settings::section()
         .title(fl!("event-additional-section"))
@@ -467,6 +606,10 @@ pub fn render_event_dialog<'a>(
             settings::item::builder(fl!("event-url"))
                 .control(url_input),
         )
+        .add(
+            settings::item::builder(fl!("event-attachments"))
+                .control(attachments_content),
+        )
         .add(
             settings::item::builder(fl!("event-notes"))
                 .control(notes_editor),
@@ -475,17 +618,33 @@ pub fn render_event_dialog<'a>(
     // === Dialog Buttons ===
     let cancel_btn = button::text(fl!("button-cancel")).on_press(Message::CancelEventDialog);
 
+    let confirm_message = (!is_read_only).then_some(Message::ConfirmEventDialog);
+
     let confirm_btn = if is_edit_mode {
-        button::suggested(fl!("button-save")).on_press(Message::ConfirmEventDialog)
+        button::suggested(fl!("button-save")).on_press_maybe(confirm_message)
     } else {
-        button::suggested(fl!("button-create")).on_press(Message::ConfirmEventDialog)
+        button::suggested(fl!("button-create")).on_press_maybe(confirm_message)
     };
 
-    let buttons = row()
+    let mut buttons = row()
         .spacing(8)
-        .push(widget::horizontal_space())
-        .push(cancel_btn)
-        .push(confirm_btn);
+        .align_y(cosmic::iced::Alignment::Center);
+    let buttons = if state.confirm_discard {
+        // Closing with unsaved changes: ask before dropping them
+        buttons
+            .push(text::body(fl!("event-unsaved-changes")))
+            .push(widget::horizontal_space())
+            .push(button::text(fl!("button-keep-editing")).on_press(Message::KeepEditingEventDialog))
+            .push(button::destructive(fl!("button-discard")).on_press(Message::DiscardEventDialog))
+    } else {
+        if is_read_only {
+            buttons = buttons.push(text::caption(fl!("event-calendar-read-only")));
+        }
+        buttons
+            .push(widget::horizontal_space())
+            .push(cancel_btn)
+            .push(confirm_btn)
+    };
 
     // === Build the form layout using settings view_column ===
     let form_content = settings::view_column(vec![
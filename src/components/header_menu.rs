@@ -7,6 +7,8 @@ use std::sync::LazyLock;
 use crate::fl;
 use crate::menu_action::MenuAction;
 use crate::message::Message;
+use crate::services::Palette;
+use crate::settings::{AppSettings, CompactSensitivity, EventClickAction, EventOverlapLayout, EventSortOrder, HighlightColor, StartupView, TimeSnap, ViewImageScale, WeekScrollStart, WeekendDays, DRAG_THRESHOLD_OPTIONS, NEXT_DAY_CUTOFF_HOURS};
 use crate::ui_constants::{ICON_ADD, ICON_SEARCH, ICON_TODAY, MENU_ITEM_HEIGHT, MENU_ITEM_WIDTH, MENU_SPACING};
 
 /// Static menu ID for responsive menu bar - must persist across renders for collapse state tracking
@@ -17,8 +19,17 @@ pub fn render_header_start<'a>(
     core: &'a Core,
     key_binds: &'a HashMap<menu::KeyBind, MenuAction>,
     sidebar_visible: bool,
-    show_week_numbers: bool,
+    settings: &AppSettings,
+    focus_mode: bool,
+    show_legend: bool,
+    has_selected_event: bool,
 ) -> Vec<Element<'a, Message>> {
+    let export_selected = if has_selected_event {
+        menu::Item::Button(fl!("menu-export-selected"), None, MenuAction::ExportSelected)
+    } else {
+        menu::Item::ButtonDisabled(fl!("menu-export-selected"), None, MenuAction::ExportSelected)
+    };
+
     vec![
         // Use the official COSMIC nav_bar_toggle for consistent styling
         widget::nav_bar_toggle()
@@ -37,23 +48,210 @@ pub fn render_header_start<'a>(
                 vec![
                     (fl!("menu-file"), vec![
                         menu::Item::Button(fl!("menu-new-event"), None, MenuAction::NewEvent),
+                        menu::Item::Button(fl!("menu-quick-add-event"), None, MenuAction::QuickAddEvent),
+                        menu::Item::Button(fl!("menu-new-event-on-picked-dates"), None, MenuAction::NewEventOnPickedDates),
                         menu::Item::Button(fl!("menu-new-calendar"), None, MenuAction::NewCalendar),
+                        menu::Item::Folder(fl!("menu-calendar-palette"), vec![
+                            menu::Item::Button(fl!("menu-palette-pastel"), None, MenuAction::ApplyPalette(Palette::Pastel)),
+                            menu::Item::Button(fl!("menu-palette-vivid"), None, MenuAction::ApplyPalette(Palette::Vivid)),
+                            menu::Item::Button(
+                                fl!("menu-palette-colorblind-safe"),
+                                None,
+                                MenuAction::ApplyPalette(Palette::ColorblindSafe),
+                            ),
+                        ]),
                         menu::Item::Divider,
                         menu::Item::Button(fl!("menu-import-ical"), None, MenuAction::ImportICal),
+                        menu::Item::Button(fl!("menu-import-clipboard"), None, MenuAction::ImportFromClipboard),
                         menu::Item::Button(fl!("menu-export-ical"), None, MenuAction::ExportICal),
+                        menu::Item::Button(fl!("menu-export-range"), None, MenuAction::ExportRange),
+                        export_selected,
+                        menu::Item::Divider,
+                        menu::Item::Button(fl!("menu-sync-all"), None, MenuAction::SyncAll),
+                        menu::Item::Divider,
+                        menu::Item::Button(fl!("menu-export-view-image"), None, MenuAction::ExportViewImage),
+                        menu::Item::Folder(fl!("menu-view-image-scale"), vec![
+                            menu::Item::CheckBox(
+                                fl!("menu-view-image-scale-1x"),
+                                None,
+                                settings.view_image_scale == ViewImageScale::Standard,
+                                MenuAction::ViewImageScale(ViewImageScale::Standard),
+                            ),
+                            menu::Item::CheckBox(
+                                fl!("menu-view-image-scale-2x"),
+                                None,
+                                settings.view_image_scale == ViewImageScale::Double,
+                                MenuAction::ViewImageScale(ViewImageScale::Double),
+                            ),
+                        ]),
                     ]),
                     (fl!("menu-edit"), vec![
+                        menu::Item::Button(fl!("menu-make-recurring"), None, MenuAction::MakeRecurring),
+                        menu::Item::Button(fl!("menu-toggle-all-day"), None, MenuAction::ToggleAllDay),
+                        menu::Item::Button(fl!("menu-skip-occurrence"), None, MenuAction::SkipOccurrence),
+                        menu::Item::Button(fl!("menu-copy-event-link"), None, MenuAction::CopyEventLink),
+                        menu::Item::Button(fl!("menu-copy-week-forward"), None, MenuAction::CopyWeekForward),
+                        menu::Item::Button(fl!("menu-edit-day-note"), None, MenuAction::EditDayNote),
+                        menu::Item::Button(fl!("menu-merge-duplicates"), None, MenuAction::MergeDuplicates),
+                        menu::Item::CheckBox(fl!("menu-confirm-event-moves"), None, settings.confirm_event_moves, MenuAction::ToggleConfirmEventMoves),
+                        menu::Item::Folder(
+                            fl!("menu-drag-threshold"),
+                            DRAG_THRESHOLD_OPTIONS
+                                .iter()
+                                .map(|&pixels| {
+                                    let label = if pixels == 0 {
+                                        fl!("menu-drag-threshold-off")
+                                    } else {
+                                        fl!("menu-drag-threshold-pixels", pixels = pixels)
+                                    };
+                                    menu::Item::CheckBox(label, None, settings.drag_threshold == pixels, MenuAction::DragThreshold(pixels))
+                                })
+                                .collect(),
+                        ),
+                        menu::Item::Folder(fl!("menu-event-click"), vec![
+                            menu::Item::CheckBox(
+                                fl!("menu-event-click-select"),
+                                None,
+                                settings.event_click_action == EventClickAction::Select,
+                                MenuAction::EventClickAction(EventClickAction::Select),
+                            ),
+                            menu::Item::CheckBox(
+                                fl!("menu-event-click-edit"),
+                                None,
+                                settings.event_click_action == EventClickAction::Edit,
+                                MenuAction::EventClickAction(EventClickAction::Edit),
+                            ),
+                        ]),
+                        menu::Item::Folder(
+                            fl!("menu-time-snap"),
+                            [TimeSnap::Five, TimeSnap::Ten, TimeSnap::Fifteen, TimeSnap::Thirty]
+                                .into_iter()
+                                .map(|snap| {
+                                    menu::Item::CheckBox(
+                                        fl!("menu-time-snap-minutes", minutes = snap.minutes()),
+                                        None,
+                                        settings.time_snap == snap,
+                                        MenuAction::TimeSnap(snap),
+                                    )
+                                })
+                                .collect(),
+                        ),
+                        menu::Item::Folder(
+                            fl!("menu-new-event-rounding"),
+                            [
+                                menu::Item::CheckBox(
+                                    fl!("menu-new-event-rounding-enabled"),
+                                    None,
+                                    settings.round_new_event_start,
+                                    MenuAction::ToggleRoundNewEventStart,
+                                ),
+                                menu::Item::Divider,
+                            ]
+                            .into_iter()
+                            .chain([TimeSnap::Five, TimeSnap::Ten, TimeSnap::Fifteen, TimeSnap::Thirty].into_iter().map(|rounding| {
+                                menu::Item::CheckBox(
+                                    fl!("menu-time-snap-minutes", minutes = rounding.minutes()),
+                                    None,
+                                    settings.new_event_rounding == rounding,
+                                    MenuAction::NewEventRounding(rounding),
+                                )
+                            }))
+                            .collect(),
+                        ),
+                        menu::Item::Divider,
                         menu::Item::Button(fl!("menu-settings"), None, MenuAction::Settings),
                     ]),
                     (fl!("menu-view"), vec![
                         menu::Item::Button(fl!("menu-today"), None, MenuAction::Today),
+                        menu::Item::Button(fl!("menu-return-to-selected"), None, MenuAction::ReturnToSelected),
                         menu::Item::Divider,
                         menu::Item::Button(fl!("menu-day-view"), None, MenuAction::ViewDay),
                         menu::Item::Button(fl!("menu-week-view"), None, MenuAction::ViewWeek),
                         menu::Item::Button(fl!("menu-month-view"), None, MenuAction::ViewMonth),
                         menu::Item::Button(fl!("menu-year-view"), None, MenuAction::ViewYear),
                         menu::Item::Divider,
-                        menu::Item::CheckBox(fl!("menu-show-week-numbers"), None, show_week_numbers, MenuAction::ToggleWeekNumbers),
+                        menu::Item::CheckBox(fl!("menu-focus-mode"), None, focus_mode, MenuAction::ToggleFocusMode),
+                        menu::Item::CheckBox(fl!("menu-show-legend"), None, show_legend, MenuAction::ToggleLegend),
+                        menu::Item::Button(fl!("menu-month-stats"), None, MenuAction::MonthStats),
+                        menu::Item::Divider,
+                        menu::Item::CheckBox(fl!("menu-show-week-numbers"), None, settings.show_week_numbers, MenuAction::ToggleWeekNumbers),
+                        menu::Item::CheckBox(fl!("menu-highlight-current-week"), None, settings.highlight_current_week, MenuAction::ToggleHighlightCurrentWeek),
+                        menu::Item::CheckBox(fl!("menu-rolling-week"), None, settings.rolling_week, MenuAction::ToggleRollingWeek),
+                        menu::Item::CheckBox(fl!("menu-show-event-count-badges"), None, settings.show_event_count_badges, MenuAction::ToggleEventCountBadges),
+                        menu::Item::CheckBox(fl!("menu-highlight-free-days"), None, settings.highlight_free_days, MenuAction::ToggleFreeDayHighlight),
+                        menu::Item::CheckBox(fl!("menu-dim-past-events"), None, settings.dim_past_events, MenuAction::ToggleDimPastEvents),
+                        menu::Item::CheckBox(fl!("menu-half-hour-lines"), None, settings.half_hour_lines, MenuAction::ToggleHalfHourLines),
+                        menu::Item::Folder(fl!("menu-week-scroll-start"), vec![
+                            menu::Item::CheckBox(
+                                fl!("menu-week-scroll-current-time"),
+                                None,
+                                settings.week_scroll_start == WeekScrollStart::CurrentTime,
+                                MenuAction::WeekScrollStart(WeekScrollStart::CurrentTime),
+                            ),
+                            menu::Item::CheckBox(
+                                fl!("menu-week-scroll-working-hours"),
+                                None,
+                                settings.week_scroll_start == WeekScrollStart::WorkingHours,
+                                MenuAction::WeekScrollStart(WeekScrollStart::WorkingHours),
+                            ),
+                            menu::Item::CheckBox(
+                                fl!("menu-week-scroll-top"),
+                                None,
+                                settings.week_scroll_start == WeekScrollStart::Top,
+                                MenuAction::WeekScrollStart(WeekScrollStart::Top),
+                            ),
+                        ]),
+                        menu::Item::Folder(fl!("menu-weekend-days"), vec![
+                            menu::Item::CheckBox(fl!("menu-weekend-system"), None, settings.weekend_days == WeekendDays::System, MenuAction::WeekendDays(WeekendDays::System)),
+                            menu::Item::CheckBox(fl!("menu-weekend-sat-sun"), None, settings.weekend_days == WeekendDays::SaturdaySunday, MenuAction::WeekendDays(WeekendDays::SaturdaySunday)),
+                            menu::Item::CheckBox(fl!("menu-weekend-fri-sat"), None, settings.weekend_days == WeekendDays::FridaySaturday, MenuAction::WeekendDays(WeekendDays::FridaySaturday)),
+                            menu::Item::CheckBox(fl!("menu-weekend-fri"), None, settings.weekend_days == WeekendDays::FridayOnly, MenuAction::WeekendDays(WeekendDays::FridayOnly)),
+                            menu::Item::CheckBox(fl!("menu-weekend-sat"), None, settings.weekend_days == WeekendDays::SaturdayOnly, MenuAction::WeekendDays(WeekendDays::SaturdayOnly)),
+                            menu::Item::CheckBox(fl!("menu-weekend-sun"), None, settings.weekend_days == WeekendDays::SundayOnly, MenuAction::WeekendDays(WeekendDays::SundayOnly)),
+                        ]),
+                        menu::Item::Folder(fl!("menu-compact-sensitivity"), vec![
+                            menu::Item::CheckBox(fl!("menu-compact-sensitivity-low"), None, settings.compact_sensitivity == CompactSensitivity::Low, MenuAction::CompactSensitivity(CompactSensitivity::Low)),
+                            menu::Item::CheckBox(fl!("menu-compact-sensitivity-normal"), None, settings.compact_sensitivity == CompactSensitivity::Normal, MenuAction::CompactSensitivity(CompactSensitivity::Normal)),
+                            menu::Item::CheckBox(fl!("menu-compact-sensitivity-high"), None, settings.compact_sensitivity == CompactSensitivity::High, MenuAction::CompactSensitivity(CompactSensitivity::High)),
+                        ]),
+                        menu::Item::Folder(
+                            fl!("menu-today-highlight"),
+                            highlight_color_items(settings.today_highlight, MenuAction::TodayHighlight),
+                        ),
+                        menu::Item::Folder(
+                            fl!("menu-selected-highlight"),
+                            highlight_color_items(settings.selected_highlight, MenuAction::SelectedHighlight),
+                        ),
+                        menu::Item::Folder(fl!("menu-event-overlap"), vec![
+                            menu::Item::CheckBox(fl!("menu-event-overlap-side-by-side"), None, settings.event_overlap_layout == EventOverlapLayout::SideBySide, MenuAction::EventOverlapLayout(EventOverlapLayout::SideBySide)),
+                            menu::Item::CheckBox(fl!("menu-event-overlap-cascade"), None, settings.event_overlap_layout == EventOverlapLayout::Cascade, MenuAction::EventOverlapLayout(EventOverlapLayout::Cascade)),
+                        ]),
+                        menu::Item::Folder(fl!("menu-event-sort"), vec![
+                            menu::Item::CheckBox(fl!("menu-event-sort-start-time"), None, settings.event_sort_order == EventSortOrder::StartTime, MenuAction::EventSortOrder(EventSortOrder::StartTime)),
+                            menu::Item::CheckBox(fl!("menu-event-sort-all-day-first"), None, settings.event_sort_order == EventSortOrder::AllDayFirst, MenuAction::EventSortOrder(EventSortOrder::AllDayFirst)),
+                            menu::Item::CheckBox(fl!("menu-event-sort-calendar"), None, settings.event_sort_order == EventSortOrder::Calendar, MenuAction::EventSortOrder(EventSortOrder::Calendar)),
+                            menu::Item::CheckBox(fl!("menu-event-sort-priority"), None, settings.event_sort_order == EventSortOrder::Priority, MenuAction::EventSortOrder(EventSortOrder::Priority)),
+                        ]),
+                        menu::Item::Folder(fl!("menu-startup-view"), vec![
+                            menu::Item::CheckBox(fl!("menu-month-view"), None, settings.startup_view == StartupView::Month, MenuAction::StartupView(StartupView::Month)),
+                            menu::Item::CheckBox(fl!("menu-week-view"), None, settings.startup_view == StartupView::Week, MenuAction::StartupView(StartupView::Week)),
+                            menu::Item::CheckBox(fl!("menu-day-view"), None, settings.startup_view == StartupView::Day, MenuAction::StartupView(StartupView::Day)),
+                            menu::Item::CheckBox(fl!("menu-year-view"), None, settings.startup_view == StartupView::Year, MenuAction::StartupView(StartupView::Year)),
+                            menu::Item::CheckBox(fl!("menu-startup-view-last-used"), None, settings.startup_view == StartupView::LastUsed, MenuAction::StartupView(StartupView::LastUsed)),
+                        ]),
+                        menu::Item::Folder(
+                            fl!("menu-next-day-after"),
+                            std::iter::once(menu::Item::CheckBox(fl!("menu-next-day-after-never"), None, settings.next_day_after_hour.is_none(), MenuAction::NextDayAfterHour(None)))
+                                .chain(NEXT_DAY_CUTOFF_HOURS.iter().map(|&hour| {
+                                    menu::Item::CheckBox(
+                                        fl!("menu-next-day-after-hour", hour = hour),
+                                        None,
+                                        settings.next_day_after_hour == Some(hour),
+                                        MenuAction::NextDayAfterHour(Some(hour)),
+                                    )
+                                }))
+                                .collect(),
+                        ),
                         menu::Item::Divider,
                         menu::Item::Button(fl!("menu-about"), None, MenuAction::About),
                     ]),
@@ -62,6 +260,27 @@ pub fn render_header_start<'a>(
     ]
 }
 
+/// Menu checkboxes for picking a highlight color, with the current one checked
+fn highlight_color_items(
+    current: HighlightColor,
+    action: fn(HighlightColor) -> MenuAction,
+) -> Vec<menu::Item<MenuAction, String>> {
+    HighlightColor::ALL
+        .into_iter()
+        .map(|highlight| {
+            let label = match highlight {
+                HighlightColor::Accent => fl!("highlight-accent"),
+                HighlightColor::Blue => fl!("highlight-blue"),
+                HighlightColor::Green => fl!("highlight-green"),
+                HighlightColor::Orange => fl!("highlight-orange"),
+                HighlightColor::Red => fl!("highlight-red"),
+                HighlightColor::Purple => fl!("highlight-purple"),
+            };
+            menu::Item::CheckBox(label, None, current == highlight, action(highlight))
+        })
+        .collect()
+}
+
 /// Render the right side of the header (add, today, and search buttons)
 pub fn render_header_end() -> Vec<Element<'static, Message>> {
     vec![
@@ -0,0 +1,66 @@
+//! Floating legend of the visible calendars' colors and names
+//!
+//! Helps to tell event chips apart when the sidebar is hidden, and keeps the
+//! colors explained in exported view images.
+
+use cosmic::iced::{alignment, Length};
+use cosmic::widget::{column, container, row};
+use cosmic::{widget, Element};
+
+use crate::components::color_picker::parse_color_safe;
+use crate::message::Message;
+use crate::styles::overlay_sidebar_style;
+use crate::ui_constants::{BORDER_RADIUS, PADDING_MEDIUM, PADDING_STANDARD, SPACING_MEDIUM, SPACING_SMALL};
+
+/// Size of the color swatch in front of each calendar name
+const SWATCH_SIZE: f32 = 10.0;
+
+/// A calendar shown in the legend
+pub struct LegendEntry {
+    pub name: String,
+    pub color: String,
+}
+
+/// Render the legend anchored to the bottom-left of the calendar view
+/// The surrounding container fills the view but only the legend itself receives input
+pub fn render_calendar_legend(entries: Vec<LegendEntry>) -> Element<'static, Message> {
+    let list = entries.into_iter().fold(column().spacing(SPACING_SMALL), |col, entry| {
+        let color = parse_color_safe(&entry.color);
+        let swatch = container(widget::text(""))
+            .width(Length::Fixed(SWATCH_SIZE))
+            .height(Length::Fixed(SWATCH_SIZE))
+            .style(move |_theme: &cosmic::Theme| container::Style {
+                background: Some(color.into()),
+                border: cosmic::iced::Border {
+                    radius: (SWATCH_SIZE / 2.0).into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+        col.push(
+            row()
+                .spacing(SPACING_MEDIUM)
+                .align_y(cosmic::iced::Alignment::Center)
+                .push(swatch)
+                .push(widget::text::caption(entry.name)),
+        )
+    });
+
+    let legend = container(list)
+        .padding(PADDING_MEDIUM)
+        .style(|theme: &cosmic::Theme| container::Style {
+            border: cosmic::iced::Border {
+                radius: BORDER_RADIUS.into(),
+                ..Default::default()
+            },
+            ..overlay_sidebar_style(theme)
+        });
+
+    container(legend)
+        .padding(PADDING_STANDARD)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(alignment::Horizontal::Left)
+        .align_y(alignment::Vertical::Bottom)
+        .into()
+}
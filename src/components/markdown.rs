@@ -0,0 +1,210 @@
+//! Basic markdown rendering for event notes
+//!
+//! Notes are edited as plain text. When an event can't be edited (read-only
+//! calendars) they are displayed with a small markdown subset:
+//! `**bold**`, `[label](https://...)` links, bare `https://` links and
+//! `- ` / `* ` bullet lists. Anything else is shown as written.
+
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, row, text};
+use cosmic::Element;
+
+use crate::message::Message;
+
+/// Inline piece of a markdown line
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkdownInline {
+    Text(String),
+    Bold(String),
+    Link { label: String, url: String },
+}
+
+/// Line-level markdown block
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkdownBlock {
+    Paragraph(Vec<MarkdownInline>),
+    Bullet(Vec<MarkdownInline>),
+}
+
+/// Only links with these schemes are made clickable
+fn is_safe_url(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://") || url.starts_with("mailto:")
+}
+
+/// Parse notes into blocks, one per non-empty line
+pub fn parse_markdown(source: &str) -> Vec<MarkdownBlock> {
+    source
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let trimmed = line.trim_start();
+            match trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+                Some(item) => MarkdownBlock::Bullet(parse_inline(item.trim())),
+                None => MarkdownBlock::Paragraph(parse_inline(line.trim_end())),
+            }
+        })
+        .collect()
+}
+
+/// Parse bold text and links within a line
+fn parse_inline(line: &str) -> Vec<MarkdownInline> {
+    let mut pieces = Vec::new();
+    let mut plain = String::new();
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some((piece, remaining)) = parse_bold(rest)
+            .or_else(|| parse_link(rest))
+            .or_else(|| parse_bare_url(rest, &plain))
+        {
+            if !plain.is_empty() {
+                pieces.push(MarkdownInline::Text(std::mem::take(&mut plain)));
+            }
+            pieces.push(piece);
+            rest = remaining;
+            continue;
+        }
+        plain.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    if !plain.is_empty() {
+        pieces.push(MarkdownInline::Text(plain));
+    }
+    pieces
+}
+
+/// `**bold**`
+fn parse_bold(input: &str) -> Option<(MarkdownInline, &str)> {
+    let inner = input.strip_prefix("**")?;
+    let end = inner.find("**")?;
+    if end == 0 {
+        return None;
+    }
+    Some((MarkdownInline::Bold(inner[..end].to_string()), &inner[end + 2..]))
+}
+
+/// `[label](url)`
+fn parse_link(input: &str) -> Option<(MarkdownInline, &str)> {
+    let inner = input.strip_prefix('[')?;
+    let label_end = inner.find("](")?;
+    let after_label = &inner[label_end + 2..];
+    let url_end = after_label.find(')')?;
+    let url = &after_label[..url_end];
+    if !is_safe_url(url) {
+        return None;
+    }
+    Some((
+        MarkdownInline::Link {
+            label: inner[..label_end].to_string(),
+            url: url.to_string(),
+        },
+        &after_label[url_end + 1..],
+    ))
+}
+
+/// Bare `https://...` at the start of a word
+fn parse_bare_url<'a>(input: &'a str, preceding: &str) -> Option<(MarkdownInline, &'a str)> {
+    if !(input.starts_with("https://") || input.starts_with("http://")) {
+        return None;
+    }
+    if preceding.chars().last().is_some_and(|c| !c.is_whitespace() && c != '(') {
+        return None;
+    }
+    let end = input.find(char::is_whitespace).unwrap_or(input.len());
+    // Trailing punctuation usually belongs to the sentence
+    let url = input[..end].trim_end_matches(['.', ',', ';', ':', ')', '!', '?']);
+    Some((
+        MarkdownInline::Link {
+            label: url.to_string(),
+            url: url.to_string(),
+        },
+        &input[url.len()..],
+    ))
+}
+
+/// Render notes as formatted, read-only content
+pub fn render_markdown(source: &str) -> Element<'static, Message> {
+    let mut content = column().spacing(4).width(Length::Fill);
+
+    for block in parse_markdown(source) {
+        let line = match block {
+            MarkdownBlock::Paragraph(pieces) => render_inline(pieces),
+            MarkdownBlock::Bullet(pieces) => row()
+                .spacing(6)
+                .push(text::body("•"))
+                .push(render_inline(pieces))
+                .into(),
+        };
+        content = content.push(line);
+    }
+
+    content.into()
+}
+
+/// Render the inline pieces of one line
+fn render_inline(pieces: Vec<MarkdownInline>) -> Element<'static, Message> {
+    let mut line = row().align_y(cosmic::iced::Alignment::Center);
+
+    for piece in pieces {
+        line = match piece {
+            MarkdownInline::Text(value) => line.push(text::body(value)),
+            MarkdownInline::Bold(value) => line.push(text::body(value).font(cosmic::font::bold())),
+            MarkdownInline::Link { label, url } => line.push(
+                button::link(label)
+                    .padding(0)
+                    .on_press(Message::LaunchUrl(url)),
+            ),
+        };
+    }
+
+    line.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_markdown_subset() {
+        let blocks = parse_markdown(
+            "Agenda for **Monday**\n\n- Review [notes](https://example.com/n)\n* see https://example.com.",
+        );
+
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::Paragraph(vec![
+                    MarkdownInline::Text("Agenda for ".to_string()),
+                    MarkdownInline::Bold("Monday".to_string()),
+                ]),
+                MarkdownBlock::Bullet(vec![
+                    MarkdownInline::Text("Review ".to_string()),
+                    MarkdownInline::Link {
+                        label: "notes".to_string(),
+                        url: "https://example.com/n".to_string(),
+                    },
+                ]),
+                MarkdownBlock::Bullet(vec![
+                    MarkdownInline::Text("see ".to_string()),
+                    MarkdownInline::Link {
+                        label: "https://example.com".to_string(),
+                        url: "https://example.com".to_string(),
+                    },
+                    MarkdownInline::Text(".".to_string()),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsafe_links_stay_plain_text() {
+        let blocks = parse_markdown("[run](file:///etc/passwd) **open");
+        assert_eq!(
+            blocks,
+            vec![MarkdownBlock::Paragraph(vec![MarkdownInline::Text(
+                "[run](file:///etc/passwd) **open".to_string()
+            )])]
+        );
+    }
+}
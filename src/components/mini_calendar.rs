@@ -2,6 +2,8 @@ use cosmic::iced::Length;
 use cosmic::widget::{button, column, container, row};
 use cosmic::{widget, Element};
 
+use crate::fl;
+use crate::locale::Numerals;
 use crate::localized_names;
 use crate::message::Message;
 use crate::models::CalendarState;
@@ -14,6 +16,7 @@ use crate::ui_constants::{
 pub fn render_mini_calendar(
     calendar_state: &CalendarState,
     selected_day: Option<u32>,
+    numerals: Numerals,
 ) -> Element<'static, Message> {
     let month_year_text = calendar_state.month_year_text.clone();
     let year = calendar_state.year;
@@ -23,12 +26,14 @@ pub fn render_mini_calendar(
         .spacing(SPACING_MEDIUM)
         .push(
             button::icon(widget::icon::from_name(ICON_PREVIOUS))
+                .tooltip(fl!("nav-previous-month"))
                 .on_press(Message::MiniCalendarPrevMonth)
                 .padding(PADDING_TINY),
         )
         .push(container(widget::text::body(month_year_text).size(FONT_SIZE_BODY)).width(Length::Fill))
         .push(
             button::icon(widget::icon::from_name(ICON_NEXT))
+                .tooltip(fl!("nav-next-month"))
                 .on_press(Message::MiniCalendarNextMonth)
                 .padding(PADDING_TINY),
         );
@@ -57,7 +62,7 @@ pub fn render_mini_calendar(
                 let is_selected = selected_day == Some(*day);
 
                 // Create centered text content for button
-                let day_text = container(widget::text((*day).to_string()).size(FONT_SIZE_SMALL))
+                let day_text = container(widget::text(numerals.format_number(*day)).size(FONT_SIZE_SMALL))
                     .width(Length::Fill)
                     .height(Length::Fill)
                     .center_x(Length::Fill)
@@ -1,3 +1,4 @@
+mod calendar_checkbox;
 mod calendar_dialog;
 mod calendar_dialog_callbacks;
 mod calendar_list;
@@ -5,31 +6,41 @@ pub mod color_picker;
 mod day_cell;
 mod day_header;
 mod display_mode;
+mod empty_state;
 mod event_chip;
 mod event_dialog;
 mod event_dialog_callbacks;
 mod header_menu;
+mod legend;
+mod markdown;
 mod mini_calendar;
+mod now_button;
 pub mod spacer;
 mod time_grid;
 pub mod time_picker;
 mod toolbar;
+mod toast;
 
-pub use calendar_dialog::{render_calendar_dialog, render_delete_calendar_dialog, render_delete_event_dialog};
+pub use calendar_checkbox::render_calendar_checkbox;
+pub use calendar_dialog::{calendar_dialog_group_input_id, render_calendar_dialog, render_delete_calendar_dialog, render_delete_event_dialog};
 pub use calendar_list::render_calendar_list;
-pub use event_dialog::render_event_dialog;
+pub use empty_state::render_empty_state;
+pub use event_dialog::{event_dialog_input_id, render_event_dialog, repeat_label};
 pub use color_picker::{render_color_indicator, render_quick_color_picker, parse_hex_color, parse_color_safe};
 pub use day_cell::{render_day_cell_with_events, DayCellConfig};
 pub use day_header::{render_day_header, DayHeaderConfig};
-pub use event_chip::{render_quick_event_input, render_spanning_quick_event_input, render_compact_events, render_unified_events_with_selection, quick_event_input_id, DisplayEvent, span_border_radius_from_flags, ChipOpacity};
+pub use event_chip::{inline_rename_input_id, render_inline_rename_input, render_quick_event_input, render_spanning_quick_event_input, render_compact_events, render_unified_events_with_selection, quick_event_input_id, DisplayEvent, SpanPosition, end_of_day, event_title, span_border_radius_from_flags, timed_span_border_radius, ChipOpacity};
 pub use header_menu::{render_header_end, render_header_start};
+pub use legend::{render_calendar_legend, LegendEntry};
 pub use mini_calendar::render_mini_calendar;
-pub use time_grid::{render_time_grid, render_time_column_placeholder, DayColumn};
+pub use now_button::render_now_button;
+pub use time_grid::render_time_column_placeholder;
 // time_picker is used internally by event_dialog
 #[allow(unused_imports)]
 pub use time_picker::render_time_picker;
-pub use toolbar::render_toolbar;
-pub use display_mode::{EventDisplayMode, calculate_display_mode, should_use_compact};
+pub use toolbar::{render_toolbar, CalendarPicker, MonthYearPicker, ToolbarCalendar};
+pub use toast::render_toasts;
+pub use display_mode::{CompactThresholds, EventDisplayMode, calculate_display_mode, should_use_compact};
 
 // These callback structs are available for future use when we complete the refactoring
 // to make dialogs generic over message type (like time_picker.rs)
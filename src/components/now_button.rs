@@ -0,0 +1,25 @@
+//! Floating "now" button for jumping back to the current date and time
+
+use cosmic::iced::{alignment, Length};
+use cosmic::widget::{button, container};
+use cosmic::{widget, Element};
+
+use crate::fl;
+use crate::message::Message;
+use crate::ui_constants::{ICON_TODAY, PADDING_STANDARD};
+
+/// Render the floating "now" button anchored to the bottom-right of the calendar view
+/// The surrounding container fills the view but only the button itself receives input
+pub fn render_now_button() -> Element<'static, Message> {
+    let now_button = button::suggested(fl!("nav-now"))
+        .leading_icon(widget::icon::from_name(ICON_TODAY))
+        .on_press(Message::JumpToNow);
+
+    container(now_button)
+        .padding(PADDING_STANDARD)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(alignment::Horizontal::Right)
+        .align_y(alignment::Vertical::Bottom)
+        .into()
+}
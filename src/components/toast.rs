@@ -0,0 +1,67 @@
+//! Floating toast notifications shown at the bottom-center of the calendar view
+
+use cosmic::iced::{alignment, Length};
+use cosmic::widget::{button, column, container, row};
+use cosmic::{widget, Element};
+
+use crate::message::Message;
+use crate::models::{Toast, ToastQueue, ToastSeverity};
+use crate::styles::overlay_sidebar_style;
+use crate::ui_constants::{BORDER_RADIUS, ICON_CLOSE, PADDING_MEDIUM, PADDING_STANDARD, SPACING_MEDIUM, SPACING_SMALL};
+
+/// Render the toast stack anchored to the bottom-center of the calendar view
+/// The surrounding container fills the view but only the toasts themselves receive input
+pub fn render_toasts(toasts: &ToastQueue) -> Element<'_, Message> {
+    let stack = toasts
+        .iter()
+        .fold(column().spacing(SPACING_SMALL).align_x(alignment::Horizontal::Center), |col, toast| {
+            col.push(render_toast(toast))
+        });
+
+    container(stack)
+        .padding(PADDING_STANDARD)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(alignment::Horizontal::Center)
+        .align_y(alignment::Vertical::Bottom)
+        .into()
+}
+
+/// Render a single toast: text, optional action button and a close button
+fn render_toast(toast: &Toast) -> Element<'_, Message> {
+    let mut content = row()
+        .spacing(SPACING_MEDIUM)
+        .align_y(cosmic::iced::Alignment::Center)
+        .push(widget::text::body(toast.text.as_str()));
+
+    if let Some(ref action) = toast.action {
+        content = content.push(
+            button::suggested(action.label.as_str()).on_press(Message::ToastActionPressed(toast.id)),
+        );
+    }
+
+    content = content.push(
+        button::icon(widget::icon::from_name(ICON_CLOSE)).on_press(Message::DismissToast(toast.id)),
+    );
+
+    let severity = toast.severity;
+    container(content)
+        .padding(PADDING_MEDIUM)
+        .style(move |theme: &cosmic::Theme| {
+            // Errors and successes get a colored outline, info toasts stay neutral
+            let (width, color) = match severity {
+                ToastSeverity::Info => (0.0, cosmic::iced::Color::TRANSPARENT),
+                ToastSeverity::Success => (1.0, theme.cosmic().success_color().into()),
+                ToastSeverity::Error => (1.0, theme.cosmic().destructive_color().into()),
+            };
+            container::Style {
+                border: cosmic::iced::Border {
+                    radius: BORDER_RADIUS.into(),
+                    width,
+                    color,
+                },
+                ..overlay_sidebar_style(theme)
+            }
+        })
+        .into()
+}
@@ -1,35 +1,261 @@
-use cosmic::widget::{button, row};
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, container, popover, row};
 use cosmic::{widget, Element};
 
+use crate::components::color_picker::parse_hex_color;
+use crate::components::spacer::spacer;
+use crate::fl;
+use crate::localized_names::get_month_names;
 use crate::message::Message;
-use crate::ui_constants::{ICON_NEXT, ICON_PREVIOUS, SPACING_MEDIUM, PADDING_TINY, PADDING_SMALL};
+use crate::ui_constants::{COLOR_DEFAULT_GRAY, ICON_EXPANDED, ICON_NEXT, ICON_PREVIOUS, SPACING_MEDIUM, SPACING_SMALL, PADDING_TINY, PADDING_SMALL};
+
+/// Size of the calendar color swatches in the toolbar picker
+const SWATCH_SIZE: f32 = 12.0;
+
+/// Months per row in the month/year picker grid
+const MONTHS_PER_ROW: usize = 3;
+
+/// A calendar that new events can be added to
+pub struct ToolbarCalendar {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+}
+
+/// Toolbar picker showing which calendar new events go to
+pub struct CalendarPicker {
+    /// Writable calendars to choose from
+    pub calendars: Vec<ToolbarCalendar>,
+    /// Currently selected calendar ID
+    pub selected_id: Option<String>,
+    /// Cached color of the selected calendar
+    pub selected_color: String,
+    /// Whether the calendar list popover is open
+    pub open: bool,
+}
+
+/// Toolbar title dropdown for jumping straight to a month and year
+pub struct MonthYearPicker {
+    /// Year and month currently displayed
+    pub current_year: i32,
+    pub current_month: u32,
+    /// Year shown in the popover's stepper, or None when the popover is closed
+    pub browse_year: Option<i32>,
+}
 
 /// Render the calendar toolbar with navigation controls
 /// primary_text is displayed bold, secondary_text is displayed in normal weight
-pub fn render_toolbar(primary_text: &str, secondary_text: &str) -> Element<'static, Message> {
+/// With a month/year picker the title opens a popover to jump to any month
+pub fn render_toolbar(
+    primary_text: &str,
+    secondary_text: &str,
+    picker: CalendarPicker,
+    month_year_picker: Option<MonthYearPicker>,
+) -> Element<'static, Message> {
     let primary = primary_text.to_string();
     let secondary = secondary_text.to_string();
 
+    let title = row()
+        .spacing(SPACING_MEDIUM)
+        .align_y(cosmic::iced::Alignment::Center)
+        .push(widget::text::title4(primary))
+        .push(widget::text::body(secondary));
+
+    let title: Element<'static, Message> = match month_year_picker {
+        Some(month_year_picker) => {
+            let name = format!("{} {}", primary_text, secondary_text);
+            render_month_year_picker(title.into(), name, month_year_picker)
+        }
+        None => title.into(),
+    };
+
     row()
         .padding(PADDING_SMALL)
         .spacing(SPACING_MEDIUM)
         .align_y(cosmic::iced::Alignment::Center)
         .push(
             button::icon(widget::icon::from_name(ICON_PREVIOUS))
+                .tooltip(fl!("nav-previous"))
                 .on_press(Message::PreviousPeriod)
                 .padding(PADDING_TINY)
         )
         .push(
             button::icon(widget::icon::from_name(ICON_NEXT))
+                .tooltip(fl!("nav-next"))
                 .on_press(Message::NextPeriod)
                 .padding(PADDING_TINY)
         )
+        .push(title)
+        .push(spacer(Length::Fill, Length::Shrink))
+        .push(render_calendar_picker(picker))
+        .into()
+}
+
+/// Render the title as a button opening a month grid with a year stepper
+/// `name` is the title as plain text, for screen readers
+fn render_month_year_picker<'a>(
+    title: Element<'a, Message>,
+    name: String,
+    picker: MonthYearPicker,
+) -> Element<'a, Message> {
+    let title_button = button::custom(
+        row()
+            .spacing(SPACING_MEDIUM)
+            .align_y(cosmic::iced::Alignment::Center)
+            .push(title)
+            .push(widget::icon::from_name(ICON_EXPANDED).size(16)),
+    )
+    .on_press(Message::ToggleMonthYearPicker)
+    .name(name)
+    .padding([4, 8])
+    .class(cosmic::theme::Button::Text);
+
+    let Some(browse_year) = picker.browse_year else {
+        return title_button.into();
+    };
+
+    let year_stepper = row()
+        .spacing(SPACING_MEDIUM)
+        .align_y(cosmic::iced::Alignment::Center)
+        .push(
+            button::icon(widget::icon::from_name(ICON_PREVIOUS))
+                .tooltip(fl!("nav-previous-year"))
+                .on_press(Message::StepMonthYearPicker(-1))
+                .padding(PADDING_TINY),
+        )
         .push(
-            row()
-                .spacing(SPACING_MEDIUM)
-                .align_y(cosmic::iced::Alignment::Center)
-                .push(widget::text::title4(primary))
-                .push(widget::text::body(secondary))
+            widget::text::heading(browse_year.to_string())
+                .width(Length::Fill)
+                .align_x(cosmic::iced::alignment::Horizontal::Center),
         )
+        .push(
+            button::icon(widget::icon::from_name(ICON_NEXT))
+                .tooltip(fl!("nav-next-year"))
+                .on_press(Message::StepMonthYearPicker(1))
+                .padding(PADDING_TINY),
+        );
+
+    let mut grid = column().spacing(SPACING_SMALL).push(year_stepper);
+    let month_names = get_month_names();
+    for (row_index, names) in month_names.chunks(MONTHS_PER_ROW).enumerate() {
+        let mut month_row = row().spacing(SPACING_SMALL);
+        for (offset, name) in names.iter().enumerate() {
+            let month = (row_index * MONTHS_PER_ROW + offset + 1) as u32;
+            let is_current = browse_year == picker.current_year && month == picker.current_month;
+            month_row = month_row.push(
+                button::custom(
+                    widget::text::body(name.clone())
+                        .width(Length::Fill)
+                        .align_x(cosmic::iced::alignment::Horizontal::Center),
+                )
+                .on_press(Message::SelectMonthYear(browse_year, month))
+                .width(Length::Fill)
+                .class(if is_current {
+                    cosmic::theme::Button::Suggested
+                } else {
+                    cosmic::theme::Button::Text
+                }),
+            );
+        }
+        grid = grid.push(month_row);
+    }
+
+    let popup = container(grid)
+        .padding(PADDING_SMALL)
+        .width(Length::Fixed(300.0))
+        .style(popup_style);
+
+    popover(title_button)
+        .popup(popup)
+        .on_close(Message::ToggleMonthYearPicker)
+        .into()
+}
+
+/// Render the selected calendar swatch and name, opening a list of calendars to switch to
+fn render_calendar_picker(picker: CalendarPicker) -> Element<'static, Message> {
+    let selected_name = picker
+        .selected_id
+        .as_ref()
+        .and_then(|id| picker.calendars.iter().find(|c| &c.id == id))
+        .map(|c| c.name.clone())
+        .unwrap_or_default();
+
+    let picker_button = button::custom(
+        row()
+            .spacing(SPACING_MEDIUM)
+            .align_y(cosmic::iced::Alignment::Center)
+            .push(color_swatch(&picker.selected_color))
+            .push(widget::text::body(selected_name))
+            .push(widget::icon::from_name(ICON_EXPANDED).size(16)),
+    )
+    .on_press(Message::ToggleCalendarPicker)
+    .name(fl!("calendar-select"))
+    .padding([4, 8])
+    .class(cosmic::theme::Button::Text);
+
+    if !picker.open {
+        return picker_button.into();
+    }
+
+    let mut list = column().spacing(SPACING_SMALL);
+    for calendar in picker.calendars {
+        let is_selected = picker.selected_id.as_deref() == Some(calendar.id.as_str());
+        list = list.push(
+            button::custom(
+                row()
+                    .spacing(SPACING_MEDIUM)
+                    .align_y(cosmic::iced::Alignment::Center)
+                    .push(color_swatch(&calendar.color))
+                    .push(widget::text::body(calendar.name)),
+            )
+            .on_press(Message::SelectCalendar(calendar.id))
+            .width(Length::Fill)
+            .class(if is_selected {
+                cosmic::theme::Button::Suggested
+            } else {
+                cosmic::theme::Button::Text
+            }),
+        );
+    }
+
+    let popup = container(list)
+        .padding(PADDING_SMALL)
+        .width(Length::Fixed(220.0))
+        .style(popup_style);
+
+    popover(picker_button)
+        .popup(popup)
+        .on_close(Message::ToggleCalendarPicker)
+        .into()
+}
+
+/// Background and border shared by the toolbar popovers
+fn popup_style(theme: &cosmic::Theme) -> container::Style {
+    let cosmic = theme.cosmic();
+    container::Style {
+        background: Some(cosmic::iced::Background::Color(cosmic.background.base.into())),
+        border: cosmic::iced::Border {
+            radius: cosmic.corner_radii.radius_m.into(),
+            width: 1.0,
+            color: cosmic.bg_divider().into(),
+        },
+        ..Default::default()
+    }
+}
+
+/// Small rounded square in a calendar's color
+fn color_swatch(hex: &str) -> Element<'static, Message> {
+    let color = parse_hex_color(hex).unwrap_or(COLOR_DEFAULT_GRAY);
+    container(widget::text(""))
+        .width(SWATCH_SIZE)
+        .height(SWATCH_SIZE)
+        .style(move |_theme: &cosmic::Theme| container::Style {
+            background: Some(cosmic::iced::Background::Color(color)),
+            border: cosmic::iced::Border {
+                radius: 3.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
         .into()
 }
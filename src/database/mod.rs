@@ -1,3 +1,6 @@
 mod schema;
 
 pub use schema::Database;
+
+#[cfg(test)]
+pub(crate) use schema::tests::create_import_event;
@@ -1,17 +1,21 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use log::{debug, info};
 use rusqlite::{Connection, params, Result as SqlResult};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
 use std::error::Error;
 use std::path::PathBuf;
 
-use crate::caldav::CalendarEvent;
+use crate::caldav::{AlertTime, CalendarEvent};
 
 /// Current database schema version for migrations
-const SCHEMA_VERSION: i32 = 5;
+const SCHEMA_VERSION: i32 = 12;
 
 /// Database connection wrapper with encryption support
 pub struct Database {
     conn: Connection,
+    /// Calendars whose events were written since they were last taken
+    changed_calendars: RefCell<HashSet<String>>,
 }
 
 impl std::fmt::Debug for Database {
@@ -40,7 +44,7 @@ impl Database {
         }
 
         let conn = Connection::open(&path)?;
-        let mut db = Database { conn };
+        let mut db = Database { conn, changed_calendars: RefCell::default() };
 
         // Initialize schema
         db.init_schema()?;
@@ -70,7 +74,7 @@ impl Database {
         // The key must be set before any other operations
         conn.pragma_update(None, "key", passphrase)?;
 
-        let mut db = Database { conn };
+        let mut db = Database { conn, changed_calendars: RefCell::default() };
         db.init_schema()?;
 
         Ok(db)
@@ -152,11 +156,15 @@ impl Database {
                 repeat_until TEXT,
                 exception_dates TEXT NOT NULL DEFAULT '[]',
                 invitees TEXT NOT NULL DEFAULT '[]',
-                alert TEXT NOT NULL DEFAULT 'None',
-                alert_second TEXT,
+                alerts TEXT NOT NULL DEFAULT '[]',
                 attachments TEXT NOT NULL DEFAULT '[]',
                 url TEXT,
                 notes TEXT,
+                alarms TEXT NOT NULL DEFAULT '[]',
+                show_as TEXT NOT NULL DEFAULT '"Busy"',
+                priority INTEGER,
+                overrides TEXT NOT NULL DEFAULT '{}',
+                display_order INTEGER,
                 created_at TEXT NOT NULL DEFAULT (datetime('now')),
                 updated_at TEXT NOT NULL DEFAULT (datetime('now')),
                 UNIQUE(calendar_id, uid)
@@ -166,6 +174,13 @@ impl Database {
             CREATE INDEX IF NOT EXISTS idx_events_start_time ON events(start_time);
             CREATE INDEX IF NOT EXISTS idx_events_calendar_id ON events(calendar_id);
             CREATE INDEX IF NOT EXISTS idx_events_calendar_date ON events(calendar_id, start_time);
+
+            -- Free-text notes attached to a date rather than an event
+            CREATE TABLE IF NOT EXISTS day_notes (
+                date TEXT PRIMARY KEY,
+                text TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );
             "#,
         )?;
 
@@ -289,6 +304,105 @@ impl Database {
             )?;
         }
 
+        if from_version < 6 {
+            // Migrate from v5 to v6: Keep imported VALARM components
+            self.conn.execute_batch(
+                r#"
+                -- Add alarms column for imported reminder components
+                -- Stored as JSON array of EventAlarm objects
+                ALTER TABLE events ADD COLUMN alarms TEXT NOT NULL DEFAULT '[]';
+                "#,
+            )?;
+        }
+
+        if from_version < 7 {
+            // Migrate from v6 to v7: Keep busy/tentative/free availability
+            self.conn.execute_batch(
+                r#"
+                -- Add show_as column derived from STATUS and TRANSP
+                -- Stored as JSON ShowAs value
+                ALTER TABLE events ADD COLUMN show_as TEXT NOT NULL DEFAULT '"Busy"';
+                "#,
+            )?;
+        }
+
+        if from_version < 8 {
+            // Migrate from v7 to v8: Keep the iCalendar PRIORITY
+            self.conn.execute_batch(
+                r#"
+                -- Add priority column (1-9, NULL when undefined)
+                ALTER TABLE events ADD COLUMN priority INTEGER;
+                "#,
+            )?;
+        }
+
+        if from_version < 9 {
+            // Migrate from v8 to v9: Keep modified occurrences of recurring events
+            self.conn.execute_batch(
+                r#"
+                -- Add overrides column for RECURRENCE-ID occurrences
+                -- Stored as JSON object of OccurrenceOverride keyed by the replaced date
+                ALTER TABLE events ADD COLUMN overrides TEXT NOT NULL DEFAULT '{}';
+                "#,
+            )?;
+        }
+
+        if from_version < 10 {
+            // Migrate from v9 to v10: Day notes
+            self.conn.execute_batch(
+                r#"
+                -- Free-text notes attached to a date rather than an event
+                CREATE TABLE IF NOT EXISTS day_notes (
+                    date TEXT PRIMARY KEY,
+                    text TEXT NOT NULL,
+                    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+                );
+                "#,
+            )?;
+        }
+
+        if from_version < 11 {
+            // Migrate from v10 to v11: Manual order of all-day events
+            self.conn.execute_batch(
+                r#"
+                -- Add display_order column (NULL sorts chronologically)
+                ALTER TABLE events ADD COLUMN display_order INTEGER;
+                "#,
+            )?;
+        }
+
+        if from_version < 12 {
+            // Migrate from v11 to v12: Any number of reminders per event
+            self.conn.execute_batch(
+                r#"
+                -- Add alerts column replacing alert and alert_second
+                -- Stored as JSON array of AlertTime values
+                ALTER TABLE events ADD COLUMN alerts TEXT NOT NULL DEFAULT '[]';
+                "#,
+            )?;
+
+            // Fold the two old alert columns into the list
+            let rows: Vec<(String, String, String, Option<String>)> = {
+                let mut stmt = self.conn.prepare("SELECT calendar_id, uid, alert, alert_second FROM events")?;
+                let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))?;
+                rows.collect::<SqlResult<_>>()?
+            };
+            for (calendar_id, uid, alert, alert_second) in rows {
+                let alerts: Vec<AlertTime> = std::iter::once(Some(alert))
+                    .chain(std::iter::once(alert_second))
+                    .flatten()
+                    .filter_map(|s| serde_json::from_str::<AlertTime>(&s).ok())
+                    .filter(|a| *a != AlertTime::None)
+                    .collect();
+                if !alerts.is_empty() {
+                    self.conn.execute(
+                        "UPDATE events SET alerts = ?3 WHERE calendar_id = ?1 AND uid = ?2",
+                        params![calendar_id, uid, serde_json::to_string(&alerts)?],
+                    )?;
+                }
+            }
+        }
+
         self.set_schema_version(SCHEMA_VERSION)?;
         Ok(())
     }
@@ -301,8 +415,7 @@ impl Database {
         let travel_time = serde_json::to_string(&event.travel_time)?;
         let repeat = serde_json::to_string(&event.repeat)?;
         let invitees = serde_json::to_string(&event.invitees)?;
-        let alert = serde_json::to_string(&event.alert)?;
-        let alert_second = event.alert_second.as_ref().map(|a| serde_json::to_string(a)).transpose()?;
+        let alerts = serde_json::to_string(&event.alerts)?;
         let attachments = serde_json::to_string(&event.attachments)?;
         let repeat_until = event.repeat_until.map(|d| d.format("%Y-%m-%d").to_string());
         // Convert exception_dates to JSON array of date strings
@@ -310,12 +423,16 @@ impl Database {
             .map(|d| d.format("%Y-%m-%d").to_string())
             .collect();
         let exception_dates_json = serde_json::to_string(&exception_dates)?;
+        let alarms = serde_json::to_string(&event.alarms)?;
+        let show_as = serde_json::to_string(&event.show_as)?;
+        let overrides = serde_json::to_string(&event.overrides)?;
 
         self.conn.execute(
             r#"
             INSERT INTO events (uid, calendar_id, summary, location, all_day, start_time, end_time,
-                               travel_time, repeat, repeat_until, exception_dates, invitees, alert, alert_second, attachments, url, notes)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
+                               travel_time, repeat, repeat_until, exception_dates, invitees, alerts, attachments, url, notes, alarms, show_as, priority, overrides,
+                               display_order)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)
             "#,
             params![
                 event.uid,
@@ -330,13 +447,18 @@ impl Database {
                 repeat_until,
                 exception_dates_json,
                 invitees,
-                alert,
-                alert_second,
+                alerts,
                 attachments,
                 event.url,
                 event.notes,
+                alarms,
+                show_as,
+                event.priority,
+                overrides,
+                event.display_order,
             ],
         )?;
+        self.mark_changed(calendar_id);
         Ok(())
     }
 
@@ -346,8 +468,7 @@ impl Database {
         let travel_time = serde_json::to_string(&event.travel_time)?;
         let repeat = serde_json::to_string(&event.repeat)?;
         let invitees = serde_json::to_string(&event.invitees)?;
-        let alert = serde_json::to_string(&event.alert)?;
-        let alert_second = event.alert_second.as_ref().map(|a| serde_json::to_string(a)).transpose()?;
+        let alerts = serde_json::to_string(&event.alerts)?;
         let attachments = serde_json::to_string(&event.attachments)?;
         let repeat_until = event.repeat_until.map(|d| d.format("%Y-%m-%d").to_string());
         // Convert exception_dates to JSON array of date strings
@@ -355,6 +476,9 @@ impl Database {
             .map(|d| d.format("%Y-%m-%d").to_string())
             .collect();
         let exception_dates_json = serde_json::to_string(&exception_dates)?;
+        let alarms = serde_json::to_string(&event.alarms)?;
+        let show_as = serde_json::to_string(&event.show_as)?;
+        let overrides = serde_json::to_string(&event.overrides)?;
 
         self.conn.execute(
             r#"
@@ -369,11 +493,15 @@ impl Database {
                 repeat_until = ?10,
                 exception_dates = ?11,
                 invitees = ?12,
-                alert = ?13,
-                alert_second = ?14,
-                attachments = ?15,
-                url = ?16,
-                notes = ?17,
+                alerts = ?13,
+                attachments = ?14,
+                url = ?15,
+                notes = ?16,
+                alarms = ?17,
+                show_as = ?18,
+                priority = ?19,
+                overrides = ?20,
+                display_order = ?21,
                 updated_at = datetime('now')
             WHERE calendar_id = ?1 AND uid = ?2
             "#,
@@ -390,13 +518,18 @@ impl Database {
                 repeat_until,
                 exception_dates_json,
                 invitees,
-                alert,
-                alert_second,
+                alerts,
                 attachments,
                 event.url,
                 event.notes,
+                alarms,
+                show_as,
+                event.priority,
+                overrides,
+                event.display_order,
             ],
         )?;
+        self.mark_changed(calendar_id);
         Ok(())
     }
 
@@ -406,6 +539,9 @@ impl Database {
             "DELETE FROM events WHERE calendar_id = ?1 AND uid = ?2",
             params![calendar_id, uid]
         )?;
+        if rows > 0 {
+            self.mark_changed(calendar_id);
+        }
         Ok(rows > 0)
     }
 
@@ -413,8 +549,8 @@ impl Database {
     pub fn get_events_for_calendar(&self, calendar_id: &str) -> Result<Vec<CalendarEvent>, Box<dyn Error>> {
         let mut stmt = self.conn.prepare(
             r#"SELECT uid, summary, location, all_day, start_time, end_time,
-                      travel_time, repeat, repeat_until, exception_dates, invitees, alert, alert_second,
-                      attachments, url, notes
+                      travel_time, repeat, repeat_until, exception_dates, invitees, alerts,
+                      attachments, url, notes, alarms, show_as, priority, overrides, display_order
                FROM events WHERE calendar_id = ?1"#
         )?;
 
@@ -426,9 +562,11 @@ impl Database {
             let repeat_until_str: Option<String> = row.get(8)?;
             let exception_dates_str: String = row.get::<_, Option<String>>(9)?.unwrap_or_else(|| "[]".to_string());
             let invitees_str: String = row.get(10)?;
-            let alert_str: String = row.get(11)?;
-            let alert_second_str: Option<String> = row.get(12)?;
-            let attachments_str: String = row.get(13)?;
+            let alerts_str: String = row.get(11)?;
+            let attachments_str: String = row.get(12)?;
+            let alarms_str: String = row.get::<_, Option<String>>(15)?.unwrap_or_else(|| "[]".to_string());
+            let show_as_str: Option<String> = row.get(16)?;
+            let overrides_str: Option<String> = row.get(18)?;
 
             // Parse exception_dates from JSON array of date strings
             let exception_dates_strings: Vec<String> = serde_json::from_str(&exception_dates_str).unwrap_or_default();
@@ -452,11 +590,15 @@ impl Database {
                 repeat_until: repeat_until_str.and_then(|s| chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
                 exception_dates,
                 invitees: serde_json::from_str(&invitees_str).unwrap_or_default(),
-                alert: serde_json::from_str(&alert_str).unwrap_or_default(),
-                alert_second: alert_second_str.and_then(|s| serde_json::from_str(&s).ok()),
+                alerts: serde_json::from_str(&alerts_str).unwrap_or_default(),
                 attachments: serde_json::from_str(&attachments_str).unwrap_or_default(),
-                url: row.get(14)?,
-                notes: row.get(15)?,
+                url: row.get(13)?,
+                notes: row.get(14)?,
+                show_as: show_as_str.and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default(),
+                priority: row.get(17)?,
+                alarms: serde_json::from_str(&alarms_str).unwrap_or_default(),
+                overrides: overrides_str.and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default(),
+                display_order: row.get(19)?,
             })
         })?
         .collect::<SqlResult<Vec<_>>>()?;
@@ -470,9 +612,71 @@ impl Database {
             "DELETE FROM events WHERE calendar_id = ?1",
             params![calendar_id],
         )?;
+        if rows > 0 {
+            self.mark_changed(calendar_id);
+        }
         Ok(rows)
     }
 
+    /// Remember that a calendar's events were written
+    fn mark_changed(&self, calendar_id: &str) {
+        self.changed_calendars.borrow_mut().insert(calendar_id.to_string());
+    }
+
+    /// Calendars whose events were written since the last call
+    /// (writes that were rolled back are included)
+    pub fn take_changed_calendars(&self) -> HashSet<String> {
+        self.changed_calendars.take()
+    }
+
+    /// Start a transaction so a batch of writes is committed as a whole
+    pub fn begin_transaction(&self) -> Result<(), Box<dyn Error>> {
+        self.conn.execute_batch("BEGIN")?;
+        debug!("Database: Began transaction");
+        Ok(())
+    }
+
+    /// Commit the open transaction
+    pub fn commit_transaction(&self) -> Result<(), Box<dyn Error>> {
+        self.conn.execute_batch("COMMIT")?;
+        debug!("Database: Committed transaction");
+        Ok(())
+    }
+
+    /// Whether a transaction is currently open
+    pub fn in_transaction(&self) -> bool {
+        !self.conn.is_autocommit()
+    }
+
+    // ==================== Day Note Operations ====================
+
+    /// Get all day notes keyed by date
+    pub fn get_day_notes(&self) -> Result<BTreeMap<NaiveDate, String>, Box<dyn Error>> {
+        let mut stmt = self.conn.prepare("SELECT date, text FROM day_notes")?;
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?;
+
+        let mut notes = BTreeMap::new();
+        for row in rows {
+            let (date, text) = row?;
+            notes.insert(NaiveDate::parse_from_str(&date, "%Y-%m-%d")?, text);
+        }
+        Ok(notes)
+    }
+
+    /// Save the note for a date, removing it when the text is empty
+    pub fn set_day_note(&self, date: NaiveDate, text: &str) -> Result<(), Box<dyn Error>> {
+        let date = date.format("%Y-%m-%d").to_string();
+        if text.trim().is_empty() {
+            self.conn.execute("DELETE FROM day_notes WHERE date = ?1", params![date])?;
+        } else {
+            self.conn.execute(
+                "INSERT OR REPLACE INTO day_notes (date, text, updated_at) VALUES (?1, ?2, datetime('now'))",
+                params![date, text],
+            )?;
+        }
+        Ok(())
+    }
+
     /// Delete all events from all calendars
     /// Used for development/testing to start fresh
     #[cfg(debug_assertions)]
@@ -483,10 +687,11 @@ impl Database {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
-    use crate::caldav::{AlertTime, RepeatFrequency, TravelTime};
+    use crate::caldav::{AlertTime, RepeatFrequency, ShowAs, TravelTime};
     use chrono::TimeZone;
+    use std::collections::BTreeMap;
 
     #[test]
     fn test_database_creation() {
@@ -527,11 +732,15 @@ mod tests {
             repeat_until: None,
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::FifteenMinutes,
-            alert_second: None,
+            alerts: vec![AlertTime::FifteenMinutes],
             attachments: vec![],
             url: None,
             notes: Some("A test event".to_string()),
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         };
 
         db.insert_event("cal1", &event).unwrap();
@@ -542,7 +751,7 @@ mod tests {
         assert_eq!(events[0].summary, "Test Event");
 
         // Delete event
-        let deleted = db.delete_event("event1").unwrap();
+        let deleted = db.delete_event("cal1", "event1").unwrap();
         assert!(deleted);
 
         let events = db.get_events_for_calendar("cal1").unwrap();
@@ -556,4 +765,106 @@ mod tests {
         // Clean up
         let _ = std::fs::remove_file(&db_path);
     }
+
+    #[test]
+    fn test_day_notes() {
+        let temp_dir = std::env::temp_dir();
+        let db_path = temp_dir.join("sol_test_day_notes.db");
+        let _ = std::fs::remove_file(&db_path);
+
+        let db = Database::open_at(db_path.clone()).unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 12, 24).unwrap();
+
+        db.set_day_note(date, "Wrap presents").unwrap();
+        assert_eq!(db.get_day_notes().unwrap().get(&date).map(String::as_str), Some("Wrap presents"));
+
+        // Saving again replaces the note
+        db.set_day_note(date, "Call family").unwrap();
+        let notes = db.get_day_notes().unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[&date], "Call family");
+
+        // An empty note removes it
+        db.set_day_note(date, "  ").unwrap();
+        assert!(db.get_day_notes().unwrap().is_empty());
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_changed_calendars_tracked() {
+        let temp_dir = std::env::temp_dir();
+        let db_path = temp_dir.join("sol_test_changed_calendars.db");
+        let _ = std::fs::remove_file(&db_path);
+
+        let db = Database::open_at(db_path.clone()).unwrap();
+        assert!(db.take_changed_calendars().is_empty());
+
+        db.insert_event("cal1", &create_import_event(1)).unwrap();
+        db.update_event("cal1", &create_import_event(1)).unwrap();
+        db.insert_event("cal2", &create_import_event(2)).unwrap();
+        let changed = db.take_changed_calendars();
+        assert_eq!(changed, HashSet::from(["cal1".to_string(), "cal2".to_string()]));
+
+        // Reading doesn't count, and taking clears the set
+        db.get_events_for_calendar("cal1").unwrap();
+        assert!(db.take_changed_calendars().is_empty());
+
+        // Deleting an event that doesn't exist changes nothing
+        assert!(!db.delete_event("cal1", "missing").unwrap());
+        assert!(db.take_changed_calendars().is_empty());
+        db.delete_event("cal2", "import-2").unwrap();
+        assert_eq!(db.take_changed_calendars(), HashSet::from(["cal2".to_string()]));
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    /// Timed event "import-<index>" on 1 December 2025, shared with the import tests
+    pub(crate) fn create_import_event(index: u32) -> CalendarEvent {
+        CalendarEvent {
+            uid: format!("import-{}", index),
+            summary: format!("Imported {}", index),
+            location: None,
+            all_day: false,
+            start: Utc.with_ymd_and_hms(2025, 12, 1, 9, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 12, 1, 10, 0, 0).unwrap(),
+            travel_time: TravelTime::None,
+            repeat: RepeatFrequency::Never,
+            repeat_until: None,
+            exception_dates: vec![],
+            invitees: vec![],
+            alerts: vec![],
+            attachments: vec![],
+            url: None,
+            notes: None,
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
+        }
+    }
+
+    #[test]
+    fn test_import_batch_commits() {
+        let temp_dir = std::env::temp_dir();
+        let db_path = temp_dir.join("sol_test_import_batch.db");
+        let _ = std::fs::remove_file(&db_path);
+
+        let db = Database::open_at(db_path.clone()).unwrap();
+        db.insert_event("cal1", &create_import_event(0)).unwrap();
+
+        // A batch of imported events is written in one transaction
+        db.begin_transaction().unwrap();
+        assert!(db.in_transaction());
+        for index in 1..=10 {
+            db.insert_event("cal1", &create_import_event(index)).unwrap();
+        }
+        db.commit_transaction().unwrap();
+
+        assert!(!db.in_transaction());
+        assert_eq!(db.get_events_for_calendar("cal1").unwrap().len(), 11);
+
+        let _ = std::fs::remove_file(&db_path);
+    }
 }
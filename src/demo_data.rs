@@ -3,10 +3,11 @@
 //! This module generates realistic calendar events across a full year
 //! to showcase the calendar application's capabilities.
 
-use crate::caldav::{AlertTime, CalendarEvent, RepeatFrequency, TravelTime};
+use crate::caldav::{AlertTime, CalendarEvent, RepeatFrequency, ShowAs, TravelTime};
 use crate::database::Database;
 use chrono::{Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
 use log::info;
+use std::collections::BTreeMap;
 use std::error::Error;
 use uuid::Uuid;
 
@@ -75,11 +76,15 @@ fn generate_recurring_meetings(db: &Database, start: NaiveDate, end: NaiveDate)
             repeat_until: Some(end),
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::FifteenMinutes,
-            alert_second: None,
+            alerts: vec![AlertTime::FifteenMinutes],
             attachments: vec![],
             url: None,
             notes: Some("Daily sync with the team. Discuss blockers and progress.".to_string()),
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         };
         db.insert_event("work", &event)?;
         count += 1;
@@ -105,11 +110,15 @@ fn generate_recurring_meetings(db: &Database, start: NaiveDate, end: NaiveDate)
             repeat_until: Some(end),
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::ThirtyMinutes,
-            alert_second: None,
+            alerts: vec![AlertTime::ThirtyMinutes],
             attachments: vec![],
             url: None,
             notes: Some("Weekly check-in. Bring status updates and questions.".to_string()),
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         };
         db.insert_event("work", &event)?;
         count += 1;
@@ -135,11 +144,15 @@ fn generate_recurring_meetings(db: &Database, start: NaiveDate, end: NaiveDate)
             repeat_until: Some(end),
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::OneHour,
-            alert_second: None,
+            alerts: vec![AlertTime::OneHour],
             attachments: vec![],
             url: None,
             notes: Some("Demo completed work to stakeholders.".to_string()),
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         };
         db.insert_event("work", &event)?;
         count += 1;
@@ -361,11 +374,15 @@ fn generate_health_fitness(db: &Database, start: NaiveDate, end: NaiveDate) -> R
             repeat_until: Some(end),
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::ThirtyMinutes,
-            alert_second: None,
+            alerts: vec![AlertTime::ThirtyMinutes],
             attachments: vec![],
             url: None,
             notes: Some("Strength training day".to_string()),
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         };
         db.insert_event("personal", &event)?;
         count += 1;
@@ -391,11 +408,15 @@ fn generate_health_fitness(db: &Database, start: NaiveDate, end: NaiveDate) -> R
             repeat_until: Some(end),
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::ThirtyMinutes,
-            alert_second: None,
+            alerts: vec![AlertTime::ThirtyMinutes],
             attachments: vec![],
             url: None,
             notes: Some("Strength training day".to_string()),
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         };
         db.insert_event("personal", &event)?;
         count += 1;
@@ -421,11 +442,15 @@ fn generate_health_fitness(db: &Database, start: NaiveDate, end: NaiveDate) -> R
             repeat_until: Some(end),
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::FifteenMinutes,
-            alert_second: None,
+            alerts: vec![AlertTime::FifteenMinutes],
             attachments: vec![],
             url: None,
             notes: Some("5K run".to_string()),
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         };
         db.insert_event("personal", &event)?;
         count += 1;
@@ -499,11 +524,15 @@ fn generate_varied_events(db: &Database, start: NaiveDate, end: NaiveDate) -> Re
                         repeat_until: None,
                         exception_dates: vec![],
                         invitees: vec![],
-                        alert: AlertTime::OneWeek,
-                        alert_second: None,
+                        alerts: vec![AlertTime::OneWeek],
                         attachments: vec![],
                         url: None,
                         notes: Some("Time off - out of office".to_string()),
+                        show_as: ShowAs::Busy,
+                        priority: None,
+                        alarms: vec![],
+                        overrides: BTreeMap::new(),
+                        display_order: None,
                     };
                     db.insert_event("personal", &event)?;
                     count += 1;
@@ -585,11 +614,15 @@ fn insert_event(
         repeat_until: None,
         exception_dates: vec![],
         invitees: vec![],
-        alert: template.alert,
-        alert_second: None,
+        alerts: vec![template.alert],
         attachments: vec![],
         url: None,
         notes: template.notes.map(String::from),
+        show_as: ShowAs::Busy,
+        priority: None,
+        alarms: vec![],
+        overrides: BTreeMap::new(),
+        display_order: None,
     };
 
     db.insert_event(calendar_id, &event)?;
@@ -0,0 +1,81 @@
+//! Command palette dialog UI component
+//!
+//! A single text field for creating events from the keyboard, e.g.
+//! "Lunch tomorrow 12:30-13:30". A live preview shows how the text
+//! will be interpreted before the event is created.
+
+use chrono::{Duration, NaiveDate};
+use cosmic::iced::Length;
+use cosmic::iced_widget::text_input;
+use cosmic::widget::{button, column, dialog, text};
+use cosmic::{widget, Element};
+
+use crate::dialogs::ActiveDialog;
+use crate::fl;
+use crate::message::Message;
+use crate::quick_entry::parse_quick_entry;
+
+/// Text input ID for the command palette
+pub fn command_palette_input_id() -> text_input::Id {
+    text_input::Id::new("command_palette_input")
+}
+
+/// Render the command palette dialog
+/// `default_date` is used when the text doesn't name a date and
+/// `default_duration` when a time has no explicit end
+pub fn render_command_palette_dialog(
+    active_dialog: &ActiveDialog,
+    default_date: NaiveDate,
+    default_duration: Duration,
+) -> Element<'_, Message> {
+    let text_value = match active_dialog {
+        ActiveDialog::CommandPalette { text } => text,
+        _ => return widget::text("").into(),
+    };
+
+    let input = widget::text_input(fl!("command-palette-placeholder"), text_value)
+        .id(command_palette_input_id())
+        .on_input(Message::CommandPaletteChanged)
+        .on_submit(|_| Message::CommitCommandPalette)
+        .width(Length::Fill);
+
+    let today = chrono::Local::now().date_naive();
+    let entry = parse_quick_entry(text_value, today, default_date, default_duration);
+    let has_summary = !entry.summary.is_empty();
+
+    // Preview: "Thu, Dec 4 · 12:30 – 13:30 · Lunch"
+    let when = match entry.times {
+        Some((start, end)) => format!(
+            "{} · {} – {}",
+            entry.date.format("%a, %b %-d"),
+            start.format("%H:%M"),
+            end.format("%H:%M")
+        ),
+        None => format!(
+            "{} · {}",
+            entry.date.format("%a, %b %-d"),
+            fl!("command-palette-all-day")
+        ),
+    };
+    let preview = if has_summary {
+        format!("{} · {}", when, entry.summary)
+    } else {
+        when
+    };
+
+    let content = column()
+        .spacing(12)
+        .push(input)
+        .push(text(preview).size(12));
+
+    dialog()
+        .title(fl!("dialog-command-palette-title"))
+        .control(content)
+        .primary_action(
+            button::suggested(fl!("button-create"))
+                .on_press_maybe(has_summary.then_some(Message::CommitCommandPalette)),
+        )
+        .secondary_action(button::text(fl!("button-cancel")).on_press(Message::CloseDialog))
+        .width(Length::Fixed(450.0))
+        .into()
+}
@@ -0,0 +1,60 @@
+//! Make recurring dialog UI component
+//!
+//! Lets the user pick a recurrence for a one-off event. The series is
+//! anchored at the event's existing date and time.
+
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, dialog, row, text};
+use cosmic::{widget, Element};
+
+use crate::caldav::RepeatFrequency;
+use crate::components::repeat_label;
+use crate::dialogs::ActiveDialog;
+use crate::fl;
+use crate::message::Message;
+
+/// Render the make recurring dialog
+pub fn render_convert_to_recurring_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
+    let repeat = match active_dialog {
+        ActiveDialog::ConvertToRecurring { repeat, .. } => repeat,
+        _ => return widget::text("").into(),
+    };
+
+    let repeat_options = [
+        RepeatFrequency::Daily,
+        RepeatFrequency::Weekly,
+        RepeatFrequency::Biweekly,
+        RepeatFrequency::Monthly,
+        RepeatFrequency::Yearly,
+    ];
+
+    let mut repeat_buttons = row().spacing(4);
+    for opt in repeat_options {
+        let is_selected = repeat == &opt;
+        repeat_buttons = repeat_buttons.push(
+            button::custom(text(repeat_label(&opt)).size(12))
+                .on_press(Message::ConvertToRecurringRepeatChanged(opt))
+                .padding([4, 8])
+                .class(if is_selected {
+                    cosmic::theme::Button::Suggested
+                } else {
+                    cosmic::theme::Button::Standard
+                }),
+        );
+    }
+
+    let content = column()
+        .spacing(12)
+        .push(text(fl!("convert-recurring-message")).size(14))
+        .push(repeat_buttons);
+
+    dialog()
+        .title(fl!("dialog-convert-recurring-title"))
+        .control(content)
+        .primary_action(
+            button::suggested(fl!("button-make-recurring")).on_press(Message::ConfirmConvertToRecurring),
+        )
+        .secondary_action(button::text(fl!("button-cancel")).on_press(Message::CloseDialog))
+        .width(Length::Fixed(450.0))
+        .into()
+}
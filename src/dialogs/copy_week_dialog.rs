@@ -0,0 +1,42 @@
+//! Copy week dialog UI component
+//!
+//! Confirms copying the displayed week's one-off events to the following
+//! week, showing how many events will be created.
+
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, dialog, text};
+use cosmic::{widget, Element};
+
+use crate::dialogs::ActiveDialog;
+use crate::fl;
+use crate::message::Message;
+
+/// Render the copy week confirmation dialog
+pub fn render_copy_week_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
+    let copies = match active_dialog {
+        ActiveDialog::CopyWeek { copies } => copies,
+        _ => return widget::text("").into(),
+    };
+
+    // Nothing to copy - just report and offer to close
+    if copies.is_empty() {
+        return dialog()
+            .title(fl!("dialog-copy-week-title"))
+            .body(fl!("copy-week-none"))
+            .primary_action(button::suggested(fl!("button-ok")).on_press(Message::CloseDialog))
+            .into();
+    }
+
+    let content = column()
+        .spacing(12)
+        .push(text(fl!("copy-week-count", count = (copies.len() as i64))).size(14))
+        .push(text(fl!("copy-week-note")).size(12));
+
+    dialog()
+        .title(fl!("dialog-copy-week-title"))
+        .control(content)
+        .primary_action(button::suggested(fl!("button-copy")).on_press(Message::ConfirmCopyWeek))
+        .secondary_action(button::text(fl!("button-cancel")).on_press(Message::CloseDialog))
+        .width(Length::Fixed(450.0))
+        .into()
+}
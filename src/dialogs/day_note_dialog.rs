@@ -0,0 +1,45 @@
+//! Day note dialog UI component
+//!
+//! Edits the free-text note attached to a date, for journaling alongside
+//! the calendar. Saving an empty note removes it.
+
+use cosmic::iced::Length;
+use cosmic::iced_widget::text_input;
+use cosmic::widget::{button, column, dialog, text};
+use cosmic::{widget, Element};
+
+use crate::dialogs::ActiveDialog;
+use crate::fl;
+use crate::message::Message;
+
+/// Text input ID for the day note
+pub fn day_note_input_id() -> text_input::Id {
+    text_input::Id::new("day_note_input")
+}
+
+/// Render the day note dialog
+pub fn render_day_note_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
+    let (date, text_value) = match active_dialog {
+        ActiveDialog::DayNote { date, text } => (date, text),
+        _ => return widget::text("").into(),
+    };
+
+    let input = widget::text_input(fl!("day-note-placeholder"), text_value)
+        .id(day_note_input_id())
+        .on_input(Message::DayNoteChanged)
+        .on_submit(|_| Message::SaveDayNote)
+        .width(Length::Fill);
+
+    let content = column()
+        .spacing(12)
+        .push(input)
+        .push(text(fl!("day-note-hint")).size(12));
+
+    dialog()
+        .title(fl!("dialog-day-note-title", date = date.format("%A, %B %-d, %Y").to_string()))
+        .control(content)
+        .primary_action(button::suggested(fl!("button-save")).on_press(Message::SaveDayNote))
+        .secondary_action(button::text(fl!("button-cancel")).on_press(Message::CloseDialog))
+        .width(Length::Fixed(450.0))
+        .into()
+}
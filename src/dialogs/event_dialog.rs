@@ -47,14 +47,14 @@ pub struct EventDialogData {
     pub travel_time: TravelTime,
     /// Repeat/recurrence settings
     pub repeat: RepeatFrequency,
+    /// iCalendar priority (1 highest to 9 lowest, None when undefined)
+    pub priority: Option<u8>,
     /// Selected calendar ID for the event
     pub calendar_id: String,
     /// Invitees (email addresses)
     pub invitees: Vec<String>,
-    /// Alert/reminder settings
-    pub alert: AlertTime,
-    /// Second alert (optional)
-    pub alert_second: Option<AlertTime>,
+    /// Reminders, in the order they were added
+    pub alerts: Vec<AlertTime>,
     /// File attachments (paths or URLs)
     pub attachments: Vec<String>,
     /// URL associated with the event
@@ -80,10 +80,10 @@ impl EventDialogData {
             end_time: default_end_time,
             travel_time: TravelTime::None,
             repeat: RepeatFrequency::Never,
+            priority: None,
             calendar_id,
             invitees: vec![],
-            alert: AlertTime::None,
-            alert_second: None,
+            alerts: vec![],
             attachments: vec![],
             url: String::new(),
             notes: String::new(),
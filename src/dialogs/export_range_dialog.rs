@@ -0,0 +1,87 @@
+//! Export date range dialog UI component
+//!
+//! Exports only the events within a chosen date range from the selected
+//! calendars, e.g. to share just next month's schedule.
+
+use chrono::NaiveDate;
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, dialog, row, text};
+use cosmic::{widget, Element};
+
+use crate::components::render_calendar_checkbox;
+use crate::dialogs::ActiveDialog;
+use crate::fl;
+use crate::message::Message;
+
+/// Date format used by the range inputs
+pub const EXPORT_RANGE_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Parse the range inputs, returning None unless both dates are valid and in order
+pub fn parse_export_range(start: &str, end: &str) -> Option<(NaiveDate, NaiveDate)> {
+    let start = NaiveDate::parse_from_str(start.trim(), EXPORT_RANGE_DATE_FORMAT).ok()?;
+    let end = NaiveDate::parse_from_str(end.trim(), EXPORT_RANGE_DATE_FORMAT).ok()?;
+    (start <= end).then_some((start, end))
+}
+
+/// Render the export date range dialog
+/// `calendars` lists (id, name, color) of every calendar that can be exported
+pub fn render_export_range_dialog(
+    active_dialog: &ActiveDialog,
+    calendars: Vec<(String, String, String)>,
+) -> Element<'_, Message> {
+    let (start, end, calendar_ids) = match active_dialog {
+        ActiveDialog::ExportRange { start, end, calendar_ids } => (start, end, calendar_ids),
+        _ => return widget::text("").into(),
+    };
+
+    let range_row = row()
+        .spacing(8)
+        .align_y(cosmic::iced::Alignment::Center)
+        .push(
+            widget::text_input("YYYY-MM-DD", start.as_str())
+                .on_input(Message::ExportRangeStartChanged)
+                .width(Length::Fill),
+        )
+        .push(text("–"))
+        .push(
+            widget::text_input("YYYY-MM-DD", end.as_str())
+                .on_input(Message::ExportRangeEndChanged)
+                .width(Length::Fill),
+        );
+
+    let calendar_list = calendars.into_iter().fold(column().spacing(4), |col, (id, name, color)| {
+        let is_checked = calendar_ids.contains(&id);
+        col.push(render_calendar_checkbox(
+            name.clone(),
+            Some(name),
+            &color,
+            is_checked,
+            Message::ExportRangeToggleCalendar(id),
+        ))
+    });
+
+    let range = parse_export_range(start, end);
+    let mut content = column()
+        .spacing(12)
+        .push(text(fl!("export-range-dates")).size(14))
+        .push(range_row);
+    if range.is_none() {
+        content = content.push(text::caption(fl!("export-range-invalid")));
+    }
+    content = content
+        .push(text(fl!("export-range-calendars")).size(14))
+        .push(calendar_list);
+
+    let can_export = range.is_some() && !calendar_ids.is_empty();
+
+    dialog()
+        .title(fl!("dialog-export-range-title"))
+        .control(content)
+        .primary_action(
+            button::suggested(fl!("button-export"))
+                .on_press_maybe(can_export.then_some(Message::ConfirmExportRange)),
+        )
+        .secondary_action(button::text(fl!("button-cancel")).on_press(Message::CloseDialog))
+        .width(Length::Fixed(450.0))
+        .into()
+}
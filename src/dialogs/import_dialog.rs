@@ -8,6 +8,7 @@ use cosmic::widget::{button, column, container, dialog, radio, scrollable, text}
 use cosmic::{widget, Element};
 
 use crate::calendars::CalendarSource;
+use crate::components::event_title;
 use crate::dialogs::ActiveDialog;
 use crate::fl;
 use crate::message::Message;
@@ -80,11 +81,11 @@ pub fn render_import_dialog<'a>(
         // Show ALL events in scrollable area
         for event in events.iter() {
             let event_text = if event.all_day {
-                format!("• {}", event.summary)
+                format!("• {}", event_title(&event.summary))
             } else {
                 format!(
                     "• {} ({} - {})",
-                    event.summary,
+                    event_title(&event.summary),
                     event.start.format("%H:%M"),
                     event.end.format("%H:%M")
                 )
@@ -2,7 +2,7 @@
 //!
 //! Shows the final result of a calendar import operation with:
 //! - Success/failure indicator
-//! - Import statistics (imported, skipped, failed)
+//! - Import statistics (imported, updated, skipped, failed)
 //! - Ok button to close
 //! - Revert button to undo the import
 
@@ -17,17 +17,18 @@ use crate::message::Message;
 /// Render the import result dialog
 pub fn render_import_result_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
     // Extract result data
-    let (success, imported_count, skipped_count, failed_count, source_file_name, calendar_name, error_message) = match active_dialog {
+    let (success, imported_count, updated_count, skipped_count, failed_count, source_file_name, calendar_name, error_message) = match active_dialog {
         ActiveDialog::ImportResult {
             success,
             imported_count,
+            updated_count,
             skipped_count,
             failed_count,
             source_file_name,
             calendar_name,
             error_message,
             ..
-        } => (success, imported_count, skipped_count, failed_count, source_file_name, calendar_name, error_message),
+        } => (success, imported_count, updated_count, skipped_count, failed_count, source_file_name, calendar_name, error_message),
         _ => return widget::text("").into(),
     };
 
@@ -51,6 +52,9 @@ pub fn render_import_result_dialog(active_dialog: &ActiveDialog) -> Element<'_,
     if *imported_count > 0 {
         stats = stats.push(text(format!("✓ {}: {}", fl!("import-imported"), imported_count)).size(14));
     }
+    if *updated_count > 0 {
+        stats = stats.push(text(format!("↻ {}: {}", fl!("import-updated"), updated_count)).size(14));
+    }
     if *skipped_count > 0 {
         stats = stats.push(text(format!("⊘ {}: {}", fl!("import-skipped"), skipped_count)).size(14));
     }
@@ -72,11 +76,11 @@ pub fn render_import_result_dialog(active_dialog: &ActiveDialog) -> Element<'_,
         .push(status_text)
         .push(stats);
 
-    // Buttons: Ok (primary) and Revert (destructive, only if imported > 0)
+    // Buttons: Ok (primary) and Revert (destructive, only if anything was imported or updated)
     let ok_button = button::suggested(fl!("button-ok"))
         .on_press(Message::CloseDialog);
 
-    let revert_button = if *imported_count > 0 {
+    let revert_button = if *imported_count > 0 || *updated_count > 0 {
         Some(button::destructive(fl!("button-revert"))
             .on_press(Message::RevertImport))
     } else {
@@ -0,0 +1,58 @@
+//! Make timed dialog UI component
+//!
+//! Asks for a start time when turning an all-day event into a timed one.
+//! The event keeps its first day and gets the calendar's default duration.
+
+use chrono::NaiveTime;
+use cosmic::iced::Length;
+use cosmic::iced_widget::text_input;
+use cosmic::widget::{button, column, dialog, text};
+use cosmic::{widget, Element};
+
+use crate::dialogs::ActiveDialog;
+use crate::fl;
+use crate::message::Message;
+
+/// Text input ID for the start time
+pub fn make_timed_input_id() -> text_input::Id {
+    text_input::Id::new("make_timed_input")
+}
+
+/// Parse the start time typed in the dialog
+pub fn parse_make_timed_start(start: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(start.trim(), "%H:%M").ok()
+}
+
+/// Render the make timed dialog
+pub fn render_make_timed_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
+    let start = match active_dialog {
+        ActiveDialog::MakeTimed { start, .. } => start,
+        _ => return widget::text("").into(),
+    };
+    let valid = parse_make_timed_start(start).is_some();
+
+    let input = widget::text_input("09:00", start)
+        .id(make_timed_input_id())
+        .on_input(Message::MakeTimedStartChanged)
+        .on_submit(|_| Message::ConfirmMakeTimed)
+        .width(Length::Fill);
+
+    let mut content = column()
+        .spacing(12)
+        .push(text(fl!("make-timed-message")))
+        .push(input);
+    if !valid {
+        content = content.push(text(fl!("make-timed-invalid")).size(12));
+    }
+
+    dialog()
+        .title(fl!("dialog-make-timed-title"))
+        .control(content)
+        .primary_action(
+            button::suggested(fl!("button-make-timed"))
+                .on_press_maybe(valid.then_some(Message::ConfirmMakeTimed)),
+        )
+        .secondary_action(button::text(fl!("button-cancel")).on_press(Message::CloseDialog))
+        .width(Length::Fixed(450.0))
+        .into()
+}
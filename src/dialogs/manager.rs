@@ -30,8 +30,10 @@
 //! in `CosmicCalendar` because `text_editor::Content` doesn't implement `Clone`.
 //! The centralized `Message::CloseDialog` handler closes all legacy dialog fields.
 
-use crate::caldav::CalendarEvent;
-use chrono::{NaiveDate, NaiveTime};
+use crate::caldav::{CalendarEvent, RepeatFrequency};
+use crate::models::MonthStats;
+use crate::services::DuplicateGroup;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use log::{debug, info};
 
 /// Identifies which dialog or transient UI element is currently active.
@@ -63,6 +65,15 @@ pub enum ActiveDialog {
         /// Event title being typed
         text: String,
     },
+    /// Inline rename of an event title on its chip
+    EventRename {
+        /// Calendar containing the event
+        calendar_id: String,
+        /// Displayed event UID (may be an occurrence UID)
+        uid: String,
+        /// Title being typed
+        text: String,
+    },
     /// Color picker for a specific calendar
     ColorPicker {
         calendar_id: String,
@@ -71,12 +82,28 @@ pub enum ActiveDialog {
     CalendarCreate {
         name: String,
         color: String,
+        /// Sidebar group name (empty for ungrouped)
+        group: String,
+        /// Length of new events in minutes (None for the app default)
+        default_duration: Option<u32>,
     },
     /// Edit existing calendar dialog
     CalendarEdit {
         calendar_id: String,
         name: String,
         color: String,
+        /// Sidebar group name (empty for ungrouped)
+        group: String,
+        /// Length of new events in minutes (None for the app default)
+        default_duration: Option<u32>,
+        /// Minutes between background syncs (None syncs only on demand)
+        sync_interval: Option<u32>,
+        /// When the calendar last synced (shown only, not edited)
+        last_synced: Option<DateTime<Utc>>,
+        /// Whether all-day events shade days as holidays
+        holiday_overlay: bool,
+        /// .ics file the calendar is kept exported to (empty when off)
+        auto_export_path: String,
     },
     /// Delete calendar confirmation dialog
     CalendarDelete {
@@ -122,6 +149,16 @@ pub enum ActiveDialog {
         imported_uids: Vec<String>,
         /// Target calendar ID
         calendar_id: String,
+        /// All events being imported; `current` indexes the next one
+        events: Vec<CalendarEvent>,
+        /// Previous versions of existing events the import updated (for rollback)
+        replaced_events: Vec<CalendarEvent>,
+        /// Number of events skipped because they already exist unchanged
+        skipped_count: usize,
+        /// Number of events that failed to import
+        failed_count: usize,
+        /// Source file name for the result dialog
+        source_file_name: String,
     },
     /// Import result dialog shown after import completes
     ImportResult {
@@ -129,7 +166,9 @@ pub enum ActiveDialog {
         success: bool,
         /// Number of events successfully imported
         imported_count: usize,
-        /// Number of events skipped (duplicates)
+        /// Number of existing events (same UID) updated with changed details
+        updated_count: usize,
+        /// Number of events skipped (unchanged duplicates)
         skipped_count: usize,
         /// Number of events that failed to import
         failed_count: usize,
@@ -139,6 +178,8 @@ pub enum ActiveDialog {
         calendar_name: String,
         /// List of imported event UIDs (for potential revert)
         imported_uids: Vec<String>,
+        /// Previous versions of the updated events (restored on revert)
+        replaced_events: Vec<CalendarEvent>,
         /// Target calendar ID (for revert)
         calendar_id: String,
         /// Optional error message if import failed
@@ -154,6 +195,78 @@ pub enum ActiveDialog {
         create_new_calendar: bool,
         new_calendar_name: String,
     },
+
+    /// Merge duplicates confirmation with the groups found by the analyzer
+    MergeDuplicates {
+        /// Duplicate groups to merge (empty when none were found)
+        groups: Vec<DuplicateGroup>,
+    },
+
+    /// Copy week confirmation with the events that will be created
+    CopyWeek {
+        /// Planned (calendar_id, event) copies in the following week
+        copies: Vec<(String, CalendarEvent)>,
+    },
+
+    /// Export the events in a date range from the chosen calendars
+    ExportRange {
+        /// Range start input (YYYY-MM-DD)
+        start: String,
+        /// Range end input (YYYY-MM-DD)
+        end: String,
+        /// Calendars to export
+        calendar_ids: Vec<String>,
+    },
+
+    /// Command palette for creating an event from typed text
+    CommandPalette {
+        /// Text entered so far
+        text: String,
+    },
+
+    /// Make a one-off event recurring
+    ConvertToRecurring {
+        /// Calendar containing the event
+        calendar_id: String,
+        /// UID of the event to convert
+        uid: String,
+        /// Chosen recurrence (pre-populated with weekly)
+        repeat: RepeatFrequency,
+    },
+
+    /// Give an all-day event a start time
+    MakeTimed {
+        /// Calendar containing the event
+        calendar_id: String,
+        /// UID of the event to convert
+        uid: String,
+        /// Start time being typed (HH:MM, pre-populated with working hours start)
+        start: String,
+    },
+
+    /// Summary of the displayed month's events
+    MonthStats {
+        /// Totals computed when the dialog was opened
+        stats: MonthStats,
+    },
+
+    /// Edit the free-text note attached to a date
+    DayNote {
+        /// Date the note belongs to
+        date: NaiveDate,
+        /// Note text being typed
+        text: String,
+    },
+
+    /// Sync conflict: an event changed both locally and on the server
+    SyncConflict {
+        /// Calendar the event belongs to
+        calendar_id: String,
+        /// Locally modified version
+        local: CalendarEvent,
+        /// Remotely modified version
+        remote: CalendarEvent,
+    },
 }
 
 impl Default for ActiveDialog {
@@ -251,6 +364,14 @@ impl ActiveDialog {
         }
     }
 
+    /// Get inline rename data if renaming (calendar_id, uid, text)
+    pub fn event_rename_data(&self) -> Option<(&str, &str, &str)> {
+        match self {
+            ActiveDialog::EventRename { calendar_id, uid, text } => Some((calendar_id, uid, text)),
+            _ => None,
+        }
+    }
+
     /// Get full quick event range if editing (start_date, end_date, text)
     pub fn quick_event_range(&self) -> Option<(NaiveDate, NaiveDate, &str)> {
         match self {
@@ -332,6 +453,12 @@ pub enum DialogAction {
         calendar_id: String,
         name: String,
         color: String,
+        group: String,
+        default_duration: Option<u32>,
+        sync_interval: Option<u32>,
+        last_synced: Option<DateTime<Utc>>,
+        holiday_overlay: bool,
+        auto_export_path: String,
     },
     /// Open delete calendar confirmation
     OpenCalendarDelete {
@@ -485,6 +612,8 @@ impl DialogManager {
                     ActiveDialog::CalendarCreate {
                         name: String::new(),
                         color: default_color,
+                        group: String::new(),
+                        default_duration: None,
                     },
                 );
                 None
@@ -493,6 +622,12 @@ impl DialogManager {
                 calendar_id,
                 name,
                 color,
+                group,
+                default_duration,
+                sync_interval,
+                last_synced,
+                holiday_overlay,
+                auto_export_path,
             } => {
                 Self::open(
                     current,
@@ -500,6 +635,12 @@ impl DialogManager {
                         calendar_id,
                         name,
                         color,
+                        group,
+                        default_duration,
+                        sync_interval,
+                        last_synced,
+                        holiday_overlay,
+                        auto_export_path,
                     },
                 );
                 None
@@ -621,6 +762,8 @@ mod tests {
             ActiveDialog::CalendarCreate {
                 name: String::new(),
                 color: "#FF0000".to_string(),
+                group: String::new(),
+                default_duration: None,
             },
         );
 
@@ -0,0 +1,70 @@
+//! Merge duplicates dialog UI component
+//!
+//! Lists groups of duplicate events found by the dedup analyzer and asks the
+//! user to confirm merging them. Each group is shown with its title, date and
+//! number of copies.
+
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, container, dialog, scrollable, text};
+use cosmic::{widget, Element};
+
+use crate::components::event_title;
+use crate::dialogs::ActiveDialog;
+use crate::fl;
+use crate::message::Message;
+
+/// Maximum height of the duplicate groups list before it scrolls
+const GROUP_LIST_MAX_HEIGHT: f32 = 240.0;
+
+/// Render the merge duplicates confirmation dialog
+pub fn render_merge_duplicates_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
+    let groups = match active_dialog {
+        ActiveDialog::MergeDuplicates { groups } => groups,
+        _ => return widget::text("").into(),
+    };
+
+    // Nothing to merge - just report and offer to close
+    if groups.is_empty() {
+        return dialog()
+            .title(fl!("dialog-merge-duplicates-title"))
+            .body(fl!("merge-duplicates-none"))
+            .primary_action(button::suggested(fl!("button-ok")).on_press(Message::CloseDialog))
+            .into();
+    }
+
+    let redundant: usize = groups.iter().map(|g| g.redundant_count()).sum();
+
+    let mut group_list = column().spacing(4);
+    for group in groups {
+        let Some(first) = group.events.first() else {
+            continue;
+        };
+        group_list = group_list.push(
+            text(format!(
+                "• {} ({}) ×{}",
+                event_title(&first.summary),
+                first.start.format("%Y-%m-%d %H:%M"),
+                group.events.len()
+            ))
+            .size(12),
+        );
+    }
+
+    let content = column()
+        .spacing(12)
+        .push(text(fl!("merge-duplicates-found", count = (redundant as i64))).size(14))
+        .push(
+            container(scrollable(group_list))
+                .max_height(GROUP_LIST_MAX_HEIGHT)
+                .width(Length::Fill),
+        )
+        .push(text(fl!("merge-duplicates-warning")).size(12));
+
+    dialog()
+        .title(fl!("dialog-merge-duplicates-title"))
+        .control(content)
+        .primary_action(button::destructive(fl!("button-merge")).on_press(Message::ConfirmMergeDuplicates))
+        .secondary_action(button::text(fl!("button-cancel")).on_press(Message::CloseDialog))
+        .width(Length::Fixed(450.0))
+        .into()
+}
@@ -27,10 +27,19 @@
 mod manager;
 mod event_dialog;
 mod calendar_dialog;
+mod command_palette_dialog;
+mod convert_recurring_dialog;
+mod copy_week_dialog;
+mod day_note_dialog;
+mod export_range_dialog;
 mod import_dialog;
 mod import_progress_dialog;
 mod import_result_dialog;
+mod make_timed_dialog;
+mod merge_duplicates_dialog;
+mod month_stats_dialog;
 mod subscribe_dialog;
+mod sync_conflict_dialog;
 
 pub use manager::{
     ActiveDialog,
@@ -38,10 +47,19 @@ pub use manager::{
     DialogManager,
     QuickEventResult,
 };
+pub use command_palette_dialog::{command_palette_input_id, render_command_palette_dialog};
+pub use convert_recurring_dialog::render_convert_to_recurring_dialog;
+pub use copy_week_dialog::render_copy_week_dialog;
+pub use day_note_dialog::{day_note_input_id, render_day_note_dialog};
+pub use export_range_dialog::{parse_export_range, render_export_range_dialog, EXPORT_RANGE_DATE_FORMAT};
 pub use import_dialog::render_import_dialog;
 pub use import_progress_dialog::render_import_progress_dialog;
 pub use import_result_dialog::render_import_result_dialog;
+pub use make_timed_dialog::{make_timed_input_id, parse_make_timed_start, render_make_timed_dialog};
+pub use merge_duplicates_dialog::render_merge_duplicates_dialog;
+pub use month_stats_dialog::render_month_stats_dialog;
 pub use subscribe_dialog::view_subscribe_dialog;
+pub use sync_conflict_dialog::render_sync_conflict_dialog;
 
 // Keep old exports for backwards compatibility during migration
 // Note: EventDialogData is managed through legacy fields because text_editor::Content
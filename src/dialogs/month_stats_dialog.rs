@@ -0,0 +1,103 @@
+//! Month statistics dialog UI component
+//!
+//! Summarizes the displayed month: event count, scheduled hours, the busiest
+//! day and a breakdown per calendar.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, container, dialog, row, text};
+use cosmic::{widget, Element};
+
+use crate::components::color_picker::parse_color_safe;
+use crate::dialogs::ActiveDialog;
+use crate::fl;
+use crate::localized_names;
+use crate::message::Message;
+
+/// Size of the calendar color swatch in the breakdown
+const SWATCH_SIZE: f32 = 10.0;
+
+/// Format minutes as hours with one decimal
+fn hours(minutes: i64) -> String {
+    format!("{:.1}", minutes as f64 / 60.0)
+}
+
+/// A label on the left with its value on the right
+fn stat_row(label: String, value: String) -> Element<'static, Message> {
+    row()
+        .push(text(label).size(14).width(Length::Fill))
+        .push(text(value).size(14))
+        .into()
+}
+
+/// Render the month statistics dialog
+pub fn render_month_stats_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
+    let stats = match active_dialog {
+        ActiveDialog::MonthStats { stats } => stats,
+        _ => return widget::text("").into(),
+    };
+
+    let title = fl!(
+        "dialog-month-stats-title",
+        month = format!("{} {}", localized_names::get_month_name(stats.month), stats.year)
+    );
+
+    // Nothing scheduled - just report and offer to close
+    if stats.total_events == 0 {
+        return dialog()
+            .title(title)
+            .body(fl!("month-stats-none"))
+            .primary_action(button::suggested(fl!("button-ok")).on_press(Message::CloseDialog))
+            .into();
+    }
+
+    let busiest = stats.busiest_day.map_or_else(String::new, |(date, count)| {
+        fl!(
+            "month-stats-busiest-value",
+            date = date.format("%a %-d").to_string(),
+            count = (count as i64)
+        )
+    });
+
+    let mut content = column()
+        .spacing(8)
+        .push(stat_row(fl!("month-stats-events"), stats.total_events.to_string()))
+        .push(stat_row(fl!("month-stats-hours"), fl!("month-stats-hours-value", hours = hours(stats.total_minutes))))
+        .push(stat_row(fl!("month-stats-busiest"), busiest))
+        .push(text("").size(4)) // Spacer
+        .push(text(fl!("month-stats-by-calendar")).size(12));
+
+    for calendar in &stats.calendars {
+        let color = parse_color_safe(&calendar.color);
+        let swatch = container(widget::text(""))
+            .width(Length::Fixed(SWATCH_SIZE))
+            .height(Length::Fixed(SWATCH_SIZE))
+            .style(move |_theme: &cosmic::Theme| container::Style {
+                background: Some(color.into()),
+                border: cosmic::iced::Border {
+                    radius: (SWATCH_SIZE / 2.0).into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+
+        content = content.push(
+            row()
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(swatch)
+                .push(text(calendar.name.clone()).size(14).width(Length::Fill))
+                .push(text(fl!(
+                    "month-stats-calendar-value",
+                    count = (calendar.events as i64),
+                    hours = hours(calendar.minutes)
+                )).size(14)),
+        );
+    }
+
+    dialog()
+        .title(title)
+        .control(content)
+        .primary_action(button::suggested(fl!("button-ok")).on_press(Message::CloseDialog))
+        .width(Length::Fixed(400.0))
+        .into()
+}
@@ -0,0 +1,76 @@
+//! Sync conflict dialog UI component
+//!
+//! Shown when two-way sync finds an event that changed both locally and on
+//! the server. Displays both versions side by side and lets the user keep
+//! the local version, the remote version, or both.
+
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, dialog, row, text};
+use cosmic::{widget, Element};
+
+use crate::caldav::CalendarEvent;
+use crate::components::event_title;
+use crate::dialogs::ActiveDialog;
+use crate::fl;
+use crate::message::Message;
+use crate::services::ConflictResolution;
+
+/// Render the sync conflict dialog
+pub fn render_sync_conflict_dialog(active_dialog: &ActiveDialog) -> Element<'_, Message> {
+    let (local, remote) = match active_dialog {
+        ActiveDialog::SyncConflict { local, remote, .. } => (local, remote),
+        _ => return widget::text("").into(),
+    };
+
+    let versions = row()
+        .spacing(16)
+        .push(render_event_version(fl!("sync-conflict-local"), local))
+        .push(render_event_version(fl!("sync-conflict-remote"), remote));
+
+    let content = column()
+        .spacing(16)
+        .push(text(fl!("sync-conflict-message")).size(14))
+        .push(versions);
+
+    dialog()
+        .title(fl!("dialog-sync-conflict-title"))
+        .control(content)
+        .primary_action(
+            button::suggested(fl!("button-keep-local"))
+                .on_press(Message::ResolveSyncConflict(ConflictResolution::KeepLocal)),
+        )
+        .secondary_action(
+            button::standard(fl!("button-keep-remote"))
+                .on_press(Message::ResolveSyncConflict(ConflictResolution::KeepRemote)),
+        )
+        .tertiary_action(
+            button::text(fl!("button-keep-both"))
+                .on_press(Message::ResolveSyncConflict(ConflictResolution::KeepBoth)),
+        )
+        .width(Length::Fixed(520.0))
+        .into()
+}
+
+/// Render one version of the conflicting event
+fn render_event_version(heading: String, event: &CalendarEvent) -> Element<'static, Message> {
+    // Event times are stored as wall-clock values, so they are shown as is
+    let (start, end) = (event.start, event.end);
+    let time_range = if event.all_day {
+        format!("{} - {}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"))
+    } else {
+        format!("{} - {}", start.format("%Y-%m-%d %H:%M"), end.format("%H:%M"))
+    };
+
+    let mut version = column()
+        .spacing(4)
+        .width(Length::Fill)
+        .push(text(heading).size(14))
+        .push(text(event_title(&event.summary)).size(12))
+        .push(text(time_range).size(12));
+
+    if let Some(location) = &event.location {
+        version = version.push(text(location.clone()).size(12));
+    }
+
+    version.into()
+}
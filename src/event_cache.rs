@@ -0,0 +1,148 @@
+//! Persistent cache of the events shown at startup
+//!
+//! Loading events and expanding recurrences for the first month and week can
+//! make startup slow with large calendars. The display events of the current
+//! period are saved here so the first frame can be drawn straight away; the
+//! real events are loaded in the background and replace the cached ones.
+//!
+//! The cache is keyed by the displayed period and the modification times of
+//! the event database and the calendar config, so any change to either
+//! invalidates it. It holds the same data as the (unencrypted) database.
+
+use chrono::NaiveDate;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use crate::calendars::CalendarManagerConfig;
+use crate::components::DisplayEvent;
+use crate::database::Database;
+
+/// Display events keyed by date, as cached by the app
+pub type EventsByDate = HashMap<NaiveDate, Vec<DisplayEvent>>;
+
+/// Display events for the period shown at startup
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventCache {
+    /// Modification times of the database and calendar config when saved
+    fingerprint: Vec<u128>,
+    /// Month shown in the month view (year, month)
+    month: (i32, u32),
+    /// Days shown in the week view
+    week_days: Vec<NaiveDate>,
+    month_events: EventsByDate,
+    week_events: EventsByDate,
+}
+
+impl EventCache {
+    /// Load cached events for the given month and week
+    /// Returns None when there is no cache or it no longer matches
+    pub fn load(month: (i32, u32), week_days: &[NaiveDate]) -> Option<(EventsByDate, EventsByDate)> {
+        let contents = fs::read_to_string(Self::cache_path()).ok()?;
+        let cache: EventCache = match serde_json::from_str(&contents) {
+            Ok(cache) => cache,
+            Err(e) => {
+                warn!("EventCache: Ignoring unreadable cache: {}", e);
+                return None;
+            }
+        };
+
+        if !cache.matches(&Self::fingerprint(), month, week_days) {
+            debug!("EventCache: Cache is stale, loading events from the database");
+            return None;
+        }
+
+        debug!(
+            "EventCache: Loaded {} month days and {} week days from cache",
+            cache.month_events.len(),
+            cache.week_events.len()
+        );
+        Some((cache.month_events, cache.week_events))
+    }
+
+    /// Snapshot the events for the given month and week, fingerprinted with the
+    /// current state of the database and config so it can be written later
+    pub fn new(
+        month: (i32, u32),
+        week_days: &[NaiveDate],
+        month_events: &EventsByDate,
+        week_events: &EventsByDate,
+    ) -> Self {
+        EventCache {
+            fingerprint: Self::fingerprint(),
+            month,
+            week_days: week_days.to_vec(),
+            month_events: month_events.clone(),
+            week_events: week_events.clone(),
+        }
+    }
+
+    /// Write the cache file (serializing can take a while, so this runs in the background)
+    pub fn write(&self) -> Result<(), io::Error> {
+        let path = Self::cache_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(&path, json)
+    }
+
+    /// Whether this cache was saved for the same data and period
+    fn matches(&self, fingerprint: &[u128], month: (i32, u32), week_days: &[NaiveDate]) -> bool {
+        self.fingerprint == fingerprint && self.month == month && self.week_days == week_days
+    }
+
+    /// Modification times of the files events are loaded from (0 when missing)
+    fn fingerprint() -> Vec<u128> {
+        [Database::get_database_path(), CalendarManagerConfig::config_path()]
+            .iter()
+            .map(|path| {
+                fs::metadata(path)
+                    .and_then(|meta| meta.modified())
+                    .ok()
+                    .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                    .map_or(0, |age| age.as_nanos())
+            })
+            .collect()
+    }
+
+    /// Get the cache file path
+    fn cache_path() -> PathBuf {
+        let mut path = dirs::cache_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("sol-calendar");
+        path.push("events.json");
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_matches_only_same_data_and_period() {
+        let week: Vec<NaiveDate> = (1..=7)
+            .map(|day| NaiveDate::from_ymd_opt(2025, 12, day).unwrap())
+            .collect();
+        let cache = EventCache {
+            fingerprint: vec![10, 20],
+            month: (2025, 12),
+            week_days: week.clone(),
+            month_events: HashMap::new(),
+            week_events: HashMap::new(),
+        };
+
+        assert!(cache.matches(&[10, 20], (2025, 12), &week));
+        // The database changed since the cache was written
+        assert!(!cache.matches(&[11, 20], (2025, 12), &week));
+        // A different period is displayed
+        assert!(!cache.matches(&[10, 20], (2026, 1), &week));
+        assert!(!cache.matches(&[10, 20], (2025, 12), &week[1..]));
+    }
+}
@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use std::sync::OnceLock;
 
 use crate::menu_action::MenuAction;
+use crate::views::CalendarView;
 
 /// Global keyboard shortcuts registry
 static KEY_BINDS: OnceLock<HashMap<menu::KeyBind, MenuAction>> = OnceLock::new();
@@ -22,6 +23,15 @@ pub fn init_key_binds() -> HashMap<menu::KeyBind, MenuAction> {
         MenuAction::NewEvent,
     );
 
+    // Quick Add Event (command palette): Ctrl+K
+    key_binds.insert(
+        menu::KeyBind {
+            modifiers: vec![menu::key_bind::Modifier::Ctrl],
+            key: Key::Character("k".into()),
+        },
+        MenuAction::QuickAddEvent,
+    );
+
     // Today: Ctrl+Shift+T
     key_binds.insert(
         menu::KeyBind {
@@ -85,6 +95,33 @@ pub fn init_key_binds() -> HashMap<menu::KeyBind, MenuAction> {
         MenuAction::NavigateNext,
     );
 
+    // Peek Previous Period: Alt+Left (shows the previous period, selected day stays put)
+    key_binds.insert(
+        menu::KeyBind {
+            modifiers: vec![menu::key_bind::Modifier::Alt],
+            key: Key::Named(Named::ArrowLeft),
+        },
+        MenuAction::PeekPrevious,
+    );
+
+    // Peek Next Period: Alt+Right (shows the next period, selected day stays put)
+    key_binds.insert(
+        menu::KeyBind {
+            modifiers: vec![menu::key_bind::Modifier::Alt],
+            key: Key::Named(Named::ArrowRight),
+        },
+        MenuAction::PeekNext,
+    );
+
+    // Return to Selected Day: Alt+Home (ends peeking)
+    key_binds.insert(
+        menu::KeyBind {
+            modifiers: vec![menu::key_bind::Modifier::Alt],
+            key: Key::Named(Named::Home),
+        },
+        MenuAction::ReturnToSelected,
+    );
+
     // Scroll Timeline Up: Ctrl+Shift+Up (scroll up 1 hour in Day/Week view)
     key_binds.insert(
         menu::KeyBind {
@@ -140,3 +177,28 @@ pub fn init_key_binds() -> HashMap<menu::KeyBind, MenuAction> {
 pub fn get_key_binds() -> &'static HashMap<menu::KeyBind, MenuAction> {
     KEY_BINDS.get().expect("KEY_BINDS not initialized")
 }
+
+/// Timeline jump for a bare Home (00:00) or End (23:00) key in the Day/Week view
+/// Kept out of KEY_BINDS so it only applies when no text input took the key
+pub fn timeline_jump_for_key(key: &Key) -> Option<MenuAction> {
+    match key {
+        Key::Named(Named::Home) => Some(MenuAction::ScrollTimelineTop),
+        Key::Named(Named::End) => Some(MenuAction::ScrollTimelineBottom),
+        _ => None,
+    }
+}
+
+/// View switched to by a bare number key: 1 Day, 2 Week, 3 Month, 4 Year
+/// Kept out of KEY_BINDS so the View menu keeps showing the Ctrl+Shift shortcuts
+pub fn view_for_number_key(key: &Key) -> Option<CalendarView> {
+    match key {
+        Key::Character(c) => match c.as_str() {
+            "1" => Some(CalendarView::Day),
+            "2" => Some(CalendarView::Week),
+            "3" => Some(CalendarView::Month),
+            "4" => Some(CalendarView::Year),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -1,7 +1,8 @@
 use crate::app::CosmicCalendar;
 use crate::components::{render_calendar_dialog, render_delete_calendar_dialog, render_delete_event_dialog, render_event_dialog};
-use crate::dialogs::{render_import_dialog, render_import_progress_dialog, render_import_result_dialog, view_subscribe_dialog};
+use crate::dialogs::{render_command_palette_dialog, render_convert_to_recurring_dialog, render_copy_week_dialog, render_day_note_dialog, render_export_range_dialog, render_import_dialog, render_import_progress_dialog, render_import_result_dialog, render_make_timed_dialog, render_merge_duplicates_dialog, render_month_stats_dialog, render_sync_conflict_dialog, view_subscribe_dialog};
 use crate::message::Message;
+use crate::services::CalendarHandler;
 use crate::styles;
 use crate::ui_constants::{BORDER_RADIUS, SIDEBAR_WIDTH};
 use cosmic::iced::widget::stack;
@@ -118,6 +119,61 @@ fn render_dialog_overlay<'a>(
             let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
             return stack![with_drag_preview, dialog_with_backdrop].into();
         }
+        ActiveDialog::MergeDuplicates { .. } => {
+            let dialog = render_merge_duplicates_dialog(&app.active_dialog);
+            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
+        ActiveDialog::CopyWeek { .. } => {
+            let dialog = render_copy_week_dialog(&app.active_dialog);
+            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
+        ActiveDialog::ExportRange { .. } => {
+            let calendars = app
+                .calendar_manager
+                .sources()
+                .iter()
+                .map(|c| (c.info().id.clone(), c.info().name.clone(), c.info().color.clone()))
+                .collect();
+            let dialog = render_export_range_dialog(&app.active_dialog, calendars);
+            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
+        ActiveDialog::CommandPalette { .. } => {
+            let duration = CalendarHandler::event_duration(
+                &app.calendar_manager,
+                app.selected_calendar_id.as_deref().unwrap_or_default(),
+            );
+            let dialog = render_command_palette_dialog(&app.active_dialog, app.selected_date, duration);
+            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
+        ActiveDialog::ConvertToRecurring { .. } => {
+            let dialog = render_convert_to_recurring_dialog(&app.active_dialog);
+            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
+        ActiveDialog::MakeTimed { .. } => {
+            let dialog = render_make_timed_dialog(&app.active_dialog);
+            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
+        ActiveDialog::MonthStats { .. } => {
+            let dialog = render_month_stats_dialog(&app.active_dialog);
+            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
+        ActiveDialog::DayNote { .. } => {
+            let dialog = render_day_note_dialog(&app.active_dialog);
+            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
+        ActiveDialog::SyncConflict { .. } => {
+            let dialog = render_sync_conflict_dialog(&app.active_dialog);
+            let dialog_with_backdrop = wrap_dialog_with_backdrop(dialog);
+            return stack![with_drag_preview, dialog_with_backdrop].into();
+        }
         _ => {}
     }
 
@@ -182,6 +238,12 @@ fn render_drag_preview_overlay<'a>(
     let color = crate::components::parse_hex_color(color_hex)
         .unwrap_or(cosmic::iced::Color::from_rgb(0.5, 0.5, 0.5));
 
+    // Where the event will land, e.g. "Wed 14:00" (date only when dropping on a day)
+    let target_label = drag_state.target_date().map(|date| match drag_state.target_time() {
+        Some(time) => format!("{} {}", date.format("%a"), app.locale.format_time(time)),
+        None => date.format("%a, %b %-d").to_string(),
+    });
+
     // Create the drag preview chip - styled similar to event chips
     let preview_content = text(summary)
         .size(11)
@@ -214,8 +276,24 @@ fn render_drag_preview_overlay<'a>(
     let preview_x = cursor_x + DRAG_PREVIEW_OFFSET_X;
     let preview_y = cursor_y + DRAG_PREVIEW_OFFSET_Y;
 
+    // Target label below the chip so it's readable while the cursor moves
+    let preview = cosmic::widget::column()
+        .spacing(2)
+        .push(preview_chip)
+        .push_maybe(target_label.map(|label| {
+            container(text(label).size(11))
+                .padding([2, 6])
+                .style(|theme: &cosmic::Theme| container::Style {
+                    border: cosmic::iced::Border {
+                        radius: BORDER_RADIUS.into(),
+                        ..Default::default()
+                    },
+                    ..styles::overlay_sidebar_style(theme)
+                })
+        }));
+
     // Wrap in a container that fills the screen and positions the preview
-    let positioned_preview = container(preview_chip)
+    let positioned_preview = container(preview)
         .padding([preview_y as u16, 0, 0, preview_x as u16])
         .width(Length::Fill)
         .height(Length::Fill)
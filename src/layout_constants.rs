@@ -194,6 +194,18 @@ pub const ICON_TODAY: &str = "x-office-calendar-symbolic";
 /// Add/plus icon
 pub const ICON_ADD: &str = "list-add-symbolic";
 
+/// Expanded section icon (sidebar calendar groups)
+pub const ICON_EXPANDED: &str = "pan-down-symbolic";
+
+/// Collapsed section icon (sidebar calendar groups)
+pub const ICON_COLLAPSED: &str = "pan-end-symbolic";
+
+/// Close icon (toast dismiss button)
+pub const ICON_CLOSE: &str = "window-close-symbolic";
+
+/// Day note icon (month cells and day view header)
+pub const ICON_DAY_NOTE: &str = "accessories-text-editor-symbolic";
+
 // =============================================================================
 // Font Sizes
 // =============================================================================
@@ -1,6 +1,8 @@
 /// Locale-aware formatting and settings based on system configuration
 use std::env;
-use chrono::Datelike;
+use std::fmt::Write;
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use log::warn;
 
 /// Date format order
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,6 +12,40 @@ pub enum DateFormat {
     YMD, // Year-Month-Day (e.g., 2024-12-31) - ISO 8601, East Asia
 }
 
+/// Digits used to write numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Numerals {
+    #[default]
+    Latin,       // 0123456789
+    ArabicIndic, // ٠١٢٣٤٥٦٧٨٩ - Arabic (Mashriq)
+    Persian,     // ۰۱۲۳۴۵۶۷۸۹ - Persian, Pashto
+    Devanagari,  // ०१२३४५६७८९ - Marathi, Nepali
+    Bengali,     // ০১২৩৪৫৬৭৮৯
+    Myanmar,     // ၀၁၂၃၄၅၆၇၈၉
+}
+
+impl Numerals {
+    /// Write a number (day, week or year number) in these digits
+    pub fn format_number(self, number: u32) -> String {
+        self.localize_digits(&number.to_string())
+    }
+
+    /// Replace the ASCII digits of already formatted text, keeping everything else
+    pub fn localize_digits(self, text: &str) -> String {
+        let zero = match self {
+            Numerals::Latin => return text.to_string(),
+            Numerals::ArabicIndic => '\u{0660}',
+            Numerals::Persian => '\u{06F0}',
+            Numerals::Devanagari => '\u{0966}',
+            Numerals::Bengali => '\u{09E6}',
+            Numerals::Myanmar => '\u{1040}',
+        };
+        text.chars()
+            .map(|c| c.to_digit(10).and_then(|digit| char::from_u32(zero as u32 + digit)).unwrap_or(c))
+            .collect()
+    }
+}
+
 /// Locale preferences for calendar display
 #[derive(Debug, Clone, PartialEq)]
 pub struct LocalePreferences {
@@ -17,6 +53,14 @@ pub struct LocalePreferences {
     pub first_day_of_week: chrono::Weekday,
     pub date_format: DateFormat,
     pub locale_string: String,
+    /// Days shaded as the weekend
+    pub weekend_days: Vec<chrono::Weekday>,
+    /// User-supplied strftime date format, replaces the locale date layout when set
+    pub custom_date_format: Option<String>,
+    /// User-supplied strftime time format, replaces the 12h/24h layout when set
+    pub custom_time_format: Option<String>,
+    /// Digits for day and week numbers in the calendar grids
+    pub numerals: Numerals,
 }
 
 impl LocalePreferences {
@@ -30,17 +74,60 @@ impl LocalePreferences {
         let use_24_hour = detect_24_hour_format(&locale_string);
         let first_day_of_week = detect_first_day_of_week(&locale_string);
         let date_format = detect_date_format(&locale_string);
+        let weekend_days = detect_weekend_days(&locale_string);
+        let numerals = detect_numerals(&locale_string);
 
         LocalePreferences {
             use_24_hour,
             first_day_of_week,
             date_format,
             locale_string,
+            weekend_days,
+            custom_date_format: None,
+            custom_time_format: None,
+            numerals,
         }
     }
 
+    /// Use a configured set of weekend days, or the locale default when `None`
+    pub fn with_weekend_days(mut self, days: Option<Vec<chrono::Weekday>>) -> Self {
+        self.set_weekend_days(days);
+        self
+    }
+
+    /// Change the weekend days, falling back to the locale default when `None`
+    pub fn set_weekend_days(&mut self, days: Option<Vec<chrono::Weekday>>) {
+        self.weekend_days = days.unwrap_or_else(|| detect_weekend_days(&self.locale_string));
+    }
+
+    /// Apply custom date and time format strings
+    /// Invalid formats are ignored so the locale default is used instead
+    pub fn with_custom_formats(mut self, date_format: Option<&str>, time_format: Option<&str>) -> Self {
+        self.custom_date_format = date_format.and_then(|format| {
+            if is_valid_date_format(format) {
+                Some(format.to_string())
+            } else {
+                warn!("LocalePreferences: Ignoring invalid date format {:?}", format);
+                None
+            }
+        });
+        self.custom_time_format = time_format.and_then(|format| {
+            if is_valid_time_format(format) {
+                Some(format.to_string())
+            } else {
+                warn!("LocalePreferences: Ignoring invalid time format {:?}", format);
+                None
+            }
+        });
+        self
+    }
+
     /// Format hour for display (12h or 24h format)
     pub fn format_hour(&self, hour: u32) -> String {
+        if let (Some(format), Some(time)) = (&self.custom_time_format, NaiveTime::from_hms_opt(hour, 0, 0)) {
+            return time.format(format).to_string();
+        }
+
         if self.use_24_hour {
             format!("{:02}:00", hour)
         } else {
@@ -57,6 +144,19 @@ impl LocalePreferences {
         }
     }
 
+    /// Format a time of day for display (12h or 24h format, with minutes)
+    pub fn format_time(&self, time: NaiveTime) -> String {
+        if let Some(format) = &self.custom_time_format {
+            return time.format(format).to_string();
+        }
+
+        if self.use_24_hour {
+            time.format("%H:%M").to_string()
+        } else {
+            time.format("%-I:%M %p").to_string()
+        }
+    }
+
     /// Get the number of days to subtract from Monday to get first day of week
     /// Monday = 0, Tuesday = 1, ..., Sunday = 6
     #[allow(dead_code)] // Reserved for future locale-aware week calculation
@@ -74,18 +174,17 @@ impl LocalePreferences {
     }
 
     /// Check if a given weekday is a weekend day
-    /// Weekend is typically Saturday and Sunday, but this can vary by locale
+    /// Weekend is typically Saturday and Sunday, but this varies by locale and setting
     pub fn is_weekend(&self, weekday: chrono::Weekday) -> bool {
-        use chrono::Weekday;
-
-        // For most locales, weekend is Saturday and Sunday
-        // In some Middle Eastern countries, weekend is Friday and Saturday
-        // For now, use standard Saturday/Sunday
-        matches!(weekday, Weekday::Sat | Weekday::Sun)
+        self.weekend_days.contains(&weekday)
     }
 
     /// Format a date range for week view (e.g., "Nov 24 - 30, 2024" or "24 - 30 Nov, 2024")
     pub fn format_week_range(&self, first_day: &chrono::NaiveDate, last_day: &chrono::NaiveDate, week_number: u32) -> String {
+        if let Some(format) = &self.custom_date_format {
+            return format!("W{} - {} - {}", week_number, first_day.format(format), last_day.format(format));
+        }
+
         match self.date_format {
             DateFormat::MDY => {
                 // US format: "W48 - Nov 24 - 30, 2024"
@@ -179,7 +278,12 @@ impl LocalePreferences {
     }
 
     /// Format a short date for day view header (e.g., "Monday, Nov 24" or "Monday, 24 Nov")
+    /// A custom date format replaces the whole header, day name included
     pub fn format_day_header(&self, date: &chrono::NaiveDate, day_name: &str) -> String {
+        if let Some(format) = &self.custom_date_format {
+            return date.format(format).to_string();
+        }
+
         match self.date_format {
             DateFormat::MDY => {
                 // US format: "Monday, Nov 24"
@@ -203,6 +307,26 @@ impl Default for LocalePreferences {
     }
 }
 
+/// Check that a strftime format renders a date without errors
+/// Time-only specifiers such as `%H` cannot be rendered for a date and are rejected
+pub fn is_valid_date_format(format: &str) -> bool {
+    let sample = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap_or_default();
+    renders_non_empty(sample.format(format))
+}
+
+/// Check that a strftime format renders a time without errors
+/// Date specifiers such as `%Y` cannot be rendered for a time and are rejected
+pub fn is_valid_time_format(format: &str) -> bool {
+    let sample = NaiveTime::from_hms_opt(13, 5, 0).unwrap_or_default();
+    renders_non_empty(sample.format(format))
+}
+
+/// Render a formatter, treating errors and blank output as invalid
+fn renders_non_empty(formatted: impl std::fmt::Display) -> bool {
+    let mut output = String::new();
+    write!(output, "{}", formatted).is_ok() && !output.trim().is_empty()
+}
+
 /// Detect if locale uses 24-hour format
 fn detect_24_hour_format(locale: &str) -> bool {
     // Most locales use 24-hour format except:
@@ -269,6 +393,52 @@ fn detect_first_day_of_week(locale: &str) -> chrono::Weekday {
     Weekday::Mon
 }
 
+/// Detect weekend days from locale
+fn detect_weekend_days(locale: &str) -> Vec<chrono::Weekday> {
+    use chrono::Weekday;
+
+    let locale_lower = locale.to_lowercase();
+
+    // Locales with a Friday-Saturday weekend
+    let friday_saturday_locales = [
+        "ar_sa", "ar_ae", "ar_bh", "ar_eg", "ar_iq", "ar_jo", "ar_kw",
+        "ar_ly", "ar_om", "ar_qa", "ar_sd", "ar_sy", "ar_ye", "he_il"
+    ];
+
+    // Locales with a Friday-only weekend
+    let friday_locales = ["fa_ir"];
+
+    if friday_saturday_locales.iter().any(|l| locale_lower.starts_with(l)) {
+        return vec![Weekday::Fri, Weekday::Sat];
+    }
+
+    if friday_locales.iter().any(|l| locale_lower.starts_with(l)) {
+        return vec![Weekday::Fri];
+    }
+
+    // Saturday-Sunday for most of the world
+    vec![Weekday::Sat, Weekday::Sun]
+}
+
+/// Detect the digits a locale writes numbers with (Latin unless the locale uses its own)
+fn detect_numerals(locale: &str) -> Numerals {
+    let locale_lower = locale.to_lowercase();
+    let language = locale_lower.split(['_', '.', '@']).next().unwrap_or("");
+
+    // Arabic in the Maghreb is written with Latin digits
+    let maghreb_locales = ["ar_ma", "ar_dz", "ar_tn", "ar_ly", "ar_eh"];
+
+    match language {
+        "ar" if maghreb_locales.iter().any(|l| locale_lower.starts_with(l)) => Numerals::Latin,
+        "ar" => Numerals::ArabicIndic,
+        "fa" | "ps" => Numerals::Persian,
+        "mr" | "ne" => Numerals::Devanagari,
+        "bn" => Numerals::Bengali,
+        "my" => Numerals::Myanmar,
+        _ => Numerals::Latin,
+    }
+}
+
 /// Detect date format from locale
 fn detect_date_format(locale: &str) -> DateFormat {
     let locale_lower = locale.to_lowercase();
@@ -333,23 +503,97 @@ mod tests {
             first_day_of_week: Weekday::Mon,
             date_format: DateFormat::DMY,
             locale_string: "de_DE.UTF-8".to_string(),
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            custom_date_format: None,
+            custom_time_format: None,
+            numerals: Numerals::Latin,
         };
 
         assert_eq!(locale_24h.format_hour(0), "00:00");
         assert_eq!(locale_24h.format_hour(13), "13:00");
         assert_eq!(locale_24h.format_hour(23), "23:00");
+        assert_eq!(locale_24h.format_time(NaiveTime::from_hms_opt(14, 30, 0).unwrap()), "14:30");
 
         let locale_12h = LocalePreferences {
             use_24_hour: false,
             first_day_of_week: Weekday::Sun,
             date_format: DateFormat::MDY,
             locale_string: "en_US.UTF-8".to_string(),
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            custom_date_format: None,
+            custom_time_format: None,
+            numerals: Numerals::Latin,
         };
 
         assert_eq!(locale_12h.format_hour(0), "12 AM");
         assert_eq!(locale_12h.format_hour(1), "1 AM");
         assert_eq!(locale_12h.format_hour(12), "12 PM");
         assert_eq!(locale_12h.format_hour(13), "1 PM");
+        assert_eq!(locale_12h.format_time(NaiveTime::from_hms_opt(14, 30, 0).unwrap()), "2:30 PM");
+        assert_eq!(locale_12h.format_time(NaiveTime::from_hms_opt(0, 5, 0).unwrap()), "12:05 AM");
+    }
+
+    #[test]
+    fn test_custom_formats() {
+        assert!(is_valid_date_format("%a %-d %b"));
+        assert!(is_valid_time_format("%H.%M"));
+        assert!(!is_valid_date_format("%H:%M"));
+        assert!(!is_valid_time_format("%Y"));
+        assert!(!is_valid_date_format("%Q"));
+        assert!(!is_valid_date_format("  "));
+
+        let base = LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: Weekday::Mon,
+            date_format: DateFormat::DMY,
+            locale_string: "de_DE.UTF-8".to_string(),
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            custom_date_format: None,
+            custom_time_format: None,
+            numerals: Numerals::Latin,
+        };
+        let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+
+        let custom = base.clone().with_custom_formats(Some("%a %-d %b"), Some("%H.%M"));
+        assert_eq!(custom.format_day_header(&date, "Tuesday"), "Tue 2 Jan");
+        assert_eq!(custom.format_hour(9), "09.00");
+        assert_eq!(custom.format_time(NaiveTime::from_hms_opt(9, 15, 0).unwrap()), "09.15");
+
+        // Invalid formats fall back to the locale default
+        let invalid = base.with_custom_formats(Some("%H"), Some("%Q"));
+        assert_eq!(invalid.custom_date_format, None);
+        assert_eq!(invalid.format_day_header(&date, "Tuesday"), "Tuesday, 2 Jan");
+        assert_eq!(invalid.format_hour(9), "09:00");
+    }
+
+    #[test]
+    fn test_weekend_days() {
+        assert_eq!(detect_weekend_days("de_DE.UTF-8"), vec![Weekday::Sat, Weekday::Sun]);
+        assert_eq!(detect_weekend_days("he_IL.UTF-8"), vec![Weekday::Fri, Weekday::Sat]);
+
+        let locale = LocalePreferences {
+            use_24_hour: true,
+            first_day_of_week: Weekday::Sun,
+            date_format: DateFormat::DMY,
+            locale_string: "en_GB.UTF-8".to_string(),
+            weekend_days: vec![Weekday::Sat, Weekday::Sun],
+            custom_date_format: None,
+            custom_time_format: None,
+            numerals: Numerals::Latin,
+        }
+        .with_weekend_days(Some(vec![Weekday::Fri, Weekday::Sat]));
+
+        // Week starting Sunday 2024-01-07: only the Friday and Saturday columns are shaded
+        let week_start = NaiveDate::from_ymd_opt(2024, 1, 7).unwrap();
+        let shaded: Vec<usize> = (0..7)
+            .filter(|&col| locale.is_weekend((week_start + chrono::Duration::days(col as i64)).weekday()))
+            .collect();
+        assert_eq!(shaded, vec![5, 6]);
+
+        // Clearing the setting falls back to the locale default
+        let locale = locale.with_weekend_days(None);
+        assert!(locale.is_weekend(Weekday::Sun));
+        assert!(!locale.is_weekend(Weekday::Fri));
     }
 
     #[test]
@@ -361,4 +605,20 @@ mod tests {
         assert_eq!(detect_date_format("zh_CN.UTF-8"), DateFormat::YMD);
         assert_eq!(detect_date_format("ko_KR.UTF-8"), DateFormat::YMD);
     }
+
+    #[test]
+    fn test_numerals() {
+        assert_eq!(detect_numerals("en_US.UTF-8"), Numerals::Latin);
+        assert_eq!(detect_numerals("ar_EG.UTF-8"), Numerals::ArabicIndic);
+        assert_eq!(detect_numerals("ar_MA.UTF-8"), Numerals::Latin);
+        assert_eq!(detect_numerals("fa_IR.UTF-8"), Numerals::Persian);
+        assert_eq!(detect_numerals("bn_BD"), Numerals::Bengali);
+        // Language codes that only start like a listed one keep Latin digits
+        assert_eq!(detect_numerals("arn_CL.UTF-8"), Numerals::Latin);
+
+        assert_eq!(Numerals::Latin.format_number(52), "52");
+        assert_eq!(Numerals::ArabicIndic.format_number(15), "١٥");
+        assert_eq!(Numerals::Persian.format_number(2024), "۲۰۲۴");
+        assert_eq!(Numerals::Devanagari.localize_digits("W07"), "W०७");
+    }
 }
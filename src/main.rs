@@ -8,6 +8,7 @@ mod database;
 #[cfg(debug_assertions)]
 mod demo_data;
 mod dialogs;
+mod event_cache;
 mod keyboard;
 mod layout;
 mod layout_constants;
@@ -19,6 +20,7 @@ mod menu_action;
 mod message;
 mod models;
 mod protocols;
+mod quick_entry;
 mod selection;
 mod services;
 mod settings;
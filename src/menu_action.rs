@@ -1,28 +1,76 @@
 use cosmic::widget::menu;
 
 use crate::message::Message;
+use crate::services::Palette;
+use crate::settings::{CompactSensitivity, EventClickAction, EventOverlapLayout, EventSortOrder, HighlightColor, StartupView, TimeSnap, ViewImageScale, WeekScrollStart, WeekendDays};
 use crate::views::CalendarView;
 
 /// Menu actions for the application menu bar
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MenuAction {
     NewEvent,
+    QuickAddEvent,
+    NewEventOnPickedDates,
     NewCalendar,
+    ApplyPalette(Palette),
     ImportICal,
+    ImportFromClipboard,
     ExportICal,
+    ExportRange,
+    ExportSelected,
+    SyncAll,
+    ExportViewImage,
+    ViewImageScale(ViewImageScale),
     Settings,
+    MergeDuplicates,
+    CopyWeekForward,
+    MakeRecurring,
+    ToggleAllDay,
+    SkipOccurrence,
+    CopyEventLink,
+    EditDayNote,
+    ToggleConfirmEventMoves,
+    DragThreshold(u32),
+    EventClickAction(EventClickAction),
     Today,
     ViewYear,
     ViewMonth,
     ViewWeek,
     ViewDay,
     ToggleWeekNumbers,
+    ToggleHighlightCurrentWeek,
+    ToggleRollingWeek,
+    ToggleEventCountBadges,
+    ToggleDimPastEvents,
+    ToggleHalfHourLines,
+    ToggleFreeDayHighlight,
+    ToggleFocusMode,
+    ToggleLegend,
+    MonthStats,
+    WeekScrollStart(WeekScrollStart),
+    CompactSensitivity(CompactSensitivity),
+    EventOverlapLayout(EventOverlapLayout),
+    TimeSnap(TimeSnap),
+    ToggleRoundNewEventStart,
+    NewEventRounding(TimeSnap),
+    EventSortOrder(EventSortOrder),
+    TodayHighlight(HighlightColor),
+    SelectedHighlight(HighlightColor),
+    WeekendDays(WeekendDays),
+    StartupView(StartupView),
+    NextDayAfterHour(Option<u32>),
     About,
     // Navigation shortcuts (Ctrl+Shift+Arrow keys)
     NavigatePrevious,
     NavigateNext,
+    // Peek shortcuts (Alt+Arrow keys, Alt+Home to return)
+    PeekPrevious,
+    PeekNext,
+    ReturnToSelected,
     ScrollTimelineUp,
     ScrollTimelineDown,
+    ScrollTimelineTop,
+    ScrollTimelineBottom,
     // Event actions
     DeleteSelectedEvent,
     // View cycling (V + Arrow keys)
@@ -36,21 +84,66 @@ impl menu::action::MenuAction for MenuAction {
     fn message(&self) -> Self::Message {
         match self {
             MenuAction::NewEvent => Message::NewEvent,
+            MenuAction::QuickAddEvent => Message::OpenCommandPalette,
+            MenuAction::NewEventOnPickedDates => Message::CreateOnPickedDates,
             MenuAction::NewCalendar => Message::OpenNewCalendarDialog,
+            MenuAction::ApplyPalette(palette) => Message::ApplyPalette(*palette),
             MenuAction::ImportICal => Message::ImportICal,
+            MenuAction::ImportFromClipboard => Message::ImportFromClipboard,
             MenuAction::ExportICal => Message::ExportICal,
+            MenuAction::ExportRange => Message::OpenExportRangeDialog,
+            MenuAction::ExportSelected => Message::ExportSelectedEvents,
+            MenuAction::SyncAll => Message::SyncAll,
+            MenuAction::ExportViewImage => Message::ExportViewImage,
+            MenuAction::ViewImageScale(scale) => Message::SetViewImageScale(*scale),
             MenuAction::Settings => Message::Settings,
+            MenuAction::MergeDuplicates => Message::FindDuplicateEvents,
+            MenuAction::CopyWeekForward => Message::CopyWeekForward,
+            MenuAction::MakeRecurring => Message::ConvertSelectedToRecurring,
+            MenuAction::ToggleAllDay => Message::ToggleSelectedEventAllDay,
+            MenuAction::SkipOccurrence => Message::SkipSelectedOccurrence,
+            MenuAction::CopyEventLink => Message::CopySelectedEventLink,
+            MenuAction::EditDayNote => Message::EditSelectedDayNote,
+            MenuAction::ToggleConfirmEventMoves => Message::ToggleConfirmEventMoves,
+            MenuAction::DragThreshold(pixels) => Message::SetDragThreshold(*pixels),
+            MenuAction::EventClickAction(action) => Message::SetEventClickAction(*action),
             MenuAction::Today => Message::Today,
             MenuAction::ViewYear => Message::ChangeView(CalendarView::Year),
             MenuAction::ViewMonth => Message::ChangeView(CalendarView::Month),
             MenuAction::ViewWeek => Message::ChangeView(CalendarView::Week),
             MenuAction::ViewDay => Message::ChangeView(CalendarView::Day),
             MenuAction::ToggleWeekNumbers => Message::ToggleWeekNumbers,
+            MenuAction::ToggleHighlightCurrentWeek => Message::ToggleHighlightCurrentWeek,
+            MenuAction::ToggleRollingWeek => Message::ToggleRollingWeek,
+            MenuAction::ToggleEventCountBadges => Message::ToggleEventCountBadges,
+            MenuAction::ToggleDimPastEvents => Message::ToggleDimPastEvents,
+            MenuAction::ToggleHalfHourLines => Message::ToggleHalfHourLines,
+            MenuAction::ToggleFreeDayHighlight => Message::ToggleFreeDayHighlight,
+            MenuAction::ToggleFocusMode => Message::ToggleFocusMode,
+            MenuAction::ToggleLegend => Message::ToggleLegend,
+            MenuAction::MonthStats => Message::ShowMonthStats,
+            MenuAction::WeekScrollStart(start) => Message::SetWeekScrollStart(*start),
+            MenuAction::CompactSensitivity(sensitivity) => Message::SetCompactSensitivity(*sensitivity),
+            MenuAction::EventOverlapLayout(layout) => Message::SetEventOverlapLayout(*layout),
+            MenuAction::TimeSnap(snap) => Message::SetTimeSnap(*snap),
+            MenuAction::ToggleRoundNewEventStart => Message::ToggleRoundNewEventStart,
+            MenuAction::NewEventRounding(rounding) => Message::SetNewEventRounding(*rounding),
+            MenuAction::EventSortOrder(order) => Message::SetEventSortOrder(*order),
+            MenuAction::TodayHighlight(highlight) => Message::SetTodayHighlight(*highlight),
+            MenuAction::SelectedHighlight(highlight) => Message::SetSelectedHighlight(*highlight),
+            MenuAction::WeekendDays(weekend) => Message::SetWeekendDays(*weekend),
+            MenuAction::StartupView(view) => Message::SetStartupView(*view),
+            MenuAction::NextDayAfterHour(hour) => Message::SetNextDayAfterHour(*hour),
             MenuAction::About => Message::About,
             MenuAction::NavigatePrevious => Message::PreviousPeriod,
             MenuAction::NavigateNext => Message::NextPeriod,
+            MenuAction::PeekPrevious => Message::PeekPreviousPeriod,
+            MenuAction::PeekNext => Message::PeekNextPeriod,
+            MenuAction::ReturnToSelected => Message::ReturnToSelectedDate,
             MenuAction::ScrollTimelineUp => Message::ScrollTimelineUp,
             MenuAction::ScrollTimelineDown => Message::ScrollTimelineDown,
+            MenuAction::ScrollTimelineTop => Message::ScrollTimelineTop,
+            MenuAction::ScrollTimelineBottom => Message::ScrollTimelineBottom,
             MenuAction::DeleteSelectedEvent => Message::RequestDeleteSelectedEvent,
             MenuAction::CycleViewNext => Message::CycleViewNext,
             MenuAction::CycleViewPrevious => Message::CycleViewPrevious,
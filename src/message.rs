@@ -1,7 +1,12 @@
 use chrono::{NaiveDate, NaiveTime};
 use crate::app::EventDialogField;
 use crate::caldav::{AlertTime, CalendarEvent, RepeatFrequency, TravelTime};
-use crate::dialogs::DialogAction;
+use crate::calendars::SyncConflict;
+use crate::dialogs::{DialogAction, QuickEventResult};
+use crate::event_cache::EventsByDate;
+use crate::models::{ToastAction, ToastSeverity};
+use crate::services::{ConflictResolution, FetchedSync, Palette};
+use crate::settings::{CompactSensitivity, EventClickAction, EventOverlapLayout, EventSortOrder, HighlightColor, StartupView, TimeSnap, ViewImageScale, WeekScrollStart, WeekendDays};
 use crate::views::CalendarView;
 use cosmic::iced::widget::scrollable::Viewport;
 use cosmic::widget::text_editor;
@@ -24,7 +29,15 @@ pub enum Message {
     CycleViewPrevious,
     PreviousPeriod,
     NextPeriod,
+    /// Show the previous period without moving the selected date
+    PeekPreviousPeriod,
+    /// Show the next period without moving the selected date
+    PeekNextPeriod,
+    /// Stop peeking and show the period containing the selected date again
+    ReturnToSelectedDate,
     Today,
+    /// Jump to the current moment: today in Month/Year, current time in Week/Day
+    JumpToNow,
     SelectDay(i32, u32, u32), // (year, month, day)
     /// Select a day without navigating away from current month view (for adjacent month days)
     SelectDayNoNavigate(NaiveDate),
@@ -32,11 +45,66 @@ pub enum Message {
     // UI state
     /// Timer tick for updating current time indicator (every minute)
     TimeTick,
+    /// Replace events shown from the startup cache with ones loaded on the UI thread
+    /// (fallback when the background reload fails)
+    ReloadCachedEvents,
+    /// Events for the period shown from the startup cache, loaded in the background (month, week)
+    CachedEventsReloaded(EventsByDate, EventsByDate),
+    /// Show a toast notification (text, severity, optional action button)
+    ShowToast(String, ToastSeverity, Option<ToastAction>),
+    /// Dismiss a toast by id
+    DismissToast(u64),
+    /// A toast's action button was pressed - dismiss it and send its message
+    ToastActionPressed(u64),
     ToggleSidebar,
     /// Triggered on window resize to sync sidebar with condensed state
     WindowResized,
     ToggleSearch,
     ToggleWeekNumbers,
+    /// Toggle outlining the current week in the year view
+    ToggleHighlightCurrentWeek,
+    /// Toggle the rolling 7-day week view
+    ToggleRollingWeek,
+    /// Toggle dimming of events that have already ended
+    ToggleDimPastEvents,
+    /// Toggle shading of free day runs in the month view
+    ToggleFreeDayHighlight,
+    /// Toggle the half-hour gridlines in the week and day views
+    ToggleHalfHourLines,
+    /// Toggle event count badges on month day cells with hidden events
+    ToggleEventCountBadges,
+    /// Set where the week view scrolls to when opened
+    SetWeekScrollStart(WeekScrollStart),
+    /// Set which view the app opens in
+    SetStartupView(StartupView),
+    /// Set how many pixels an event must be dragged before it starts moving
+    SetDragThreshold(u32),
+    /// Set the hour from which the day view opens on tomorrow (None to turn off)
+    SetNextDayAfterHour(Option<u32>),
+    /// Collapse or expand the week view all-day section
+    ToggleAllDaySection,
+    /// Toggle offering an undo after moving events by dragging
+    ToggleConfirmEventMoves,
+    /// Set what a single click on an event does
+    SetEventClickAction(EventClickAction),
+    /// Set which days are shaded as the weekend
+    SetWeekendDays(WeekendDays),
+    /// Set how early month cells switch to compact event indicators
+    SetCompactSensitivity(CompactSensitivity),
+    /// Set how overlapping events are laid out in the week and day views
+    SetEventOverlapLayout(EventOverlapLayout),
+    /// Set the increment time selections snap to in the week and day views
+    SetTimeSnap(TimeSnap),
+    /// Toggle rounding the start of new events created at the current time
+    ToggleRoundNewEventStart,
+    /// Set the increment new event start times are rounded up to
+    SetNewEventRounding(TimeSnap),
+    /// Set the order of the events within a day
+    SetEventSortOrder(EventSortOrder),
+    /// Set the color of today's highlight in the month view
+    SetTodayHighlight(HighlightColor),
+    /// Set the color of the selected day's highlight in the month view
+    SetSelectedHighlight(HighlightColor),
     /// Week view scroll position changed - tracks scroll via on_scroll callback (COSMIC pattern)
     WeekViewScroll(Viewport),
     /// Restore week view scroll position after quick event dialog closes
@@ -45,17 +113,39 @@ pub enum Message {
     ScrollTimelineUp,
     /// Scroll timeline down by one hour (Ctrl+Shift+Down in Day/Week view)
     ScrollTimelineDown,
+    /// Scroll timeline to the start of the day (Home in Day/Week view)
+    ScrollTimelineTop,
+    /// Scroll timeline to the last hour of the day (End in Day/Week view)
+    ScrollTimelineBottom,
+    /// Scroll timeline to an hour (clicked in the timeline mini-map)
+    ScrollTimelineToHour(u32),
 
     // Calendar management
     ToggleCalendar(String),
     /// Select a calendar as the active calendar for new events
     SelectCalendar(String),
+    /// Open or close the toolbar's selected calendar picker
+    ToggleCalendarPicker,
+    /// Show only the selected calendar's events, or all events again
+    ToggleFocusMode,
+    /// Show or hide the floating calendar color legend
+    ToggleLegend,
+    /// Show statistics for the month displayed in the month view
+    ShowMonthStats,
+    /// Open or close the toolbar's month/year picker
+    ToggleMonthYearPicker,
+    /// Move the month/year picker's year by the given number of years
+    StepMonthYearPicker(i32),
+    /// Jump directly to a month (year, month)
+    SelectMonthYear(i32, u32),
     /// Toggle the color picker for a calendar (open if closed, close if open)
     ToggleColorPicker(String),
     /// Close the color picker (when clicking outside)
     CloseColorPicker,
     /// Change a calendar's color
     ChangeCalendarColor(String, String),
+    /// Recolor all calendars with a preset palette
+    ApplyPalette(Palette),
     /// Open the calendar dialog in Create mode
     OpenNewCalendarDialog,
     /// Open the calendar dialog in Edit mode for a specific calendar
@@ -68,6 +158,20 @@ pub enum Message {
     CalendarDialogNameChanged(String),
     /// Update calendar color selection in dialog
     CalendarDialogColorChanged(String),
+    /// Update calendar sidebar group in dialog
+    CalendarDialogGroupChanged(String),
+    /// Move focus from the name to the group field of the new calendar dialog (Enter in the name field)
+    CalendarDialogFocusGroup,
+    /// Default event duration picked in the calendar dialog (minutes, None for the app default)
+    CalendarDialogDurationChanged(Option<u32>),
+    /// Change the background sync interval in the calendar edit dialog (None syncs on demand)
+    CalendarDialogSyncIntervalChanged(Option<u32>),
+    /// Toggle showing the calendar as a holiday overlay in the calendar edit dialog
+    CalendarDialogHolidayOverlayToggled(bool),
+    /// Update the auto-export file path in the calendar edit dialog (empty turns it off)
+    CalendarDialogAutoExportPathChanged(String),
+    /// Collapse or expand a calendar group in the sidebar
+    ToggleCalendarGroup(String),
     /// Confirm the calendar dialog (Create or Edit)
     ConfirmCalendarDialog,
     /// Cancel the calendar dialog
@@ -94,6 +198,10 @@ pub enum Message {
     SelectionEnd,
     /// Cancel the current selection
     SelectionCancel,
+    /// Keyboard modifiers changed (Ctrl picks dates instead of selecting a range)
+    ModifiersChanged(cosmic::iced::keyboard::Modifiers),
+    /// Open the event dialog to create one event on each picked date
+    CreateOnPickedDates,
 
     // Time-based selection - Drag selection for timed events in week/day views
     /// Start a time-based selection at given date and time (mouse press on hour cell)
@@ -102,6 +210,8 @@ pub enum Message {
     TimeSelectionUpdate(NaiveDate, NaiveTime),
     /// End the time selection (mouse release) - opens quick event input with time
     TimeSelectionEnd,
+    /// Start a quick timed event at the last clicked time slot (Enter key)
+    QuickEventAtClickedTime,
 
     // Event management - Quick events
     /// Start creating a quick event on a specific date
@@ -114,6 +224,14 @@ pub enum Message {
     CommitQuickEvent,
     /// Cancel quick event editing (on Escape or click outside)
     CancelQuickEvent,
+    /// Create a quick event even though an identical one exists
+    CreateQuickEventAnyway(QuickEventResult),
+    /// Start renaming an event inline on its chip (unique_id: "calendar_id:uid")
+    BeginInlineRename(String),
+    /// Update the title while renaming inline
+    InlineRenameChanged(String),
+    /// Save the inline rename (on Enter press)
+    CommitInlineRename,
     /// Delete an event by its UID
     DeleteEvent(String),
     /// Request to delete the currently selected event (opens confirmation dialog)
@@ -126,18 +244,50 @@ pub enum Message {
     CancelDeleteEvent,
     /// Select an event (single click) for viewing/editing
     SelectEvent(String),
+    /// Open the make recurring dialog for an event (unique_id: calendar_id:uid)
+    ConvertToRecurring(String),
+    /// Open the make recurring dialog for the selected event
+    ConvertSelectedToRecurring,
+    /// Change the recurrence chosen in the make recurring dialog
+    ConvertToRecurringRepeatChanged(RepeatFrequency),
+    /// Apply the chosen recurrence to the event
+    ConfirmConvertToRecurring,
+    /// Switch an event between all-day and timed (unique_id: calendar_id:uid)
+    ToggleEventAllDay(String),
+    /// Switch the selected event between all-day and timed
+    ToggleSelectedEventAllDay,
+    /// Start time typed in the make timed dialog
+    MakeTimedStartChanged(String),
+    /// Make the all-day event timed at the entered start time
+    ConfirmMakeTimed,
+    /// Copy a calendar://event link for an event to the clipboard (uid, may be an occurrence uid)
+    CopyEventLink(String),
+    /// Copy a calendar://event link for the selected event
+    CopySelectedEventLink,
+    /// Skip one occurrence of a recurring event without confirmation (unique_id: calendar_id:uid)
+    SkipOccurrence(String),
+    /// Skip the selected occurrence of a recurring event
+    SkipSelectedOccurrence,
+    /// Restore a skipped occurrence (master uid, occurrence date) from the undo toast
+    UndoSkipOccurrence(String, NaiveDate),
 
     // Event drag-and-drop
     /// Start dragging an event to move it (calendar_id, uid, original_date, summary, color)
     DragEventStart(String, String, NaiveDate, String, String),
     /// Update the drag target date as user drags over cells
     DragEventUpdate(NaiveDate),
+    /// Update the drag target to a position in a day's all-day stack (date, index)
+    DragEventOverAllDay(NaiveDate, usize),
     /// Update cursor position during drag (x, y)
     DragEventCursorMove(f32, f32),
+    /// Scroll the week/day timeline while dragging near its edge
+    DragAutoScrollTick,
     /// End the drag operation (drop the event)
     DragEventEnd,
     /// Cancel the drag operation
     DragEventCancel,
+    /// Revert the last drag move (from the undo toast)
+    UndoEventMove,
 
     // Event management - Event dialog
     /// Open the event dialog for creating a new event
@@ -146,6 +296,10 @@ pub enum Message {
     OpenEditEventDialog(String, String),
     /// Toggle edit mode for a specific field
     EventDialogToggleEdit(EventDialogField, bool),
+    /// Move focus to the next dialog control (Tab)
+    EventDialogFocusNext,
+    /// Move focus to the previous dialog control (Shift+Tab)
+    EventDialogFocusPrevious,
     /// Update event title in dialog
     EventDialogTitleChanged(String),
     /// Update event location in dialog
@@ -188,6 +342,8 @@ pub enum Message {
     EventDialogTravelTimeChanged(TravelTime),
     /// Update repeat frequency in dialog
     EventDialogRepeatChanged(RepeatFrequency),
+    /// Update priority in dialog (None = undefined)
+    EventDialogPriorityChanged(Option<u8>),
     /// Update selected calendar in dialog
     EventDialogCalendarChanged(String),
     /// Update invitee input text
@@ -196,14 +352,16 @@ pub enum Message {
     EventDialogAddInvitee,
     /// Remove an invitee from the list
     EventDialogRemoveInvitee(usize),
-    /// Update alert setting in dialog
-    EventDialogAlertChanged(AlertTime),
-    /// Update second alert setting in dialog
-    EventDialogAlertSecondChanged(Option<AlertTime>),
+    /// Add a reminder to the event in the dialog (ignored when already present)
+    EventDialogAddAlert(AlertTime),
+    /// Remove a reminder from the event in the dialog
+    EventDialogRemoveAlert(usize),
     /// Add an attachment
     EventDialogAddAttachment(String),
     /// Remove an attachment
     EventDialogRemoveAttachment(usize),
+    /// A file was dropped onto the window
+    FileDropped(PathBuf),
     /// Update URL in dialog
     EventDialogUrlChanged(String),
     /// Handle text editor action for notes
@@ -212,6 +370,38 @@ pub enum Message {
     ConfirmEventDialog,
     /// Cancel the event dialog
     CancelEventDialog,
+    /// Close the event dialog and drop its unsaved changes
+    DiscardEventDialog,
+    /// Dismiss the discard prompt and keep editing the event
+    KeepEditingEventDialog,
+
+    // Maintenance
+    /// Scan all calendars for duplicate events and show the merge dialog
+    FindDuplicateEvents,
+    /// Merge the duplicate groups shown in the merge dialog
+    ConfirmMergeDuplicates,
+    /// Offer to copy the displayed week's one-off events to the next week
+    CopyWeekForward,
+    /// Create the copies shown in the copy week dialog
+    ConfirmCopyWeek,
+
+    // Command palette
+    /// Open the command palette for quick keyboard event entry
+    OpenCommandPalette,
+    /// Command palette text changed
+    CommandPaletteChanged(String),
+    /// Create the event described in the command palette
+    CommitCommandPalette,
+
+    // Day notes
+    /// Open the note editor for a date
+    EditDayNote(NaiveDate),
+    /// Open the note editor for the selected date
+    EditSelectedDayNote,
+    /// Day note text changed
+    DayNoteChanged(String),
+    /// Save the note being edited (an empty note removes it)
+    SaveDayNote,
 
     // Mini calendar
     MiniCalendarPrevMonth,
@@ -230,6 +420,10 @@ pub enum Message {
     // Import/Export operations
     /// Import events from a file path
     ImportFile(PathBuf),
+    /// Import iCalendar text from the clipboard (e.g. copied from an email)
+    ImportFromClipboard,
+    /// Clipboard contents read for an import
+    ClipboardImportRead(Option<String>),
     /// Show import dialog with parsed events (events, source_file_name)
     ShowImportDialog(Vec<CalendarEvent>, String),
     /// Select target calendar for import
@@ -240,12 +434,40 @@ pub enum Message {
     CancelImport,
     /// Update import progress (current_index, event_summary)
     ImportProgressUpdate(usize, String),
+    /// Import the next batch of a large import (batches are separate messages so cancel can run between them)
+    ImportNextBatch,
     /// Cancel import progress and rollback
     CancelImportProgress,
     /// Revert completed import (rollback all imported events)
     RevertImport,
     /// Export calendar to file (calendar_id, file_path)
     ExportCalendarToFile(String, PathBuf),
+    /// Open the dialog for exporting a date range
+    OpenExportRangeDialog,
+    /// Export range start date input changed
+    ExportRangeStartChanged(String),
+    /// Export range end date input changed
+    ExportRangeEndChanged(String),
+    /// Include or exclude a calendar from the range export
+    ExportRangeToggleCalendar(String),
+    /// Confirm the range export (opens file picker)
+    ConfirmExportRange,
+    /// Write the range export to the chosen path
+    ExportRangeToFile(PathBuf),
+    /// Export the selected events (opens file picker)
+    ExportSelectedEvents,
+    /// Write the selected events to the chosen path
+    ExportSelectedEventsToFile(PathBuf),
+    /// Save the current view as a PNG image (opens file picker)
+    ExportViewImage,
+    /// Capture the window for a view image export to the chosen path
+    ExportViewImageToFile(PathBuf),
+    /// Window capture finished - crop it to the view bounds (if known), encode and write it to the path
+    ViewImageCaptured(PathBuf, Option<cosmic::iced::Rectangle>, cosmic::iced::window::Screenshot),
+    /// View image written (or the error) in the background
+    ViewImageSaved(Result<(), String>),
+    /// Set the resolution of exported view images
+    SetViewImageScale(ViewImageScale),
 
     /// Process URL from command line (webcal://, ics://, calendar://)
     ProcessUrl(String),
@@ -271,6 +493,20 @@ pub enum Message {
     /// Cancel subscription
     CancelSubscription,
 
+    // Sync
+    /// Sync every enabled calendar now
+    SyncAll,
+    /// Background check: sync the calendars whose own interval has elapsed
+    SyncDue,
+    /// Server events of a running sync were fetched in the background
+    SyncFetched(FetchedSync),
+    /// Background check: write calendars whose auto-export debounce has passed
+    AutoExportDue,
+    /// Sync found events changed both locally and remotely - queue them for resolution
+    SyncConflictsDetected(Vec<SyncConflict>),
+    /// Resolve the currently displayed sync conflict
+    ResolveSyncConflict(ConflictResolution),
+
     /// No-op message for cancelled operations
     None,
 }
@@ -0,0 +1,106 @@
+//! Debounced re-export of calendars to their auto-export .ics files
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Quiet time after the last change before a calendar is written out, so a
+/// burst of edits (a drag, an import) results in a single export
+pub const AUTO_EXPORT_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// Calendars waiting to be re-exported and the path each was last exported to
+#[derive(Debug, Default)]
+pub struct AutoExportQueue {
+    /// Auto-export path last seen, keyed by calendar id
+    paths: HashMap<String, String>,
+    /// Calendars to export at the deadline, with their target paths
+    pending: HashMap<String, String>,
+    due: Option<Instant>,
+}
+
+impl AutoExportQueue {
+    /// Track a calendar whose file is already up to date (written by an earlier
+    /// run), so it is only exported again once its events or path change
+    pub fn track(&mut self, calendar_id: &str, path: &str) {
+        self.paths.insert(calendar_id.to_string(), path.to_string());
+    }
+
+    /// Queue an export when the calendar's events changed or its target path is
+    /// new since the last one. Returns whether it was queued.
+    pub fn observe(&mut self, calendar_id: &str, path: &str, changed: bool, now: Instant) -> bool {
+        let new_path = self.paths.get(calendar_id).map(String::as_str) != Some(path);
+        if !changed && !new_path {
+            return false;
+        }
+        self.paths.insert(calendar_id.to_string(), path.to_string());
+        self.pending.insert(calendar_id.to_string(), path.to_string());
+        self.due = Some(now + AUTO_EXPORT_DEBOUNCE);
+        true
+    }
+
+    /// Stop tracking calendars that are no longer auto-exported
+    pub fn retain(&mut self, calendar_ids: &[String]) {
+        self.paths.retain(|id, _| calendar_ids.contains(id));
+        self.pending.retain(|id, _| calendar_ids.contains(id));
+        if self.pending.is_empty() {
+            self.due = None;
+        }
+    }
+
+    /// Forget a calendar so its next observation exports it again (e.g. after a failed write)
+    pub fn forget(&mut self, calendar_id: &str) {
+        self.paths.remove(calendar_id);
+    }
+
+    /// Take the queued (calendar id, path) pairs once the debounce has passed
+    pub fn take_due(&mut self, now: Instant) -> Vec<(String, String)> {
+        match self.due {
+            Some(due) if now >= due => {
+                self.due = None;
+                self.pending.drain().collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Whether an export is waiting for its deadline
+    pub fn is_waiting(&self) -> bool {
+        self.due.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_export_queue_debounce() {
+        let start = Instant::now();
+        let mut queue = AutoExportQueue::default();
+
+        // Calendars exported by an earlier run are not written again at startup
+        queue.track("work", "/tmp/work.ics");
+        assert!(!queue.observe("work", "/tmp/work.ics", false, start));
+        assert!(!queue.is_waiting());
+
+        assert!(queue.observe("work", "/tmp/work.ics", true, start));
+        // A further change restarts the quiet time
+        let later = start + Duration::from_secs(1);
+        assert!(queue.observe("work", "/tmp/work.ics", true, later));
+        assert!(queue.take_due(start + AUTO_EXPORT_DEBOUNCE).is_empty());
+
+        let due = queue.take_due(later + AUTO_EXPORT_DEBOUNCE);
+        assert_eq!(due, vec![("work".to_string(), "/tmp/work.ics".to_string())]);
+        assert!(!queue.is_waiting());
+
+        // A new path exports even when the events are unchanged
+        assert!(queue.observe("work", "/tmp/other.ics", false, later));
+        queue.retain(&[]);
+        assert!(!queue.is_waiting());
+        assert!(queue.observe("work", "/tmp/other.ics", false, later));
+
+        // A forgotten calendar (failed write) is queued again
+        queue.take_due(later + AUTO_EXPORT_DEBOUNCE);
+        queue.forget("work");
+        assert!(queue.observe("work", "/tmp/other.ics", false, later));
+    }
+}
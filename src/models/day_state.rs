@@ -32,11 +32,6 @@ impl DayState {
         }
     }
 
-    /// Create DayState for today
-    pub fn current(locale: &LocalePreferences) -> Self {
-        Self::new(chrono::Local::now().date_naive(), locale)
-    }
-
     /// Navigate to previous day
     #[allow(dead_code)] // Navigation used by view transitions
     pub fn previous(&self, locale: &LocalePreferences) -> Self {
@@ -1,9 +1,17 @@
+mod auto_export;
 mod calendar_state;
 mod week_state;
 mod day_state;
 mod year_state;
+mod move_undo;
+mod month_stats;
+mod toast;
 
+pub use auto_export::AutoExportQueue;
 pub use calendar_state::{CalendarState, CalendarDay};
 pub use week_state::WeekState;
 pub use day_state::DayState;
 pub use year_state::YearState;
+pub use move_undo::MoveUndo;
+pub use month_stats::MonthStats;
+pub use toast::{Toast, ToastAction, ToastQueue, ToastSeverity};
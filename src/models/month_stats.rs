@@ -0,0 +1,169 @@
+//! Summary of the events in a displayed month
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use std::collections::{HashMap, HashSet};
+
+use crate::components::DisplayEvent;
+
+/// Events and scheduled time of one calendar within the month
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarStats {
+    pub name: String,
+    pub color: String,
+    pub events: usize,
+    pub minutes: i64,
+}
+
+/// Totals for a month, computed from the display event cache
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthStats {
+    pub year: i32,
+    pub month: u32,
+    /// Distinct events (multi-day events count once)
+    pub total_events: usize,
+    /// Scheduled time of timed events; all-day events are not counted
+    pub total_minutes: i64,
+    /// Day with the most events and its event count (earliest on ties)
+    pub busiest_day: Option<(NaiveDate, usize)>,
+    /// Per-calendar breakdown in sidebar order, leaving out calendars without events
+    pub calendars: Vec<CalendarStats>,
+}
+
+impl MonthStats {
+    /// Summarize the events of the given month
+    ///
+    /// `calendars` lists (id, name) in sidebar order. Days outside the month
+    /// (shown at the edges of the month grid) are ignored.
+    pub fn from_events(
+        year: i32,
+        month: u32,
+        events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+        calendars: &[(String, String)],
+    ) -> Self {
+        let mut days: Vec<(&NaiveDate, &Vec<DisplayEvent>)> = events_by_date
+            .iter()
+            .filter(|(date, _)| date.year() == year && date.month() == month)
+            .collect();
+        days.sort_by_key(|(date, _)| **date);
+
+        let mut seen: HashSet<(&str, &str)> = HashSet::new();
+        let mut per_calendar: HashMap<&str, (String, usize, i64)> = HashMap::new();
+        let mut total_minutes = 0;
+        let mut busiest_day: Option<(NaiveDate, usize)> = None;
+
+        for (date, events) in days {
+            if !events.is_empty() && busiest_day.map_or(true, |(_, count)| events.len() > count) {
+                busiest_day = Some((*date, events.len()));
+            }
+
+            for event in events {
+                let minutes = Self::scheduled_minutes(event);
+                total_minutes += minutes;
+
+                let entry = per_calendar
+                    .entry(event.calendar_id.as_str())
+                    .or_insert_with(|| (event.color.clone(), 0, 0));
+                entry.2 += minutes;
+                if seen.insert((event.calendar_id.as_str(), event.uid.as_str())) {
+                    entry.1 += 1;
+                }
+            }
+        }
+
+        let calendars = calendars
+            .iter()
+            .filter_map(|(id, name)| {
+                per_calendar.get(id.as_str()).map(|(color, events, minutes)| CalendarStats {
+                    name: name.clone(),
+                    color: color.clone(),
+                    events: *events,
+                    minutes: *minutes,
+                })
+            })
+            .collect();
+
+        Self {
+            year,
+            month,
+            total_events: seen.len(),
+            total_minutes,
+            busiest_day,
+            calendars,
+        }
+    }
+
+    /// Minutes a timed event takes up on its day; events ending past
+    /// midnight count until the end of the day
+    fn scheduled_minutes(event: &DisplayEvent) -> i64 {
+        match (event.all_day, event.start_time, event.end_time) {
+            (false, Some(start), Some(end)) if end > start => (end - start).num_minutes(),
+            (false, Some(start), _) => (NaiveTime::MIN - start).num_minutes() + 24 * 60,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::ShowAs;
+
+    fn event(uid: &str, calendar_id: &str, time: Option<((u32, u32), (u32, u32))>) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: calendar_id.to_string(),
+            uid: uid.to_string(),
+            summary: uid.to_string(),
+            color: "#3B82F6".to_string(),
+            all_day: time.is_none(),
+            start_time: time.and_then(|((h, m), _)| NaiveTime::from_hms_opt(h, m, 0)),
+            end_time: time.and_then(|(_, (h, m))| NaiveTime::from_hms_opt(h, m, 0)),
+            show_as: ShowAs::Busy,
+            priority: None,
+            location: None,
+            notes_preview: None,
+            display_order: None,
+            span_start: None,
+            span_end: None,
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_month_stats_from_events() {
+        let mut events_by_date = HashMap::new();
+        events_by_date.insert(date(3), vec![
+            event("standup", "work", Some(((9, 0), (9, 30)))),
+            event("trip", "personal", None),
+        ]);
+        events_by_date.insert(date(4), vec![
+            event("trip", "personal", None),
+            event("late", "work", Some(((22, 0), (1, 0)))),
+        ]);
+        events_by_date.insert(date(10), vec![event("review", "work", Some(((14, 0), (15, 30))))]);
+        // Shown in the month grid but part of the previous month
+        events_by_date.insert(
+            NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+            vec![event("old", "work", Some(((8, 0), (17, 0))))],
+        );
+
+        let calendars = vec![
+            ("personal".to_string(), "Personal".to_string()),
+            ("work".to_string(), "Work".to_string()),
+            ("empty".to_string(), "Empty".to_string()),
+        ];
+        let stats = MonthStats::from_events(2025, 3, &events_by_date, &calendars);
+
+        assert_eq!(stats.total_events, 4);
+        assert_eq!(stats.total_minutes, 30 + 120 + 90);
+        assert_eq!(stats.busiest_day, Some((date(3), 2)));
+        let breakdown: Vec<(&str, usize, i64)> = stats
+            .calendars
+            .iter()
+            .map(|c| (c.name.as_str(), c.events, c.minutes))
+            .collect();
+        assert_eq!(breakdown, [("Personal", 1, 0), ("Work", 3, 240)]);
+    }
+}
@@ -0,0 +1,32 @@
+use std::time::{Duration, Instant};
+
+use crate::caldav::CalendarEvent;
+
+/// How long the undo bar stays visible after a drag move
+const MOVE_UNDO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A drag move that has just been applied and can still be reverted
+#[derive(Debug, Clone)]
+pub struct MoveUndo {
+    /// Calendar containing the moved event
+    pub calendar_id: String,
+    /// The event as it was before the move
+    pub original: CalendarEvent,
+    /// When the move happened
+    pub moved_at: Instant,
+}
+
+impl MoveUndo {
+    pub fn new(calendar_id: String, original: CalendarEvent) -> Self {
+        Self {
+            calendar_id,
+            original,
+            moved_at: Instant::now(),
+        }
+    }
+
+    /// Whether the undo window has passed
+    pub fn is_expired(&self) -> bool {
+        self.moved_at.elapsed() >= MOVE_UNDO_TIMEOUT
+    }
+}
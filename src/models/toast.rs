@@ -0,0 +1,101 @@
+use std::time::{Duration, Instant};
+
+use crate::message::Message;
+
+/// How long a plain toast stays visible
+const TOAST_TIMEOUT: Duration = Duration::from_secs(5);
+/// Toasts with an action or reporting an error stay a little longer
+const TOAST_TIMEOUT_LONG: Duration = Duration::from_secs(10);
+/// Oldest toasts are dropped once this many are shown
+const MAX_TOASTS: usize = 3;
+
+/// How a toast is styled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastSeverity {
+    Info,
+    Success,
+    Error,
+}
+
+/// Button shown on a toast (e.g. "Undo") and the message it sends
+#[derive(Debug, Clone)]
+pub struct ToastAction {
+    pub label: String,
+    pub message: Box<Message>,
+}
+
+impl ToastAction {
+    pub fn new(label: String, message: Message) -> Self {
+        Self {
+            label,
+            message: Box::new(message),
+        }
+    }
+}
+
+/// A transient notification
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub id: u64,
+    pub text: String,
+    pub severity: ToastSeverity,
+    pub action: Option<ToastAction>,
+    pub shown_at: Instant,
+}
+
+impl Toast {
+    /// Whether the toast has been visible long enough to dismiss
+    pub fn is_expired(&self) -> bool {
+        let timeout = if self.action.is_some() || self.severity == ToastSeverity::Error {
+            TOAST_TIMEOUT_LONG
+        } else {
+            TOAST_TIMEOUT
+        };
+        self.shown_at.elapsed() >= timeout
+    }
+}
+
+/// Stack of visible toasts, newest last
+#[derive(Debug, Default)]
+pub struct ToastQueue {
+    toasts: Vec<Toast>,
+    next_id: u64,
+}
+
+impl ToastQueue {
+    /// Show a toast, returning its id
+    pub fn push(&mut self, text: String, severity: ToastSeverity, action: Option<ToastAction>) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.toasts.push(Toast {
+            id,
+            text,
+            severity,
+            action,
+            shown_at: Instant::now(),
+        });
+        if self.toasts.len() > MAX_TOASTS {
+            self.toasts.remove(0);
+        }
+        id
+    }
+
+    /// Remove a toast, returning it if it was still shown
+    pub fn dismiss(&mut self, id: u64) -> Option<Toast> {
+        let index = self.toasts.iter().position(|t| t.id == id)?;
+        Some(self.toasts.remove(index))
+    }
+
+    /// Drop toasts whose timeout has passed
+    pub fn expire(&mut self) {
+        self.toasts.retain(|t| !t.is_expired());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+}
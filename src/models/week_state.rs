@@ -10,18 +10,35 @@ pub struct WeekState {
     pub week_range_text: String, // Pre-formatted week range with locale-aware format
     pub today: NaiveDate,
     pub first_day_of_week: Weekday,
+    pub rolling: bool, // 7 days from an anchor date instead of a calendar-aligned week
 }
 
 impl WeekState {
     /// Create a new WeekState for the week containing the given date
     pub fn new(date: NaiveDate, first_day_of_week: Weekday, locale: &LocalePreferences) -> Self {
-        let today = chrono::Local::now().date_naive();
-
         // Find the first day of the week containing the date
         let weekday = date.weekday();
         let days_since_first = days_between_weekdays(first_day_of_week, weekday);
         let first_day = date - chrono::Duration::days(days_since_first as i64);
 
+        Self::from_first_day(date, first_day, first_day_of_week, false, locale)
+    }
+
+    /// Create a rolling WeekState showing the 7 days starting at `anchor`
+    pub fn rolling(anchor: NaiveDate, first_day_of_week: Weekday, locale: &LocalePreferences) -> Self {
+        Self::from_first_day(anchor, anchor, first_day_of_week, true, locale)
+    }
+
+    /// Build the state for the 7 days starting at `first_day`
+    fn from_first_day(
+        date: NaiveDate,
+        first_day: NaiveDate,
+        first_day_of_week: Weekday,
+        rolling: bool,
+        locale: &LocalePreferences,
+    ) -> Self {
+        let today = chrono::Local::now().date_naive();
+
         // Build the 7 days of the week
         let mut days = Vec::with_capacity(7);
         for i in 0..7 {
@@ -43,6 +60,7 @@ impl WeekState {
             week_range_text,
             today,
             first_day_of_week,
+            rolling,
         }
     }
 
@@ -60,13 +78,23 @@ impl WeekState {
     /// Navigate to previous week
     #[allow(dead_code)] // Navigation used by view transitions
     pub fn previous(&self, locale: &LocalePreferences) -> Self {
-        Self::new(self.days[0] - chrono::Duration::days(7), self.first_day_of_week, locale)
+        self.shifted(-7, locale)
     }
 
     /// Navigate to next week
     #[allow(dead_code)] // Navigation used by view transitions
     pub fn next(&self, locale: &LocalePreferences) -> Self {
-        Self::new(self.days[0] + chrono::Duration::days(7), self.first_day_of_week, locale)
+        self.shifted(7, locale)
+    }
+
+    /// Move the week by a number of days, keeping the rolling mode
+    fn shifted(&self, days: i64, locale: &LocalePreferences) -> Self {
+        let first_day = self.days[0] + chrono::Duration::days(days);
+        if self.rolling {
+            Self::rolling(first_day, self.first_day_of_week, locale)
+        } else {
+            Self::new(first_day, self.first_day_of_week, locale)
+        }
     }
 
     /// Check if a given date is today
@@ -63,8 +63,9 @@ impl Protocol for LocalProtocol {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::caldav::{AlertTime, RepeatFrequency, TravelTime};
+    use crate::caldav::{RepeatFrequency, ShowAs, TravelTime};
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     #[test]
     fn test_local_protocol() {
@@ -89,11 +90,15 @@ mod tests {
             repeat_until: None,
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::None,
-            alert_second: None,
+            alerts: vec![],
             attachments: vec![],
             url: None,
             notes: None,
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         };
 
         // Add event
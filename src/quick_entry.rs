@@ -0,0 +1,246 @@
+//! Natural-language quick entry parsing
+//!
+//! Turns text such as "Lunch with Sam tomorrow 12:30-13:30" or
+//! "Standup fri at 9am" into a title, a date and an optional time range.
+//! Used by the command palette to create events without clicking a slot.
+//!
+//! Recognised tokens:
+//! - Dates: `today`, `tomorrow`, weekday names (`friday`, `fri`), ISO dates (`2025-12-02`)
+//! - Times: `10:00`, `9am`, `3:30pm`, or a bare hour after `at` (`at 9`)
+//! - Ranges: `10-11`, `10:00-11:30`, `2pm-4pm`
+//!
+//! Everything else becomes the event title.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+
+/// Result of parsing a quick entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuickEntry {
+    /// Event title (the words that weren't dates or times)
+    pub summary: String,
+    /// Day the event is on
+    pub date: NaiveDate,
+    /// Start and end time, or None for an all-day event
+    pub times: Option<(NaiveTime, NaiveTime)>,
+}
+
+/// Parse quick entry text
+/// `today` anchors relative words; `default_date` is used when no date is given
+/// and `default_duration` when a start time has no explicit end
+pub fn parse_quick_entry(
+    text: &str,
+    today: NaiveDate,
+    default_date: NaiveDate,
+    default_duration: Duration,
+) -> QuickEntry {
+    let mut date = None;
+    let mut start = None;
+    let mut end = None;
+    let mut words = Vec::new();
+
+    let mut tokens = text.split_whitespace().peekable();
+    while let Some(token) = tokens.next() {
+        let lower = token.trim_end_matches(',').to_lowercase();
+
+        // Fillers are only dropped when they introduce a date or time
+        if lower == "on" || lower == "at" {
+            let next = tokens.peek().map(|t| t.trim_end_matches(',').to_lowercase());
+            let allow_bare = lower == "at";
+            if let Some(next) = next {
+                if lower == "on" && parse_date_word(&next, today).is_some() {
+                    continue;
+                }
+                if allow_bare && start.is_none() {
+                    if let Some(time) = parse_time(&next, true) {
+                        start = Some(time);
+                        tokens.next();
+                        continue;
+                    }
+                }
+            }
+            words.push(token);
+            continue;
+        }
+
+        if date.is_none() {
+            if let Some(parsed) = parse_date_word(&lower, today) {
+                date = Some(parsed);
+                continue;
+            }
+        }
+
+        if start.is_none() {
+            if let Some((range_start, range_end)) = parse_time_range(&lower) {
+                start = Some(range_start);
+                end = Some(range_end);
+                continue;
+            }
+            if let Some(time) = parse_time(&lower, false) {
+                start = Some(time);
+                continue;
+            }
+        }
+
+        words.push(token);
+    }
+
+    let times = start.map(|start| {
+        let end = end
+            .filter(|end| *end > start)
+            .unwrap_or_else(|| default_end(start, default_duration));
+        (start, end)
+    });
+
+    QuickEntry {
+        summary: words.join(" "),
+        date: date.unwrap_or(default_date),
+        times,
+    }
+}
+
+/// End time for a start without an explicit end, clamped to the same day
+fn default_end(start: NaiveTime, duration: Duration) -> NaiveTime {
+    let (end, wrapped) = start.overflowing_add_signed(duration);
+    if wrapped != 0 {
+        NaiveTime::from_hms_opt(23, 59, 0).unwrap_or(start)
+    } else {
+        end
+    }
+}
+
+/// Parse a date word relative to today
+fn parse_date_word(word: &str, today: NaiveDate) -> Option<NaiveDate> {
+    match word {
+        "today" => return Some(today),
+        "tomorrow" | "tmrw" => return today.succ_opt(),
+        _ => {}
+    }
+
+    if let Some(weekday) = parse_weekday(word) {
+        // Next occurrence of the weekday, today included
+        let days_ahead = (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+        return Some(today + Duration::days(days_ahead as i64));
+    }
+
+    NaiveDate::parse_from_str(word, "%Y-%m-%d").ok()
+}
+
+/// Parse a full or three-letter weekday name
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    let weekday = match word {
+        "monday" | "mon" => Weekday::Mon,
+        "tuesday" | "tue" | "tues" => Weekday::Tue,
+        "wednesday" | "wed" => Weekday::Wed,
+        "thursday" | "thu" | "thurs" => Weekday::Thu,
+        "friday" | "fri" => Weekday::Fri,
+        "saturday" | "sat" => Weekday::Sat,
+        "sunday" | "sun" => Weekday::Sun,
+        _ => return None,
+    };
+    Some(weekday)
+}
+
+/// Parse a time range such as "10-11" or "2pm-4:30pm"
+fn parse_time_range(word: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let (start, end) = word.split_once('-')?;
+    let end_time = parse_time(end, true)?;
+
+    // "2-4pm": the start borrows the end's am/pm suffix
+    let start_time = parse_time(start, true).map(|time| {
+        match end.ends_with("pm") && !start.ends_with("am") && !start.ends_with("pm") {
+            true => parse_time(&format!("{}pm", start), true).unwrap_or(time),
+            false => time,
+        }
+    })?;
+
+    Some((start_time, end_time))
+}
+
+/// Parse a time such as "10:00", "9am" or "3:30pm"
+/// A bare hour ("9") is only accepted when `allow_bare` is set
+fn parse_time(word: &str, allow_bare: bool) -> Option<NaiveTime> {
+    let (digits, meridiem) = if let Some(rest) = word.strip_suffix("am") {
+        (rest, Some(false))
+    } else if let Some(rest) = word.strip_suffix("pm") {
+        (rest, Some(true))
+    } else {
+        (word, None)
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit() || c == ':') {
+        return None;
+    }
+
+    let (hour, minute) = match digits.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+        Some(_) => return None,
+        None if meridiem.is_some() || allow_bare => (digits.parse::<u32>().ok()?, 0),
+        None => return None,
+    };
+
+    let hour = match meridiem {
+        Some(pm) => {
+            if !(1..=12).contains(&hour) {
+                return None;
+            }
+            match (hour, pm) {
+                (12, false) => 0,
+                (12, true) => 12,
+                (h, true) => h + 12,
+                (h, false) => h,
+            }
+        }
+        None => hour,
+    };
+
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_relative_date_and_range() {
+        // 2025-12-03 is a Wednesday
+        let today = NaiveDate::from_ymd_opt(2025, 12, 3).unwrap();
+
+        let entry = parse_quick_entry("Lunch with Sam tomorrow 12:30-13:30", today, today, Duration::hours(1));
+        assert_eq!(entry.summary, "Lunch with Sam");
+        assert_eq!(entry.date, NaiveDate::from_ymd_opt(2025, 12, 4).unwrap());
+        assert_eq!(entry.times, Some((time(12, 30), time(13, 30))));
+
+        let entry = parse_quick_entry("Standup on fri at 9am", today, today, Duration::hours(1));
+        assert_eq!(entry.summary, "Standup");
+        assert_eq!(entry.date, NaiveDate::from_ymd_opt(2025, 12, 5).unwrap());
+        assert_eq!(entry.times, Some((time(9, 0), time(10, 0))));
+
+        let entry = parse_quick_entry("Review 2-4pm", today, today, Duration::hours(1));
+        assert_eq!(entry.times, Some((time(14, 0), time(16, 0))));
+
+        // A start without an end lasts the calendar's default duration
+        let entry = parse_quick_entry("Sync at 9", today, today, Duration::minutes(30));
+        assert_eq!(entry.times, Some((time(9, 0), time(9, 30))));
+    }
+
+    #[test]
+    fn test_parse_without_date_or_time_is_all_day() {
+        let today = NaiveDate::from_ymd_opt(2025, 12, 3).unwrap();
+        let selected = NaiveDate::from_ymd_opt(2025, 12, 10).unwrap();
+
+        let entry = parse_quick_entry("Meet at the station", today, selected, Duration::hours(1));
+        assert_eq!(entry.summary, "Meet at the station");
+        assert_eq!(entry.date, selected);
+        assert_eq!(entry.times, None);
+
+        // Bare numbers are part of the title unless introduced by "at"
+        let entry = parse_quick_entry("Buy 3 apples 2025-12-24", today, today, Duration::hours(1));
+        assert_eq!(entry.summary, "Buy 3 apples");
+        assert_eq!(entry.date, NaiveDate::from_ymd_opt(2025, 12, 24).unwrap());
+        assert_eq!(entry.times, None);
+    }
+}
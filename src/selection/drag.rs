@@ -3,6 +3,33 @@
 use chrono::{NaiveDate, NaiveTime};
 use log::debug;
 
+/// Distance from the top/bottom edge of the timeline where dragging scrolls it
+pub const EDGE_SCROLL_ZONE: f32 = 48.0;
+
+/// Largest scroll step per auto-scroll tick, reached at (or past) the edge
+pub const EDGE_SCROLL_MAX_STEP: f32 = 16.0;
+
+/// Pixels to scroll the timeline for a cursor at `cursor_y` while dragging
+///
+/// `top` and `height` are the visible timeline bounds in window coordinates.
+/// Negative scrolls up, positive down, and the step grows the closer the
+/// cursor gets to the edge. Zero outside the edge zones, including when the
+/// cursor left the timeline (e.g. onto the all-day section above it).
+pub fn edge_scroll_delta(cursor_y: f32, top: f32, height: f32) -> f32 {
+    let zone = EDGE_SCROLL_ZONE.min(height / 2.0);
+    let from_top = cursor_y - top;
+    let from_bottom = top + height - cursor_y;
+    if zone <= 0.0 || from_top < 0.0 || from_bottom < 0.0 {
+        0.0
+    } else if from_top < zone {
+        -EDGE_SCROLL_MAX_STEP * (zone - from_top) / zone
+    } else if from_bottom < zone {
+        EDGE_SCROLL_MAX_STEP * (zone - from_bottom) / zone
+    } else {
+        0.0
+    }
+}
+
 /// Display information for the drag preview.
 /// Separated from EventDragState to maintain clean architecture.
 #[derive(Debug, Clone, Default)]
@@ -68,6 +95,9 @@ impl DragTarget {
 /// EventDragState tracks dragging an existing event to a new location.
 ///
 /// Display concerns (preview rendering) are separated into DragPreviewInfo.
+///
+/// With a drag threshold set, a press only becomes a drag once the cursor has
+/// moved that far; until then it is pending and releasing it counts as a click.
 #[derive(Debug, Clone, Default)]
 pub struct EventDragState {
     /// The calendar ID of the event being dragged
@@ -80,8 +110,18 @@ pub struct EventDragState {
     pub original_time: Option<NaiveTime>,
     /// The current target location (where the event would be dropped)
     target: Option<DragTarget>,
+    /// Position in the day's all-day stack under the cursor (for reordering)
+    stack_index: Option<usize>,
     /// Whether a drag operation is currently active
     pub is_active: bool,
+    /// Whether an event is pressed but not yet moved past the threshold
+    pub is_pending: bool,
+    /// Cursor distance in pixels before a press starts a drag (0 starts immediately)
+    threshold: f32,
+    /// Cursor position when the event was pressed
+    press_position: Option<(f32, f32)>,
+    /// Last known cursor position, kept between drags to know where a press happens
+    last_cursor: Option<(f32, f32)>,
     /// Display information for the drag preview (separated concern)
     pub preview: DragPreviewInfo,
 }
@@ -92,6 +132,11 @@ impl EventDragState {
         Self::default()
     }
 
+    /// Set how far the cursor must move before a press starts a drag
+    pub fn set_threshold(&mut self, pixels: f32) {
+        self.threshold = pixels.max(0.0);
+    }
+
     /// Start dragging an event (date-only, for month view)
     pub fn start(&mut self, calendar_id: String, event_uid: String, original_date: NaiveDate, summary: String, color: String) {
         self.start_internal(calendar_id, event_uid, original_date, None, summary, color);
@@ -111,12 +156,32 @@ impl EventDragState {
         self.original_date = Some(original_date);
         self.original_time = original_time;
         self.target = Some(DragTarget { date: original_date, time: original_time });
-        self.is_active = true;
+        self.is_active = self.threshold <= 0.0;
+        self.is_pending = !self.is_active;
+        self.press_position = self.last_cursor;
         self.preview.set_event_info(summary, color);
+        if let Some((x, y)) = self.last_cursor.filter(|_| self.is_active) {
+            self.preview.update_cursor(x, y);
+        }
     }
 
-    /// Update cursor position during drag
+    /// Update cursor position, turning a pending press into a drag once it moved far enough
     pub fn update_cursor(&mut self, x: f32, y: f32) {
+        self.last_cursor = Some((x, y));
+
+        if self.is_pending {
+            match self.press_position {
+                Some((px, py)) if (x - px).hypot(y - py) < self.threshold => {}
+                Some(_) => {
+                    debug!("EventDragState: Moved past threshold, starting drag");
+                    self.is_pending = false;
+                    self.is_active = true;
+                }
+                // The press came before any cursor movement was seen
+                None => self.press_position = Some((x, y)),
+            }
+        }
+
         if self.is_active {
             self.preview.update_cursor(x, y);
         }
@@ -127,6 +192,30 @@ impl EventDragState {
         if self.is_active {
             debug!("EventDragState: Updating target to {}", target_date);
             self.target = Some(DragTarget::date_only(target_date));
+            self.stack_index = None;
+        }
+    }
+
+    /// Update the target to a position in a day's all-day stack (week view)
+    pub fn update_in_stack(&mut self, target_date: NaiveDate, index: usize) {
+        if self.is_active {
+            debug!("EventDragState: Updating target to {} at all-day position {}", target_date, index);
+            self.target = Some(DragTarget::date_only(target_date));
+            self.stack_index = Some(index);
+        }
+    }
+
+    /// The reorder to apply if the event is dropped on another chip of its own day
+    /// Returns (calendar_id, event_uid, date, new_index) without ending the drag
+    pub fn stack_reorder(&self) -> Option<(String, String, NaiveDate, usize)> {
+        if !self.is_active {
+            return None;
+        }
+        match (&self.calendar_id, &self.event_uid, self.original_date, self.target, self.stack_index) {
+            (Some(cal_id), Some(uid), Some(original), Some(target), Some(index)) if original == target.date => {
+                Some((cal_id.clone(), uid.clone(), original, index))
+            }
+            _ => None,
         }
     }
 
@@ -136,6 +225,7 @@ impl EventDragState {
         if self.is_active {
             debug!("EventDragState: Updating target to {} {:?}", target_date, target_time);
             self.target = Some(DragTarget::with_time(target_date, target_time));
+            self.stack_index = None;
         }
     }
 
@@ -144,6 +234,8 @@ impl EventDragState {
     /// For time-aware moves, use end_with_time()
     pub fn end(&mut self) -> Option<(String, String, NaiveDate, NaiveDate)> {
         if !self.is_active {
+            // A press released before it became a drag is a click
+            self.reset();
             return None;
         }
 
@@ -205,7 +297,10 @@ impl EventDragState {
         self.original_date = None;
         self.original_time = None;
         self.target = None;
+        self.stack_index = None;
         self.is_active = false;
+        self.is_pending = false;
+        self.press_position = None;
         self.preview.reset();
     }
 
@@ -215,7 +310,6 @@ impl EventDragState {
     }
 
     /// Get the target time (if any)
-    #[allow(dead_code)] // Reserved for week/day view time-based operations
     pub fn target_time(&self) -> Option<NaiveTime> {
         self.target.and_then(|t| t.time)
     }
@@ -3,6 +3,7 @@
 //! This module provides state tracking for:
 //! - Drag selection across day/time cells for creating new events
 //! - Event drag-and-drop for moving existing events
+//! - Dates picked with Ctrl for creating an event on each of them
 //!
 //! The architecture supports both date-only (month view) and date+time (week/day views) operations.
 //!
@@ -50,7 +51,7 @@ mod state;
 
 // Re-export public types
 #[allow(unused_imports)] // Part of selection API
-pub use drag::{DragPreviewInfo, DragTarget, EventDragState};
+pub use drag::{edge_scroll_delta, DragPreviewInfo, DragTarget, EventDragState};
 #[allow(unused_imports)] // Part of selection API, used by tests
 pub use point::SelectionPoint;
 #[allow(unused_imports)] // Part of selection API, used by tests
@@ -108,6 +109,23 @@ mod tests {
         assert_eq!(range.start_time(), Some(time));
     }
 
+    #[test]
+    fn test_selection_state_contains_time_slots() {
+        let mut state = SelectionState::new();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        // Dragging from the 9:30 slot to the 10:00 slot
+        state.start_with_time(date, at(9, 30));
+        state.update_with_time(date, at(10, 0));
+
+        assert!(!state.contains_time(date, at(9, 15), 15));
+        assert!(state.contains_time(date, at(9, 30), 15));
+        assert!(state.contains_time(date, at(9, 45), 15));
+        assert!(state.contains_time(date, at(10, 0), 15));
+        assert!(!state.contains_time(date, at(10, 15), 15));
+    }
+
     #[test]
     fn test_selection_state_update() {
         let mut state = SelectionState::new();
@@ -216,6 +234,30 @@ mod tests {
         assert!(state.end_date().is_none());
     }
 
+    #[test]
+    fn test_selection_state_pick_dates() {
+        let mut state = SelectionState::new();
+        let day = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+
+        // Ctrl-drag over three days, then Ctrl-click two unrelated ones
+        state.pick(&SelectionRange::from_dates(day(3), day(5)));
+        state.pick(&SelectionRange::from_dates(day(12), day(12)));
+        state.pick(&SelectionRange::from_dates(day(20), day(20)));
+        assert_eq!(state.picked_dates(), vec![day(3), day(4), day(5), day(12), day(20)]);
+
+        // Ctrl-clicking a picked date again removes it
+        state.pick(&SelectionRange::from_dates(day(4), day(4)));
+        assert!(!state.is_picked(day(4)));
+
+        // Picks survive finishing a regular selection
+        state.start(day(25));
+        state.end();
+        assert_eq!(state.picked_dates().len(), 4);
+
+        state.clear_picked();
+        assert!(state.picked_dates().is_empty());
+    }
+
     // EventDragState tests
 
     #[test]
@@ -339,6 +381,37 @@ mod tests {
         assert!(state.event_color().is_none());
     }
 
+    #[test]
+    fn test_event_drag_state_threshold() {
+        let mut state = EventDragState::new();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let target = NaiveDate::from_ymd_opt(2024, 1, 18).unwrap();
+        state.set_threshold(8.0);
+        state.update_cursor(100.0, 100.0);
+
+        state.start("cal".to_string(), "event-123".to_string(), date, "Test Event".to_string(), "#0000ff".to_string());
+        assert!(!state.is_active);
+        assert!(state.is_pending);
+
+        // Small jitter while clicking doesn't start a drag or change the target
+        state.update_cursor(103.0, 104.0);
+        state.update(target);
+        assert!(!state.is_active);
+        assert_eq!(state.target_date(), Some(date));
+
+        state.update_cursor(110.0, 100.0);
+        assert!(state.is_active);
+        assert!(!state.is_pending);
+        assert_eq!(state.cursor_position(), Some((110.0, 100.0)));
+
+        // Released before moving far enough: a click, and the state is cleared
+        state.cancel();
+        state.start("cal".to_string(), "event-123".to_string(), date, "Test Event".to_string(), "#0000ff".to_string());
+        assert!(state.end().is_none());
+        assert!(!state.is_pending);
+        assert!(state.event_uid.is_none());
+    }
+
     #[test]
     fn test_drag_preview_info() {
         let mut preview = DragPreviewInfo::new();
@@ -355,4 +428,18 @@ mod tests {
         assert!(preview.color.is_none());
         assert!(preview.cursor_position.is_none());
     }
+
+    #[test]
+    fn test_edge_scroll_delta() {
+        // Timeline visible from y=100 to y=500
+        assert_eq!(edge_scroll_delta(300.0, 100.0, 400.0), 0.0);
+        assert!(edge_scroll_delta(110.0, 100.0, 400.0) < 0.0);
+        assert!(edge_scroll_delta(490.0, 100.0, 400.0) > 0.0);
+        // Faster closer to the edge, full speed at it
+        assert!(edge_scroll_delta(495.0, 100.0, 400.0) > edge_scroll_delta(480.0, 100.0, 400.0));
+        assert_eq!(edge_scroll_delta(500.0, 100.0, 400.0), drag::EDGE_SCROLL_MAX_STEP);
+        assert_eq!(edge_scroll_delta(100.0, 100.0, 400.0), -drag::EDGE_SCROLL_MAX_STEP);
+        // Off the timeline, e.g. over the all-day section
+        assert_eq!(edge_scroll_delta(50.0, 100.0, 400.0), 0.0);
+    }
 }
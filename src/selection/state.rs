@@ -2,6 +2,7 @@
 
 use chrono::{NaiveDate, NaiveTime};
 use log::debug;
+use std::collections::BTreeSet;
 
 use super::point::SelectionPoint;
 use super::range::SelectionRange;
@@ -14,6 +15,9 @@ use super::range::SelectionRange;
 /// Supports both:
 /// - Date-only selection (month view): Uses SelectionPoint with time = None
 /// - Time-based selection (week/day views): Uses SelectionPoint with time = Some(t)
+///
+/// Ctrl-click and Ctrl-drag additionally pick dates into a set that survives
+/// between drags, for creating one event on each of several unrelated dates.
 #[derive(Debug, Clone, Default)]
 pub struct SelectionState {
     /// The point where the selection started (mouse press)
@@ -22,6 +26,8 @@ pub struct SelectionState {
     end: Option<SelectionPoint>,
     /// Whether a drag selection is currently active
     pub is_active: bool,
+    /// Dates picked with Ctrl for bulk creation (not cleared by reset)
+    picked: BTreeSet<NaiveDate>,
 }
 
 impl SelectionState {
@@ -116,16 +122,17 @@ impl SelectionState {
             .unwrap_or(false)
     }
 
-    /// Check if a date+hour cell is within the current time-based selection
-    /// Used for highlighting hour cells in week/day views
-    pub fn contains_time(&self, date: NaiveDate, hour: u32) -> bool {
+    /// Check if a time slot (date, start, length in minutes) is within the current time-based selection
+    /// Used for highlighting the snap slots of hour cells in week/day views
+    pub fn contains_time(&self, date: NaiveDate, cell_start: NaiveTime, minutes: u32) -> bool {
         let Some(range) = self.get_range() else {
             return false;
         };
 
-        // Create time points for the start and end of the hour
-        let cell_start = NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
-        let cell_end = NaiveTime::from_hms_opt(hour, 59, 59).unwrap();
+        // Last second of the slot, so a slot ending at the next one's start doesn't overlap it
+        let cell_end = cell_start
+            .overflowing_add_signed(chrono::Duration::seconds(i64::from(minutes) * 60 - 1))
+            .0;
 
         // Get selection times (default to full day if not set)
         let sel_start_time = range.start.time.unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).unwrap());
@@ -161,6 +168,36 @@ impl SelectionState {
         }
     }
 
+    /// Add a finished selection to the picked dates
+    /// A single day toggles that date; a multi-day range adds all its dates
+    pub fn pick(&mut self, range: &SelectionRange) {
+        if range.is_multi_day() {
+            debug!("SelectionState: Picking {} dates", range.day_count());
+            self.picked.extend(range.dates());
+        } else if !self.picked.remove(&range.start_date()) {
+            debug!("SelectionState: Picking {}", range.start_date());
+            self.picked.insert(range.start_date());
+        }
+    }
+
+    /// Check if a date was picked for bulk creation
+    pub fn is_picked(&self, date: NaiveDate) -> bool {
+        self.picked.contains(&date)
+    }
+
+    /// Picked dates in ascending order
+    pub fn picked_dates(&self) -> Vec<NaiveDate> {
+        self.picked.iter().copied().collect()
+    }
+
+    /// Forget all picked dates
+    pub fn clear_picked(&mut self) {
+        if !self.picked.is_empty() {
+            debug!("SelectionState: Clearing {} picked dates", self.picked.len());
+            self.picked.clear();
+        }
+    }
+
     /// Get the start date (for backwards compatibility)
     #[allow(dead_code)] // Part of selection API
     pub fn start_date(&self) -> Option<NaiveDate> {
@@ -3,7 +3,7 @@
 //! This handler manages calendar CRUD operations (not events, but the calendars themselves).
 //! It handles creating, editing, deleting calendars, toggling visibility, and color changes.
 
-use crate::calendars::CalendarManager;
+use crate::calendars::{CalendarManager, DEFAULT_EVENT_DURATION_MINUTES};
 use crate::components::color_picker::CALENDAR_COLORS;
 use log::{debug, error, info, warn};
 use std::error::Error;
@@ -42,6 +42,12 @@ impl Error for CalendarError {}
 pub struct NewCalendarData {
     pub name: String,
     pub color: String,
+    /// Sidebar group to list the calendar under
+    pub group: Option<String>,
+    /// Create the calendar read-only (used for subscriptions)
+    pub read_only: bool,
+    /// Length of new timed events in minutes (None uses the app default)
+    pub default_duration_minutes: Option<u32>,
 }
 
 /// Data for updating a calendar
@@ -49,6 +55,16 @@ pub struct UpdateCalendarData {
     pub name: Option<String>,
     pub color: Option<String>,
     pub enabled: Option<bool>,
+    /// New sidebar group (`Some(None)` removes the calendar from its group)
+    pub group: Option<Option<String>>,
+    /// New event duration (`Some(None)` resets it to the app default)
+    pub default_duration_minutes: Option<Option<u32>>,
+    /// New background sync interval (`Some(None)` syncs only on demand)
+    pub sync_interval_minutes: Option<Option<u32>>,
+    /// Show the calendar's all-day events as holiday shading instead of events
+    pub holiday_overlay: Option<bool>,
+    /// New auto-export file (`Some(None)` stops auto-exporting)
+    pub auto_export_path: Option<Option<String>>,
 }
 
 /// Calendar Handler - centralized calendar management.
@@ -120,6 +136,18 @@ impl CalendarHandler {
                id, data.name, data.color);
         manager.add_local_calendar(id.clone(), data.name.clone(), data.color);
 
+        if data.group.is_some() || data.read_only || data.default_duration_minutes.is_some() {
+            if let Some(calendar) = manager.sources_mut().iter_mut().find(|c| c.info().id == id) {
+                calendar.info_mut().group = data.group;
+                calendar.info_mut().read_only = data.read_only;
+                calendar.info_mut().default_duration_minutes = data.default_duration_minutes;
+            }
+            manager.save_config().map_err(|e| {
+                error!("CalendarHandler: Failed to save config: {}", e);
+                CalendarError::ConfigError(e.to_string())
+            })?;
+        }
+
         info!("CalendarHandler: Successfully created calendar '{}' (id={})", data.name, id);
         Ok(id)
     }
@@ -163,6 +191,31 @@ impl CalendarHandler {
             calendar.set_enabled(enabled);
         }
 
+        if let Some(group) = data.group {
+            debug!("CalendarHandler: Updating group (grouped={})", group.is_some());
+            calendar.info_mut().group = group;
+        }
+
+        if let Some(duration) = data.default_duration_minutes {
+            debug!("CalendarHandler: Updating default event duration to {:?} minutes", duration);
+            calendar.info_mut().default_duration_minutes = duration;
+        }
+
+        if let Some(interval) = data.sync_interval_minutes {
+            debug!("CalendarHandler: Updating sync interval to {:?} minutes", interval);
+            calendar.info_mut().sync_interval_minutes = interval;
+        }
+
+        if let Some(holiday_overlay) = data.holiday_overlay {
+            debug!("CalendarHandler: Updating holiday overlay to {}", holiday_overlay);
+            calendar.info_mut().holiday_overlay = holiday_overlay;
+        }
+
+        if let Some(path) = data.auto_export_path {
+            debug!("CalendarHandler: Auto-export {}", if path.is_some() { "enabled" } else { "disabled" });
+            calendar.info_mut().auto_export_path = path;
+        }
+
         // Save configuration
         manager
             .save_config()
@@ -218,6 +271,11 @@ impl CalendarHandler {
                 name: None,
                 color: Some(color),
                 enabled: None,
+                group: None,
+                default_duration_minutes: None,
+                sync_interval_minutes: None,
+                holiday_overlay: None,
+                auto_export_path: None,
             },
         )
     }
@@ -255,6 +313,73 @@ impl CalendarHandler {
         Ok((info.name.clone(), info.color.clone(), info.enabled))
     }
 
+    /// Get the sidebar group a calendar belongs to
+    pub fn get_group(manager: &CalendarManager, calendar_id: &str) -> Option<String> {
+        manager
+            .sources()
+            .iter()
+            .find(|c| c.info().id == calendar_id)
+            .and_then(|c| c.info().group.clone())
+    }
+
+    /// Get a calendar's custom event duration in minutes, if it has one
+    pub fn get_default_duration(manager: &CalendarManager, calendar_id: &str) -> Option<u32> {
+        manager
+            .sources()
+            .iter()
+            .find(|c| c.info().id == calendar_id)
+            .and_then(|c| c.info().default_duration_minutes)
+    }
+
+    /// Get a calendar's sync interval in minutes and when it last synced
+    pub fn get_sync_schedule(
+        manager: &CalendarManager,
+        calendar_id: &str,
+    ) -> (Option<u32>, Option<chrono::DateTime<chrono::Utc>>) {
+        manager
+            .sources()
+            .iter()
+            .find(|c| c.info().id == calendar_id)
+            .map_or((None, None), |c| (c.info().sync_interval_minutes, c.info().last_synced))
+    }
+
+    /// Check whether a calendar is shown as a holiday overlay
+    pub fn is_holiday_overlay(manager: &CalendarManager, calendar_id: &str) -> bool {
+        manager
+            .sources()
+            .iter()
+            .any(|c| c.info().id == calendar_id && c.info().holiday_overlay)
+    }
+
+    /// Get the file a calendar is auto-exported to, if any
+    pub fn get_auto_export_path(manager: &CalendarManager, calendar_id: &str) -> Option<String> {
+        manager
+            .sources()
+            .iter()
+            .find(|c| c.info().id == calendar_id)
+            .and_then(|c| c.info().auto_export_path.clone())
+    }
+
+    /// Length of new timed events in a calendar (the app default for unknown calendars)
+    pub fn event_duration(manager: &CalendarManager, calendar_id: &str) -> chrono::Duration {
+        manager
+            .sources()
+            .iter()
+            .find(|c| c.info().id == calendar_id)
+            .map(|c| c.info().default_event_duration())
+            .unwrap_or_else(|| chrono::Duration::minutes(DEFAULT_EVENT_DURATION_MINUTES))
+    }
+
+    /// Normalize a user-entered group name (blank means ungrouped)
+    pub fn normalize_group(group: &str) -> Option<String> {
+        let group = group.trim();
+        if group.is_empty() {
+            None
+        } else {
+            Some(group.to_string())
+        }
+    }
+
     /// Get the first available calendar ID (for selecting a default)
     pub fn get_first_calendar_id(manager: &CalendarManager) -> Option<String> {
         let id = manager.sources().first().map(|c| c.info().id.clone());
@@ -267,11 +392,20 @@ impl CalendarHandler {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_normalize_group() {
+        assert_eq!(CalendarHandler::normalize_group("  "), None);
+        assert_eq!(CalendarHandler::normalize_group(" Work "), Some("Work".to_string()));
+    }
+
     #[test]
     fn test_validate_empty_name() {
         let data = NewCalendarData {
             name: "".to_string(),
             color: "#FF0000".to_string(),
+            group: None,
+            read_only: false,
+            default_duration_minutes: None,
         };
         let result = CalendarHandler::validate(&data);
         assert!(matches!(result, Err(CalendarError::ValidationError(_))));
@@ -282,6 +416,9 @@ mod tests {
         let data = NewCalendarData {
             name: "Work".to_string(),
             color: "#FF0000".to_string(),
+            group: None,
+            read_only: false,
+            default_duration_minutes: None,
         };
         let result = CalendarHandler::validate(&data);
         assert!(result.is_ok());
@@ -0,0 +1,274 @@
+//! Dedup Analyzer - Finds and merges duplicate events.
+//!
+//! Repeated imports can leave several copies of the same event in a calendar,
+//! each with a different UID. Events are considered duplicates when they share
+//! the same summary, start and end within one calendar. Merging keeps the copy
+//! with the richest metadata, fills in anything the other copies add, and
+//! deletes the rest.
+
+use crate::caldav::CalendarEvent;
+use crate::calendars::{CalendarManager, CalendarSource};
+use crate::services::EventHandler;
+use log::{debug, error, info};
+use std::collections::HashMap;
+use std::error::Error;
+
+/// Result type for dedup operations
+pub type DedupResult<T> = Result<T, DedupError>;
+
+/// Error types for dedup operations
+#[derive(Debug)]
+pub enum DedupError {
+    /// Failed to read events from a calendar
+    FetchFailed(String),
+    /// Failed to write merged events
+    MergeFailed(String),
+}
+
+impl std::fmt::Display for DedupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DedupError::FetchFailed(msg) => write!(f, "Failed to read events: {}", msg),
+            DedupError::MergeFailed(msg) => write!(f, "Failed to merge duplicates: {}", msg),
+        }
+    }
+}
+
+impl Error for DedupError {}
+
+/// A set of events in one calendar that are copies of each other
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateGroup {
+    /// Calendar containing the duplicates
+    pub calendar_id: String,
+    /// All copies, richest first (the first one is kept on merge)
+    pub events: Vec<CalendarEvent>,
+}
+
+impl DuplicateGroup {
+    /// Number of events that would be removed by merging this group
+    pub fn redundant_count(&self) -> usize {
+        self.events.len().saturating_sub(1)
+    }
+}
+
+/// Dedup Analyzer - duplicate detection and merging.
+pub struct DedupAnalyzer;
+
+impl DedupAnalyzer {
+    /// Find groups of duplicate events in a calendar
+    pub fn find_duplicates(calendar: &dyn CalendarSource) -> DedupResult<Vec<DuplicateGroup>> {
+        let calendar_id = calendar.info().id.clone();
+        let events = calendar.fetch_events().map_err(|e| {
+            error!("DedupAnalyzer: Failed to fetch events from '{}': {}", calendar_id, e);
+            DedupError::FetchFailed(e.to_string())
+        })?;
+
+        let groups = Self::group_duplicates(&calendar_id, events);
+        debug!("DedupAnalyzer: Found {} duplicate groups in '{}'", groups.len(), calendar_id);
+        Ok(groups)
+    }
+
+    /// Find duplicate groups across all calendars
+    pub fn find_all_duplicates(manager: &CalendarManager) -> DedupResult<Vec<DuplicateGroup>> {
+        let mut groups = Vec::new();
+        for calendar in manager.sources() {
+            groups.extend(Self::find_duplicates(calendar.as_ref())?);
+        }
+        info!("DedupAnalyzer: Found {} duplicate groups", groups.len());
+        Ok(groups)
+    }
+
+    /// Group events by (summary, start, end), keeping only groups with more than one event
+    fn group_duplicates(calendar_id: &str, events: Vec<CalendarEvent>) -> Vec<DuplicateGroup> {
+        let mut by_key: HashMap<(String, i64, i64), Vec<CalendarEvent>> = HashMap::new();
+        for event in events {
+            let key = (
+                event.summary.trim().to_string(),
+                event.start.timestamp(),
+                event.end.timestamp(),
+            );
+            by_key.entry(key).or_default().push(event);
+        }
+
+        let mut groups: Vec<DuplicateGroup> = by_key
+            .into_values()
+            .filter(|events| events.len() > 1)
+            .map(|mut events| {
+                // Richest first; ties broken by UID so results are stable
+                events.sort_by(|a, b| {
+                    Self::metadata_score(b)
+                        .cmp(&Self::metadata_score(a))
+                        .then_with(|| a.uid.cmp(&b.uid))
+                });
+                DuplicateGroup {
+                    calendar_id: calendar_id.to_string(),
+                    events,
+                }
+            })
+            .collect();
+
+        groups.sort_by(|a, b| a.events[0].start.cmp(&b.events[0].start));
+        groups
+    }
+
+    /// Count how many optional fields an event fills in
+    fn metadata_score(event: &CalendarEvent) -> usize {
+        [
+            event.location.is_some(),
+            event.notes.is_some(),
+            event.url.is_some(),
+        ]
+        .iter()
+        .filter(|set| **set)
+        .count()
+            + event.alerts.len()
+            + event.invitees.len()
+            + event.attachments.len()
+    }
+
+    /// Combine a duplicate group into a single event
+    /// The richest copy is kept and gaps are filled from the other copies
+    pub fn merge_group(group: &DuplicateGroup) -> Option<CalendarEvent> {
+        let (first, rest) = group.events.split_first()?;
+        let mut merged = first.clone();
+
+        for other in rest {
+            if merged.location.is_none() {
+                merged.location = other.location.clone();
+            }
+            if merged.notes.is_none() {
+                merged.notes = other.notes.clone();
+            }
+            if merged.url.is_none() {
+                merged.url = other.url.clone();
+            }
+            for alert in &other.alerts {
+                if !merged.alerts.contains(alert) {
+                    merged.alerts.push(alert.clone());
+                }
+            }
+            for invitee in &other.invitees {
+                if !merged.invitees.contains(invitee) {
+                    merged.invitees.push(invitee.clone());
+                }
+            }
+            for attachment in &other.attachments {
+                if !merged.attachments.contains(attachment) {
+                    merged.attachments.push(attachment.clone());
+                }
+            }
+            for date in &other.exception_dates {
+                if !merged.exception_dates.contains(date) {
+                    merged.exception_dates.push(*date);
+                }
+            }
+            for (date, change) in &other.overrides {
+                merged.overrides.entry(*date).or_insert_with(|| change.clone());
+            }
+        }
+
+        Some(merged)
+    }
+
+    /// Merge every group, returning the number of events removed
+    pub fn merge_duplicates(
+        manager: &mut CalendarManager,
+        groups: &[DuplicateGroup],
+    ) -> DedupResult<usize> {
+        let mut removed = 0;
+
+        for group in groups {
+            let Some(merged) = Self::merge_group(group) else {
+                continue;
+            };
+
+            EventHandler::update_event(manager, &group.calendar_id, merged.clone())
+                .map_err(|e| DedupError::MergeFailed(e.to_string()))?;
+
+            for duplicate in group.events.iter().skip(1) {
+                EventHandler::delete_event_in_calendar(manager, &group.calendar_id, &duplicate.uid)
+                    .map_err(|e| DedupError::MergeFailed(e.to_string()))?;
+                removed += 1;
+            }
+
+            debug!(
+                "DedupAnalyzer: Merged {} copies into uid={} in '{}'",
+                group.events.len(),
+                merged.uid,
+                group.calendar_id
+            );
+        }
+
+        info!("DedupAnalyzer: Removed {} duplicate events", removed);
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::{RepeatFrequency, ShowAs, TravelTime};
+    use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
+
+    fn create_test_event(uid: &str, summary: &str, hour: u32) -> CalendarEvent {
+        CalendarEvent {
+            uid: uid.to_string(),
+            summary: summary.to_string(),
+            location: None,
+            all_day: false,
+            start: Utc.with_ymd_and_hms(2025, 11, 30, hour, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 11, 30, hour + 1, 0, 0).unwrap(),
+            travel_time: TravelTime::None,
+            repeat: RepeatFrequency::Never,
+            repeat_until: None,
+            exception_dates: vec![],
+            invitees: vec![],
+            alerts: vec![],
+            attachments: vec![],
+            url: None,
+            notes: None,
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
+        }
+    }
+
+    #[test]
+    fn test_group_duplicates_matches_summary_and_times() {
+        let events = vec![
+            create_test_event("a", "Standup", 9),
+            create_test_event("b", "Standup", 9),
+            create_test_event("c", "Standup", 10),
+            create_test_event("d", "Review", 9),
+        ];
+
+        let groups = DedupAnalyzer::group_duplicates("cal", events);
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].events.len(), 2);
+        assert_eq!(groups[0].redundant_count(), 1);
+    }
+
+    #[test]
+    fn test_merge_keeps_richest_and_fills_gaps() {
+        let mut rich = create_test_event("rich", "Standup", 9);
+        rich.location = Some("Room 1".to_string());
+        rich.url = Some("https://example.com/standup".to_string());
+        rich.invitees = vec!["a@example.com".to_string()];
+        let mut other = create_test_event("other", "Standup", 9);
+        other.notes = Some("Agenda".to_string());
+        other.invitees = vec!["b@example.com".to_string()];
+
+        let groups = DedupAnalyzer::group_duplicates("cal", vec![other, rich]);
+        let merged = DedupAnalyzer::merge_group(&groups[0]).unwrap();
+
+        assert_eq!(merged.uid, "rich");
+        assert_eq!(merged.location.as_deref(), Some("Room 1"));
+        assert_eq!(merged.notes.as_deref(), Some("Agenda"));
+        assert_eq!(merged.invitees.len(), 2);
+    }
+}
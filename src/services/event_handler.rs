@@ -28,10 +28,12 @@
 //! CalendarManager → CalendarSource → Protocol
 //! ```
 
-use crate::caldav::CalendarEvent;
+use crate::caldav::{CalendarEvent, RepeatFrequency};
 use crate::calendars::CalendarManager;
+use chrono::{Duration, NaiveDate, NaiveTime, TimeZone, Utc};
 use log::{debug, error, info, trace, warn};
 use std::error::Error;
+use uuid::Uuid;
 
 /// Result type for event handler operations
 pub type EventResult<T> = Result<T, EventError>;
@@ -261,6 +263,44 @@ impl EventHandler {
         Ok(deleted)
     }
 
+    /// Delete an event by UID from a specific calendar.
+    ///
+    /// Unlike `delete_event`, an event with the same UID in another calendar
+    /// is left alone.
+    pub fn delete_event_in_calendar(
+        calendar_manager: &mut CalendarManager,
+        calendar_id: &str,
+        uid: &str,
+    ) -> EventResult<()> {
+        info!("EventHandler: Deleting event uid={} from calendar '{}'", uid, calendar_id);
+
+        let calendar = calendar_manager
+            .sources_mut()
+            .iter_mut()
+            .find(|c| c.info().id == calendar_id)
+            .ok_or_else(|| {
+                error!("EventHandler: Calendar '{}' not found for delete", calendar_id);
+                EventError::CalendarNotFound(calendar_id.to_string())
+            })?;
+
+        calendar
+            .delete_event(uid)
+            .map_err(|e| {
+                error!("EventHandler: Failed to delete event: {}", e);
+                EventError::StorageError(e.to_string())
+            })?;
+
+        calendar
+            .sync()
+            .map_err(|e| {
+                error!("EventHandler: Sync failed after delete: {}", e);
+                EventError::SyncError(e.to_string())
+            })?;
+
+        info!("EventHandler: Successfully deleted event uid={} from calendar '{}'", uid, calendar_id);
+        Ok(())
+    }
+
     /// Add an exception date to a recurring event.
     ///
     /// This is used to delete a single occurrence of a recurring event.
@@ -291,6 +331,219 @@ impl EventHandler {
         Ok(())
     }
 
+    /// Remove an exception date from a recurring event.
+    ///
+    /// Restores a previously skipped occurrence (used to undo skipping it).
+    pub fn remove_exception_date(
+        calendar_manager: &mut CalendarManager,
+        uid: &str,
+        exception_date: chrono::NaiveDate,
+    ) -> EventResult<()> {
+        info!("EventHandler: Removing exception date {} from event uid={}", exception_date, uid);
+
+        let (mut event, calendar_id) = Self::find_event(calendar_manager, uid)?;
+
+        let before = event.exception_dates.len();
+        event.exception_dates.retain(|date| *date != exception_date);
+        if event.exception_dates.len() == before {
+            debug!("EventHandler: Exception date {} not present for event uid={}", exception_date, uid);
+            return Ok(());
+        }
+
+        Self::update_event(calendar_manager, &calendar_id, event)?;
+
+        info!("EventHandler: Successfully removed exception date {} from event uid={}", exception_date, uid);
+        Ok(())
+    }
+
+    /// Turn a one-off event into a recurring series anchored at its current date.
+    pub fn convert_to_recurring(
+        calendar_manager: &mut CalendarManager,
+        calendar_id: &str,
+        uid: &str,
+        repeat: RepeatFrequency,
+    ) -> EventResult<()> {
+        info!("EventHandler: Converting event uid={} in calendar '{}' to recurring", uid, calendar_id);
+
+        if repeat == RepeatFrequency::Never {
+            return Err(EventError::ValidationError(
+                "A recurrence must be chosen".to_string(),
+            ));
+        }
+
+        let mut event = Self::find_event_in_calendar(calendar_manager, calendar_id, uid)?;
+        if event.repeat != RepeatFrequency::Never {
+            warn!("EventHandler: Event uid={} is already recurring", uid);
+            return Err(EventError::ValidationError(
+                "Event is already recurring".to_string(),
+            ));
+        }
+
+        event.repeat = repeat;
+        event.repeat_until = None;
+        event.exception_dates.clear();
+
+        Self::update_event(calendar_manager, calendar_id, event)
+    }
+
+    /// Set an event's manual position among its day's all-day events.
+    /// Returns whether the event had to be updated.
+    pub fn set_display_order(
+        calendar_manager: &mut CalendarManager,
+        calendar_id: &str,
+        uid: &str,
+        order: u32,
+    ) -> EventResult<bool> {
+        let mut event = Self::find_event_in_calendar(calendar_manager, calendar_id, uid)?;
+        if event.display_order == Some(order) {
+            return Ok(false);
+        }
+
+        debug!("EventHandler: Setting display order {} for event uid={} in calendar '{}'", order, uid, calendar_id);
+        event.display_order = Some(order);
+        Self::update_event(calendar_manager, calendar_id, event)?;
+        Ok(true)
+    }
+
+    /// Switch an event between all-day and timed without changing its dates.
+    ///
+    /// `start` and `duration` are used when an all-day event becomes timed.
+    /// Returns whether the event is now all-day.
+    pub fn toggle_all_day(
+        calendar_manager: &mut CalendarManager,
+        calendar_id: &str,
+        uid: &str,
+        start: NaiveTime,
+        duration: Duration,
+    ) -> EventResult<bool> {
+        info!("EventHandler: Toggling all-day for event uid={} in calendar '{}'", uid, calendar_id);
+
+        let event = Self::find_event_in_calendar(calendar_manager, calendar_id, uid)?;
+        let event = Self::with_all_day_toggled(&event, start, duration);
+        let all_day = event.all_day;
+
+        Self::update_event(calendar_manager, calendar_id, event)?;
+        Ok(all_day)
+    }
+
+    /// Timed events become all-day over the days they cover; all-day events
+    /// become timed on their first day, starting at `start` for `duration`
+    fn with_all_day_toggled(event: &CalendarEvent, start: NaiveTime, duration: Duration) -> CalendarEvent {
+        if event.all_day {
+            let start = Utc.from_utc_datetime(&event.start.date_naive().and_time(start));
+            CalendarEvent {
+                all_day: false,
+                start,
+                end: start + duration,
+                ..event.clone()
+            }
+        } else {
+            // An end at midnight belongs to the previous day
+            let first = event.start.date_naive();
+            let last = (event.end - Duration::seconds(1)).date_naive().max(first);
+            CalendarEvent {
+                all_day: true,
+                start: Utc.from_utc_datetime(&first.and_time(NaiveTime::MIN)),
+                end: Utc.from_utc_datetime(&last.and_hms_opt(23, 59, 59).unwrap()),
+                ..event.clone()
+            }
+        }
+    }
+
+    /// Plan copies of a week's one-off events, moved forward by one week.
+    ///
+    /// Returns (calendar_id, new event) pairs with fresh UIDs. Recurring events
+    /// and read-only calendars are skipped, as are events that already have an
+    /// identical copy (same title and times) in the following week.
+    pub fn plan_week_copy(
+        calendar_manager: &CalendarManager,
+        week_start: NaiveDate,
+        week_end: NaiveDate,
+    ) -> Vec<(String, CalendarEvent)> {
+        let mut copies = Vec::new();
+
+        for calendar in calendar_manager.sources() {
+            let calendar_id = &calendar.info().id;
+            if !calendar.is_enabled() || calendar_manager.is_read_only(calendar_id) {
+                continue;
+            }
+            let Ok(events) = calendar.fetch_events() else {
+                warn!("EventHandler: Failed to fetch events from calendar '{}' for week copy", calendar_id);
+                continue;
+            };
+            copies.extend(
+                Self::week_copies(&events, week_start, week_end)
+                    .into_iter()
+                    .map(|event| (calendar_id.clone(), event)),
+            );
+        }
+
+        debug!("EventHandler: Planned {} event copies for week of {}", copies.len(), week_start);
+        copies
+    }
+
+    /// Copy the one-off events starting within a week to the following week
+    fn week_copies(events: &[CalendarEvent], week_start: NaiveDate, week_end: NaiveDate) -> Vec<CalendarEvent> {
+        let shift = Duration::days(7);
+
+        events
+            .iter()
+            .filter(|e| e.repeat == RepeatFrequency::Never)
+            .filter(|e| {
+                let date = e.start.date_naive();
+                date >= week_start && date <= week_end
+            })
+            .filter(|e| {
+                !events.iter().any(|other| {
+                    other.summary == e.summary
+                        && other.start == e.start + shift
+                        && other.end == e.end + shift
+                })
+            })
+            .map(|e| CalendarEvent {
+                uid: Uuid::new_v4().to_string(),
+                start: e.start + shift,
+                end: e.end + shift,
+                ..e.clone()
+            })
+            .collect()
+    }
+
+    /// Copy an event onto each of the given dates, keeping its times and length
+    ///
+    /// Each copy gets a fresh UID. Used to create one event per picked date
+    /// for irregular schedules that a recurrence rule can't express.
+    pub fn copies_on_dates(event: &CalendarEvent, dates: &[NaiveDate]) -> Vec<CalendarEvent> {
+        let first = event.start.date_naive();
+
+        dates
+            .iter()
+            .map(|&date| {
+                let shift = date - first;
+                CalendarEvent {
+                    uid: Uuid::new_v4().to_string(),
+                    start: event.start + shift,
+                    end: event.end + shift,
+                    ..event.clone()
+                }
+            })
+            .collect()
+    }
+
+    /// Add planned copies to their calendars, returning how many were created
+    pub fn add_event_copies(
+        calendar_manager: &mut CalendarManager,
+        copies: Vec<(String, CalendarEvent)>,
+    ) -> EventResult<usize> {
+        let mut added = 0;
+        for (calendar_id, event) in copies {
+            Self::add_event(calendar_manager, &calendar_id, event)?;
+            added += 1;
+        }
+        info!("EventHandler: Added {} event copies", added);
+        Ok(added)
+    }
+
     /// Find an event by UID across all calendars.
     ///
     /// Returns the event and the calendar ID it was found in.
@@ -383,8 +636,9 @@ impl EventHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::caldav::{AlertTime, RepeatFrequency, TravelTime};
+    use crate::caldav::{RepeatFrequency, ShowAs, TravelTime};
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     fn create_test_event(uid: &str, summary: &str) -> CalendarEvent {
         CalendarEvent {
@@ -399,11 +653,15 @@ mod tests {
             repeat_until: None,
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::None,
-            alert_second: None,
+            alerts: vec![],
             attachments: vec![],
             url: None,
             notes: None,
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         }
     }
 
@@ -435,4 +693,72 @@ mod tests {
         let result = EventHandler::validate_event(&event);
         assert!(matches!(result, Err(EventError::ValidationError(_))));
     }
+
+    #[test]
+    fn test_week_copies_skips_recurring_and_existing_copies() {
+        let week_start = NaiveDate::from_ymd_opt(2025, 11, 24).unwrap();
+        let week_end = NaiveDate::from_ymd_opt(2025, 11, 30).unwrap();
+
+        let one_off = create_test_event("one-off", "Gym");
+        let mut recurring = create_test_event("recurring", "Standup");
+        recurring.repeat = RepeatFrequency::Weekly;
+        let copied = create_test_event("copied", "Review");
+        let mut existing_copy = create_test_event("existing", "Review");
+        existing_copy.start += Duration::days(7);
+        existing_copy.end += Duration::days(7);
+
+        let events = vec![one_off.clone(), recurring, copied, existing_copy];
+        let copies = EventHandler::week_copies(&events, week_start, week_end);
+
+        assert_eq!(copies.len(), 1);
+        assert_eq!(copies[0].summary, "Gym");
+        assert_ne!(copies[0].uid, one_off.uid);
+        assert_eq!(copies[0].start, one_off.start + Duration::days(7));
+    }
+
+    #[test]
+    fn test_toggle_all_day_keeps_dates() {
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+
+        // Timed to all-day covers the event's day
+        let timed = create_test_event("standup", "Standup");
+        let all_day = EventHandler::with_all_day_toggled(&timed, nine, Duration::hours(1));
+        assert!(all_day.all_day);
+        assert_eq!(all_day.start, Utc.with_ymd_and_hms(2025, 11, 30, 0, 0, 0).unwrap());
+        assert_eq!(all_day.end, Utc.with_ymd_and_hms(2025, 11, 30, 23, 59, 59).unwrap());
+
+        // An event ending at midnight doesn't spill into the next day
+        let late = CalendarEvent {
+            start: Utc.with_ymd_and_hms(2025, 11, 30, 22, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap(),
+            ..create_test_event("late", "Late shift")
+        };
+        let late_all_day = EventHandler::with_all_day_toggled(&late, nine, Duration::hours(1));
+        assert_eq!(late_all_day.end.date_naive(), NaiveDate::from_ymd_opt(2025, 11, 30).unwrap());
+
+        // All-day back to timed starts at the given time on the first day
+        let timed_again = EventHandler::with_all_day_toggled(&all_day, nine, Duration::minutes(45));
+        assert!(!timed_again.all_day);
+        assert_eq!(timed_again.start, Utc.with_ymd_and_hms(2025, 11, 30, 9, 0, 0).unwrap());
+        assert_eq!(timed_again.end, Utc.with_ymd_and_hms(2025, 11, 30, 9, 45, 0).unwrap());
+        assert_eq!(timed_again.uid, timed.uid);
+    }
+
+    #[test]
+    fn test_copies_on_dates_keeps_times() {
+        let event = create_test_event("class", "Pottery class");
+        let dates = [
+            NaiveDate::from_ymd_opt(2025, 11, 30).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 12, 4).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 12, 18).unwrap(),
+        ];
+
+        let copies = EventHandler::copies_on_dates(&event, &dates);
+
+        assert_eq!(copies.len(), 3);
+        assert_eq!(copies[0].start, event.start);
+        assert_eq!(copies[2].start, Utc.with_ymd_and_hms(2025, 12, 18, 10, 0, 0).unwrap());
+        assert_eq!(copies[2].end, Utc.with_ymd_and_hms(2025, 12, 18, 11, 0, 0).unwrap());
+        assert_ne!(copies[1].uid, copies[2].uid);
+    }
 }
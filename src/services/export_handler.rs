@@ -3,15 +3,84 @@
 //! This handler manages importing and exporting calendar data in various formats,
 //! primarily iCalendar (.ics) format.
 
-use crate::caldav::{AlertTime, CalendarEvent, RepeatFrequency, TravelTime};
+use crate::caldav::{
+    priority_from_ical, AlarmAction, AlertTime, CalendarEvent, EventAlarm, OccurrenceOverride, RepeatFrequency, ShowAs,
+    TravelTime,
+};
 use crate::calendars::CalendarManager;
-use chrono::{DateTime, Utc};
-use icalendar::{Calendar, Component, DatePerhapsTime, Event, EventLike};
+use chrono::{DateTime, NaiveDate, Utc};
+use icalendar::{Calendar, Component, DatePerhapsTime, Event, EventLike, Property};
 use log::{debug, error, info, warn};
+use std::collections::{BTreeMap, HashMap};
 use std::error::Error;
 use std::fs;
 use std::path::Path;
 
+/// CSS3 color names used for the RFC 7986 COLOR property, spread over the hues
+/// calendar colors are usually picked from
+const CSS_COLORS: &[(&str, (u8, u8, u8))] = &[
+    ("black", (0, 0, 0)),
+    ("dimgray", (105, 105, 105)),
+    ("gray", (128, 128, 128)),
+    ("silver", (192, 192, 192)),
+    ("white", (255, 255, 255)),
+    ("maroon", (128, 0, 0)),
+    ("firebrick", (178, 34, 34)),
+    ("crimson", (220, 20, 60)),
+    ("red", (255, 0, 0)),
+    ("tomato", (255, 99, 71)),
+    ("salmon", (250, 128, 114)),
+    ("lightpink", (255, 182, 193)),
+    ("hotpink", (255, 105, 180)),
+    ("deeppink", (255, 20, 147)),
+    ("mediumvioletred", (199, 21, 133)),
+    ("orangered", (255, 69, 0)),
+    ("darkorange", (255, 140, 0)),
+    ("orange", (255, 165, 0)),
+    ("goldenrod", (218, 165, 32)),
+    ("gold", (255, 215, 0)),
+    ("khaki", (240, 230, 140)),
+    ("yellow", (255, 255, 0)),
+    ("olive", (128, 128, 0)),
+    ("yellowgreen", (154, 205, 50)),
+    ("lime", (0, 255, 0)),
+    ("limegreen", (50, 205, 50)),
+    ("lightgreen", (144, 238, 144)),
+    ("mediumseagreen", (60, 179, 113)),
+    ("seagreen", (46, 139, 87)),
+    ("green", (0, 128, 0)),
+    ("darkgreen", (0, 100, 0)),
+    ("mediumaquamarine", (102, 205, 170)),
+    ("lightseagreen", (32, 178, 170)),
+    ("teal", (0, 128, 128)),
+    ("darkturquoise", (0, 206, 209)),
+    ("cyan", (0, 255, 255)),
+    ("paleturquoise", (175, 238, 238)),
+    ("skyblue", (135, 206, 235)),
+    ("deepskyblue", (0, 191, 255)),
+    ("dodgerblue", (30, 144, 255)),
+    ("cornflowerblue", (100, 149, 237)),
+    ("royalblue", (65, 105, 225)),
+    ("steelblue", (70, 130, 180)),
+    ("blue", (0, 0, 255)),
+    ("mediumblue", (0, 0, 205)),
+    ("navy", (0, 0, 128)),
+    ("slateblue", (106, 90, 205)),
+    ("mediumpurple", (147, 112, 219)),
+    ("blueviolet", (138, 43, 226)),
+    ("darkorchid", (153, 50, 204)),
+    ("purple", (128, 0, 128)),
+    ("indigo", (75, 0, 130)),
+    ("plum", (221, 160, 221)),
+    ("orchid", (218, 112, 214)),
+    ("magenta", (255, 0, 255)),
+    ("sienna", (160, 82, 45)),
+    ("chocolate", (210, 105, 30)),
+    ("peru", (205, 133, 63)),
+    ("tan", (210, 180, 140)),
+    ("brown", (165, 42, 42)),
+];
+
 /// Result type for export operations
 #[allow(dead_code)] // Part of export API for future use
 pub type ExportResult<T> = Result<T, ExportError>;
@@ -51,6 +120,65 @@ impl Error for ExportError {}
 pub struct ExportHandler;
 
 impl ExportHandler {
+    /// Write a captured view (RGBA pixels) to a PNG file
+    ///
+    /// `source_scale` is the display scale the pixels were captured at and
+    /// `target_scale` the number of image pixels per logical pixel to write.
+    pub fn export_view_image<P: AsRef<Path>>(
+        path: P,
+        rgba: &[u8],
+        width: u32,
+        height: u32,
+        source_scale: f32,
+        target_scale: u32,
+    ) -> ExportResult<()> {
+        let path = path.as_ref();
+        if rgba.len() != (width as usize) * (height as usize) * 4 {
+            return Err(ExportError::FormatError("Captured image has unexpected size".to_string()));
+        }
+
+        let ratio = target_scale as f32 / source_scale.max(0.1);
+        let out_width = ((width as f32 * ratio).round() as u32).max(1);
+        let out_height = ((height as f32 * ratio).round() as u32).max(1);
+        let pixels = Self::resize_rgba(rgba, width, height, out_width, out_height);
+
+        let file = fs::File::create(path).map_err(|e| {
+            error!("ExportHandler: Failed to create image file {:?}: {}", path, e);
+            ExportError::IoError(e.to_string())
+        })?;
+        let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), out_width, out_height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&pixels))
+            .map_err(|e| {
+                error!("ExportHandler: Failed to encode image {:?}: {}", path, e);
+                ExportError::IoError(e.to_string())
+            })?;
+
+        info!("ExportHandler: Exported {}x{} view image to {:?}", out_width, out_height, path);
+        Ok(())
+    }
+
+    /// Nearest-neighbour resize of an RGBA buffer
+    fn resize_rgba(rgba: &[u8], width: u32, height: u32, out_width: u32, out_height: u32) -> Vec<u8> {
+        if width == out_width && height == out_height {
+            return rgba.to_vec();
+        }
+
+        let mut out = Vec::with_capacity((out_width as usize) * (out_height as usize) * 4);
+        for y in 0..out_height {
+            let src_y = ((y as u64 * height as u64) / out_height as u64) as usize;
+            for x in 0..out_width {
+                let src_x = ((x as u64 * width as u64) / out_width as u64) as usize;
+                let offset = (src_y * width as usize + src_x) * 4;
+                out.extend_from_slice(&rgba[offset..offset + 4]);
+            }
+        }
+        out
+    }
+
     /// Export a single event to iCalendar format
     #[allow(dead_code)] // Part of export API
     pub fn event_to_ical(event: &CalendarEvent) -> Calendar {
@@ -89,6 +217,19 @@ impl ExportHandler {
     ) -> ExportResult<Calendar> {
         info!("ExportHandler: Exporting calendar '{}' to iCal format", calendar_id);
 
+        let (name, events) = Self::fetch_calendar_events(manager, calendar_id)?;
+        let mut ical = Self::events_to_ical(&events);
+        Self::add_calendar_properties(&mut ical, Some(&name));
+
+        info!("ExportHandler: Successfully exported calendar '{}'", calendar_id);
+        Ok(ical)
+    }
+
+    /// Fetch the name and all events of a calendar for export
+    fn fetch_calendar_events(
+        manager: &CalendarManager,
+        calendar_id: &str,
+    ) -> ExportResult<(String, Vec<CalendarEvent>)> {
         let calendar = manager
             .sources()
             .iter()
@@ -106,33 +247,155 @@ impl ExportHandler {
             })?;
 
         debug!("ExportHandler: Found {} events to export", events.len());
+        Ok((calendar.info().name.clone(), events))
+    }
 
-        let mut ical = Calendar::new();
+    /// Add the X-WR-CALNAME and X-WR-TIMEZONE properties other apps use to label
+    /// an imported calendar and interpret its times
+    fn add_calendar_properties(ical: &mut Calendar, name: Option<&str>) {
+        if let Some(name) = name {
+            ical.append_property(Property::new("X-WR-CALNAME", name));
+        }
+        ical.append_property(Property::new("X-WR-TIMEZONE", &Self::system_timezone()));
+    }
+
+    /// IANA name of the system timezone, from `TZ` or the /etc/localtime symlink
+    /// Falls back to UTC, which is what event times are exported in
+    fn system_timezone() -> String {
+        if let Ok(tz) = std::env::var("TZ") {
+            let tz = tz.trim_start_matches(':');
+            if !tz.is_empty() && !tz.starts_with('/') {
+                return tz.to_string();
+            }
+        }
 
+        fs::read_link("/etc/localtime")
+            .ok()
+            .and_then(|target| {
+                let target = target.to_string_lossy().into_owned();
+                target
+                    .split_once("zoneinfo/")
+                    .map(|(_, zone)| zone.to_string())
+            })
+            .filter(|zone| !zone.is_empty())
+            .unwrap_or_else(|| "UTC".to_string())
+    }
+
+    /// Build an iCalendar with one VEVENT per event
+    fn events_to_ical(events: &[CalendarEvent]) -> Calendar {
+        let mut ical = Calendar::new();
         for event in events {
-            let mut ical_event = Event::new();
-            ical_event.summary(&event.summary);
-            ical_event.uid(&event.uid);
-            ical_event.starts(event.start);
-            ical_event.ends(event.end);
-
-            if let Some(ref location) = event.location {
-                ical_event.location(location);
+            ical.push(Self::event_to_vevent(event));
+        }
+        ical
+    }
+
+    /// Build an iCalendar of events from several calendars, each VEVENT carrying
+    /// its calendar's color. RFC 7986 COLOR only takes CSS3 color names, so the
+    /// nearest name goes there and the exact hex in X-APPLE-CALENDAR-COLOR.
+    fn colored_events_to_ical(events: &[(CalendarEvent, String)]) -> Calendar {
+        let mut ical = Calendar::new();
+        for (event, color) in events {
+            let mut ical_event = Self::event_to_vevent(event);
+            if let Some(name) = Self::nearest_css_color(color) {
+                ical_event.add_property("COLOR", name);
             }
+            ical_event.add_property("X-APPLE-CALENDAR-COLOR", color);
+            ical.push(ical_event);
+        }
+        ical
+    }
+
+    /// CSS3 color name closest to a "#RRGGBB" color (None if it isn't one)
+    fn nearest_css_color(hex: &str) -> Option<&'static str> {
+        let hex = hex.strip_prefix('#')?;
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |i: usize| hex.get(i..i + 2).and_then(|c| u8::from_str_radix(c, 16).ok());
+        let (r, g, b) = (channel(0)? as i32, channel(2)? as i32, channel(4)? as i32);
+
+        CSS_COLORS
+            .iter()
+            .min_by_key(|(_, (cr, cg, cb))| {
+                let (dr, dg, db) = (r - *cr as i32, g - *cg as i32, b - *cb as i32);
+                dr * dr + dg * dg + db * db
+            })
+            .map(|(name, _)| *name)
+    }
+
+    /// Convert an event to a VEVENT (alarms are appended to the serialized text separately)
+    fn event_to_vevent(event: &CalendarEvent) -> Event {
+        let mut ical_event = Event::new();
+        ical_event.summary(&event.summary);
+        ical_event.uid(&event.uid);
+        ical_event.starts(event.start);
+        ical_event.ends(event.end);
+
+        if let Some(ref location) = event.location {
+            ical_event.location(location);
+        }
+
+        if let Some(ref notes) = event.notes {
+            ical_event.description(notes);
+        }
 
-            if let Some(ref notes) = event.notes {
-                ical_event.description(notes);
+        if let Some(ref url) = event.url {
+            ical_event.url(url);
+        }
+
+        match event.show_as {
+            ShowAs::Busy => {}
+            ShowAs::Tentative => {
+                ical_event.add_property("STATUS", "TENTATIVE");
+            }
+            ShowAs::Free => {
+                ical_event.add_property("TRANSP", "TRANSPARENT");
             }
+        }
+
+        if let Some(priority) = event.priority {
+            ical_event.add_property("PRIORITY", priority.to_string());
+        }
 
-            if let Some(ref url) = event.url {
-                ical_event.url(url);
+        // Repeating events are written as a series so every occurrence comes along
+        if let Some(rule) = event.repeat.to_rrule(event.repeat_until, event.all_day) {
+            ical_event.add_property("RRULE", rule);
+            if !event.exception_dates.is_empty() {
+                ical_event.append_property(Self::exdate_property(event));
             }
+        }
 
-            ical.push(ical_event);
+        ical_event
+    }
+
+    /// EXDATE listing the skipped occurrences of a repeating event
+    fn exdate_property(event: &CalendarEvent) -> Property {
+        let dates: Vec<String> = event
+            .exception_dates
+            .iter()
+            .map(|date| {
+                if event.all_day {
+                    date.format("%Y%m%d").to_string()
+                } else {
+                    format!("{}T{}Z", date.format("%Y%m%d"), event.start.format("%H%M%S"))
+                }
+            })
+            .collect();
+        let mut property = Property::new("EXDATE", &dates.join(","));
+        if event.all_day {
+            property.add_parameter("VALUE", "DATE");
         }
+        property.done()
+    }
 
-        info!("ExportHandler: Successfully exported calendar '{}'", calendar_id);
-        Ok(ical)
+    /// Dates listed in an EXDATE value (dates or date-times, comma separated)
+    fn parse_exdates(value: &str) -> Vec<NaiveDate> {
+        value
+            .split(',')
+            .filter_map(|item| item.trim().get(..8))
+            .filter_map(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+            .collect()
     }
 
     /// Export a calendar to an iCalendar file
@@ -144,8 +407,10 @@ impl ExportHandler {
     ) -> ExportResult<()> {
         info!("ExportHandler: Exporting calendar '{}' to file {:?}", calendar_id, path.as_ref());
 
-        let ical = Self::calendar_to_ical(manager, calendar_id)?;
-        let ical_string = ical.to_string();
+        let (name, events) = Self::fetch_calendar_events(manager, calendar_id)?;
+        let mut ical = Self::events_to_ical(&events);
+        Self::add_calendar_properties(&mut ical, Some(&name));
+        let ical_string = Self::append_alarms(&ical.to_string(), &events);
 
         fs::write(&path, ical_string).map_err(|e| {
             error!("ExportHandler: Failed to write file: {}", e);
@@ -165,7 +430,7 @@ impl ExportHandler {
         info!("ExportHandler: Exporting all calendars to file {:?}", path.as_ref());
 
         let mut combined = Calendar::new();
-        let mut total_events = 0;
+        let mut exported = Vec::new();
 
         for calendar in manager.sources() {
             if !calendar.is_enabled() {
@@ -191,12 +456,16 @@ impl ExportHandler {
                     }
 
                     combined.push(ical_event);
-                    total_events += 1;
+                    exported.push(event);
                 }
             }
         }
 
-        let ical_string = combined.to_string();
+        // Several calendars are combined, so only the timezone is written
+        Self::add_calendar_properties(&mut combined, None);
+
+        let total_events = exported.len();
+        let ical_string = Self::append_alarms(&combined.to_string(), &exported);
         fs::write(&path, ical_string).map_err(|e| {
             error!("ExportHandler: Failed to write file: {}", e);
             ExportError::IoError(e.to_string())
@@ -206,6 +475,83 @@ impl ExportHandler {
         Ok(())
     }
 
+    /// Export the events of the given calendars that fall within a date range
+    /// Returns the number of events written
+    pub fn export_range<P: AsRef<Path>>(
+        manager: &CalendarManager,
+        calendar_ids: &[String],
+        start: NaiveDate,
+        end: NaiveDate,
+        path: P,
+    ) -> ExportResult<usize> {
+        info!(
+            "ExportHandler: Exporting {} calendars from {} to {} to file {:?}",
+            calendar_ids.len(),
+            start,
+            end,
+            path.as_ref()
+        );
+
+        let mut exported = Vec::new();
+        for calendar_id in calendar_ids {
+            let (_, events) = Self::fetch_calendar_events(manager, calendar_id)?;
+            let in_range = Self::events_in_range(&events, start, end);
+            debug!("ExportHandler: {} of {} events from '{}' are in range", in_range.len(), events.len(), calendar_id);
+            exported.extend(in_range);
+        }
+
+        // Several calendars may be combined, so only the timezone is written
+        let mut ical = Self::events_to_ical(&exported);
+        Self::add_calendar_properties(&mut ical, None);
+        let ical_string = Self::append_alarms(&ical.to_string(), &exported);
+
+        fs::write(&path, ical_string).map_err(|e| {
+            error!("ExportHandler: Failed to write file: {}", e);
+            ExportError::IoError(e.to_string())
+        })?;
+
+        info!("ExportHandler: Exported {} events to {:?}", exported.len(), path.as_ref());
+        Ok(exported.len())
+    }
+
+    /// Export a hand-picked set of events, each paired with its calendar's color
+    /// Returns the number of events written
+    pub fn export_events<P: AsRef<Path>>(events: &[(CalendarEvent, String)], path: P) -> ExportResult<usize> {
+        info!("ExportHandler: Exporting {} selected events to file {:?}", events.len(), path.as_ref());
+
+        // The events may come from several calendars, so only the timezone is written
+        let mut ical = Self::colored_events_to_ical(events);
+        Self::add_calendar_properties(&mut ical, None);
+        let exported: Vec<CalendarEvent> = events.iter().map(|(event, _)| event.clone()).collect();
+        let ical_string = Self::append_alarms(&ical.to_string(), &exported);
+
+        fs::write(&path, ical_string).map_err(|e| {
+            error!("ExportHandler: Failed to write file: {}", e);
+            ExportError::IoError(e.to_string())
+        })?;
+
+        info!("ExportHandler: Exported {} events to {:?}", exported.len(), path.as_ref());
+        Ok(exported.len())
+    }
+
+    /// Events that take place on at least one day between start and end (inclusive)
+    /// Recurring events are kept whole (written with RRULE and EXDATE) when
+    /// their series overlaps the range
+    fn events_in_range(events: &[CalendarEvent], start: NaiveDate, end: NaiveDate) -> Vec<CalendarEvent> {
+        events
+            .iter()
+            .filter(|event| {
+                let event_start = event.start.date_naive();
+                if matches!(event.repeat, RepeatFrequency::Never) {
+                    event_start <= end && event.end.date_naive() >= start
+                } else {
+                    event_start <= end && event.repeat_until.is_none_or(|until| until >= start)
+                }
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Read an iCalendar file (placeholder for future import functionality)
     #[allow(dead_code)] // Part of export API
     pub fn read_ical_file<P: AsRef<Path>>(path: P) -> ExportResult<String> {
@@ -229,23 +575,14 @@ impl ExportHandler {
     pub fn parse_ical_string(ical_str: &str) -> ExportResult<Vec<CalendarEvent>> {
         debug!("ExportHandler: Parsing iCal string ({} bytes)", ical_str.len());
 
+        let ical_str = &Self::unfold_lines(ical_str);
         let calendar = ical_str.parse::<Calendar>().map_err(|e| {
             error!("ExportHandler: Failed to parse iCalendar: {}", e);
             ExportError::ParseError(e.to_string())
         })?;
 
-        let mut events = Vec::new();
-        for component in calendar.components {
-            if let icalendar::CalendarComponent::Event(ical_event) = component {
-                match Self::ical_event_to_calendar_event(&ical_event) {
-                    Ok(event) => events.push(event),
-                    Err(e) => {
-                        warn!("ExportHandler: Skipping invalid event: {}", e);
-                        continue;
-                    }
-                }
-            }
-        }
+        let mut events = Self::collect_events(&calendar);
+        Self::attach_alarms(ical_str, &mut events);
 
         info!("ExportHandler: Successfully parsed {} events", events.len());
         Ok(events)
@@ -253,10 +590,10 @@ impl ExportHandler {
 
     /// Parse iCalendar string and extract calendar name and events
     /// Returns (calendar_name, events) tuple
-    #[allow(dead_code)] // Part of import API
     pub fn parse_ical_string_with_name(ical_str: &str) -> ExportResult<(String, Vec<CalendarEvent>)> {
         debug!("ExportHandler: Parsing iCal string with name ({} bytes)", ical_str.len());
 
+        let ical_str = &Self::unfold_lines(ical_str);
         let calendar = ical_str.parse::<Calendar>().map_err(|e| {
             error!("ExportHandler: Failed to parse iCalendar: {}", e);
             ExportError::ParseError(e.to_string())
@@ -271,11 +608,25 @@ impl ExportHandler {
 
         debug!("ExportHandler: Extracted calendar name: {}", calendar_name);
 
+        let mut events = Self::collect_events(&calendar);
+        Self::attach_alarms(ical_str, &mut events);
+
+        info!("ExportHandler: Successfully parsed calendar '{}' with {} events", calendar_name, events.len());
+        Ok((calendar_name, events))
+    }
+
+    /// Convert the VEVENTs of a calendar, folding RECURRENCE-ID overrides into their series
+    /// Overrides whose series isn't in the file are kept as standalone events
+    fn collect_events(calendar: &Calendar) -> Vec<CalendarEvent> {
         let mut events = Vec::new();
-        for component in calendar.components {
+        let mut overrides = Vec::new();
+        for component in &calendar.components {
             if let icalendar::CalendarComponent::Event(ical_event) = component {
-                match Self::ical_event_to_calendar_event(&ical_event) {
-                    Ok(event) => events.push(event),
+                match Self::ical_event_to_calendar_event(ical_event) {
+                    Ok(event) => match Self::recurrence_id_date(ical_event) {
+                        Some(date) => overrides.push((date, event)),
+                        None => events.push(event),
+                    },
                     Err(e) => {
                         warn!("ExportHandler: Skipping invalid event: {}", e);
                         continue;
@@ -284,8 +635,220 @@ impl ExportHandler {
             }
         }
 
-        info!("ExportHandler: Successfully parsed calendar '{}' with {} events", calendar_name, events.len());
-        Ok((calendar_name, events))
+        for (date, occurrence) in overrides {
+            match events
+                .iter_mut()
+                .find(|e| e.uid == occurrence.uid && e.repeat != RepeatFrequency::Never)
+            {
+                Some(series) => {
+                    debug!("ExportHandler: Modified occurrence {} of uid={}", date, series.uid);
+                    series.overrides.insert(date, OccurrenceOverride::from_event(&occurrence));
+                }
+                None => {
+                    warn!("ExportHandler: No series for modified occurrence of uid={}", occurrence.uid);
+                    events.push(occurrence);
+                }
+            }
+        }
+        events
+    }
+
+    /// Date of the occurrence a VEVENT replaces, from its RECURRENCE-ID (date or date-time)
+    fn recurrence_id_date(ical_event: &Event) -> Option<NaiveDate> {
+        let value = ical_event.property_value("RECURRENCE-ID")?;
+        NaiveDate::parse_from_str(value.trim().get(..8)?, "%Y%m%d").ok()
+    }
+
+    /// Parse an RFC 5545 duration such as "PT1H", "PT90M", "P1D" or "P1DT2H30M"
+    /// Returns None for malformed or negative values
+    fn parse_ical_duration(value: &str) -> Option<chrono::Duration> {
+        let value = value.trim();
+        let value = value.strip_prefix('+').unwrap_or(value);
+        let rest = value.strip_prefix('P')?;
+
+        let mut total = chrono::Duration::zero();
+        let mut number = String::new();
+        let mut in_time = false;
+        let mut has_component = false;
+
+        for c in rest.chars() {
+            match c {
+                '0'..='9' => number.push(c),
+                'T' if !in_time && number.is_empty() => in_time = true,
+                'W' | 'D' | 'H' | 'M' | 'S' => {
+                    let amount: i64 = number.parse().ok()?;
+                    number.clear();
+                    total += match (c, in_time) {
+                        ('W', false) => chrono::Duration::weeks(amount),
+                        ('D', false) => chrono::Duration::days(amount),
+                        ('H', true) => chrono::Duration::hours(amount),
+                        ('M', true) => chrono::Duration::minutes(amount),
+                        ('S', true) => chrono::Duration::seconds(amount),
+                        _ => return None,
+                    };
+                    has_component = true;
+                }
+                _ => return None,
+            }
+        }
+
+        (has_component && number.is_empty()).then_some(total)
+    }
+
+    /// Normalize line endings to LF and unfold continuation lines (RFC 5545 3.1)
+    /// Outlook and other producers fold long values over several CRLF lines
+    fn unfold_lines(ical_str: &str) -> String {
+        ical_str
+            .replace("\r\n", "\n")
+            .replace('\r', "\n")
+            .replace("\n ", "")
+            .replace("\n\t", "")
+    }
+
+    /// Attach VALARM components from the raw iCalendar text to the parsed events
+    /// The icalendar parser drops nested components, so alarms are read from the text directly
+    fn attach_alarms(ical_str: &str, events: &mut [CalendarEvent]) {
+        let mut alarms_by_uid = Self::extract_alarms(ical_str);
+        for event in events.iter_mut() {
+            if let Some(alarms) = alarms_by_uid.remove(&event.uid) {
+                debug!("ExportHandler: Kept {} alarms for uid={}", alarms.len(), event.uid);
+                event.alerts = alarms.iter().filter_map(EventAlarm::as_alert).collect();
+                event.alerts.dedup();
+                event.alarms = alarms;
+            }
+        }
+    }
+
+    /// Collect the VALARM components of every VEVENT, keyed by event UID
+    fn extract_alarms(ical_str: &str) -> HashMap<String, Vec<EventAlarm>> {
+        let unfolded = Self::unfold_lines(ical_str);
+
+        let mut alarms_by_uid: HashMap<String, Vec<EventAlarm>> = HashMap::new();
+        let mut in_event = false;
+        let mut event_uid: Option<String> = None;
+        let mut event_alarms: Vec<EventAlarm> = Vec::new();
+        let mut alarm_lines: Option<Vec<String>> = None;
+
+        for line in unfolded.lines() {
+            let upper = line.to_ascii_uppercase();
+            match upper.as_str() {
+                "BEGIN:VEVENT" => {
+                    in_event = true;
+                    event_uid = None;
+                    event_alarms.clear();
+                }
+                "END:VEVENT" => {
+                    if let Some(uid) = event_uid.take() {
+                        if !event_alarms.is_empty() {
+                            // Recurrence overrides share the UID - keep the master's alarms
+                            alarms_by_uid.entry(uid).or_insert_with(|| std::mem::take(&mut event_alarms));
+                        }
+                    }
+                    in_event = false;
+                }
+                "BEGIN:VALARM" if in_event => alarm_lines = Some(Vec::new()),
+                "END:VALARM" if in_event => {
+                    if let Some(lines) = alarm_lines.take() {
+                        let value_of = |name: &str| {
+                            lines.iter().find_map(|l| {
+                                let (key, value) = l.split_once(':')?;
+                                let key = key.split(';').next().unwrap_or(key);
+                                key.eq_ignore_ascii_case(name).then(|| value.to_string())
+                            })
+                        };
+                        let action = AlarmAction::from_ical(&value_of("ACTION").unwrap_or_default());
+                        let trigger = value_of("TRIGGER").unwrap_or_default();
+                        event_alarms.push(EventAlarm { action, trigger, lines });
+                    }
+                }
+                _ => {
+                    if let Some(ref mut lines) = alarm_lines {
+                        lines.push(line.to_string());
+                    } else if in_event && (upper.starts_with("UID:") || upper.starts_with("UID;")) {
+                        event_uid = line.split_once(':').map(|(_, uid)| uid.trim().to_string());
+                    }
+                }
+            }
+        }
+
+        alarms_by_uid
+    }
+
+    /// Reminders of an event that no imported alarm already covers, as TRIGGER values
+    fn reminder_triggers(event: &CalendarEvent) -> Vec<String> {
+        let covered: Vec<i64> = event
+            .alarms
+            .iter()
+            .filter_map(|alarm| alarm.as_alert()?.minutes_before(&event.travel_time))
+            .collect();
+        event
+            .alerts
+            .iter()
+            .filter(|alert| {
+                alert
+                    .minutes_before(&event.travel_time)
+                    .is_some_and(|mins| !covered.contains(&mins))
+            })
+            .filter_map(|alert| alert.ical_trigger(&event.travel_time))
+            .collect()
+    }
+
+    /// Re-emit each event's VALARM components inside its VEVENT, followed by
+    /// a DISPLAY alarm for every reminder not among them
+    fn append_alarms(ical_string: &str, events: &[CalendarEvent]) -> String {
+        let alarms_by_uid: HashMap<&str, (&[EventAlarm], Vec<String>)> = events
+            .iter()
+            .filter(|e| !e.alarms.is_empty() || !e.alerts.is_empty())
+            .map(|e| (e.uid.as_str(), (e.alarms.as_slice(), Self::reminder_triggers(e))))
+            .collect();
+        if alarms_by_uid.is_empty() {
+            return ical_string.to_string();
+        }
+
+        let mut out = String::with_capacity(ical_string.len());
+        let mut event_uid: Option<&str> = None;
+
+        for line in ical_string.lines() {
+            if line.starts_with("UID:") {
+                event_uid = alarms_by_uid.get_key_value(&line[4..]).map(|(uid, _)| *uid);
+            } else if line == "END:VEVENT" {
+                if let Some((alarms, triggers)) = event_uid.take().and_then(|uid| alarms_by_uid.get(uid)) {
+                    for alarm in alarms.iter() {
+                        out.push_str("BEGIN:VALARM\r\n");
+                        for alarm_line in &alarm.lines {
+                            out.push_str(&Self::fold_line(alarm_line));
+                            out.push_str("\r\n");
+                        }
+                        out.push_str("END:VALARM\r\n");
+                    }
+                    for trigger in triggers {
+                        out.push_str("BEGIN:VALARM\r\nACTION:DISPLAY\r\nDESCRIPTION:Reminder\r\n");
+                        out.push_str(&format!("TRIGGER:{}\r\n", trigger));
+                        out.push_str("END:VALARM\r\n");
+                    }
+                }
+            }
+            out.push_str(line);
+            out.push_str("\r\n");
+        }
+
+        out
+    }
+
+    /// Fold a content line to 75 octets per line (RFC 5545 3.1)
+    fn fold_line(line: &str) -> String {
+        let mut folded = String::with_capacity(line.len() + line.len() / 74 * 3);
+        let mut line_len = 0;
+        for c in line.chars() {
+            let char_len = c.len_utf8();
+            if line_len + char_len > 75 {
+                folded.push_str("\r\n ");
+                line_len = 1;
+            }
+            folded.push(c);
+            line_len += char_len;
+        }
+        folded
     }
 
     /// Convert an icalendar::Event to a CalendarEvent
@@ -337,7 +900,10 @@ impl ExportHandler {
             }
         };
 
-        // Extract end time (default to start + 1 hour)
+        // Extract end time (DTEND, else DTSTART + DURATION, else start + 1 hour)
+        let duration = ical_event
+            .property_value("DURATION")
+            .and_then(Self::parse_ical_duration);
         let end = if let Some(end_prop) = ical_event.get_end() {
             match end_prop {
                 DatePerhapsTime::DateTime(cal_dt) => {
@@ -359,6 +925,8 @@ impl ExportHandler {
                     DateTime::from_naive_utc_and_offset(dt, Utc)
                 }
             }
+        } else if let Some(duration) = duration {
+            start + duration
         } else {
             start + chrono::Duration::hours(1)
         };
@@ -367,6 +935,19 @@ impl ExportHandler {
         let location = ical_event.get_location().map(|s| s.to_string());
         let notes = ical_event.get_description().map(|s| s.to_string());
         let url = ical_event.get_url().map(|s| s.to_string());
+        let show_as = ShowAs::from_ical(
+            ical_event.property_value("STATUS"),
+            ical_event.property_value("TRANSP"),
+        );
+        let priority = priority_from_ical(ical_event.property_value("PRIORITY"));
+        let (repeat, repeat_until) = ical_event
+            .property_value("RRULE")
+            .map(RepeatFrequency::from_rrule)
+            .unwrap_or((RepeatFrequency::Never, None));
+        let exception_dates = ical_event
+            .property_value("EXDATE")
+            .map(Self::parse_exdates)
+            .unwrap_or_default();
 
         debug!("ExportHandler: Parsed event uid={}", uid);
 
@@ -378,15 +959,19 @@ impl ExportHandler {
             start,
             end,
             travel_time: TravelTime::None,
-            repeat: RepeatFrequency::Never,
-            repeat_until: None,
-            exception_dates: vec![],
+            repeat,
+            repeat_until,
+            exception_dates,
             invitees: vec![],
-            alert: AlertTime::None,
-            alert_second: None,
+            alerts: vec![],
             attachments: vec![],
             url,
             notes,
+            show_as,
+            priority,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         })
     }
 
@@ -597,11 +1182,15 @@ mod tests {
             repeat_until: None,
             exception_dates: vec![],
             invitees: vec![],
-            alert: AlertTime::None,
-            alert_second: None,
+            alerts: vec![],
             attachments: vec![],
             url: None,
             notes: Some("Test notes".to_string()),
+            show_as: ShowAs::Busy,
+            priority: None,
+            alarms: vec![],
+            overrides: BTreeMap::new(),
+            display_order: None,
         }
     }
 
@@ -617,4 +1206,298 @@ mod tests {
         assert!(ical_string.contains("END:VEVENT"));
         assert!(ical_string.contains("END:VCALENDAR"));
     }
+
+    #[test]
+    fn test_export_range_excludes_events_outside_it() {
+        let event_at = |uid: &str, day: u32| CalendarEvent {
+            uid: uid.to_string(),
+            start: Utc.with_ymd_and_hms(2025, 12, day, 10, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 12, day, 11, 0, 0).unwrap(),
+            ..create_test_event()
+        };
+        let mut weekly = event_at("weekly", 1);
+        weekly.repeat = RepeatFrequency::Weekly;
+        let mut ended_series = event_at("ended-series", 1);
+        ended_series.repeat = RepeatFrequency::Daily;
+        ended_series.repeat_until = NaiveDate::from_ymd_opt(2025, 12, 5);
+        let events = vec![
+            event_at("before", 3),
+            event_at("first-day", 10),
+            event_at("last-day", 20),
+            event_at("after", 21),
+            weekly,
+            ended_series,
+        ];
+
+        let start = NaiveDate::from_ymd_opt(2025, 12, 10).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 12, 20).unwrap();
+        let in_range = ExportHandler::events_in_range(&events, start, end);
+        let uids: Vec<&str> = in_range.iter().map(|e| e.uid.as_str()).collect();
+        assert_eq!(uids, vec!["first-day", "last-day", "weekly"]);
+
+        let ical_string = ExportHandler::events_to_ical(&in_range).to_string();
+        assert!(ical_string.contains("UID:first-day"));
+        assert!(!ical_string.contains("UID:before"));
+        assert!(!ical_string.contains("UID:after"));
+    }
+
+    #[test]
+    fn test_export_range_writes_series_started_before_it() {
+        // A weekly series starting on Monday 1 December with the 15th skipped
+        let mut weekly = CalendarEvent { uid: "weekly-standup".to_string(), ..create_test_event() };
+        weekly.repeat = RepeatFrequency::Weekly;
+        weekly.repeat_until = NaiveDate::from_ymd_opt(2025, 12, 29);
+        weekly.exception_dates = vec![NaiveDate::from_ymd_opt(2025, 12, 15).unwrap()];
+
+        let start = NaiveDate::from_ymd_opt(2025, 12, 10).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 12, 20).unwrap();
+        let in_range = ExportHandler::events_in_range(std::slice::from_ref(&weekly), start, end);
+        assert_eq!(in_range.len(), 1);
+
+        // The file holds the series, not just its first instance before the range
+        let ical_string = ExportHandler::events_to_ical(&in_range).to_string();
+        assert!(ical_string.contains("RRULE:FREQ=WEEKLY;UNTIL=20251229T235959Z"));
+        assert!(ical_string.contains("EXDATE:20251215T100000Z"));
+
+        let imported = ExportHandler::parse_ical_string(&ical_string).unwrap();
+        assert_eq!(imported[0].repeat, RepeatFrequency::Weekly);
+        assert_eq!(imported[0].repeat_until, weekly.repeat_until);
+        assert_eq!(imported[0].exception_dates, weekly.exception_dates);
+    }
+
+    #[test]
+    fn test_export_selected_events_keeps_calendar_colors() {
+        let work = CalendarEvent { uid: "selected-work".to_string(), ..create_test_event() };
+        let home = CalendarEvent { uid: "selected-home".to_string(), ..create_test_event() };
+        let selected = vec![(work, "#3584e4".to_string()), (home, "#e66100".to_string())];
+
+        let path = std::env::temp_dir().join(format!("sol-calendar-selected-{}.ics", std::process::id()));
+        let count = ExportHandler::export_events(&selected, &path).unwrap();
+        let ical_string = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(count, 2);
+        assert!(ical_string.contains("UID:selected-work"));
+        assert!(ical_string.contains("UID:selected-home"));
+        assert!(ical_string.contains("\nCOLOR:royalblue"));
+        assert!(ical_string.contains("\nCOLOR:chocolate"));
+        assert!(ical_string.contains("X-APPLE-CALENDAR-COLOR:#3584e4"));
+        assert!(ical_string.contains("X-APPLE-CALENDAR-COLOR:#e66100"));
+
+        // Both events come back when the file is imported again
+        let imported = ExportHandler::parse_ical_string(&ical_string).unwrap();
+        assert_eq!(imported.len(), 2);
+    }
+
+    #[test]
+    fn test_nearest_css_color() {
+        assert_eq!(ExportHandler::nearest_css_color("#ff0000"), Some("red"));
+        assert_eq!(ExportHandler::nearest_css_color("#2EC27E"), Some("mediumseagreen"));
+        assert_eq!(ExportHandler::nearest_css_color("#F5C211"), Some("gold"));
+        assert_eq!(ExportHandler::nearest_css_color("blue"), None);
+        assert_eq!(ExportHandler::nearest_css_color("#12345"), None);
+    }
+
+    #[test]
+    fn test_resize_rgba_doubles_and_halves() {
+        // 2x1 image: red, blue
+        let rgba = [255, 0, 0, 255, 0, 0, 255, 255];
+
+        let doubled = ExportHandler::resize_rgba(&rgba, 2, 1, 4, 2);
+        assert_eq!(doubled.len(), 4 * 2 * 4);
+        assert_eq!(&doubled[0..8], &[255, 0, 0, 255, 255, 0, 0, 255]);
+        assert_eq!(&doubled[8..16], &[0, 0, 255, 255, 0, 0, 255, 255]);
+
+        let halved = ExportHandler::resize_rgba(&doubled, 4, 2, 2, 1);
+        assert_eq!(halved, rgba);
+    }
+
+    #[test]
+    fn test_alarm_actions_round_trip() {
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:alarm-test-1\r\n\
+DTSTAMP:20251201T090000Z\r\n\
+DTSTART:20251201T100000Z\r\n\
+DTEND:20251201T110000Z\r\n\
+SUMMARY:Alarm test\r\n\
+BEGIN:VALARM\r\n\
+ACTION:DISPLAY\r\n\
+DESCRIPTION:Reminder\r\n\
+TRIGGER:-PT15M\r\n\
+END:VALARM\r\n\
+BEGIN:VALARM\r\n\
+ACTION:AUDIO\r\n\
+TRIGGER;RELATED=END:PT0S\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let events = ExportHandler::parse_ical_string(ical).unwrap();
+        assert_eq!(events.len(), 1);
+        let alarms = &events[0].alarms;
+        assert_eq!(alarms.len(), 2);
+        assert_eq!(alarms[0].action, AlarmAction::Display);
+        assert_eq!(alarms[0].trigger, "-PT15M");
+        assert_eq!(alarms[1].action, AlarmAction::Audio);
+        assert_eq!(alarms[1].trigger, "PT0S");
+
+        // Export and re-import: both alarms survive unchanged
+        let exported = ExportHandler::append_alarms(
+            &ExportHandler::events_to_ical(&events).to_string(),
+            &events,
+        );
+        let reimported = ExportHandler::parse_ical_string(&exported).unwrap();
+        assert_eq!(reimported[0].alarms, events[0].alarms);
+        // Only the DISPLAY alarm before the start is a reminder
+        assert_eq!(reimported[0].alerts, vec![AlertTime::FifteenMinutes]);
+    }
+
+    #[test]
+    fn test_reminders_round_trip() {
+        let mut event = create_test_event();
+        event.alerts = vec![AlertTime::FifteenMinutes, AlertTime::OneHour, AlertTime::OneDay];
+        let events = vec![event];
+
+        let exported = ExportHandler::append_alarms(
+            &ExportHandler::events_to_ical(&events).to_string(),
+            &events,
+        );
+        assert_eq!(exported.matches("BEGIN:VALARM").count(), 3);
+        assert!(exported.contains("TRIGGER:-PT1440M"));
+
+        let reimported = ExportHandler::parse_ical_string(&exported).unwrap();
+        assert_eq!(reimported[0].alerts, events[0].alerts);
+        assert_eq!(reimported[0].alarms.len(), 3);
+
+        // Exporting the re-imported event doesn't duplicate its alarms
+        let again = ExportHandler::append_alarms(
+            &ExportHandler::events_to_ical(&reimported).to_string(),
+            &reimported,
+        );
+        assert_eq!(again.matches("BEGIN:VALARM").count(), 3);
+    }
+
+    #[test]
+    fn test_calendar_name_round_trip() {
+        let events = vec![create_test_event()];
+        let mut ical = ExportHandler::events_to_ical(&events);
+        ExportHandler::add_calendar_properties(&mut ical, Some("Work Calendar"));
+        let exported = ical.to_string();
+
+        assert!(exported.contains("X-WR-CALNAME:Work Calendar"));
+        assert!(exported.contains("X-WR-TIMEZONE:"));
+
+        let (name, reimported) = ExportHandler::parse_ical_string_with_name(&exported).unwrap();
+        assert_eq!(name, "Work Calendar");
+        assert_eq!(reimported.len(), 1);
+    }
+
+    #[test]
+    fn test_moved_occurrence_joins_its_series() {
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:weekly-1\r\n\
+DTSTAMP:20251201T090000Z\r\n\
+DTSTART:20251201T100000Z\r\n\
+DTEND:20251201T103000Z\r\n\
+RRULE:FREQ=WEEKLY;UNTIL=20251222T100000Z\r\n\
+SUMMARY:Standup\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:weekly-1\r\n\
+DTSTAMP:20251201T090000Z\r\n\
+RECURRENCE-ID:20251208T100000Z\r\n\
+DTSTART:20251209T140000Z\r\n\
+DTEND:20251209T143000Z\r\n\
+SUMMARY:Standup (moved)\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        // The override is folded into the series instead of importing as a duplicate
+        let (_, events) = ExportHandler::parse_ical_string_with_name(ical).unwrap();
+        assert_eq!(events.len(), 1);
+        let series = &events[0];
+        assert_eq!(series.repeat, RepeatFrequency::Weekly);
+        assert_eq!(series.repeat_until, NaiveDate::from_ymd_opt(2025, 12, 22));
+        let moved_date = NaiveDate::from_ymd_opt(2025, 12, 8).unwrap();
+        assert_eq!(series.overrides.len(), 1);
+        assert_eq!(series.overrides[&moved_date].start, Utc.with_ymd_and_hms(2025, 12, 9, 14, 0, 0).unwrap());
+
+        // Expanding the series substitutes the moved occurrence for its date
+        let occurrences = CalendarManager::expand_recurring_event(
+            series,
+            NaiveDate::from_ymd_opt(2025, 12, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 12, 31).unwrap(),
+        );
+        assert_eq!(occurrences.len(), 4);
+        let (_, moved) = occurrences.iter().find(|(date, _)| *date == moved_date).unwrap();
+        assert_eq!(moved.summary, "Standup (moved)");
+        assert_eq!(moved.start, Utc.with_ymd_and_hms(2025, 12, 9, 14, 0, 0).unwrap());
+        assert_eq!(moved.uid, "weekly-1_20251208");
+        assert!(occurrences
+            .iter()
+            .filter(|(date, _)| *date != moved_date)
+            .all(|(_, occurrence)| occurrence.summary == "Standup"));
+    }
+
+    #[test]
+    fn test_duration_without_dtend() {
+        let ical = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+PRODID:-//Test//EN\r\n\
+BEGIN:VEVENT\r\n\
+UID:duration-1\r\n\
+DTSTAMP:20251201T090000Z\r\n\
+DTSTART:20251201T100000Z\r\n\
+DURATION:PT90M\r\n\
+SUMMARY:Workshop\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let (_, events) = ExportHandler::parse_ical_string_with_name(ical).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].end - events[0].start, chrono::Duration::minutes(90));
+
+        assert_eq!(ExportHandler::parse_ical_duration("P1DT2H"), Some(chrono::Duration::hours(26)));
+        assert_eq!(ExportHandler::parse_ical_duration("-PT15M"), None);
+    }
+
+    #[test]
+    fn test_folded_crlf_import() {
+        let ical = [
+            "BEGIN:VCALENDAR",
+            "VERSION:2.0",
+            "PRODID:-//Microsoft Corporation//Outlook 16.0 MIMEDIR//EN",
+            "X-WR-CALNAME:Outlook",
+            "BEGIN:VEVENT",
+            "UID:folded-1",
+            "DTSTAMP:20251201T090000Z",
+            "DTSTART:20251201T100000Z",
+            "DTEND:20251201T110000Z",
+            "SUMMARY:Quarterly planning",
+            "DESCRIPTION:Agenda: review the roadmap, agree on priorities for the next ",
+            " quarter and assign owners to each of the open items before the end of ",
+            "\tthe meeting",
+            "END:VEVENT",
+            "END:VCALENDAR",
+            "",
+        ]
+        .join("\r\n");
+
+        let (name, events) = ExportHandler::parse_ical_string_with_name(&ical).unwrap();
+        assert_eq!(name, "Outlook");
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].notes.as_deref(),
+            Some(
+                "Agenda: review the roadmap, agree on priorities for the next quarter \
+                 and assign owners to each of the open items before the end of the meeting"
+            )
+        );
+    }
 }
@@ -9,26 +9,34 @@
 //! - `SettingsHandler` - Application settings (load, save, validate settings)
 //! - `SyncHandler` - Synchronization (sync calendars with backends)
 //! - `ExportHandler` - Import/Export (iCalendar import/export)
+//! - `DedupAnalyzer` - Duplicate event detection and merging
+//! - `Palette` - Preset color sets applied to all calendars at once
 
 mod calendar_handler;
+mod dedup_analyzer;
 mod event_handler;
 mod export_handler;
+mod palette;
 mod settings_handler;
 mod sync_handler;
 
 pub use calendar_handler::{CalendarHandler, NewCalendarData, UpdateCalendarData};
+pub use dedup_analyzer::{DedupAnalyzer, DuplicateGroup};
 pub use event_handler::EventHandler;
 pub use export_handler::ExportHandler;
+pub use palette::Palette;
 pub use settings_handler::SettingsHandler;
 
 // Internal types - exported for potential future use but not currently needed externally
 #[allow(unused_imports)]
 pub(crate) use calendar_handler::{CalendarError, CalendarResult};
 #[allow(unused_imports)]
+pub(crate) use dedup_analyzer::{DedupError, DedupResult};
+#[allow(unused_imports)]
 pub(crate) use event_handler::{EventError, EventResult};
 #[allow(unused_imports)]
 pub(crate) use export_handler::{ExportError, ExportResult};
 #[allow(unused_imports)]
 pub(crate) use settings_handler::{SettingsError, SettingsResult};
 #[allow(unused_imports)]
-pub(crate) use sync_handler::{SyncHandler, SyncError, SyncResult, SyncReport, CalendarSyncStatus};
+pub(crate) use sync_handler::{SyncHandler, SyncError, SyncResult, SyncReport, CalendarSyncStatus, ConflictResolution, FetchedSync, PendingSync};
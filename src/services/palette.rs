@@ -0,0 +1,87 @@
+//! Palette - Preset color sets for calendars.
+//!
+//! Applying a palette recolors every calendar in one go, in sidebar order,
+//! so calendars get colors that go well together instead of being picked
+//! one at a time. The colorblind-safe set is the Okabe-Ito palette, whose
+//! colors stay distinguishable with the common forms of color blindness.
+
+use crate::calendars::CalendarManager;
+use crate::services::calendar_handler::{CalendarError, CalendarResult};
+use log::{error, info};
+
+/// A named set of calendar colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Palette {
+    /// Soft, light colors
+    Pastel,
+    /// Saturated, high-contrast colors
+    Vivid,
+    /// Okabe-Ito colors, distinguishable with color vision deficiencies
+    ColorblindSafe,
+}
+
+impl Palette {
+    /// Colors of the palette, in the order they are given to calendars
+    pub fn colors(self) -> &'static [&'static str] {
+        match self {
+            Palette::Pastel => &[
+                "#8CB4E8", "#F2A7A7", "#A8D8B9", "#F5D08A", "#C3A8E0", "#F2B8D2", "#9FD6D6", "#D9C2A7",
+            ],
+            Palette::Vivid => &[
+                "#3584E4", "#E01B24", "#2EC27E", "#FF7800", "#9141AC", "#F5C211", "#00A0B0", "#E0467C",
+            ],
+            Palette::ColorblindSafe => &[
+                "#0072B2", "#E69F00", "#009E73", "#D55E00", "#56B4E9", "#CC79A7", "#F0E442", "#7F7F7F",
+            ],
+        }
+    }
+
+    /// Colors for `count` calendars, starting over when there are more calendars than colors
+    pub fn assign(self, count: usize) -> Vec<String> {
+        self.colors().iter().cycle().take(count).map(|c| c.to_string()).collect()
+    }
+
+    /// Recolor every calendar with this palette and save the configuration
+    /// Returns the number of calendars recolored
+    pub fn apply(self, manager: &mut CalendarManager) -> CalendarResult<usize> {
+        let colors = self.assign(manager.sources().len());
+        for (calendar, color) in manager.sources_mut().iter_mut().zip(colors) {
+            calendar.info_mut().color = color;
+        }
+
+        manager.save_config().map_err(|e| {
+            error!("Palette: Failed to save config: {}", e);
+            CalendarError::ConfigError(e.to_string())
+        })?;
+
+        let count = manager.sources().len();
+        info!("Palette: Applied {:?} palette to {} calendars", self, count);
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::parse_hex_color;
+
+    #[test]
+    fn test_palette_assign_cycles_valid_colors() {
+        for palette in [Palette::Pastel, Palette::Vivid, Palette::ColorblindSafe] {
+            let colors = palette.colors();
+            assert!(colors.iter().all(|c| parse_hex_color(c).is_ok()), "{:?}", palette);
+
+            // Each calendar gets a different color until the palette runs out
+            let assigned = palette.assign(colors.len() + 2);
+            assert_eq!(assigned.len(), colors.len() + 2);
+            let mut distinct = assigned[..colors.len()].to_vec();
+            distinct.sort();
+            distinct.dedup();
+            assert_eq!(distinct.len(), colors.len());
+            assert_eq!(assigned[colors.len()], colors[0]);
+            assert_eq!(assigned[colors.len() + 1], colors[1]);
+        }
+
+        assert!(Palette::Vivid.assign(0).is_empty());
+    }
+}
@@ -3,7 +3,8 @@
 //! This handler provides a single point of contact for all settings operations,
 //! including loading, saving, validation, and applying settings changes.
 
-use crate::settings::AppSettings;
+use crate::settings::{AppSettings, CompactSensitivity, EventClickAction, EventOverlapLayout, EventSortOrder, HighlightColor, StartupView, TimeSnap, ViewImageScale, WeekScrollStart, WeekendDays};
+use crate::views::CalendarView;
 use log::{debug, error, info, warn};
 use std::error::Error;
 
@@ -84,6 +85,189 @@ impl SettingsHandler {
         Self::save(settings)
     }
 
+    /// Toggle outlining the current week in the year view and save
+    pub fn toggle_highlight_current_week(settings: &mut AppSettings) -> SettingsResult<()> {
+        settings.highlight_current_week = !settings.highlight_current_week;
+        info!("SettingsHandler: Highlight current week set to {}", settings.highlight_current_week);
+        Self::save(settings)
+    }
+
+    /// Toggle dimming of events that have already ended and save
+    pub fn toggle_dim_past_events(settings: &mut AppSettings) -> SettingsResult<()> {
+        settings.dim_past_events = !settings.dim_past_events;
+        info!("SettingsHandler: Dim past events set to {}", settings.dim_past_events);
+        Self::save(settings)
+    }
+
+    /// Toggle shading of free day runs in the month view and save
+    pub fn toggle_free_day_highlight(settings: &mut AppSettings) -> SettingsResult<()> {
+        settings.highlight_free_days = !settings.highlight_free_days;
+        info!("SettingsHandler: Highlight free days set to {}", settings.highlight_free_days);
+        Self::save(settings)
+    }
+
+    /// Toggle the half-hour gridlines of the week and day views and save
+    pub fn toggle_half_hour_lines(settings: &mut AppSettings) -> SettingsResult<()> {
+        settings.half_hour_lines = !settings.half_hour_lines;
+        info!("SettingsHandler: Half-hour lines set to {}", settings.half_hour_lines);
+        Self::save(settings)
+    }
+
+    /// Toggle the rolling 7-day week view and save
+    pub fn toggle_rolling_week(settings: &mut AppSettings) -> SettingsResult<()> {
+        settings.rolling_week = !settings.rolling_week;
+        info!("SettingsHandler: Rolling week set to {}", settings.rolling_week);
+        Self::save(settings)
+    }
+
+    /// Toggle event count badges on month day cells and save
+    pub fn toggle_event_count_badges(settings: &mut AppSettings) -> SettingsResult<()> {
+        settings.show_event_count_badges = !settings.show_event_count_badges;
+        info!("SettingsHandler: Event count badges set to {}", settings.show_event_count_badges);
+        Self::save(settings)
+    }
+
+    /// Toggle the undo offer after drag moves and save
+    pub fn toggle_confirm_event_moves(settings: &mut AppSettings) -> SettingsResult<()> {
+        settings.confirm_event_moves = !settings.confirm_event_moves;
+        info!("SettingsHandler: Confirm event moves set to {}", settings.confirm_event_moves);
+        Self::save(settings)
+    }
+
+    /// Toggle collapsing the week view all-day section and save
+    pub fn toggle_all_day_section_collapsed(settings: &mut AppSettings) -> SettingsResult<()> {
+        settings.collapse_all_day_section = !settings.collapse_all_day_section;
+        info!("SettingsHandler: All-day section collapsed set to {}", settings.collapse_all_day_section);
+        Self::save(settings)
+    }
+
+    /// Set where the week view scrolls to when opened and save
+    pub fn set_week_scroll_start(settings: &mut AppSettings, start: WeekScrollStart) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting week scroll start to {:?}", start);
+        settings.week_scroll_start = start;
+        Self::save(settings)
+    }
+
+    /// Set what a single click on an event does and save
+    pub fn set_event_click_action(settings: &mut AppSettings, action: EventClickAction) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting event click action to {:?}", action);
+        settings.event_click_action = action;
+        Self::save(settings)
+    }
+
+    /// Set which view the app opens in and save
+    pub fn set_startup_view(settings: &mut AppSettings, view: StartupView) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting startup view to {:?}", view);
+        settings.startup_view = view;
+        Self::save(settings)
+    }
+
+    /// Set how far an event must be dragged before it starts moving and save
+    pub fn set_drag_threshold(settings: &mut AppSettings, pixels: u32) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting drag threshold to {}px", pixels);
+        settings.drag_threshold = pixels;
+        Self::save(settings)
+    }
+
+    /// Set the hour from which the day view opens on tomorrow and save
+    pub fn set_next_day_after_hour(settings: &mut AppSettings, hour: Option<u32>) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting next day cutoff hour to {:?}", hour);
+        settings.next_day_after_hour = hour;
+        Self::save(settings)
+    }
+
+    /// Remember the open view for the next start (only saves when it changed)
+    pub fn set_last_view(settings: &mut AppSettings, view: CalendarView) -> SettingsResult<()> {
+        if settings.last_view == Some(view) {
+            return Ok(());
+        }
+        debug!("SettingsHandler: Remembering last view {:?}", view);
+        settings.last_view = Some(view);
+        Self::save(settings)
+    }
+
+    /// Set which days are shaded as the weekend and save
+    pub fn set_weekend_days(settings: &mut AppSettings, weekend: WeekendDays) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting weekend days to {:?}", weekend);
+        settings.weekend_days = weekend;
+        Self::save(settings)
+    }
+
+    /// Set how early month cells switch to compact event indicators and save
+    pub fn set_compact_sensitivity(settings: &mut AppSettings, sensitivity: CompactSensitivity) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting compact sensitivity to {:?}", sensitivity);
+        settings.compact_sensitivity = sensitivity;
+        Self::save(settings)
+    }
+
+    /// Set how overlapping events are laid out and save
+    pub fn set_event_overlap_layout(settings: &mut AppSettings, layout: EventOverlapLayout) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting event overlap layout to {:?}", layout);
+        settings.event_overlap_layout = layout;
+        Self::save(settings)
+    }
+
+    /// Set the order of the events within a day and save
+    pub fn set_event_sort_order(settings: &mut AppSettings, order: EventSortOrder) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting event sort order to {:?}", order);
+        settings.event_sort_order = order;
+        Self::save(settings)
+    }
+
+    /// Set the increment time selections snap to and save
+    pub fn set_time_snap(settings: &mut AppSettings, snap: TimeSnap) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting time snap to {:?}", snap);
+        settings.time_snap = snap;
+        Self::save(settings)
+    }
+
+    /// Toggle rounding the start of new events created at the current time and save
+    pub fn toggle_round_new_event_start(settings: &mut AppSettings) -> SettingsResult<()> {
+        settings.round_new_event_start = !settings.round_new_event_start;
+        info!("SettingsHandler: Round new event start set to {}", settings.round_new_event_start);
+        Self::save(settings)
+    }
+
+    /// Set the increment new event start times are rounded up to and save
+    pub fn set_new_event_rounding(settings: &mut AppSettings, rounding: TimeSnap) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting new event rounding to {:?}", rounding);
+        settings.new_event_rounding = rounding;
+        Self::save(settings)
+    }
+
+    /// Set the color of today's highlight and save
+    pub fn set_today_highlight(settings: &mut AppSettings, highlight: HighlightColor) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting today highlight to {:?}", highlight);
+        settings.today_highlight = highlight;
+        Self::save(settings)
+    }
+
+    /// Set the color of the selected day's highlight and save
+    pub fn set_selected_highlight(settings: &mut AppSettings, highlight: HighlightColor) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting selected day highlight to {:?}", highlight);
+        settings.selected_highlight = highlight;
+        Self::save(settings)
+    }
+
+    /// Set the resolution of exported view images and save
+    pub fn set_view_image_scale(settings: &mut AppSettings, scale: ViewImageScale) -> SettingsResult<()> {
+        info!("SettingsHandler: Setting view image scale to {:?}", scale);
+        settings.view_image_scale = scale;
+        Self::save(settings)
+    }
+
+    /// Collapse or expand a sidebar calendar group and save
+    pub fn toggle_calendar_group_collapsed(settings: &mut AppSettings, group: &str) -> SettingsResult<()> {
+        if let Some(index) = settings.collapsed_calendar_groups.iter().position(|g| g == group) {
+            settings.collapsed_calendar_groups.remove(index);
+            debug!("SettingsHandler: Expanded calendar group");
+        } else {
+            settings.collapsed_calendar_groups.push(group.to_string());
+            debug!("SettingsHandler: Collapsed calendar group");
+        }
+        Self::save(settings)
+    }
+
     /// Reset settings to defaults and save
     #[allow(dead_code)] // Part of settings API
     pub fn reset_to_defaults() -> SettingsResult<AppSettings> {
@@ -116,4 +300,35 @@ mod tests {
 
         assert_ne!(settings.show_week_numbers, original);
     }
+
+    #[test]
+    fn test_startup_view_resolve() {
+        assert_eq!(StartupView::Week.resolve(Some(CalendarView::Day)), CalendarView::Week);
+        assert_eq!(StartupView::LastUsed.resolve(Some(CalendarView::Day)), CalendarView::Day);
+        assert_eq!(StartupView::LastUsed.resolve(None), CalendarView::Month);
+    }
+
+    #[test]
+    fn test_time_snap_round_up() {
+        let time = |h, m, s| chrono::NaiveTime::from_hms_opt(h, m, s).unwrap();
+
+        assert_eq!(TimeSnap::Fifteen.round_up(time(14, 37, 0)), time(14, 45, 0));
+        assert_eq!(TimeSnap::Fifteen.round_up(time(14, 45, 0)), time(14, 45, 0));
+        // A started minute counts towards the next increment
+        assert_eq!(TimeSnap::Fifteen.round_up(time(14, 45, 20)), time(15, 0, 0));
+        assert_eq!(TimeSnap::Thirty.round_up(time(9, 1, 0)), time(9, 30, 0));
+        // Late in the day the start stays on the same day
+        assert_eq!(TimeSnap::Thirty.round_up(time(23, 50, 0)), time(23, 30, 0));
+    }
+
+    #[test]
+    fn test_compact_sensitivity_thresholds() {
+        let low = CompactSensitivity::Low.thresholds();
+        let normal = CompactSensitivity::Normal.thresholds();
+        let high = CompactSensitivity::High.thresholds();
+
+        assert_eq!(normal, crate::components::CompactThresholds::default());
+        assert!(low.min_width < normal.min_width && normal.min_width < high.min_width);
+        assert!(low.min_height < normal.min_height && normal.min_height < high.min_height);
+    }
 }
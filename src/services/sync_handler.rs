@@ -9,9 +9,13 @@
 
 #![allow(dead_code)] // Prepared for future CalDAV sync feature
 
-use crate::calendars::CalendarManager;
+use crate::caldav::{CalDavClient, CalendarEvent};
+use crate::calendars::{CalendarManager, CalendarSource, SyncConflict};
+use crate::services::EventHandler;
+use chrono::{DateTime, Utc};
 use log::{debug, error, info, warn};
 use std::error::Error;
+use uuid::Uuid;
 
 /// Result type for sync operations
 pub type SyncResult<T> = Result<T, SyncError>;
@@ -60,7 +64,7 @@ pub struct CalendarSyncStatus {
 }
 
 /// Overall sync result
-#[derive(Debug)]
+#[derive(Debug, Clone, Default)]
 pub struct SyncReport {
     pub total: usize,
     pub succeeded: usize,
@@ -72,6 +76,72 @@ impl SyncReport {
     pub fn all_succeeded(&self) -> bool {
         self.failed == 0
     }
+
+    /// Count one calendar's sync outcome
+    fn record(&mut self, calendar_id: String, calendar_name: String, result: Result<(), String>) {
+        self.total += 1;
+        match &result {
+            Ok(()) => self.succeeded += 1,
+            Err(_) => self.failed += 1,
+        }
+        self.statuses.push(CalendarSyncStatus {
+            calendar_id,
+            calendar_name,
+            success: result.is_ok(),
+            error_message: result.err(),
+        });
+    }
+}
+
+/// A sync whose local calendars are done and whose server calendars still
+/// need fetching. The fetch blocks on the network, so it runs off the UI thread.
+#[derive(Debug)]
+pub struct PendingSync {
+    /// Outcome of the calendars synced so far
+    pub report: SyncReport,
+    /// Calendar ids with the client to fetch their server events with
+    pub fetches: Vec<(String, CalDavClient)>,
+}
+
+impl PendingSync {
+    /// Fetch the server events of every pending calendar (blocking)
+    pub fn fetch(self, manual: bool) -> FetchedSync {
+        let fetched = self
+            .fetches
+            .into_iter()
+            .map(|(calendar_id, client)| {
+                let result = client.fetch_events().map_err(|e| e.to_string());
+                (calendar_id, result)
+            })
+            .collect();
+        FetchedSync {
+            manual,
+            report: self.report,
+            fetched,
+        }
+    }
+}
+
+/// Server events fetched in the background, ready to merge into their calendars
+#[derive(Debug, Clone)]
+pub struct FetchedSync {
+    /// Whether the user asked for the sync (reported in a toast) or the scheduler did
+    pub manual: bool,
+    /// Outcome of the calendars synced before the fetch
+    pub report: SyncReport,
+    /// Server events, or why fetching them failed, by calendar id
+    pub fetched: Vec<(String, Result<Vec<CalendarEvent>, String>)>,
+}
+
+/// User decision for an event that changed both locally and remotely
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// Keep the local version and push it to the server
+    KeepLocal,
+    /// Discard local changes and keep the server version
+    KeepRemote,
+    /// Keep the server version and save the local version as a separate event
+    KeepBoth,
 }
 
 /// Sync Handler - centralized synchronization management.
@@ -100,6 +170,7 @@ impl SyncHandler {
             }
         })?;
 
+        calendar.info_mut().last_synced = Some(Utc::now());
         info!("SyncHandler: Successfully synced calendar '{}'", calendar_id);
         Ok(())
     }
@@ -107,6 +178,94 @@ impl SyncHandler {
     /// Sync all enabled calendars
     pub fn sync_all(manager: &mut CalendarManager) -> SyncReport {
         info!("SyncHandler: Starting sync of all enabled calendars");
+        Self::sync_matching(manager, |_| true)
+    }
+
+    /// Start syncing all enabled calendars; server fetches are left to the caller
+    pub fn begin_sync_all(manager: &mut CalendarManager) -> PendingSync {
+        info!("SyncHandler: Starting sync of all enabled calendars");
+        Self::begin_sync_matching(manager, |_| true)
+    }
+
+    /// Start syncing the enabled calendars whose own sync interval has elapsed
+    pub fn begin_sync_due(manager: &mut CalendarManager, now: DateTime<Utc>) -> PendingSync {
+        debug!("SyncHandler: Syncing calendars due at {}", now);
+        Self::begin_sync_matching(manager, |calendar| calendar.info().is_sync_due(now))
+    }
+
+    /// Sync the local calendars accepted by the filter now and collect the
+    /// clients of the server calendars, which are fetched in the background
+    fn begin_sync_matching(
+        manager: &mut CalendarManager,
+        filter: impl Fn(&dyn CalendarSource) -> bool,
+    ) -> PendingSync {
+        let mut report = SyncReport::default();
+        let mut fetches = Vec::new();
+
+        for calendar in manager.sources_mut().iter_mut() {
+            if !calendar.is_enabled() || !filter(&**calendar) {
+                continue;
+            }
+
+            let calendar_id = calendar.info().id.clone();
+            if let Some(client) = calendar.remote_client() {
+                debug!("SyncHandler: Queueing server fetch for calendar '{}'", calendar_id);
+                fetches.push((calendar_id, client));
+                continue;
+            }
+
+            let calendar_name = calendar.info().name.clone();
+            let result = calendar.sync().map_err(|e| e.to_string());
+            if result.is_ok() {
+                calendar.info_mut().last_synced = Some(Utc::now());
+            } else {
+                warn!("SyncHandler: Sync failed for calendar '{}'", calendar_id);
+            }
+            report.record(calendar_id, calendar_name, result);
+        }
+
+        PendingSync { report, fetches }
+    }
+
+    /// Merge server events fetched in the background into their calendars
+    /// and complete the sync report
+    pub fn finish_sync(manager: &mut CalendarManager, fetched: FetchedSync) -> SyncReport {
+        let mut report = fetched.report;
+
+        for (calendar_id, result) in fetched.fetched {
+            let Some(calendar) = manager
+                .sources_mut()
+                .iter_mut()
+                .find(|c| c.info().id == calendar_id)
+            else {
+                // Removed while its events were being fetched
+                debug!("SyncHandler: Calendar '{}' gone before its sync finished", calendar_id);
+                continue;
+            };
+
+            let calendar_name = calendar.info().name.clone();
+            match result {
+                Ok(remote) => {
+                    calendar.apply_remote_events(remote);
+                    calendar.info_mut().last_synced = Some(Utc::now());
+                    report.record(calendar_id, calendar_name, Ok(()));
+                }
+                Err(e) => {
+                    warn!("SyncHandler: Fetch failed for calendar '{}'", calendar_id);
+                    report.record(calendar_id, calendar_name, Err(e));
+                }
+            }
+        }
+
+        info!("SyncHandler: Sync complete - {} succeeded, {} failed", report.succeeded, report.failed);
+        report
+    }
+
+    /// Sync the enabled calendars accepted by the filter, recording when each one synced
+    fn sync_matching(
+        manager: &mut CalendarManager,
+        filter: impl Fn(&dyn CalendarSource) -> bool,
+    ) -> SyncReport {
         let mut statuses = Vec::new();
         let mut succeeded = 0;
         let mut failed = 0;
@@ -116,6 +275,9 @@ impl SyncHandler {
                 debug!("SyncHandler: Skipping disabled calendar '{}'", calendar.info().name);
                 continue;
             }
+            if !filter(&**calendar) {
+                continue;
+            }
 
             let calendar_id = calendar.info().id.clone();
             let calendar_name = calendar.info().name.clone();
@@ -124,6 +286,7 @@ impl SyncHandler {
             match calendar.sync() {
                 Ok(()) => {
                     debug!("SyncHandler: Sync succeeded for '{}'", calendar_name);
+                    calendar.info_mut().last_synced = Some(Utc::now());
                     succeeded += 1;
                     statuses.push(CalendarSyncStatus {
                         calendar_id,
@@ -175,6 +338,58 @@ impl SyncHandler {
         }
     }
 
+    /// Collect conflicts detected by the last sync across all calendars
+    pub fn take_conflicts(manager: &mut CalendarManager) -> Vec<SyncConflict> {
+        let conflicts: Vec<SyncConflict> = manager
+            .sources_mut()
+            .iter_mut()
+            .flat_map(|c| c.take_sync_conflicts())
+            .collect();
+        if !conflicts.is_empty() {
+            warn!("SyncHandler: {} sync conflicts need resolution", conflicts.len());
+        }
+        conflicts
+    }
+
+    /// Apply the user's resolution for a sync conflict
+    pub fn resolve_conflict(
+        manager: &mut CalendarManager,
+        conflict: &SyncConflict,
+        resolution: ConflictResolution,
+    ) -> SyncResult<()> {
+        info!(
+            "SyncHandler: Resolving conflict for uid={} in calendar '{}' with {:?}",
+            conflict.local.uid, conflict.calendar_id, resolution
+        );
+
+        let to_sync_error = |e: crate::services::EventError| SyncError::SyncFailed {
+            calendar_id: conflict.calendar_id.clone(),
+            reason: e.to_string(),
+        };
+
+        match resolution {
+            ConflictResolution::KeepLocal => {
+                EventHandler::update_event(manager, &conflict.calendar_id, conflict.local.clone())
+                    .map_err(to_sync_error)?;
+            }
+            ConflictResolution::KeepRemote => {
+                EventHandler::update_event(manager, &conflict.calendar_id, conflict.remote.clone())
+                    .map_err(to_sync_error)?;
+            }
+            ConflictResolution::KeepBoth => {
+                EventHandler::update_event(manager, &conflict.calendar_id, conflict.remote.clone())
+                    .map_err(to_sync_error)?;
+                // The local copy gets a fresh UID so it no longer collides with the server version
+                let mut local_copy = conflict.local.clone();
+                local_copy.uid = Uuid::new_v4().to_string();
+                EventHandler::add_event(manager, &conflict.calendar_id, local_copy)
+                    .map_err(to_sync_error)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Check if any calendar requires network for sync
     pub fn has_remote_calendars(manager: &CalendarManager) -> bool {
         let has_remote = manager.sources().iter().any(|c| {
@@ -207,6 +422,25 @@ mod tests {
         assert!(report.all_succeeded());
     }
 
+    #[test]
+    fn test_sync_due_honors_calendar_interval() {
+        use crate::calendars::{CalendarInfo, CalendarType};
+
+        let now = Utc::now();
+        let mut info = CalendarInfo::new("work".to_string(), "Work".to_string(), CalendarType::CalDav);
+        // Calendars without an interval sync only on demand
+        assert!(!info.is_sync_due(now));
+
+        info.sync_interval_minutes = Some(60);
+        // Never synced: due straight away
+        assert!(info.is_sync_due(now));
+
+        info.last_synced = Some(now - chrono::Duration::minutes(30));
+        assert!(!info.is_sync_due(now));
+        info.last_synced = Some(now - chrono::Duration::minutes(60));
+        assert!(info.is_sync_due(now));
+    }
+
     #[test]
     fn test_sync_report_has_failures() {
         let report = SyncReport {
@@ -1,23 +1,392 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io;
 use std::path::PathBuf;
 
+use crate::components::CompactThresholds;
+use crate::views::CalendarView;
+
+/// Where the week view scrolls to when it is opened
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WeekScrollStart {
+    /// Scroll to the current time (with an hour of context above)
+    #[default]
+    CurrentTime,
+    /// Scroll to the start of the working day
+    WorkingHours,
+    /// Stay at midnight
+    Top,
+}
+
+/// Which view the app opens in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StartupView {
+    #[default]
+    Month,
+    Week,
+    Day,
+    Year,
+    /// Whichever view was open when the app was last closed
+    LastUsed,
+}
+
+impl StartupView {
+    /// The view to open, falling back to the month view if none was used yet
+    pub fn resolve(self, last_view: Option<CalendarView>) -> CalendarView {
+        match self {
+            StartupView::Month => CalendarView::Month,
+            StartupView::Week => CalendarView::Week,
+            StartupView::Day => CalendarView::Day,
+            StartupView::Year => CalendarView::Year,
+            StartupView::LastUsed => last_view.unwrap_or(CalendarView::Month),
+        }
+    }
+}
+
+/// What a single click on an event does (double-click always edits)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EventClickAction {
+    /// Select the event
+    #[default]
+    Select,
+    /// Open the event in the edit dialog
+    Edit,
+}
+
+/// Which days are shaded as the weekend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WeekendDays {
+    /// Follow the system locale
+    #[default]
+    System,
+    SaturdaySunday,
+    FridaySaturday,
+    FridayOnly,
+    SaturdayOnly,
+    SundayOnly,
+}
+
+impl WeekendDays {
+    /// Configured weekend days, or `None` to use the locale default
+    pub fn days(self) -> Option<Vec<Weekday>> {
+        match self {
+            WeekendDays::System => None,
+            WeekendDays::SaturdaySunday => Some(vec![Weekday::Sat, Weekday::Sun]),
+            WeekendDays::FridaySaturday => Some(vec![Weekday::Fri, Weekday::Sat]),
+            WeekendDays::FridayOnly => Some(vec![Weekday::Fri]),
+            WeekendDays::SaturdayOnly => Some(vec![Weekday::Sat]),
+            WeekendDays::SundayOnly => Some(vec![Weekday::Sun]),
+        }
+    }
+}
+
+/// How early month cells collapse event chips to compact dots
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CompactSensitivity {
+    /// Keep full chips until cells are quite small
+    Low,
+    /// Default thresholds
+    #[default]
+    Normal,
+    /// Collapse to dots on larger cells, e.g. on HiDPI screens
+    High,
+}
+
+impl CompactSensitivity {
+    /// Cell size thresholds for this sensitivity
+    pub fn thresholds(self) -> CompactThresholds {
+        match self {
+            CompactSensitivity::Low => CompactThresholds::scaled(0.75),
+            CompactSensitivity::Normal => CompactThresholds::default(),
+            CompactSensitivity::High => CompactThresholds::scaled(1.25),
+        }
+    }
+}
+
+/// How overlapping timed events are laid out in the week and day views
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EventOverlapLayout {
+    /// Equal-width columns next to each other
+    #[default]
+    SideBySide,
+    /// Staggered with a small horizontal offset, each event keeping most of the width
+    Cascade,
+}
+
+/// Order of the events listed within a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EventSortOrder {
+    /// Chronological, all-day events counting as starting at midnight
+    #[default]
+    StartTime,
+    /// All-day events, then timed events by start time
+    AllDayFirst,
+    /// Grouped by calendar in sidebar order
+    Calendar,
+    /// Highest priority first
+    Priority,
+}
+
+/// Increment that drag selections in the week and day views snap to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimeSnap {
+    Five,
+    Ten,
+    #[default]
+    Fifteen,
+    Thirty,
+}
+
+impl TimeSnap {
+    /// Length of one snap increment in minutes
+    pub fn minutes(self) -> u32 {
+        match self {
+            TimeSnap::Five => 5,
+            TimeSnap::Ten => 10,
+            TimeSnap::Fifteen => 15,
+            TimeSnap::Thirty => 30,
+        }
+    }
+
+    /// Round a time up to the next increment (14:37 becomes 14:45 with 15 minutes)
+    /// Times past the last increment of the day stay on that increment
+    pub fn round_up(self, time: NaiveTime) -> NaiveTime {
+        let step = self.minutes();
+        let minutes = time.hour() * 60 + time.minute() + u32::from(time.second() > 0);
+        let rounded = minutes.div_ceil(step) * step;
+        let rounded = rounded.min(24 * 60 - step);
+        NaiveTime::from_hms_opt(rounded / 60, rounded % 60, 0).unwrap_or(time)
+    }
+}
+
+/// Resolution of images exported from the current view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ViewImageScale {
+    /// One image pixel per logical pixel
+    #[default]
+    Standard,
+    /// Two image pixels per logical pixel. Sharp on high-DPI displays; on a
+    /// standard display the capture is only pixel-doubled, adding no detail
+    Double,
+}
+
+impl ViewImageScale {
+    /// Image pixels per logical pixel
+    pub fn factor(self) -> u32 {
+        match self {
+            ViewImageScale::Standard => 1,
+            ViewImageScale::Double => 2,
+        }
+    }
+}
+
+/// Color of the today and selected day highlights in the month view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HighlightColor {
+    /// The theme's accent color
+    #[default]
+    Accent,
+    Blue,
+    Green,
+    Orange,
+    Red,
+    Purple,
+}
+
+impl HighlightColor {
+    /// All colors, in the order they are offered in the menu
+    pub const ALL: [HighlightColor; 6] = [
+        HighlightColor::Accent,
+        HighlightColor::Blue,
+        HighlightColor::Green,
+        HighlightColor::Orange,
+        HighlightColor::Red,
+        HighlightColor::Purple,
+    ];
+}
+
+/// Cutoff hours offered for opening the day view on the next day
+pub const NEXT_DAY_CUTOFF_HOURS: [u32; 4] = [17, 18, 20, 22];
+
+/// Cursor distances (pixels) offered before a pressed event starts moving
+pub const DRAG_THRESHOLD_OPTIONS: [u32; 4] = [0, 4, 8, 16];
+
+/// Default cursor distance before a pressed event starts moving
+fn default_drag_threshold() -> u32 {
+    4
+}
+
+/// Default first hour of the working day
+fn default_working_hours_start() -> u32 {
+    8
+}
+
+/// Current week is outlined in the year view unless turned off
+fn default_highlight_current_week() -> bool {
+    true
+}
+
+/// Today keeps its fixed blue circle unless changed
+fn default_today_highlight() -> HighlightColor {
+    HighlightColor::Blue
+}
+
+/// Events that have ended are dimmed unless turned off
+fn default_dim_past_events() -> bool {
+    true
+}
+
+/// Half-hour gridlines are drawn unless turned off
+fn default_half_hour_lines() -> bool {
+    true
+}
+
+fn default_round_new_event_start() -> bool {
+    true
+}
+
+fn default_new_event_rounding() -> TimeSnap {
+    TimeSnap::Fifteen
+}
+
 /// Application-level settings that persist across sessions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettings {
     pub show_week_numbers: bool,
+    /// Where the week view scrolls to when entering it
+    #[serde(default)]
+    pub week_scroll_start: WeekScrollStart,
+    /// First hour of the working day (0-23)
+    #[serde(default = "default_working_hours_start")]
+    pub working_hours_start: u32,
+    /// Outline the current week in the year view
+    #[serde(default = "default_highlight_current_week")]
+    pub highlight_current_week: bool,
+    /// Show the 7 days from the selected date instead of a calendar-aligned week
+    #[serde(default)]
+    pub rolling_week: bool,
+    /// Offer to undo event moves made by dragging
+    #[serde(default)]
+    pub confirm_event_moves: bool,
+    /// Sidebar calendar groups that are currently collapsed
+    #[serde(default)]
+    pub collapsed_calendar_groups: Vec<String>,
+    /// Resolution used when exporting the current view as an image
+    #[serde(default)]
+    pub view_image_scale: ViewImageScale,
+    /// What a single click on an event does
+    #[serde(default)]
+    pub event_click_action: EventClickAction,
+    /// Collapse the week view all-day section to a single summary row
+    #[serde(default)]
+    pub collapse_all_day_section: bool,
+    /// View shown when the app starts
+    #[serde(default)]
+    pub startup_view: StartupView,
+    /// View that was open last, restored when `startup_view` is `LastUsed`
+    #[serde(default)]
+    pub last_view: Option<CalendarView>,
+    /// Pixels the cursor must move before pressing an event starts dragging it (0 drags at once)
+    #[serde(default = "default_drag_threshold")]
+    pub drag_threshold: u32,
+    /// Hour (0-23) from which the day view opens on tomorrow, None to always open on today
+    #[serde(default)]
+    pub next_day_after_hour: Option<u32>,
+    /// Days shaded as the weekend
+    #[serde(default)]
+    pub weekend_days: WeekendDays,
+    /// Show the total event count on month day cells that hide some events
+    #[serde(default)]
+    pub show_event_count_badges: bool,
+    /// Shade runs of consecutive days without events in the month view
+    #[serde(default)]
+    pub highlight_free_days: bool,
+    /// Render events that have already ended at reduced opacity
+    #[serde(default = "default_dim_past_events")]
+    pub dim_past_events: bool,
+    /// How early month cells switch to compact event indicators
+    #[serde(default)]
+    pub compact_sensitivity: CompactSensitivity,
+    /// How overlapping timed events are laid out in the week and day views
+    #[serde(default)]
+    pub event_overlap_layout: EventOverlapLayout,
+    /// Increment that time selections snap to in the week and day views
+    #[serde(default)]
+    pub time_snap: TimeSnap,
+    /// Round the start of new events created at the current time up to `new_event_rounding`
+    #[serde(default = "default_round_new_event_start")]
+    pub round_new_event_start: bool,
+    /// Increment the start of new events created at the current time is rounded up to
+    #[serde(default = "default_new_event_rounding")]
+    pub new_event_rounding: TimeSnap,
+    /// Order of the events within a day, in every view
+    #[serde(default)]
+    pub event_sort_order: EventSortOrder,
+    /// Draw fainter gridlines at the half hours in the week and day views
+    #[serde(default = "default_half_hour_lines")]
+    pub half_hour_lines: bool,
+    /// Color of the circle behind today's day number in the month view
+    #[serde(default = "default_today_highlight")]
+    pub today_highlight: HighlightColor,
+    /// Color of the selected day's border in the month view
+    #[serde(default)]
+    pub selected_highlight: HighlightColor,
+    /// strftime-style date format overriding the locale default (e.g. "%a %-d %b")
+    #[serde(default)]
+    pub custom_date_format: Option<String>,
+    /// strftime-style time format overriding the locale default (e.g. "%H.%M")
+    #[serde(default)]
+    pub custom_time_format: Option<String>,
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
             show_week_numbers: true, // Show week numbers by default
+            week_scroll_start: WeekScrollStart::default(),
+            working_hours_start: default_working_hours_start(),
+            highlight_current_week: default_highlight_current_week(),
+            rolling_week: false,
+            confirm_event_moves: false,
+            collapsed_calendar_groups: Vec::new(),
+            view_image_scale: ViewImageScale::default(),
+            event_click_action: EventClickAction::default(),
+            collapse_all_day_section: false,
+            startup_view: StartupView::default(),
+            last_view: None,
+            drag_threshold: default_drag_threshold(),
+            next_day_after_hour: None,
+            weekend_days: WeekendDays::default(),
+            show_event_count_badges: false,
+            highlight_free_days: false,
+            dim_past_events: default_dim_past_events(),
+            compact_sensitivity: CompactSensitivity::default(),
+            event_overlap_layout: EventOverlapLayout::default(),
+            time_snap: TimeSnap::default(),
+            round_new_event_start: default_round_new_event_start(),
+            new_event_rounding: default_new_event_rounding(),
+            event_sort_order: EventSortOrder::default(),
+            half_hour_lines: default_half_hour_lines(),
+            today_highlight: default_today_highlight(),
+            selected_highlight: HighlightColor::default(),
+            custom_date_format: None,
+            custom_time_format: None,
         }
     }
 }
 
 impl AppSettings {
+    /// The date to open the app on: tomorrow when starting in the day view
+    /// past the configured cutoff, today otherwise
+    pub fn startup_date(&self, view: CalendarView, now: NaiveDateTime) -> NaiveDate {
+        let today = now.date();
+        match self.next_day_after_hour {
+            Some(hour) if view == CalendarView::Day && now.hour() >= hour => today + Duration::days(1),
+            _ => today,
+        }
+    }
+
     /// Load settings from disk
     pub fn load() -> Result<Self, io::Error> {
         let path = Self::settings_path();
@@ -1,9 +1,11 @@
 use cosmic::iced::{Background, Border, Color, Shadow, Vector};
 use cosmic::widget::container;
+use crate::settings::HighlightColor;
 use crate::ui_constants::{
     SHADOW_OPACITY, SHADOW_OFFSET_X, SHADOW_OFFSET_Y, SHADOW_BLUR_RADIUS,
     BORDER_RADIUS, BORDER_WIDTH_HIGHLIGHT, BORDER_WIDTH_NORMAL,
-    COLOR_DAY_CELL_BORDER, COLOR_WEEKEND_BACKGROUND, COLOR_TODAY_BLUE
+    COLOR_DAY_CELL_BORDER, COLOR_WEEKEND_BACKGROUND, COLOR_HOLIDAY_BACKGROUND, COLOR_TODAY_BLUE,
+    COLOR_HIGHLIGHT_GREEN, COLOR_HIGHLIGHT_ORANGE, COLOR_HIGHLIGHT_RED, COLOR_HIGHLIGHT_PURPLE,
 };
 
 /// Returns the weekend background if is_weekend is true, None otherwise.
@@ -17,6 +19,28 @@ pub fn weekend_background(is_weekend: bool) -> Option<Background> {
     }
 }
 
+/// Returns the holiday tint for holidays, otherwise the weekend background if is_weekend is true.
+#[inline]
+pub fn holiday_background(is_holiday: bool, is_weekend: bool) -> Option<Background> {
+    if is_holiday {
+        Some(Background::Color(COLOR_HOLIDAY_BACKGROUND))
+    } else {
+        weekend_background(is_weekend)
+    }
+}
+
+/// Resolve a configured highlight color, following the theme for the accent option
+pub fn highlight_color(theme: &cosmic::Theme, highlight: HighlightColor) -> Color {
+    match highlight {
+        HighlightColor::Accent => theme.cosmic().accent_color().into(),
+        HighlightColor::Blue => COLOR_TODAY_BLUE,
+        HighlightColor::Green => COLOR_HIGHLIGHT_GREEN,
+        HighlightColor::Orange => COLOR_HIGHLIGHT_ORANGE,
+        HighlightColor::Red => COLOR_HIGHLIGHT_RED,
+        HighlightColor::Purple => COLOR_HIGHLIGHT_PURPLE,
+    }
+}
+
 /// Style for the overlay sidebar in mobile/condensed mode
 pub fn overlay_sidebar_style(theme: &cosmic::Theme) -> container::Style {
     container::Style {
@@ -51,12 +75,12 @@ pub fn today_filled_style(theme: &cosmic::Theme) -> container::Style {
     }
 }
 
-/// Style for selected day cell - border with accent color, preserving weekend background
-pub fn selected_day_style(theme: &cosmic::Theme, is_weekend: bool) -> container::Style {
+/// Style for selected day cell - border in the selected highlight color, preserving weekend background
+pub fn selected_day_style(theme: &cosmic::Theme, is_weekend: bool, highlight: HighlightColor) -> container::Style {
     container::Style {
         background: weekend_background(is_weekend),
         border: Border {
-            color: theme.cosmic().accent_color().into(),
+            color: highlight_color(theme, highlight),
             width: BORDER_WIDTH_HIGHLIGHT,
             radius: BORDER_RADIUS.into(),
         },
@@ -64,14 +88,25 @@ pub fn selected_day_style(theme: &cosmic::Theme, is_weekend: bool) -> container:
     }
 }
 
-/// Style for today's day number circle - fixed blue background with white text
-/// Consistent across all themes for easy recognition
-pub fn today_circle_style(_theme: &cosmic::Theme, size: f32) -> container::Style {
+/// Style for today's day number circle - today highlight background with white text
+/// When today is also the selected day, a ring in the selected color is drawn around
+/// the circle so both states stay visible even if the two colors are similar
+pub fn today_circle_style(
+    theme: &cosmic::Theme,
+    size: f32,
+    highlight: HighlightColor,
+    selected_ring: Option<HighlightColor>,
+) -> container::Style {
+    let (ring_width, ring_color) = match selected_ring {
+        Some(selected) => (BORDER_WIDTH_HIGHLIGHT, highlight_color(theme, selected)),
+        None => (0.0, Color::TRANSPARENT),
+    };
     container::Style {
-        background: Some(Background::Color(COLOR_TODAY_BLUE)),
+        background: Some(Background::Color(highlight_color(theme, highlight))),
         border: Border {
             radius: (size / 2.0).into(), // Circular
-            ..Default::default()
+            width: ring_width,
+            color: ring_color,
         },
         // White text for contrast on blue background
         text_color: Some(Color::WHITE),
@@ -79,6 +114,20 @@ pub fn today_circle_style(_theme: &cosmic::Theme, size: f32) -> container::Style
     }
 }
 
+/// Style for the event count badge in month day cells
+pub fn event_count_badge_style(theme: &cosmic::Theme) -> container::Style {
+    let cosmic = theme.cosmic();
+    container::Style {
+        background: Some(Background::Color(cosmic.accent_color().into())),
+        border: Border {
+            radius: BORDER_RADIUS.into(),
+            ..Default::default()
+        },
+        text_color: Some(cosmic.on_accent_color().into()),
+        ..Default::default()
+    }
+}
+
 /// Style for regular day cell with optional weekend background
 pub fn day_cell_style(is_weekend: bool) -> container::Style {
     container::Style {
@@ -92,6 +141,14 @@ pub fn day_cell_style(is_weekend: bool) -> container::Style {
     }
 }
 
+/// Style for a day cell on a holiday from a holiday overlay calendar
+pub fn holiday_day_style() -> container::Style {
+    container::Style {
+        background: holiday_background(true, false),
+        ..day_cell_style(false)
+    }
+}
+
 /// Style for adjacent month day cells (previous/next month) - grayed out
 pub fn adjacent_month_day_style() -> container::Style {
     container::Style {
@@ -107,12 +164,12 @@ pub fn adjacent_month_day_style() -> container::Style {
     }
 }
 
-/// Style for selected adjacent month day cells - grayed out text with accent border
-pub fn adjacent_month_selected_style(theme: &cosmic::Theme) -> container::Style {
+/// Style for selected adjacent month day cells - grayed out text with selected highlight border
+pub fn adjacent_month_selected_style(theme: &cosmic::Theme, highlight: HighlightColor) -> container::Style {
     container::Style {
         background: None,
         border: Border {
-            color: theme.cosmic().accent_color().into(),
+            color: highlight_color(theme, highlight),
             width: BORDER_WIDTH_HIGHLIGHT,
             radius: BORDER_RADIUS.into(),
         },
@@ -135,6 +192,18 @@ pub fn color_button_style(color: Color, size: f32, border_width: f32, border_col
     }
 }
 
+/// Style for day cells in a run of free days (no events)
+/// A faint success tint over the weekend shading, so free windows stand out when planning
+pub fn free_day_style(theme: &cosmic::Theme, is_weekend: bool) -> container::Style {
+    let success = theme.cosmic().success_color();
+    let alpha = if is_weekend { 0.12 } else { 0.08 };
+
+    container::Style {
+        background: Some(Background::Color(Color::from_rgba(success.red, success.green, success.blue, alpha))),
+        ..day_cell_style(is_weekend)
+    }
+}
+
 /// Style for day cells in a drag selection range
 /// Uses a semi-transparent accent color background
 pub fn selection_highlight_style(theme: &cosmic::Theme, _is_weekend: bool) -> container::Style {
@@ -0,0 +1,37 @@
+//! Auto-export handler: writes calendars to their auto-export .ics files
+
+use log::{error, info};
+
+use crate::app::CosmicCalendar;
+use crate::fl;
+use crate::models::ToastSeverity;
+use crate::services::ExportHandler;
+
+/// Write the calendars whose events changed once the debounce has passed
+pub fn handle_auto_export_due(app: &mut CosmicCalendar) {
+    // An import in progress may still be cancelled; export once it has finished
+    if app.is_importing() {
+        return;
+    }
+    let due = app.auto_exports.take_due(std::time::Instant::now());
+    if due.is_empty() {
+        return;
+    }
+
+    let mut failed = false;
+    for (calendar_id, path) in due {
+        match ExportHandler::export_to_file(&app.calendar_manager, &calendar_id, &path) {
+            Ok(()) => info!("Auto-exported calendar '{}'", calendar_id),
+            Err(e) => {
+                error!("Auto-export of calendar '{}' failed: {}", calendar_id, e);
+                // Forget it so the next refresh queues it again
+                app.auto_exports.forget(&calendar_id);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        app.toasts.push(fl!("toast-auto-export-failed"), ToastSeverity::Error, None);
+    }
+}
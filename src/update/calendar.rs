@@ -2,7 +2,9 @@
 
 use crate::app::CosmicCalendar;
 use crate::dialogs::{ActiveDialog, DialogManager};
-use crate::services::{CalendarHandler, ExportHandler, NewCalendarData, UpdateCalendarData};
+use crate::fl;
+use crate::models::ToastSeverity;
+use crate::services::{CalendarHandler, ExportHandler, NewCalendarData, Palette, UpdateCalendarData};
 use chrono::Local;
 use cosmic::app::Task;
 use log::{debug, error, info, warn};
@@ -43,6 +45,23 @@ pub fn handle_change_calendar_color(app: &mut CosmicCalendar, id: String, color:
     }
 }
 
+/// Recolor all calendars with a preset palette and save configuration
+pub fn handle_apply_palette(app: &mut CosmicCalendar, palette: Palette) {
+    debug!("handle_apply_palette: Applying {:?} palette", palette);
+
+    match palette.apply(&mut app.calendar_manager) {
+        Ok(count) => {
+            info!("Palette {:?} applied to {} calendars", palette, count);
+            app.refresh_cached_events();
+            app.update_selected_calendar_color();
+            app.toasts.push(fl!("toast-palette-applied"), ToastSeverity::Success, None);
+        }
+        Err(e) => {
+            error!("Failed to apply palette: {}", e);
+        }
+    }
+}
+
 /// Open the calendar dialog in Create mode
 pub fn handle_open_calendar_dialog_create(app: &mut CosmicCalendar) {
     debug!("handle_open_calendar_dialog_create: Opening create dialog");
@@ -54,6 +73,8 @@ pub fn handle_open_calendar_dialog_create(app: &mut CosmicCalendar) {
         ActiveDialog::CalendarCreate {
             name: String::new(),
             color: default_color,
+            group: String::new(),
+            default_duration: None,
         },
     );
 }
@@ -64,12 +85,26 @@ pub fn handle_open_calendar_dialog_edit(app: &mut CosmicCalendar, calendar_id: S
 
     match CalendarHandler::get_info(&app.calendar_manager, &calendar_id) {
         Ok((name, color, _enabled)) => {
+            let group = CalendarHandler::get_group(&app.calendar_manager, &calendar_id)
+                .unwrap_or_default();
+            let default_duration = CalendarHandler::get_default_duration(&app.calendar_manager, &calendar_id);
+            let (sync_interval, last_synced) =
+                CalendarHandler::get_sync_schedule(&app.calendar_manager, &calendar_id);
+            let holiday_overlay = CalendarHandler::is_holiday_overlay(&app.calendar_manager, &calendar_id);
+            let auto_export_path = CalendarHandler::get_auto_export_path(&app.calendar_manager, &calendar_id)
+                .unwrap_or_default();
             DialogManager::open(
                 &mut app.active_dialog,
                 ActiveDialog::CalendarEdit {
                     calendar_id,
                     name,
                     color,
+                    group,
+                    default_duration,
+                    sync_interval,
+                    last_synced,
+                    holiday_overlay,
+                    auto_export_path,
                 },
             );
         }
@@ -83,16 +118,16 @@ pub fn handle_open_calendar_dialog_edit(app: &mut CosmicCalendar, calendar_id: S
 pub fn handle_confirm_calendar_dialog(app: &mut CosmicCalendar) {
     // Extract data from active_dialog before closing
     let dialog_data = match &app.active_dialog {
-        ActiveDialog::CalendarCreate { name, color } => {
-            Some((None, name.clone(), color.clone()))
+        ActiveDialog::CalendarCreate { name, color, group, default_duration } => {
+            Some((None, name.clone(), color.clone(), group.clone(), *default_duration, None, false, String::new()))
         }
-        ActiveDialog::CalendarEdit { calendar_id, name, color } => {
-            Some((Some(calendar_id.clone()), name.clone(), color.clone()))
+        ActiveDialog::CalendarEdit { calendar_id, name, color, group, default_duration, sync_interval, holiday_overlay, auto_export_path, .. } => {
+            Some((Some(calendar_id.clone()), name.clone(), color.clone(), group.clone(), *default_duration, *sync_interval, *holiday_overlay, auto_export_path.clone()))
         }
         _ => None,
     };
 
-    let Some((calendar_id_opt, name, color)) = dialog_data else {
+    let Some((calendar_id_opt, name, color, group, default_duration, sync_interval, holiday_overlay, auto_export_path)) = dialog_data else {
         return;
     };
 
@@ -115,6 +150,9 @@ pub fn handle_confirm_calendar_dialog(app: &mut CosmicCalendar) {
                 NewCalendarData {
                     name: name.to_string(),
                     color,
+                    group: CalendarHandler::normalize_group(&group),
+                    read_only: false,
+                    default_duration_minutes: default_duration,
                 },
             ) {
                 Ok(id) => {
@@ -139,6 +177,11 @@ pub fn handle_confirm_calendar_dialog(app: &mut CosmicCalendar) {
                     name: Some(name.to_string()),
                     color: Some(color),
                     enabled: None,
+                    group: Some(CalendarHandler::normalize_group(&group)),
+                    default_duration_minutes: Some(default_duration),
+                    sync_interval_minutes: Some(sync_interval),
+                    holiday_overlay: Some(holiday_overlay),
+                    auto_export_path: Some(Some(auto_export_path.trim().to_string()).filter(|p| !p.is_empty())),
                 },
             ) {
                 Ok(()) => {
@@ -0,0 +1,47 @@
+//! Day note handlers
+//!
+//! Day notes are free text attached to a date rather than an event. They are
+//! kept in memory and written through to the database when saved.
+
+use chrono::NaiveDate;
+use cosmic::app::Task;
+use cosmic::iced_widget::text_input;
+use log::{debug, error, info};
+
+use crate::app::CosmicCalendar;
+use crate::dialogs::{day_note_input_id, ActiveDialog, DialogManager};
+use crate::fl;
+use crate::message::Message;
+use crate::models::ToastSeverity;
+
+/// Open the note editor for a date, pre-filled with its current note
+pub fn handle_edit_day_note(app: &mut CosmicCalendar, date: NaiveDate) -> Task<Message> {
+    let text = app.day_notes.get(&date).cloned().unwrap_or_default();
+    debug!("handle_edit_day_note: Editing note for {} (existing={})", date, !text.is_empty());
+    DialogManager::open(&mut app.active_dialog, ActiveDialog::DayNote { date, text });
+    text_input::focus(day_note_input_id())
+}
+
+/// Save the note being edited and close the editor
+pub fn handle_save_day_note(app: &mut CosmicCalendar) {
+    let ActiveDialog::DayNote { date, text } = &app.active_dialog else {
+        return;
+    };
+    let date = *date;
+    let text = text.trim().to_string();
+
+    if let Err(e) = app.calendar_manager.set_day_note(date, &text) {
+        error!("handle_save_day_note: Failed to save note for {}: {}", date, e);
+        app.toasts.push(fl!("toast-day-note-failed"), ToastSeverity::Error, None);
+        return;
+    }
+
+    if text.is_empty() {
+        info!("handle_save_day_note: Removed note for {}", date);
+        app.day_notes.remove(&date);
+    } else {
+        info!("handle_save_day_note: Saved note for {}", date);
+        app.day_notes.insert(date, text);
+    }
+    DialogManager::close(&mut app.active_dialog);
+}
@@ -4,14 +4,25 @@
 //! This ensures consistent validation, routing, and cache management.
 
 use chrono::{NaiveDate, NaiveTime, TimeZone, Timelike, Utc};
+use cosmic::app::Task;
+use cosmic::iced_widget::text_input;
 use cosmic::widget::{calendar::CalendarModel, text_editor};
 use log::{debug, error, info, warn};
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
-use crate::app::{CosmicCalendar, EventDialogState};
-use crate::caldav::{AlertTime, CalendarEvent, RepeatFrequency, TravelTime};
-use crate::dialogs::{DialogAction, DialogManager, QuickEventResult};
-use crate::services::EventHandler;
+use crate::app::{CosmicCalendar, EventDialogField, EventDialogState};
+use crate::caldav::{CalendarEvent, RepeatFrequency, ShowAs, TravelTime};
+use crate::dialogs::{
+    make_timed_input_id, parse_make_timed_start, ActiveDialog, DialogAction, DialogManager, QuickEventResult,
+};
+use crate::fl;
+use crate::message::Message;
+use crate::models::{MoveUndo, ToastAction, ToastSeverity};
+use crate::quick_entry::parse_quick_entry;
+use crate::services::{CalendarHandler, DedupAnalyzer, EventHandler};
+use crate::settings::EventClickAction;
+use crate::url_handler::{event_link, EventPrefill};
 
 /// Extract the master UID from an occurrence UID
 /// Occurrence UIDs have format "master-uid_YYYYMMDD" for recurring events
@@ -47,6 +58,17 @@ pub fn extract_occurrence_date(uid: &str) -> Option<NaiveDate> {
     None
 }
 
+/// Refuse a change to a read-only calendar, explaining why with a toast
+/// Returns true when the calendar is read-only and the change must be skipped
+pub fn refuse_read_only(app: &mut CosmicCalendar, calendar_id: &str) -> bool {
+    if !app.calendar_manager.is_read_only(calendar_id) {
+        return false;
+    }
+    info!("refuse_read_only: Calendar '{}' is read-only, change refused", calendar_id);
+    app.toasts.push(fl!("toast-calendar-read-only"), ToastSeverity::Info, None);
+    true
+}
+
 /// Commit the quick event being edited - create a new event in the selected calendar
 /// Uses DialogManager to get the event data from ActiveDialog::QuickEvent
 /// Supports both single-day and multi-day events (from drag selection)
@@ -60,11 +82,27 @@ pub fn handle_commit_quick_event(app: &mut CosmicCalendar) {
         DialogAction::CommitQuickEvent,
     );
 
-    let Some(QuickEventResult { start_date, end_date, start_time: evt_start_time, end_time: evt_end_time, text }) = result else {
+    let Some(result) = result else {
         debug!("handle_commit_quick_event: No quick event editing state");
         return;
     };
 
+    create_quick_event(app, result, false);
+}
+
+/// Create a quick event that was held back as a duplicate
+/// Sent by the "Create Anyway" button on the duplicate warning toast
+pub fn handle_create_quick_event_anyway(app: &mut CosmicCalendar, result: QuickEventResult) {
+    debug!("handle_create_quick_event_anyway: Creating despite duplicate");
+    create_quick_event(app, result, true);
+}
+
+/// Create the event described by a committed quick event
+/// Unless `allow_duplicate` is set, an identical event already on that day
+/// is not created again (e.g. when Enter was pressed twice)
+fn create_quick_event(app: &mut CosmicCalendar, result: QuickEventResult, allow_duplicate: bool) {
+    let QuickEventResult { start_date, end_date, start_time: evt_start_time, end_time: evt_end_time, ref text } = result;
+
     // Don't create empty events
     let text = text.trim();
     if text.is_empty() {
@@ -77,28 +115,14 @@ pub fn handle_commit_quick_event(app: &mut CosmicCalendar) {
         warn!("handle_commit_quick_event: No calendar selected for new event");
         return;
     };
+    if refuse_read_only(app, &calendar_id) {
+        return;
+    }
 
     // Determine if this is a timed event or all-day event
     let is_timed = evt_start_time.is_some();
     let is_multi_day = start_date != end_date;
 
-    if is_timed {
-        info!(
-            "handle_commit_quick_event: Creating timed event '{}' on {} from {:?} to {:?} in calendar '{}'",
-            text, start_date, evt_start_time, evt_end_time, calendar_id
-        );
-    } else if is_multi_day {
-        info!(
-            "handle_commit_quick_event: Creating multi-day event '{}' from {} to {} in calendar '{}'",
-            text, start_date, end_date, calendar_id
-        );
-    } else {
-        info!(
-            "handle_commit_quick_event: Creating all-day event '{}' on {} in calendar '{}'",
-            text, start_date, calendar_id
-        );
-    }
-
     // Set times based on whether this is a timed event
     let (start_time, end_time, all_day) = if let (Some(st), Some(et)) = (evt_start_time, evt_end_time) {
         // Timed event - use the specified times
@@ -110,6 +134,37 @@ pub fn handle_commit_quick_event(app: &mut CosmicCalendar) {
         (midnight, end_of_day, true)
     };
 
+    let times = (!all_day).then_some((start_time, end_time));
+    if !allow_duplicate && has_cached_duplicate(app, start_date, text, times) {
+        info!("handle_commit_quick_event: Identical event already exists on {}, skipping", start_date);
+        app.toasts.push(
+            fl!("toast-quick-event-duplicate"),
+            ToastSeverity::Info,
+            Some(ToastAction::new(
+                fl!("button-create-anyway"),
+                Message::CreateQuickEventAnyway(result.clone()),
+            )),
+        );
+        return;
+    }
+
+    if is_timed {
+        info!(
+            "handle_commit_quick_event: Creating timed event on {} from {:?} to {:?} in calendar '{}'",
+            start_date, evt_start_time, evt_end_time, calendar_id
+        );
+    } else if is_multi_day {
+        info!(
+            "handle_commit_quick_event: Creating multi-day event from {} to {} in calendar '{}'",
+            start_date, end_date, calendar_id
+        );
+    } else {
+        info!(
+            "handle_commit_quick_event: Creating all-day event on {} in calendar '{}'",
+            start_date, calendar_id
+        );
+    }
+
     let start = Utc.from_utc_datetime(&start_date.and_time(start_time));
     let end = Utc.from_utc_datetime(&end_date.and_time(end_time));
 
@@ -125,11 +180,15 @@ pub fn handle_commit_quick_event(app: &mut CosmicCalendar) {
         repeat_until: None,
         exception_dates: vec![],
         invitees: vec![],
-        alert: AlertTime::None,
-        alert_second: None,
+        alerts: vec![],
         attachments: vec![],
         url: None,
         notes: None,
+        show_as: ShowAs::Busy,
+        priority: None,
+        alarms: vec![],
+        overrides: BTreeMap::new(),
+        display_order: None,
     };
 
     // Use EventHandler to add the event (handles validation, storage, and sync)
@@ -143,6 +202,104 @@ pub fn handle_commit_quick_event(app: &mut CosmicCalendar) {
     app.refresh_cached_events();
 }
 
+/// Check the displayed events for one with the same title and times on a date
+/// `times` is None for all-day events
+fn has_cached_duplicate(
+    app: &CosmicCalendar,
+    date: NaiveDate,
+    summary: &str,
+    times: Option<(NaiveTime, NaiveTime)>,
+) -> bool {
+    app.cached_week_events
+        .get(&date)
+        .into_iter()
+        .chain(app.cached_month_events.get(&date))
+        .flatten()
+        .any(|event| {
+            event.summary.trim() == summary
+                && match times {
+                    Some((start, end)) => {
+                        !event.all_day && event.start_time == Some(start) && event.end_time == Some(end)
+                    }
+                    None => event.all_day,
+                }
+        })
+}
+
+/// Create an event from the command palette text
+/// The text is parsed for a date and time; the rest becomes the title
+pub fn handle_commit_command_palette(app: &mut CosmicCalendar) {
+    let text = match &app.active_dialog {
+        ActiveDialog::CommandPalette { text } => text.clone(),
+        _ => return,
+    };
+
+    let today = chrono::Local::now().date_naive();
+    let Some(calendar_id) = app.selected_calendar_id.clone() else {
+        warn!("handle_commit_command_palette: No calendar selected for new event");
+        app.toasts.push(fl!("toast-no-calendars"), ToastSeverity::Error, None);
+        return;
+    };
+
+    let duration = CalendarHandler::event_duration(&app.calendar_manager, &calendar_id);
+    let entry = parse_quick_entry(&text, today, app.selected_date, duration);
+    if entry.summary.is_empty() {
+        debug!("handle_commit_command_palette: No title, ignoring");
+        return;
+    }
+
+    if refuse_read_only(app, &calendar_id) {
+        return;
+    }
+
+    let (start_time, end_time, all_day) = match entry.times {
+        Some((start, end)) => (start, end, false),
+        None => (
+            NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(23, 59, 59).unwrap(),
+            true,
+        ),
+    };
+
+    let event = CalendarEvent {
+        uid: Uuid::new_v4().to_string(),
+        summary: entry.summary,
+        location: None,
+        all_day,
+        start: Utc.from_utc_datetime(&entry.date.and_time(start_time)),
+        end: Utc.from_utc_datetime(&entry.date.and_time(end_time)),
+        travel_time: TravelTime::None,
+        repeat: RepeatFrequency::Never,
+        repeat_until: None,
+        exception_dates: vec![],
+        invitees: vec![],
+        alerts: vec![],
+        attachments: vec![],
+        url: None,
+        notes: None,
+        show_as: ShowAs::Busy,
+        priority: None,
+        alarms: vec![],
+        overrides: BTreeMap::new(),
+        display_order: None,
+    };
+    let uid = event.uid.clone();
+
+    if let Err(e) = EventHandler::add_event(&mut app.calendar_manager, &calendar_id, event) {
+        error!("handle_commit_command_palette: Failed to add event: {}", e);
+        return;
+    }
+
+    info!(
+        "handle_commit_command_palette: Created uid={} on {} in calendar '{}' (all_day={})",
+        uid, entry.date, calendar_id, all_day
+    );
+    DialogManager::close(&mut app.active_dialog);
+    app.set_selected_date(entry.date);
+    app.refresh_cached_events();
+    app.toasts.push(fl!("toast-event-created"), ToastSeverity::Success, None);
+}
+
 /// Delete an event by its UID from all calendars
 /// This implements a robust deletion with verification and guaranteed UI refresh
 /// For recurring events, the occurrence UID (format: master-uid_YYYYMMDD) is converted
@@ -152,6 +309,12 @@ pub fn handle_delete_event(app: &mut CosmicCalendar, uid: String) {
     let master_uid = extract_master_uid(&uid);
     info!("handle_delete_event: Deleting event uid={} (master_uid={})", uid, master_uid);
 
+    if let Ok((_, calendar_id)) = EventHandler::find_event(&app.calendar_manager, master_uid) {
+        if refuse_read_only(app, &calendar_id) {
+            return;
+        }
+    }
+
     // Clear selection if deleting the selected event (check both occurrence and master UID)
     if let Some(selected) = &app.selected_event_uid {
         if selected == &uid || extract_master_uid(selected) == master_uid {
@@ -201,6 +364,52 @@ pub fn handle_select_event(app: &mut CosmicCalendar, uid: String) {
     }
 }
 
+/// Jump to an event by UID (calendar://event/<uid> links) and select it
+/// Accepts both plain UIDs and occurrence UIDs (master-uid_YYYYMMDD) of recurring events
+pub fn handle_view_event(app: &mut CosmicCalendar, uid: &str) {
+    debug!("handle_view_event: uid={}", uid);
+
+    // Try the UID as given first - a plain UID may itself end in eight digits
+    let found = EventHandler::find_event(&app.calendar_manager, uid)
+        .map(|found| (found, None))
+        .or_else(|_| {
+            EventHandler::find_event(&app.calendar_manager, extract_master_uid(uid))
+                .map(|found| (found, extract_occurrence_date(uid)))
+        });
+
+    let ((event, calendar_id), occurrence_date) = match found {
+        Ok(found) => found,
+        Err(e) => {
+            warn!("handle_view_event: {}", e);
+            app.toasts.push(fl!("toast-event-not-found"), ToastSeverity::Error, None);
+            return;
+        }
+    };
+
+    let is_recurring = event.repeat != RepeatFrequency::Never;
+    let date = match occurrence_date {
+        Some(date) if is_recurring => date,
+        _ => event.start.date_naive(),
+    };
+
+    if is_recurring && event.exception_dates.contains(&date) {
+        warn!("handle_view_event: Occurrence {} of uid={} was removed", date, event.uid);
+        app.toasts.push(fl!("toast-event-not-found"), ToastSeverity::Error, None);
+        return;
+    }
+
+    // Views key recurring occurrences by master-uid_YYYYMMDD
+    let display_uid = if is_recurring {
+        format!("{}_{}", event.uid, date.format("%Y%m%d"))
+    } else {
+        event.uid.clone()
+    };
+
+    app.set_selected_date(date);
+    app.selected_event_uid = Some(format!("{}:{}", calendar_id, display_uid));
+    info!("handle_view_event: Showing uid={} on {} in calendar '{}'", display_uid, date, calendar_id);
+}
+
 // === Event Drag Handlers ===
 
 /// Start dragging an event to move it to a new date
@@ -222,6 +431,8 @@ pub fn handle_drag_event_start(
     let unique_id = format!("{}:{}", calendar_id, uid);
 
     // Start the drag operation with display info for the preview
+    // (it only starts moving once the cursor passes the drag threshold)
+    app.event_drag_state.set_threshold(app.settings.drag_threshold as f32);
     app.event_drag_state.start(calendar_id, uid, original_date, summary, color);
 
     // Cache the unique_id for UI rendering
@@ -240,6 +451,15 @@ pub fn handle_drag_event_end(app: &mut CosmicCalendar) {
     let event_uid = app.event_drag_state.event_uid.clone();
     let calendar_id_opt = app.event_drag_state.calendar_id.clone();
 
+    // Dropped on another chip of the same day's all-day stack - reorder instead
+    if let Some((calendar_id, uid, date, index)) = app.event_drag_state.stack_reorder() {
+        if apply_all_day_reorder(app, &calendar_id, &uid, date, index) {
+            app.event_drag_state.reset();
+            app.dragging_event_unique_id = None;
+            return;
+        }
+    }
+
     // Try to end the drag and get move info
     let move_result = app.event_drag_state.end();
 
@@ -251,6 +471,11 @@ pub fn handle_drag_event_end(app: &mut CosmicCalendar) {
             info!("handle_drag_event_end: Moving calendar={} event={} (master_uid={}) from {} to {}",
                   calendar_id, uid, master_uid, original_date, new_date);
 
+            if refuse_read_only(app, &calendar_id) {
+                app.dragging_event_unique_id = None;
+                return;
+            }
+
             // Calculate the offset in days
             let offset = (new_date - original_date).num_days();
 
@@ -260,6 +485,10 @@ pub fn handle_drag_event_end(app: &mut CosmicCalendar) {
                 let new_start = event.start + chrono::Duration::days(offset);
                 let new_end = event.end + chrono::Duration::days(offset);
 
+                // Remember the original so the move can be undone
+                let undo = app.settings.confirm_event_moves
+                    .then(|| MoveUndo::new(calendar_id.clone(), event.clone()));
+
                 // Create updated event with new dates
                 let updated_event = crate::caldav::CalendarEvent {
                     start: new_start,
@@ -274,14 +503,30 @@ pub fn handle_drag_event_end(app: &mut CosmicCalendar) {
                 }
 
                 info!("handle_drag_event_end: Event moved successfully");
+                if undo.is_some() {
+                    app.toasts.push(
+                        fl!("event-moved"),
+                        ToastSeverity::Info,
+                        Some(ToastAction::new(fl!("button-undo"), Message::UndoEventMove)),
+                    );
+                }
+                app.pending_move_undo = undo;
                 app.refresh_cached_events();
             } else {
                 warn!("handle_drag_event_end: Event not found: {}", uid);
             }
         }
         None => {
-            // Event wasn't moved (clicked and released on same date) - treat as selection
+            // Event wasn't moved (clicked and released on same date) - treat as a click
             if let (Some(calendar_id), Some(uid)) = (calendar_id_opt, event_uid) {
+                if app.settings.event_click_action == EventClickAction::Edit {
+                    debug!("handle_drag_event_end: No move, editing event {}:{}", calendar_id, uid);
+                    app.selected_event_uid = None;
+                    app.dragging_event_unique_id = None;
+                    handle_open_edit_event_dialog(app, calendar_id, uid);
+                    return;
+                }
+
                 // Create unique_id for selection (calendar_id:uid)
                 let unique_id = format!("{}:{}", calendar_id, uid);
                 debug!("handle_drag_event_end: No move, selecting event {}", unique_id);
@@ -299,6 +544,116 @@ pub fn handle_drag_event_end(app: &mut CosmicCalendar) {
     app.dragging_event_unique_id = None;
 }
 
+/// Move an all-day event to a new position in its day's stack (week view)
+/// Every all-day event of the day is given its position so the order sticks.
+/// Returns false when the drop leaves the order unchanged.
+fn apply_all_day_reorder(app: &mut CosmicCalendar, calendar_id: &str, uid: &str, date: NaiveDate, index: usize) -> bool {
+    let mut stack: Vec<(String, String)> = app.cached_week_events
+        .get(&date)
+        .map(|events| {
+            events.iter()
+                .filter(|e| e.all_day)
+                .map(|e| (e.calendar_id.clone(), e.uid.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let Some(from) = stack.iter().position(|(c, u)| c == calendar_id && u == uid) else {
+        return false;
+    };
+    if from == index || index >= stack.len() {
+        return false;
+    }
+    if refuse_read_only(app, calendar_id) {
+        return true;
+    }
+
+    let dragged = stack.remove(from);
+    stack.insert(index, dragged);
+
+    let mut updated = 0;
+    for (position, (cal_id, event_uid)) in stack.iter().enumerate() {
+        // Events of read-only calendars keep their place after the ordered ones
+        if app.calendar_manager.is_read_only(cal_id) {
+            continue;
+        }
+        match EventHandler::set_display_order(&mut app.calendar_manager, cal_id, extract_master_uid(event_uid), position as u32) {
+            Ok(true) => updated += 1,
+            Ok(false) => {}
+            Err(e) => error!("apply_all_day_reorder: Failed to order event {}: {}", event_uid, e),
+        }
+    }
+
+    info!("apply_all_day_reorder: Moved event {} to position {} on {} ({} events updated)", uid, index, date, updated);
+    app.refresh_cached_events();
+    true
+}
+
+/// Revert the last drag move by restoring the event's original dates
+pub fn handle_undo_event_move(app: &mut CosmicCalendar) {
+    let Some(undo) = app.pending_move_undo.take() else {
+        return;
+    };
+
+    info!("handle_undo_event_move: Reverting move of event {}", undo.original.uid);
+    if let Err(e) = EventHandler::update_event(&mut app.calendar_manager, &undo.calendar_id, undo.original) {
+        error!("handle_undo_event_move: Failed to revert move: {}", e);
+        return;
+    }
+    app.refresh_cached_events();
+}
+
+/// Skip a single occurrence of a recurring event without the delete dialog
+/// Takes the occurrence's unique_id (calendar_id:master-uid_YYYYMMDD) and offers an undo toast
+pub fn handle_skip_occurrence(app: &mut CosmicCalendar, unique_id: String) {
+    let Some((calendar_id, uid)) = unique_id.split_once(':') else {
+        warn!("handle_skip_occurrence: Invalid event id {}", unique_id);
+        return;
+    };
+    let Some(date) = extract_occurrence_date(uid) else {
+        debug!("handle_skip_occurrence: {} is not a recurring occurrence", unique_id);
+        return;
+    };
+    let master_uid = extract_master_uid(uid).to_string();
+    if refuse_read_only(app, calendar_id) {
+        return;
+    }
+
+    if let Err(e) = EventHandler::add_exception_date(&mut app.calendar_manager, &master_uid, date) {
+        error!("handle_skip_occurrence: Failed to add exception date: {}", e);
+        return;
+    }
+
+    info!("handle_skip_occurrence: Skipped {} of uid={}", date, master_uid);
+    if app.selected_event_uid.as_ref() == Some(&unique_id) {
+        app.selected_event_uid = None;
+    }
+    app.toasts.push(
+        fl!("toast-occurrence-skipped"),
+        ToastSeverity::Info,
+        Some(ToastAction::new(fl!("button-undo"), Message::UndoSkipOccurrence(master_uid, date))),
+    );
+    app.refresh_cached_events();
+}
+
+/// Restore an occurrence skipped with handle_skip_occurrence (from the undo toast)
+pub fn handle_undo_skip_occurrence(app: &mut CosmicCalendar, master_uid: String, date: NaiveDate) {
+    info!("handle_undo_skip_occurrence: Restoring {} of uid={}", date, master_uid);
+    if let Err(e) = EventHandler::remove_exception_date(&mut app.calendar_manager, &master_uid, date) {
+        error!("handle_undo_skip_occurrence: Failed to remove exception date: {}", e);
+        return;
+    }
+    app.refresh_cached_events();
+}
+
+/// Copy a calendar://event link to the clipboard so the event can be opened again later
+/// Occurrence UIDs are kept, so the link opens the same occurrence
+pub fn handle_copy_event_link(app: &mut CosmicCalendar, uid: String) -> Task<Message> {
+    info!("handle_copy_event_link: Copying link for uid={}", uid);
+    app.toasts.push(fl!("toast-event-link-copied"), ToastSeverity::Info, None);
+    cosmic::iced::clipboard::write(event_link(&uid))
+}
+
 /// Cancel the drag operation
 pub fn handle_drag_event_cancel(app: &mut CosmicCalendar) {
     debug!("handle_drag_event_cancel: Cancelling drag");
@@ -337,22 +692,104 @@ pub fn handle_cancel_quick_event(app: &mut CosmicCalendar) {
     DialogManager::close(&mut app.active_dialog);
 }
 
+// === Inline Rename Handlers ===
+
+/// Start renaming an event in place on its chip
+/// The unique_id has the form "calendar_id:uid"; occurrences rename their whole series
+pub fn handle_begin_inline_rename(app: &mut CosmicCalendar, unique_id: &str) -> bool {
+    let Some((calendar_id, uid)) = unique_id.split_once(':') else {
+        warn!("handle_begin_inline_rename: Malformed event id");
+        return false;
+    };
+    if refuse_read_only(app, calendar_id) {
+        return false;
+    }
+
+    let master_uid = extract_master_uid(uid);
+    let event = match EventHandler::find_event_in_calendar(&app.calendar_manager, calendar_id, master_uid) {
+        Ok(event) => event,
+        Err(e) => {
+            error!("handle_begin_inline_rename: Failed to find event: {}", e);
+            return false;
+        }
+    };
+
+    debug!("handle_begin_inline_rename: Renaming uid={} in calendar '{}'", master_uid, calendar_id);
+    DialogManager::open(
+        &mut app.active_dialog,
+        ActiveDialog::EventRename {
+            calendar_id: calendar_id.to_string(),
+            uid: uid.to_string(),
+            text: event.summary,
+        },
+    );
+    true
+}
+
+/// Update the title being typed in the inline rename input
+pub fn handle_inline_rename_changed(app: &mut CosmicCalendar, text: String) {
+    if let ActiveDialog::EventRename { text: t, .. } = &mut app.active_dialog {
+        *t = text;
+    }
+}
+
+/// Save the inline rename and close the input
+/// Empty titles are discarded and leave the event unchanged
+pub fn handle_commit_inline_rename(app: &mut CosmicCalendar) {
+    let ActiveDialog::EventRename { calendar_id, uid, text } = &app.active_dialog else {
+        return;
+    };
+    let (calendar_id, master_uid, title) =
+        (calendar_id.clone(), extract_master_uid(uid).to_string(), text.trim().to_string());
+    DialogManager::close(&mut app.active_dialog);
+
+    if title.is_empty() {
+        debug!("handle_commit_inline_rename: Empty title, rename discarded");
+        return;
+    }
+
+    let mut event = match EventHandler::find_event_in_calendar(&app.calendar_manager, &calendar_id, &master_uid) {
+        Ok(event) => event,
+        Err(e) => {
+            error!("handle_commit_inline_rename: Failed to find event: {}", e);
+            return;
+        }
+    };
+    if event.summary == title {
+        return;
+    }
+    event.summary = title;
+
+    match EventHandler::update_event(&mut app.calendar_manager, &calendar_id, event) {
+        Ok(()) => {
+            info!("handle_commit_inline_rename: Renamed event uid={}", master_uid);
+            app.refresh_cached_events();
+        }
+        Err(e) => {
+            error!("handle_commit_inline_rename: Failed to rename event: {}", e);
+        }
+    }
+}
+
 // === Event Dialog Handlers ===
 
+/// Close an open event dialog before another one replaces it
+/// With unsaved changes it stays open and asks to discard or keep editing, as
+/// when closing it; returns whether the way is clear for the new dialog
+fn close_event_dialog_for_replacement(app: &mut CosmicCalendar) -> bool {
+    handle_cancel_event_dialog(app);
+    app.event_dialog.is_none()
+}
+
 /// Open the event dialog for creating a new event
-pub fn handle_open_new_event_dialog(app: &mut CosmicCalendar) {
+/// Returns false when an open dialog with unsaved changes was kept instead
+pub fn handle_open_new_event_dialog(app: &mut CosmicCalendar) -> bool {
+    if !close_event_dialog_for_replacement(app) {
+        return false;
+    }
     debug!("handle_open_new_event_dialog: Opening new event dialog");
     let today = app.selected_date;
 
-    // Default to current time (rounded to 5 minutes) and +1 hour for end time
-    let now = chrono::Local::now().time();
-    let rounded_minute = (now.minute() / 5) * 5;
-    let default_start_time = NaiveTime::from_hms_opt(now.hour(), rounded_minute, 0);
-    let default_end_time = default_start_time.map(|t| {
-        let new_hour = (t.hour() + 1) % 24;
-        NaiveTime::from_hms_opt(new_hour, t.minute(), 0).unwrap_or(t)
-    });
-
     // Use selected calendar or first available
     let calendar_id = app
         .selected_calendar_id
@@ -365,6 +802,17 @@ pub fn handle_open_new_event_dialog(app: &mut CosmicCalendar) {
         })
         .unwrap_or_default();
 
+    // Default to the current time, rounded up to the next increment unless disabled,
+    // lasting the calendar's event duration
+    let now = chrono::Local::now().time();
+    let default_start_time = if app.settings.round_new_event_start {
+        Some(app.settings.new_event_rounding.round_up(now))
+    } else {
+        NaiveTime::from_hms_opt(now.hour(), now.minute(), 0)
+    };
+    let duration = CalendarHandler::event_duration(&app.calendar_manager, &calendar_id);
+    let default_end_time = default_start_time.map(|t| t.overflowing_add_signed(duration).0);
+
     app.event_dialog = Some(EventDialogState {
         editing_uid: None,
         title: String::new(),
@@ -384,26 +832,102 @@ pub fn handle_open_new_event_dialog(app: &mut CosmicCalendar) {
             .unwrap_or_else(|| "10:00".to_string()),
         travel_time: TravelTime::None,
         repeat: RepeatFrequency::Never,
+        priority: None,
         calendar_id,
         invitees: vec![],
         invitee_input: String::new(),
-        alert: AlertTime::None,
-        alert_second: None,
+        alerts: vec![],
         attachments: vec![],
         url: String::new(),
         notes_content: text_editor::Content::new(),
-        editing_field: None,
+        editing_field: Some(EventDialogField::Title),
         start_date_picker_open: false,
         start_date_calendar: CalendarModel::new(today, today),
         end_date_picker_open: false,
         end_date_calendar: CalendarModel::new(today, today),
         start_time_picker_open: false,
         end_time_picker_open: false,
+        bulk_dates: vec![],
+        dirty: false,
+        confirm_discard: false,
     });
+    true
+}
+
+/// Open the new event dialog to create one event on each date picked with Ctrl
+pub fn handle_create_on_picked_dates(app: &mut CosmicCalendar) {
+    let dates = app.selection_state.picked_dates();
+    let Some(&first) = dates.first() else {
+        app.toasts.push(fl!("toast-no-picked-dates"), ToastSeverity::Info, None);
+        return;
+    };
+
+    if !handle_open_new_event_dialog(app) {
+        return;
+    }
+    let Some(dialog) = app.event_dialog.as_mut() else {
+        return;
+    };
+
+    debug!("handle_create_on_picked_dates: Creating on {} dates from {}", dates.len(), first);
+    dialog.start_date = first;
+    dialog.start_date_input = first.format("%Y-%m-%d").to_string();
+    dialog.start_date_calendar = CalendarModel::new(first, first);
+    dialog.end_date = first;
+    dialog.end_date_input = first.format("%Y-%m-%d").to_string();
+    dialog.end_date_calendar = CalendarModel::new(first, first);
+    dialog.bulk_dates = dates;
+}
+
+/// Open the new event dialog pre-filled from calendar://new link parameters
+/// Fields the link doesn't provide keep the new event defaults
+pub fn handle_open_event_dialog_from_url(
+    app: &mut CosmicCalendar,
+    summary: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    location: Option<String>,
+) {
+    if !handle_open_new_event_dialog(app) {
+        return;
+    }
+    let Some(dialog) = app.event_dialog.as_mut() else {
+        return;
+    };
+
+    let fallback_start = dialog
+        .start_date
+        .and_time(dialog.start_time.unwrap_or_else(|| NaiveTime::from_hms_opt(9, 0, 0).unwrap()));
+    let prefill = EventPrefill::from_url_fields(summary, start, end, location, fallback_start);
+
+    dialog.title = prefill.title;
+    dialog.location = prefill.location;
+    dialog.all_day = prefill.all_day;
+    dialog.start_date = prefill.start_date;
+    dialog.start_date_input = prefill.start_date.format("%Y-%m-%d").to_string();
+    dialog.start_date_calendar = CalendarModel::new(prefill.start_date, prefill.start_date);
+    dialog.end_date = prefill.end_date;
+    dialog.end_date_input = prefill.end_date.format("%Y-%m-%d").to_string();
+    dialog.end_date_calendar = CalendarModel::new(prefill.end_date, prefill.end_date);
+    // Time inputs keep their defaults for all-day events so unticking all-day has sensible values
+    dialog.start_time = prefill.start_time;
+    dialog.end_time = prefill.end_time;
+    if let (Some(start_time), Some(end_time)) = (prefill.start_time, prefill.end_time) {
+        dialog.start_time_input = start_time.format("%H:%M").to_string();
+        dialog.end_time_input = end_time.format("%H:%M").to_string();
+    }
+
+    info!(
+        "handle_open_event_dialog_from_url: Pre-filled dialog for {} (all_day={})",
+        prefill.start_date, prefill.all_day
+    );
 }
 
 /// Open the event dialog for editing an existing event
 pub fn handle_open_edit_event_dialog(app: &mut CosmicCalendar, calendar_id: String, uid: String) {
+    if !close_event_dialog_for_replacement(app) {
+        return;
+    }
     // Extract master UID for recurring events (occurrence UIDs have format master-uid_YYYYMMDD)
     let master_uid = extract_master_uid(&uid);
     debug!("handle_open_edit_event_dialog: Opening edit dialog for calendar_id={} uid={} (master_uid={})",
@@ -449,11 +973,11 @@ pub fn handle_open_edit_event_dialog(app: &mut CosmicCalendar, calendar_id: Stri
             .unwrap_or_else(|| "10:00".to_string()),
         travel_time: event.travel_time,
         repeat: event.repeat,
+        priority: event.priority,
         calendar_id,
         invitees: event.invitees,
         invitee_input: String::new(),
-        alert: event.alert,
-        alert_second: event.alert_second,
+        alerts: event.alerts,
         attachments: event.attachments,
         url: event.url.unwrap_or_default(),
         notes_content: text_editor::Content::with_text(&event.notes.unwrap_or_default()),
@@ -464,6 +988,9 @@ pub fn handle_open_edit_event_dialog(app: &mut CosmicCalendar, calendar_id: Stri
         end_date_calendar: CalendarModel::new(end_date, end_date),
         start_time_picker_open: false,
         end_time_picker_open: false,
+        bulk_dates: vec![],
+        dirty: false,
+        confirm_discard: false,
     });
 }
 
@@ -481,6 +1008,13 @@ pub fn handle_confirm_event_dialog(app: &mut CosmicCalendar) {
     if title.is_empty() {
         warn!("handle_confirm_event_dialog: Empty title, returning dialog");
         // Put dialog back - can't save without title
+        app.toasts.push(fl!("toast-event-title-required"), ToastSeverity::Info, None);
+        app.event_dialog = Some(dialog);
+        return;
+    }
+
+    // Save is disabled for read-only calendars, but Enter in the title still submits
+    if refuse_read_only(app, &dialog.calendar_id) {
         app.event_dialog = Some(dialog);
         return;
     }
@@ -501,6 +1035,27 @@ pub fn handle_confirm_event_dialog(app: &mut CosmicCalendar) {
     let start = Utc.from_utc_datetime(&dialog.start_date.and_time(start_time));
     let end = Utc.from_utc_datetime(&dialog.end_date.and_time(end_time));
 
+    // Imported alarms, availability and modified occurrences aren't editable in the dialog - carry them over when editing
+    let existing = dialog
+        .editing_uid
+        .as_deref()
+        .and_then(|uid| EventHandler::find_event_in_calendar(&app.calendar_manager, &dialog.calendar_id, uid).ok());
+    let show_as = existing.as_ref().map(|e| e.show_as).unwrap_or_default();
+    let overrides = existing.as_ref().map(|e| e.overrides.clone()).unwrap_or_default();
+    let display_order = existing.as_ref().and_then(|e| e.display_order);
+    // Drop imported reminders that were removed in the dialog
+    let alarms = existing
+        .map(|e| e.alarms)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|alarm| {
+            alarm.as_alert().map_or(true, |alert| {
+                let mins = alert.minutes_before(&dialog.travel_time);
+                dialog.alerts.iter().any(|a| a.minutes_before(&dialog.travel_time) == mins)
+            })
+        })
+        .collect();
+
     let event = CalendarEvent {
         uid: dialog.editing_uid.clone().unwrap_or_else(|| Uuid::new_v4().to_string()),
         summary: title.to_string(),
@@ -517,8 +1072,7 @@ pub fn handle_confirm_event_dialog(app: &mut CosmicCalendar) {
         repeat_until: None, // TODO: Add to dialog state
         exception_dates: vec![], // Exception dates are preserved when editing existing events
         invitees: dialog.invitees,
-        alert: dialog.alert,
-        alert_second: dialog.alert_second,
+        alerts: dialog.alerts,
         attachments: dialog.attachments,
         url: if dialog.url.is_empty() {
             None
@@ -533,8 +1087,33 @@ pub fn handle_confirm_event_dialog(app: &mut CosmicCalendar) {
                 Some(notes_text)
             }
         },
+        show_as,
+        priority: dialog.priority,
+        alarms,
+        overrides,
+        display_order,
     };
 
+    // Picked dates: create a copy of the new event on each of them
+    if dialog.editing_uid.is_none() && !dialog.bulk_dates.is_empty() {
+        let copies = EventHandler::copies_on_dates(&event, &dialog.bulk_dates)
+            .into_iter()
+            .map(|copy| (dialog.calendar_id.clone(), copy))
+            .collect();
+        match EventHandler::add_event_copies(&mut app.calendar_manager, copies) {
+            Ok(added) => {
+                info!("handle_confirm_event_dialog: Created {} events in calendar '{}'", added, dialog.calendar_id);
+                app.toasts.push(fl!("toast-events-created", count = (added as i64)), ToastSeverity::Success, None);
+                app.selection_state.clear_picked();
+            }
+            Err(e) => {
+                error!("handle_confirm_event_dialog: Failed to create events: {}", e);
+            }
+        }
+        app.refresh_cached_events();
+        return;
+    }
+
     // Use EventHandler for create or update
     let result = if dialog.editing_uid.is_some() {
         info!("handle_confirm_event_dialog: Updating event '{}' in calendar '{}'", title, dialog.calendar_id);
@@ -559,7 +1138,225 @@ pub fn handle_confirm_event_dialog(app: &mut CosmicCalendar) {
 }
 
 /// Cancel the event dialog
+/// With unsaved changes the dialog stays open and asks to discard or keep editing
 pub fn handle_cancel_event_dialog(app: &mut CosmicCalendar) {
+    if let Some(dialog) = app.event_dialog.as_mut() {
+        if dialog.dirty {
+            debug!("handle_cancel_event_dialog: Unsaved changes, asking before closing");
+            dialog.confirm_discard = true;
+            return;
+        }
+    }
     debug!("handle_cancel_event_dialog: Cancelling event dialog");
     app.event_dialog = None;
 }
+
+/// Close the event dialog and drop its unsaved changes
+pub fn handle_discard_event_dialog(app: &mut CosmicCalendar) {
+    debug!("handle_discard_event_dialog: Discarding unsaved changes");
+    app.event_dialog = None;
+}
+
+/// Dismiss the discard prompt and return to the event dialog
+pub fn handle_keep_editing_event_dialog(app: &mut CosmicCalendar) {
+    if let Some(dialog) = app.event_dialog.as_mut() {
+        dialog.confirm_discard = false;
+    }
+}
+
+/// Open the make recurring dialog for an event
+/// Takes the event's unique_id (calendar_id:uid); occurrences resolve to their master event
+pub fn handle_convert_to_recurring(app: &mut CosmicCalendar, unique_id: String) {
+    let Some((calendar_id, uid)) = unique_id.split_once(':') else {
+        warn!("handle_convert_to_recurring: Invalid event id {}", unique_id);
+        return;
+    };
+    let master_uid = extract_master_uid(uid);
+    if refuse_read_only(app, calendar_id) {
+        return;
+    }
+
+    let event = match EventHandler::find_event_in_calendar(&app.calendar_manager, calendar_id, master_uid) {
+        Ok(event) => event,
+        Err(e) => {
+            warn!("handle_convert_to_recurring: Event not found: {}", e);
+            return;
+        }
+    };
+
+    if event.repeat != RepeatFrequency::Never {
+        debug!("handle_convert_to_recurring: Event uid={} is already recurring", master_uid);
+        return;
+    }
+
+    DialogManager::open(
+        &mut app.active_dialog,
+        ActiveDialog::ConvertToRecurring {
+            calendar_id: calendar_id.to_string(),
+            uid: master_uid.to_string(),
+            repeat: RepeatFrequency::Weekly,
+        },
+    );
+}
+
+/// Apply the recurrence chosen in the make recurring dialog
+pub fn handle_confirm_convert_to_recurring(app: &mut CosmicCalendar) {
+    let ActiveDialog::ConvertToRecurring { calendar_id, uid, repeat } = &app.active_dialog else {
+        return;
+    };
+    let (calendar_id, uid, repeat) = (calendar_id.clone(), uid.clone(), repeat.clone());
+    DialogManager::close(&mut app.active_dialog);
+
+    match EventHandler::convert_to_recurring(&mut app.calendar_manager, &calendar_id, &uid, repeat) {
+        Ok(()) => {
+            info!("handle_confirm_convert_to_recurring: Event uid={} is now recurring", uid);
+            app.refresh_cached_events();
+        }
+        Err(e) => {
+            error!("handle_confirm_convert_to_recurring: Failed to convert event: {}", e);
+        }
+    }
+}
+
+/// Switch an event between all-day and timed
+/// Timed events become all-day straight away; all-day events first ask for a start time
+pub fn handle_toggle_event_all_day(app: &mut CosmicCalendar, unique_id: String) -> Task<Message> {
+    let Some((calendar_id, uid)) = unique_id.split_once(':') else {
+        warn!("handle_toggle_event_all_day: Invalid event id {}", unique_id);
+        return Task::none();
+    };
+    let master_uid = extract_master_uid(uid);
+    if refuse_read_only(app, calendar_id) {
+        return Task::none();
+    }
+
+    let event = match EventHandler::find_event_in_calendar(&app.calendar_manager, calendar_id, master_uid) {
+        Ok(event) => event,
+        Err(e) => {
+            warn!("handle_toggle_event_all_day: Event not found: {}", e);
+            return Task::none();
+        }
+    };
+
+    if event.all_day {
+        DialogManager::open(
+            &mut app.active_dialog,
+            ActiveDialog::MakeTimed {
+                calendar_id: calendar_id.to_string(),
+                uid: master_uid.to_string(),
+                start: format!("{:02}:00", app.settings.working_hours_start),
+            },
+        );
+        return text_input::focus(make_timed_input_id());
+    }
+
+    // The start time and duration only apply when converting to timed
+    let (calendar_id, master_uid) = (calendar_id.to_string(), master_uid.to_string());
+    apply_all_day_toggle(app, &calendar_id, &master_uid, NaiveTime::MIN);
+    Task::none()
+}
+
+/// Make the all-day event in the make timed dialog start at the entered time
+pub fn handle_confirm_make_timed(app: &mut CosmicCalendar) {
+    let ActiveDialog::MakeTimed { calendar_id, uid, start } = &app.active_dialog else {
+        return;
+    };
+    let Some(start) = parse_make_timed_start(start) else {
+        return;
+    };
+    let (calendar_id, uid) = (calendar_id.clone(), uid.clone());
+    DialogManager::close(&mut app.active_dialog);
+
+    apply_all_day_toggle(app, &calendar_id, &uid, start);
+}
+
+/// Persist an all-day toggle and report the result
+fn apply_all_day_toggle(app: &mut CosmicCalendar, calendar_id: &str, uid: &str, start: NaiveTime) {
+    let duration = CalendarHandler::event_duration(&app.calendar_manager, calendar_id);
+    match EventHandler::toggle_all_day(&mut app.calendar_manager, calendar_id, uid, start, duration) {
+        Ok(all_day) => {
+            info!("apply_all_day_toggle: Event uid={} all_day={}", uid, all_day);
+            let message = if all_day {
+                fl!("toast-event-now-all-day")
+            } else {
+                fl!("toast-event-now-timed")
+            };
+            app.toasts.push(message, ToastSeverity::Info, None);
+            app.refresh_cached_events();
+        }
+        Err(e) => {
+            error!("apply_all_day_toggle: Failed to update event uid={}: {}", uid, e);
+        }
+    }
+}
+
+/// Scan all calendars for duplicate events and open the merge dialog with the results
+pub fn handle_find_duplicate_events(app: &mut CosmicCalendar) {
+    match DedupAnalyzer::find_all_duplicates(&app.calendar_manager) {
+        Ok(groups) => {
+            info!("handle_find_duplicate_events: Found {} duplicate groups", groups.len());
+            DialogManager::open(&mut app.active_dialog, ActiveDialog::MergeDuplicates { groups });
+        }
+        Err(e) => {
+            error!("handle_find_duplicate_events: Failed to scan for duplicates: {}", e);
+        }
+    }
+}
+
+/// Plan copying the displayed week's one-off events forward and ask for confirmation
+pub fn handle_copy_week_forward(app: &mut CosmicCalendar) {
+    let (Some(&week_start), Some(&week_end)) = (app.week_state.days.first(), app.week_state.days.last()) else {
+        return;
+    };
+
+    let copies = EventHandler::plan_week_copy(&app.calendar_manager, week_start, week_end);
+    info!("handle_copy_week_forward: {} events to copy from week of {}", copies.len(), week_start);
+    DialogManager::open(&mut app.active_dialog, ActiveDialog::CopyWeek { copies });
+}
+
+/// Create the copies shown in the copy week dialog
+pub fn handle_confirm_copy_week(app: &mut CosmicCalendar) {
+    let copies = match &app.active_dialog {
+        ActiveDialog::CopyWeek { copies } => copies.clone(),
+        _ => {
+            warn!("handle_confirm_copy_week: Copy week dialog not open");
+            return;
+        }
+    };
+    DialogManager::close(&mut app.active_dialog);
+
+    match EventHandler::add_event_copies(&mut app.calendar_manager, copies) {
+        Ok(added) => {
+            info!("handle_confirm_copy_week: Copied {} events", added);
+            app.toasts.push(fl!("toast-week-copied", count = (added as i64)), ToastSeverity::Success, None);
+        }
+        Err(e) => {
+            error!("handle_confirm_copy_week: Copy failed: {}", e);
+        }
+    }
+
+    app.refresh_cached_events();
+}
+
+/// Merge the duplicate groups shown in the merge dialog
+pub fn handle_confirm_merge_duplicates(app: &mut CosmicCalendar) {
+    let groups = match &app.active_dialog {
+        ActiveDialog::MergeDuplicates { groups } => groups.clone(),
+        _ => {
+            warn!("handle_confirm_merge_duplicates: Merge dialog not open");
+            return;
+        }
+    };
+
+    match DedupAnalyzer::merge_duplicates(&mut app.calendar_manager, &groups) {
+        Ok(removed) => {
+            info!("handle_confirm_merge_duplicates: Removed {} duplicate events", removed);
+        }
+        Err(e) => {
+            error!("handle_confirm_merge_duplicates: Merge failed: {}", e);
+        }
+    }
+
+    DialogManager::close(&mut app.active_dialog);
+    app.refresh_cached_events();
+}
@@ -0,0 +1,195 @@
+//! Export of the events within a date range, or of the selected events
+//!
+//! The dialog collects the range and calendars; the file is written once a
+//! save location has been picked.
+
+use chrono::{Datelike, Months, NaiveDate};
+use cosmic::app::Task;
+use log::{debug, error, info, warn};
+use std::path::PathBuf;
+
+use crate::app::CosmicCalendar;
+use crate::caldav::CalendarEvent;
+use crate::dialogs::{parse_export_range, ActiveDialog, DialogManager, EXPORT_RANGE_DATE_FORMAT};
+use crate::fl;
+use crate::message::Message;
+use crate::models::ToastSeverity;
+use crate::services::{EventHandler, ExportHandler};
+
+use super::event::extract_master_uid;
+
+/// Open the export range dialog, pre-filled with the displayed month and enabled calendars
+pub fn handle_open_export_range_dialog(app: &mut CosmicCalendar) {
+    let state = app.cache.current_state();
+    let first = NaiveDate::from_ymd_opt(state.year, state.month, 1).unwrap_or(app.selected_date);
+    let last = first
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(first);
+
+    let calendar_ids: Vec<String> = app
+        .calendar_manager
+        .sources()
+        .iter()
+        .filter(|c| c.is_enabled())
+        .map(|c| c.info().id.clone())
+        .collect();
+
+    debug!(
+        "handle_open_export_range_dialog: {}-{:02} with {} calendars",
+        first.year(),
+        first.month(),
+        calendar_ids.len()
+    );
+    DialogManager::open(
+        &mut app.active_dialog,
+        ActiveDialog::ExportRange {
+            start: first.format(EXPORT_RANGE_DATE_FORMAT).to_string(),
+            end: last.format(EXPORT_RANGE_DATE_FORMAT).to_string(),
+            calendar_ids,
+        },
+    );
+}
+
+/// Add or remove a calendar from the export
+pub fn handle_export_range_toggle_calendar(app: &mut CosmicCalendar, calendar_id: String) {
+    if let ActiveDialog::ExportRange { calendar_ids, .. } = &mut app.active_dialog {
+        if let Some(pos) = calendar_ids.iter().position(|id| *id == calendar_id) {
+            calendar_ids.remove(pos);
+        } else {
+            calendar_ids.push(calendar_id);
+        }
+    }
+}
+
+/// Ask where to save the export; the dialog stays open until the file is written
+pub fn handle_confirm_export_range(app: &mut CosmicCalendar) -> Task<Message> {
+    let ActiveDialog::ExportRange { start, end, .. } = &app.active_dialog else {
+        return Task::none();
+    };
+    let Some((start, end)) = parse_export_range(start, end) else {
+        warn!("handle_confirm_export_range: Invalid date range");
+        return Task::none();
+    };
+
+    // Suggested filename: Calendar-YYYY-MM-DD-to-YYYY-MM-DD.ics
+    let suggested_filename = format!("Calendar-{}-to-{}.ics", start, end);
+
+    Task::perform(
+        async move {
+            rfd::AsyncFileDialog::new()
+                .set_title("Export Date Range")
+                .set_file_name(&suggested_filename)
+                .add_filter("iCalendar", &["ics", "ical", "ifb", "icalendar"])
+                .save_file()
+                .await
+                .map(|file_handle| file_handle.path().to_path_buf())
+        },
+        |result| match result {
+            Some(path) => cosmic::Action::App(Message::ExportRangeToFile(path)),
+            None => {
+                debug!("Date range export cancelled by user");
+                cosmic::Action::App(Message::None)
+            }
+        },
+    )
+}
+
+/// Write the events in the chosen range to the picked file and close the dialog
+pub fn handle_export_range_to_file(app: &mut CosmicCalendar, path: PathBuf) {
+    let ActiveDialog::ExportRange { start, end, calendar_ids } = &app.active_dialog else {
+        return;
+    };
+    let Some((start, end)) = parse_export_range(start, end) else {
+        return;
+    };
+
+    match ExportHandler::export_range(&app.calendar_manager, calendar_ids, start, end, &path) {
+        Ok(count) => {
+            info!("handle_export_range_to_file: Exported {} events", count);
+            app.toasts.push(
+                fl!("toast-range-exported", count = (count as i64)),
+                ToastSeverity::Success,
+                None,
+            );
+        }
+        Err(e) => {
+            error!("handle_export_range_to_file: Export failed: {}", e);
+            app.toasts.push(fl!("toast-export-failed"), ToastSeverity::Error, None);
+        }
+    }
+
+    DialogManager::close(&mut app.active_dialog);
+}
+
+
+/// Ask where to save the selected events
+pub fn handle_export_selected_events(app: &CosmicCalendar) -> Task<Message> {
+    if app.selected_event_uid.is_none() {
+        debug!("handle_export_selected_events: No event selected");
+        return Task::none();
+    }
+
+    Task::perform(
+        async move {
+            rfd::AsyncFileDialog::new()
+                .set_title("Export Selected Events")
+                .set_file_name("Events.ics")
+                .add_filter("iCalendar", &["ics", "ical", "ifb", "icalendar"])
+                .save_file()
+                .await
+                .map(|file_handle| file_handle.path().to_path_buf())
+        },
+        |result| match result {
+            Some(path) => cosmic::Action::App(Message::ExportSelectedEventsToFile(path)),
+            None => {
+                debug!("Selected events export cancelled by user");
+                cosmic::Action::App(Message::None)
+            }
+        },
+    )
+}
+
+/// Write the selected events, each with its calendar's color, to the picked file
+pub fn handle_export_selected_events_to_file(app: &mut CosmicCalendar, path: PathBuf) {
+    let selected: Vec<(CalendarEvent, String)> = app
+        .selected_event_uid
+        .iter()
+        .filter_map(|unique_id| selected_event_with_color(app, unique_id))
+        .collect();
+    if selected.is_empty() {
+        warn!("handle_export_selected_events_to_file: Selected event no longer exists");
+        app.toasts.push(fl!("toast-event-not-found"), ToastSeverity::Error, None);
+        return;
+    }
+
+    match ExportHandler::export_events(&selected, &path) {
+        Ok(count) => {
+            info!("handle_export_selected_events_to_file: Exported {} events", count);
+            app.toasts.push(
+                fl!("toast-range-exported", count = (count as i64)),
+                ToastSeverity::Success,
+                None,
+            );
+        }
+        Err(e) => {
+            error!("handle_export_selected_events_to_file: Export failed: {}", e);
+            app.toasts.push(fl!("toast-export-failed"), ToastSeverity::Error, None);
+        }
+    }
+}
+
+/// Look up a selected event (calendar_id:uid) and its calendar's color
+/// Occurrences resolve to their whole series
+fn selected_event_with_color(app: &CosmicCalendar, unique_id: &str) -> Option<(CalendarEvent, String)> {
+    let (calendar_id, uid) = unique_id.split_once(':')?;
+    let color = app
+        .calendar_manager
+        .sources()
+        .iter()
+        .find(|c| c.info().id == calendar_id)
+        .map(|c| c.info().color.clone())?;
+    let event =
+        EventHandler::find_event_in_calendar(&app.calendar_manager, calendar_id, extract_master_uid(uid)).ok()?;
+    Some((event, color))
+}
@@ -3,13 +3,23 @@
 //! Handles all import and export related messages.
 
 use crate::app::CosmicCalendar;
+use crate::caldav::CalendarEvent;
+use crate::calendars::CalendarManager;
 use crate::dialogs::{ActiveDialog, DialogAction, DialogManager};
+use crate::fl;
 use crate::message::Message;
+use crate::models::ToastSeverity;
 use crate::services::{EventHandler, ExportHandler};
 use cosmic::app::Task;
 use log::{debug, error, info, warn};
 use std::path::PathBuf;
 
+/// Large imports show the progress dialog above this many events
+const PROGRESS_THRESHOLD: usize = 10;
+
+/// Events imported per `ImportNextBatch` message
+const IMPORT_BATCH_SIZE: usize = 5;
+
 /// Handle import file message - parse the file and show import dialog
 pub fn handle_import_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Message> {
     info!("handle_import_file: Importing from {:?}", path);
@@ -25,7 +35,7 @@ pub fn handle_import_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Messa
     info!("handle_import_file: Validating file format");
     if let Err(e) = ExportHandler::validate_ical_file(&path) {
         error!("handle_import_file: Validation failed: {}", e);
-        // TODO: Show error dialog with validation details
+        app.toasts.push(fl!("toast-import-invalid"), ToastSeverity::Error, None);
         return Task::none();
     }
 
@@ -41,7 +51,7 @@ pub fn handle_import_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Messa
         Ok(events) => {
             if events.is_empty() {
                 error!("handle_import_file: No events found in file");
-                // TODO: Show error dialog or notification
+                app.toasts.push(fl!("toast-import-empty"), ToastSeverity::Error, None);
                 return Task::none();
             }
 
@@ -71,12 +81,12 @@ pub fn handle_import_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Messa
                         }
                         Err(e) => {
                             error!("handle_import_file: Failed to add event: {}", e);
-                            // TODO: Show error notification
+                            app.toasts.push(fl!("toast-import-failed"), ToastSeverity::Error, None);
                         }
                     }
                 } else {
                     error!("handle_import_file: No calendars available");
-                    // TODO: Show error notification
+                    app.toasts.push(fl!("toast-no-calendars"), ToastSeverity::Error, None);
                 }
             } else {
                 // Multiple events: Use import dialog for calendar selection
@@ -92,17 +102,45 @@ pub fn handle_import_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Messa
         }
         Err(e) => {
             error!("handle_import_file: Failed to parse file: {}", e);
-            // TODO: Show error dialog or notification
+            app.toasts.push(fl!("toast-import-invalid"), ToastSeverity::Error, None);
         }
     }
 
     Task::none()
 }
 
+/// Handle clipboard contents read for an import
+/// Only text that looks like iCalendar data is parsed; the events go through the import dialog
+pub fn handle_clipboard_import(app: &mut CosmicCalendar, content: Option<String>) -> Task<Message> {
+    let Some(content) = content.filter(|c| c.trim_start().starts_with("BEGIN:VCALENDAR")) else {
+        warn!("handle_clipboard_import: Clipboard does not contain iCalendar data");
+        app.toasts.push(fl!("toast-clipboard-not-calendar"), ToastSeverity::Info, None);
+        return Task::none();
+    };
+
+    match ExportHandler::parse_ical_string_with_name(&content) {
+        Ok((_, events)) if events.is_empty() => {
+            error!("handle_clipboard_import: No events found in clipboard data");
+            app.toasts.push(fl!("toast-import-empty"), ToastSeverity::Error, None);
+            Task::none()
+        }
+        Ok((calendar_name, events)) => {
+            info!("handle_clipboard_import: Parsed {} events", events.len());
+            let source_name = fl!("import-source-clipboard", name = calendar_name);
+            Task::done(cosmic::Action::App(Message::ShowImportDialog(events, source_name)))
+        }
+        Err(e) => {
+            error!("handle_clipboard_import: Failed to parse clipboard data: {}", e);
+            app.toasts.push(fl!("toast-import-invalid"), ToastSeverity::Error, None);
+            Task::none()
+        }
+    }
+}
+
 /// Handle show import dialog message (events already parsed)
 pub fn handle_show_import_dialog(
     app: &mut CosmicCalendar,
-    events: Vec<crate::caldav::CalendarEvent>,
+    events: Vec<CalendarEvent>,
     source_file_name: String,
 ) -> Task<Message> {
     info!(
@@ -186,83 +224,130 @@ pub fn handle_confirm_import(app: &mut CosmicCalendar) -> Task<Message> {
         target_calendar_id
     );
 
-    // For large imports (>10 events), show progress dialog
-    const PROGRESS_THRESHOLD: usize = 10;
+    // For large imports (>10 events), show progress dialog and import in batches;
+    // a cancel deletes and restores what the finished batches wrote
     if events.len() > PROGRESS_THRESHOLD {
         info!("handle_confirm_import: Large import detected, showing progress dialog");
-        // Open progress dialog
         app.active_dialog = ActiveDialog::ImportProgress {
             current: 0,
             total: events.len(),
             current_event: String::new(),
             import_log: Vec::new(),
             imported_uids: Vec::new(),
-            calendar_id: target_calendar_id.clone(),
+            calendar_id: target_calendar_id,
+            events,
+            replaced_events: Vec::new(),
+            skipped_count: 0,
+            failed_count: 0,
+            source_file_name,
         };
+        return Task::done(cosmic::Action::App(Message::ImportNextBatch));
     }
 
-    // Import events one by one using the event handler
-    let mut imported_count = 0;
-    let mut skipped_count = 0;
+    // Small imports run in one go
     let mut imported_uids = Vec::new();
-
-    for (index, event) in events.iter().enumerate() {
-        // Check if event already exists in the TARGET calendar (by UID)
-        let exists = app
-            .calendar_manager
-            .sources()
-            .iter()
-            .find(|cal| cal.info().id == target_calendar_id)
-            .and_then(|cal| {
-                cal.fetch_events()
-                    .ok()
-                    .map(|events| events.iter().any(|e| e.uid == event.uid))
-            })
-            .unwrap_or(false);
-
-        if exists {
-            debug!("handle_confirm_import: Skipping duplicate event uid={} in target calendar", event.uid);
-            skipped_count += 1;
-            continue;
+    let mut replaced_events = Vec::new();
+    let mut skipped_count = 0;
+    let mut failed_count = 0;
+
+    for event in &events {
+        match import_event(&mut app.calendar_manager, &target_calendar_id, event) {
+            ImportOutcome::Imported => imported_uids.push(event.uid.clone()),
+            ImportOutcome::Updated(previous) => replaced_events.push(*previous),
+            ImportOutcome::Skipped => skipped_count += 1,
+            ImportOutcome::Failed => failed_count += 1,
         }
+    }
 
-        // Add event to the target calendar
-        match EventHandler::add_event(&mut app.calendar_manager, &target_calendar_id, event.clone()) {
-            Ok(_) => {
-                imported_count += 1;
-                imported_uids.push(event.uid.clone());
+    show_import_result(
+        app,
+        imported_uids,
+        replaced_events,
+        skipped_count,
+        failed_count,
+        source_file_name,
+        target_calendar_id,
+    );
 
-                // Update progress dialog if open
-                if let ActiveDialog::ImportProgress {
-                    current,
-                    current_event,
-                    import_log,
-                    ..
-                } = &mut app.active_dialog
-                {
-                    *current = index + 1;
-                    *current_event = event.summary.clone();
-                    import_log.push(format!("✓ Imported: {}", event.summary));
-                }
-            }
+    Task::none()
+}
+
+/// Result of importing a single event
+enum ImportOutcome {
+    Imported,
+    /// An event with the same UID was updated; holds its previous version
+    Updated(Box<CalendarEvent>),
+    Skipped,
+    Failed,
+}
+
+/// Import one event into the target calendar, merging by UID: an existing
+/// event with the same UID is updated when the details differ and skipped otherwise
+fn import_event(manager: &mut CalendarManager, calendar_id: &str, event: &CalendarEvent) -> ImportOutcome {
+    // Check if event already exists in the TARGET calendar (by UID)
+    let existing = manager
+        .sources()
+        .iter()
+        .find(|cal| cal.info().id == calendar_id)
+        .and_then(|cal| cal.fetch_events().ok())
+        .and_then(|events| events.into_iter().find(|e| e.uid == event.uid));
+
+    let Some(existing) = existing else {
+        return match EventHandler::add_event(manager, calendar_id, event.clone()) {
+            Ok(_) => ImportOutcome::Imported,
             Err(e) => {
-                error!("handle_confirm_import: Failed to import event: {}", e);
-
-                // Log error in progress dialog if open
-                if let ActiveDialog::ImportProgress {
-                    import_log,
-                    ..
-                } = &mut app.active_dialog
-                {
-                    import_log.push(format!("✗ Failed: {}", event.summary));
-                }
+                error!("import_event: Failed to import event uid={}: {}", event.uid, e);
+                ImportOutcome::Failed
             }
+        };
+    };
+
+    // The manual all-day order isn't part of the file - keep the local one
+    let mut merged = event.clone();
+    merged.display_order = existing.display_order;
+    if merged == existing {
+        debug!("import_event: Skipping duplicate event uid={} in target calendar", event.uid);
+        return ImportOutcome::Skipped;
+    }
+
+    match EventHandler::update_event(manager, calendar_id, merged) {
+        Ok(_) => {
+            debug!("import_event: Updated existing event uid={} in target calendar", event.uid);
+            ImportOutcome::Updated(Box::new(existing))
+        }
+        Err(e) => {
+            error!("import_event: Failed to update event uid={}: {}", event.uid, e);
+            ImportOutcome::Failed
+        }
+    }
+}
+
+/// Put back the previous versions of events an import updated
+fn restore_replaced_events(manager: &mut CalendarManager, calendar_id: &str, replaced_events: Vec<CalendarEvent>) {
+    for event in replaced_events {
+        let uid = event.uid.clone();
+        if let Err(e) = EventHandler::update_event(manager, calendar_id, event) {
+            error!("restore_replaced_events: Failed to restore event uid={}: {}", uid, e);
         }
     }
+}
 
+/// Refresh the views and replace the current dialog with the import result
+fn show_import_result(
+    app: &mut CosmicCalendar,
+    imported_uids: Vec<String>,
+    replaced_events: Vec<CalendarEvent>,
+    skipped_count: usize,
+    failed_count: usize,
+    source_file_name: String,
+    calendar_id: String,
+) {
     info!(
-        "handle_confirm_import: Successfully imported {} events (skipped {} duplicates)",
-        imported_count, skipped_count
+        "show_import_result: Imported {} events (updated {}, skipped {} duplicates, {} failed)",
+        imported_uids.len(),
+        replaced_events.len(),
+        skipped_count,
+        failed_count
     );
 
     // Refresh the calendar view
@@ -271,29 +356,116 @@ pub fn handle_confirm_import(app: &mut CosmicCalendar) -> Task<Message> {
     // Get calendar name for display
     let calendar_name = app.calendar_manager.sources()
         .iter()
-        .find(|cal| cal.info().id == target_calendar_id)
+        .find(|cal| cal.info().id == calendar_id)
         .map(|cal| cal.info().name.clone())
-        .unwrap_or_else(|| target_calendar_id.clone());
+        .unwrap_or_else(|| calendar_id.clone());
 
-    // Show import result dialog
-    let success = imported_count > 0 || skipped_count > 0;
-    let failed_count = events.len() - imported_count - skipped_count;
+    let success = !imported_uids.is_empty() || !replaced_events.is_empty() || skipped_count > 0;
 
     app.active_dialog = ActiveDialog::ImportResult {
         success,
-        imported_count,
+        imported_count: imported_uids.len(),
+        updated_count: replaced_events.len(),
         skipped_count,
         failed_count,
         source_file_name,
         calendar_name,
         imported_uids,
-        calendar_id: target_calendar_id,
+        replaced_events,
+        calendar_id,
         error_message: None,
     };
+}
+
+/// Handle import next batch message - import a few events and schedule the next batch
+/// Returning between batches lets a cancel from the progress dialog run mid-import
+pub fn handle_import_next_batch(app: &mut CosmicCalendar) -> Task<Message> {
+    if !matches!(app.active_dialog, ActiveDialog::ImportProgress { .. }) {
+        // The import was cancelled before this batch ran
+        debug!("handle_import_next_batch: No import in progress");
+        return Task::none();
+    }
+
+    if !run_import_batch(&mut app.calendar_manager, &mut app.active_dialog) {
+        return Task::done(cosmic::Action::App(Message::ImportNextBatch));
+    }
+
+    if let ActiveDialog::ImportProgress {
+        imported_uids,
+        replaced_events,
+        skipped_count,
+        failed_count,
+        source_file_name,
+        calendar_id,
+        ..
+    } = std::mem::take(&mut app.active_dialog)
+    {
+        show_import_result(
+            app,
+            imported_uids,
+            replaced_events,
+            skipped_count,
+            failed_count,
+            source_file_name,
+            calendar_id,
+        );
+    }
 
     Task::none()
 }
 
+/// Import the next batch of the progress dialog's events, recording each outcome
+/// The batch is written in one transaction that is committed before returning, so
+/// edits made between batches are never part of the import (or of its cancel)
+/// Returns whether every event has been handled
+fn run_import_batch(manager: &mut CalendarManager, dialog: &mut ActiveDialog) -> bool {
+    let ActiveDialog::ImportProgress {
+        current,
+        total,
+        current_event,
+        import_log,
+        imported_uids,
+        calendar_id,
+        events,
+        replaced_events,
+        skipped_count,
+        failed_count,
+        ..
+    } = dialog
+    else {
+        return true;
+    };
+
+    if let Err(e) = manager.begin_batch() {
+        warn!("run_import_batch: Could not start batch transaction: {}", e);
+    }
+    for event in events.iter().skip(*current).take(IMPORT_BATCH_SIZE) {
+        let outcome = import_event(manager, calendar_id, event);
+        *current += 1;
+        *current_event = event.summary.clone();
+        match outcome {
+            ImportOutcome::Imported => {
+                imported_uids.push(event.uid.clone());
+                import_log.push(format!("✓ Imported: {}", event.summary));
+            }
+            ImportOutcome::Updated(previous) => {
+                replaced_events.push(*previous);
+                import_log.push(format!("↻ Updated: {}", event.summary));
+            }
+            ImportOutcome::Skipped => *skipped_count += 1,
+            ImportOutcome::Failed => {
+                *failed_count += 1;
+                import_log.push(format!("✗ Failed: {}", event.summary));
+            }
+        }
+    }
+    if let Err(e) = manager.commit_batch() {
+        error!("run_import_batch: Failed to commit batch: {}", e);
+    }
+
+    *current >= *total
+}
+
 /// Handle cancel import message
 pub fn handle_cancel_import(app: &mut CosmicCalendar) -> Task<Message> {
     debug!("handle_cancel_import: Canceling import");
@@ -305,13 +477,14 @@ pub fn handle_cancel_import(app: &mut CosmicCalendar) -> Task<Message> {
 pub fn handle_cancel_import_progress(app: &mut CosmicCalendar) -> Task<Message> {
     info!("handle_cancel_import_progress: Canceling import and rolling back");
 
-    // Extract imported UIDs and calendar ID from progress dialog
-    let (imported_uids, _calendar_id) = match &app.active_dialog {
+    // Extract imported UIDs, replaced events and calendar ID from progress dialog
+    let (imported_uids, replaced_events, calendar_id) = match &app.active_dialog {
         ActiveDialog::ImportProgress {
             imported_uids,
+            replaced_events,
             calendar_id,
             ..
-        } => (imported_uids.clone(), calendar_id.clone()),
+        } => (imported_uids.clone(), replaced_events.clone(), calendar_id.clone()),
         _ => {
             warn!("handle_cancel_import_progress: Not in import progress state");
             DialogManager::close(&mut app.active_dialog);
@@ -319,49 +492,85 @@ pub fn handle_cancel_import_progress(app: &mut CosmicCalendar) -> Task<Message>
         }
     };
 
+    let undone = roll_back_import(&mut app.calendar_manager, &calendar_id, &imported_uids, replaced_events);
+
+    // Refresh the calendar view
+    app.refresh_cached_events();
+
+    // Close the progress dialog
+    DialogManager::close(&mut app.active_dialog);
+
+    app.toasts.push(
+        fl!("toast-import-cancelled", count = (undone as i64)),
+        ToastSeverity::Info,
+        None,
+    );
+    Task::none()
+}
+
+/// Undo a cancelled import: delete the events its finished batches created and put
+/// back the previous versions of the ones they updated. Returns the number of events undone.
+fn roll_back_import(
+    manager: &mut CalendarManager,
+    calendar_id: &str,
+    imported_uids: &[String],
+    replaced_events: Vec<CalendarEvent>,
+) -> usize {
     info!(
-        "handle_cancel_import_progress: Rolling back {} imported events",
-        imported_uids.len()
+        "roll_back_import: Rolling back {} imported and {} updated events",
+        imported_uids.len(),
+        replaced_events.len()
     );
+    let undone = imported_uids.len() + replaced_events.len();
 
-    // Delete all imported events (rollback)
-    for uid in &imported_uids {
-        match EventHandler::delete_event(&mut app.calendar_manager, uid) {
+    let current_events = manager
+        .sources()
+        .iter()
+        .find(|cal| cal.info().id == calendar_id)
+        .and_then(|cal| cal.fetch_events().ok())
+        .unwrap_or_default();
+    let remaining: Vec<String> = current_events
+        .iter()
+        .filter(|e| imported_uids.contains(&e.uid))
+        .map(|e| e.uid.clone())
+        .collect();
+    let still_replaced: Vec<CalendarEvent> = replaced_events
+        .into_iter()
+        .filter(|previous| current_events.iter().any(|e| e.uid == previous.uid && e != previous))
+        .collect();
+    restore_replaced_events(manager, calendar_id, still_replaced);
+
+    for uid in &remaining {
+        match EventHandler::delete_event_in_calendar(manager, calendar_id, uid) {
             Ok(_) => {
-                debug!("handle_cancel_import_progress: Rolled back event uid={}", uid);
+                debug!("roll_back_import: Rolled back event uid={}", uid);
             }
             Err(e) => {
-                error!("handle_cancel_import_progress: Failed to rollback event uid={}: {}", uid, e);
+                error!("roll_back_import: Failed to rollback event uid={}: {}", uid, e);
             }
         }
     }
 
     info!(
-        "handle_cancel_import_progress: Rollback complete - deleted {} events",
-        imported_uids.len()
+        "roll_back_import: Rollback complete - deleted {} events, restored {}",
+        remaining.len(),
+        undone - imported_uids.len()
     );
-
-    // Refresh the calendar view
-    app.refresh_cached_events();
-
-    // Close the progress dialog
-    DialogManager::close(&mut app.active_dialog);
-
-    // TODO: Show cancellation notification
-    Task::none()
+    undone
 }
 
 /// Handle revert import message - rollback completed import
 pub fn handle_revert_import(app: &mut CosmicCalendar) -> Task<Message> {
     info!("handle_revert_import: Reverting import");
 
-    // Extract imported UIDs and calendar ID from result dialog
-    let (imported_uids, _calendar_id) = match &app.active_dialog {
+    // Extract imported UIDs, replaced events and calendar ID from result dialog
+    let (imported_uids, replaced_events, calendar_id) = match &app.active_dialog {
         ActiveDialog::ImportResult {
             imported_uids,
+            replaced_events,
             calendar_id,
             ..
-        } => (imported_uids.clone(), calendar_id.clone()),
+        } => (imported_uids.clone(), replaced_events.clone(), calendar_id.clone()),
         _ => {
             warn!("handle_revert_import: Not in import result state");
             DialogManager::close(&mut app.active_dialog);
@@ -370,13 +579,15 @@ pub fn handle_revert_import(app: &mut CosmicCalendar) -> Task<Message> {
     };
 
     info!(
-        "handle_revert_import: Reverting {} imported events",
-        imported_uids.len()
+        "handle_revert_import: Reverting {} imported and {} updated events",
+        imported_uids.len(),
+        replaced_events.len()
     );
+    let undone = imported_uids.len() + replaced_events.len();
 
     // Delete all imported events (revert)
     for uid in &imported_uids {
-        match EventHandler::delete_event(&mut app.calendar_manager, uid) {
+        match EventHandler::delete_event_in_calendar(&mut app.calendar_manager, &calendar_id, uid) {
             Ok(_) => {
                 debug!("handle_revert_import: Reverted event uid={}", uid);
             }
@@ -386,9 +597,13 @@ pub fn handle_revert_import(app: &mut CosmicCalendar) -> Task<Message> {
         }
     }
 
+    // Put back the events the import updated
+    restore_replaced_events(&mut app.calendar_manager, &calendar_id, replaced_events);
+
     info!(
-        "handle_revert_import: Revert complete - deleted {} events",
-        imported_uids.len()
+        "handle_revert_import: Revert complete - deleted {} events, restored {}",
+        imported_uids.len(),
+        undone - imported_uids.len()
     );
 
     // Refresh the calendar view
@@ -397,6 +612,121 @@ pub fn handle_revert_import(app: &mut CosmicCalendar) -> Task<Message> {
     // Close the result dialog
     DialogManager::close(&mut app.active_dialog);
 
-    // TODO: Show revert notification
+    app.toasts.push(
+        fl!("toast-import-reverted", count = (undone as i64)),
+        ToastSeverity::Info,
+        None,
+    );
     Task::none()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendars::LocalCalendar;
+    use crate::database::{create_import_event, Database};
+
+    /// Manager with one local calendar "cal1" on a fresh database
+    fn test_manager(file_name: &str) -> (CalendarManager, PathBuf) {
+        let db_path = std::env::temp_dir().join(file_name);
+        let _ = std::fs::remove_file(&db_path);
+        let mut manager = CalendarManager::with_database(Database::open_at(db_path.clone()).unwrap());
+        let calendar = LocalCalendar::new("cal1".to_string(), "Test".to_string(), manager.database());
+        manager.add_source(Box::new(calendar));
+        (manager, db_path)
+    }
+
+    fn calendar_events(manager: &CalendarManager) -> Vec<CalendarEvent> {
+        let mut events = manager.sources()[0].fetch_events().unwrap();
+        events.sort_by(|a, b| a.uid.cmp(&b.uid));
+        events
+    }
+
+    #[test]
+    fn test_import_event_merges_by_uid() {
+        let (mut manager, db_path) = test_manager("sol_test_import_merge.db");
+
+        // A new UID is created (with a manual all-day order, as if reordered locally)
+        let mut local = create_import_event(1);
+        local.display_order = Some(2);
+        assert!(matches!(import_event(&mut manager, "cal1", &local), ImportOutcome::Imported));
+
+        // The same details again are skipped, even though the file has no display order
+        let identical = create_import_event(1);
+        assert!(matches!(import_event(&mut manager, "cal1", &identical), ImportOutcome::Skipped));
+
+        // Changed details update the event and hand back the previous version for revert
+        let mut changed = create_import_event(1);
+        changed.summary = "Moved standup".to_string();
+        match import_event(&mut manager, "cal1", &changed) {
+            ImportOutcome::Updated(previous) => {
+                assert_eq!(previous.summary, "Imported 1");
+                assert_eq!(previous.display_order, Some(2));
+            }
+            _ => panic!("existing event with new details should be updated"),
+        }
+        let events = calendar_events(&manager);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].summary, "Moved standup");
+        // The local all-day order survives the update
+        assert_eq!(events[0].display_order, Some(2));
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[test]
+    fn test_cancel_import_after_first_batch() {
+        let (mut manager, db_path) = test_manager("sol_test_cancel_import_batch.db");
+
+        // An event that existed before the import, which the import changes
+        EventHandler::add_event(&mut manager, "cal1", create_import_event(0)).unwrap();
+        let mut changed = create_import_event(0);
+        changed.summary = "Changed by import".to_string();
+        let mut events = vec![changed];
+        events.extend((1..=11).map(create_import_event));
+
+        let mut dialog = ActiveDialog::ImportProgress {
+            current: 0,
+            total: events.len(),
+            current_event: String::new(),
+            import_log: Vec::new(),
+            imported_uids: Vec::new(),
+            calendar_id: "cal1".to_string(),
+            events,
+            replaced_events: Vec::new(),
+            skipped_count: 0,
+            failed_count: 0,
+            source_file_name: "test.ics".to_string(),
+        };
+
+        // First batch: one update and four new events, then more batches to go
+        assert!(!run_import_batch(&mut manager, &mut dialog));
+        // The batch is committed before the UI gets a turn
+        assert!(!manager.database().lock().unwrap().in_transaction());
+
+        // An event the user adds while the import runs isn't part of it
+        let mut edited = create_import_event(99);
+        edited.uid = "user-event".to_string();
+        EventHandler::add_event(&mut manager, "cal1", edited).unwrap();
+
+        let ActiveDialog::ImportProgress { current, imported_uids, replaced_events, .. } = dialog else {
+            panic!("import progress dialog expected");
+        };
+        assert_eq!(current, IMPORT_BATCH_SIZE);
+        assert_eq!(replaced_events.len(), 1);
+        let during = calendar_events(&manager);
+        assert_eq!(during.len(), IMPORT_BATCH_SIZE + 1);
+        assert_eq!(during[0].summary, "Changed by import");
+
+        // Cancelling undoes the whole batch and reports it, keeping the user's event
+        let undone = roll_back_import(&mut manager, "cal1", &imported_uids, replaced_events);
+        assert_eq!(undone, IMPORT_BATCH_SIZE);
+        let after = calendar_events(&manager);
+        assert_eq!(after.len(), 2);
+        assert_eq!(after[0].uid, "import-0");
+        assert_eq!(after[0].summary, "Imported 0");
+        assert_eq!(after[1].uid, "user-event");
+
+        let _ = std::fs::remove_file(&db_path);
+    }
+}
@@ -7,6 +7,10 @@
 //! - `calendar`: Calendar management (create, edit, delete, toggle, color)
 //! - `event`: Event management (quick events, create, delete)
 //! - `selection`: Drag selection for multi-day event creation
+//! - `sync`: Sync conflict resolution
+//! - `auto_export`: Writing calendars to their auto-export .ics files
+//! - `month_stats`: Statistics dialog for the displayed month
+//! - `view_image`: Exporting the current view as a PNG image
 //!
 //! ## Helper Functions
 //!
@@ -15,27 +19,38 @@
 //! - [`dismiss_on_focus_loss`]: Clear transient UI state when user navigates away
 //! - [`focus_quick_event_input`]: Focus the quick event text input
 //! - [`scroll_week_to_current_time`]: Scroll week view to show current time
+//! - [`scroll_week_to_start`]: Scroll week view to the configured start position
 //! - [`scroll_week_to_hour`]: Scroll week view to a specific hour
-//! - [`close_legacy_event_dialog`]: Close deprecated event dialog field
+//! - [`close_legacy_event_dialog`]: Close deprecated event dialog field unless it has unsaved changes
 //! - [`schedule_deferred_scroll_restore`]: Schedule scroll position restoration
 //! - [`close_quick_event_with_scroll_restore`]: Close quick event and restore scroll
 
+mod auto_export;
 mod calendar;
+mod day_note;
 mod event;
+mod export_range;
 mod import;
+mod month_stats;
 mod navigation;
 mod selection;
+mod sync;
+mod view_image;
 
-use chrono::{NaiveDate, Timelike};
+use chrono::{Datelike, NaiveDate, Timelike};
 use cosmic::app::Task;
 use cosmic::iced::widget::scrollable;
 use log::{debug, error, info, warn};
 
 use crate::app::CosmicCalendar;
-use crate::components::quick_event_input_id;
-use crate::dialogs::{ActiveDialog, DialogManager};
+use crate::app::EventDialogField;
+use crate::components::{calendar_dialog_group_input_id, event_dialog_input_id, inline_rename_input_id, quick_event_input_id};
+use crate::dialogs::{command_palette_input_id, ActiveDialog, DialogManager};
+use crate::fl;
 use crate::message::Message;
-use crate::services::{ExportHandler, SettingsHandler};
+use crate::models::ToastSeverity;
+use crate::services::{CalendarHandler, ExportHandler, SettingsHandler};
+use crate::settings::WeekScrollStart;
 use crate::views::{week_time_grid_id, CalendarView};
 use crate::ui_constants::HOUR_ROW_HEIGHT;
 use cosmic::iced_widget::text_input;
@@ -54,6 +69,40 @@ fn focus_quick_event_input() -> Task<Message> {
     text_input::focus(quick_event_input_id())
 }
 
+/// Focus the quick event input after a timed quick event was started
+fn focus_timed_quick_event(app: &mut CosmicCalendar) -> Task<Message> {
+    if let Some((start_time, _end_time)) = app.active_dialog.quick_event_times() {
+        // Save the current scroll position BEFORE we scroll to show the input
+        // This will be used to restore when the quick event is canceled/committed
+        app.week_view_scroll_restore = app.week_view_scroll_opt;
+        // Chain: focus input, then scroll to the start time position
+        // The scroll ensures the input is visible after focusing
+        let focus_task = focus_quick_event_input();
+        let scroll_task = scroll_week_to_hour(start_time.hour());
+        Task::batch([focus_task, scroll_task])
+    } else if app.active_dialog.is_quick_event() {
+        focus_quick_event_input()
+    } else {
+        Task::none()
+    }
+}
+
+/// Move the event dialog focus to the next (or previous) control
+/// Every focusable widget takes part, so Tab walks the whole form in layout
+/// order: text fields, date and time pickers, calendar, repeat, notes and the
+/// Save/Cancel buttons
+fn focus_event_dialog_field(app: &mut CosmicCalendar, forward: bool) -> Task<Message> {
+    #[allow(deprecated)]
+    if app.event_dialog.is_none() {
+        return Task::none();
+    }
+    if forward {
+        cosmic::iced_widget::focus_next()
+    } else {
+        cosmic::iced_widget::focus_previous()
+    }
+}
+
 /// Scroll the week view time grid to the current time
 /// Returns a Task that scrolls to show the current hour (offset by 1-2 hours to show some past)
 #[inline]
@@ -73,6 +122,26 @@ fn scroll_week_to_current_time() -> Task<Message> {
     )
 }
 
+/// Record the open view so the "last used" startup option can restore it
+fn remember_current_view(app: &mut CosmicCalendar) {
+    if let Err(e) = SettingsHandler::set_last_view(&mut app.settings, app.current_view) {
+        log::error!("Failed to remember last view: {}", e);
+    }
+}
+
+/// Scroll the week view time grid to the configured starting position
+/// Used when entering week view; honors the `week_scroll_start` setting
+fn scroll_week_to_start(app: &CosmicCalendar) -> Task<Message> {
+    match app.settings.week_scroll_start {
+        WeekScrollStart::CurrentTime => scroll_week_to_current_time(),
+        WeekScrollStart::WorkingHours => scroll_week_to_hour(app.settings.working_hours_start),
+        WeekScrollStart::Top => scrollable::scroll_to(
+            week_time_grid_id(),
+            scrollable::AbsoluteOffset { x: 0.0, y: 0.0 },
+        ),
+    }
+}
+
 /// Scroll the week view time grid to a specific hour
 /// Used to keep the view stable when focusing quick event input
 #[inline]
@@ -88,11 +157,16 @@ fn scroll_week_to_hour(hour: u32) -> Task<Message> {
 }
 
 /// Close the legacy event dialog field
+/// Unsaved changes ask to discard first; Escape on that prompt keeps editing
 /// This helper is kept because text_editor::Content doesn't implement Clone
 #[allow(deprecated)]
 #[inline]
 fn close_legacy_event_dialog(app: &mut CosmicCalendar) {
-    app.event_dialog = None;
+    if app.event_dialog.as_ref().is_some_and(|dialog| dialog.confirm_discard) {
+        handle_keep_editing_event_dialog(app);
+    } else {
+        handle_cancel_event_dialog(app);
+    }
 }
 
 /// Schedule a deferred scroll restore if there's a saved restore position
@@ -131,11 +205,11 @@ fn handle_export_calendar_to_file(
     match ExportHandler::export_to_file(&app.calendar_manager, &calendar_id, &path) {
         Ok(()) => {
             info!("Exported calendar '{}' to {:?}", calendar_id, path);
-            // TODO: Show success toast notification
+            app.toasts.push(fl!("toast-calendar-exported"), ToastSeverity::Success, None);
         }
         Err(e) => {
             error!("Failed to export calendar '{}': {}", calendar_id, e);
-            // TODO: Show error toast notification
+            app.toasts.push(fl!("toast-export-failed"), ToastSeverity::Error, None);
         }
     }
 
@@ -191,21 +265,19 @@ fn handle_process_url(app: &mut CosmicCalendar, url: String) -> Task<Message> {
                     app.current_view = new_view;
                 }
                 UrlAction::CreateEvent { summary, start, end, location } => {
-                    info!("URL Action: CreateEvent - summary={:?}, start={:?}, end={:?}, location={:?}",
-                          summary, start, end, location);
-                    // TODO: Open event dialog with pre-filled data
-                    info!("Would open event dialog with pre-filled data");
+                    info!("URL Action: CreateEvent - start={:?}, end={:?}", start, end);
+                    handle_open_event_dialog_from_url(app, summary, start, end, location);
+                    return text_input::focus(event_dialog_input_id(EventDialogField::Title));
                 }
                 UrlAction::ViewEvent { uid } => {
                     info!("URL Action: ViewEvent uid={}", uid);
-                    // TODO: Find and select the event
-                    info!("Would select and show event: {}", uid);
+                    handle_view_event(app, &uid);
                 }
             }
         }
         Err(e) => {
             error!("Failed to parse URL '{}': {}", url, e);
-            // TODO: Show error dialog
+            app.toasts.push(fl!("toast-invalid-link"), ToastSeverity::Error, None);
         }
     }
 
@@ -240,7 +312,7 @@ fn handle_process_downloaded_calendar(
         }
         Err(e) => {
             error!("Failed to parse calendar data: {}", e);
-            // TODO: Show error dialog
+            app.toasts.push(fl!("toast-calendar-unreadable"), ToastSeverity::Error, None);
             Task::none()
         }
     }
@@ -306,7 +378,7 @@ fn handle_update_subscription_calendar_name(
 }
 
 fn handle_confirm_subscription(app: &mut CosmicCalendar) -> Task<Message> {
-    use crate::services::{CalendarHandler, NewCalendarData};
+    use crate::services::NewCalendarData;
 
     if let ActiveDialog::SubscribeCalendar {
         url,
@@ -330,6 +402,10 @@ fn handle_confirm_subscription(app: &mut CosmicCalendar) -> Task<Message> {
             let new_calendar_data = NewCalendarData {
                 name: new_name.to_string(),
                 color: "#3584e4".to_string(), // Default blue color
+                group: None,
+                // Subscriptions are a snapshot of the remote calendar - edits would be lost on refresh
+                read_only: true,
+                default_duration_minutes: None,
             };
 
             match CalendarHandler::create(&mut app.calendar_manager, new_calendar_data) {
@@ -379,27 +455,53 @@ fn handle_cancel_subscription(app: &mut CosmicCalendar) -> Task<Message> {
 
 // Re-export handlers for use in this module
 use calendar::{
-    handle_change_calendar_color, handle_confirm_calendar_dialog, handle_confirm_delete_calendar,
+    handle_apply_palette, handle_change_calendar_color, handle_confirm_calendar_dialog, handle_confirm_delete_calendar,
     handle_delete_selected_calendar, handle_export_calendar_dialog,
     handle_open_calendar_dialog_create, handle_open_calendar_dialog_edit,
     handle_request_delete_calendar, handle_toggle_calendar,
 };
 use event::{
     extract_master_uid, extract_occurrence_date, handle_cancel_event_dialog, handle_cancel_quick_event,
-    handle_commit_quick_event, handle_confirm_event_dialog, handle_delete_event,
+    handle_begin_inline_rename, handle_commit_inline_rename, handle_inline_rename_changed,
+    handle_commit_command_palette, handle_commit_quick_event, handle_confirm_convert_to_recurring, handle_confirm_copy_week,
+    handle_confirm_event_dialog, handle_copy_week_forward, handle_create_quick_event_anyway,
+    handle_confirm_merge_duplicates, handle_convert_to_recurring, handle_copy_event_link,
+    handle_delete_event, handle_discard_event_dialog, handle_find_duplicate_events,
     handle_drag_event_cancel, handle_drag_event_end, handle_drag_event_start,
-    handle_drag_event_update, handle_open_edit_event_dialog, handle_open_new_event_dialog,
-    handle_quick_event_text_changed, handle_select_event, handle_start_quick_event,
-    handle_start_quick_timed_event,
+    handle_drag_event_update, handle_open_edit_event_dialog, handle_open_event_dialog_from_url,
+    handle_create_on_picked_dates, handle_open_new_event_dialog,
+    handle_quick_event_text_changed, handle_select_event, handle_skip_occurrence,
+    handle_keep_editing_event_dialog, handle_start_quick_event, handle_start_quick_timed_event,
+    handle_confirm_make_timed, handle_toggle_event_all_day, handle_undo_event_move,
+    handle_undo_skip_occurrence, handle_view_event, refuse_read_only,
+};
+use day_note::{handle_edit_day_note, handle_save_day_note};
+use export_range::{
+    handle_confirm_export_range, handle_export_range_to_file, handle_export_range_toggle_calendar,
+    handle_export_selected_events, handle_export_selected_events_to_file, handle_open_export_range_dialog,
+};
+use month_stats::handle_show_month_stats;
+use navigation::{handle_next_period, handle_peek_next_period, handle_peek_previous_period, handle_previous_period};
+use view_image::{
+    handle_export_view_image, handle_export_view_image_to_file, handle_view_image_captured,
+    handle_view_image_saved,
 };
-use navigation::{handle_next_period, handle_previous_period};
 use selection::{
     handle_selection_cancel, handle_selection_end, handle_selection_start, handle_selection_update,
     handle_time_selection_start, handle_time_selection_update, handle_time_selection_end,
+    handle_quick_event_at_clicked_time,
 };
 
 /// Handle all application messages and update state
 pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Message> {
+    let task = dispatch_message(app, message);
+    // Sync conflicts queued behind another dialog are shown once it has closed
+    sync::show_next_sync_conflict(app);
+    task
+}
+
+/// Route a message to its handler
+fn dispatch_message(app: &mut CosmicCalendar, message: Message) -> Task<Message> {
     // Sync sidebar with condensed state on every update
     let is_condensed = app.core.is_condensed();
     if is_condensed != app.last_condensed {
@@ -417,6 +519,21 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         }
         Message::CloseDialog => {
             debug!("Message::CloseDialog: Closing dialogs");
+            // Closing the progress dialog mid-import cancels it so the finished batches are undone
+            if matches!(app.active_dialog, ActiveDialog::ImportProgress { .. }) {
+                return import::handle_cancel_import_progress(app);
+            }
+            // With nothing open, Escape leaves focus mode
+            #[allow(deprecated)]
+            let nothing_open = !app.active_dialog.is_open() && app.event_dialog.is_none();
+            if nothing_open {
+                app.selection_state.clear_picked();
+            }
+            if nothing_open && app.focus_mode {
+                info!("Focus mode set to false");
+                app.focus_mode = false;
+                app.refresh_cached_events();
+            }
             // Close legacy event dialog
             close_legacy_event_dialog(app);
             // For quick events: only dismiss if empty (focus loss behavior)
@@ -436,30 +553,33 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::ChangeView(view) => {
             dismiss_on_focus_loss(app);
             app.current_view = view;
+            remember_current_view(app);
             app.sync_views_to_selected_date();
-            // Auto-scroll to current time when entering week view
-            if view == CalendarView::Week {
-                return scroll_week_to_current_time();
+            // Auto-scroll to the configured start position when entering a timeline view
+            if matches!(view, CalendarView::Day | CalendarView::Week) {
+                return scroll_week_to_start(app);
             }
         }
         Message::CycleViewNext => {
             dismiss_on_focus_loss(app);
             let new_view = app.current_view.next();
             app.current_view = new_view;
+            remember_current_view(app);
             app.sync_views_to_selected_date();
-            // Auto-scroll to current time when entering week view
-            if new_view == CalendarView::Week {
-                return scroll_week_to_current_time();
+            // Auto-scroll to the configured start position when entering a timeline view
+            if matches!(new_view, CalendarView::Day | CalendarView::Week) {
+                return scroll_week_to_start(app);
             }
         }
         Message::CycleViewPrevious => {
             dismiss_on_focus_loss(app);
             let new_view = app.current_view.previous();
             app.current_view = new_view;
+            remember_current_view(app);
             app.sync_views_to_selected_date();
-            // Auto-scroll to current time when entering week view
-            if new_view == CalendarView::Week {
-                return scroll_week_to_current_time();
+            // Auto-scroll to the configured start position when entering a timeline view
+            if matches!(new_view, CalendarView::Day | CalendarView::Week) {
+                return scroll_week_to_start(app);
             }
         }
         Message::PreviousPeriod => {
@@ -470,10 +590,32 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             dismiss_on_focus_loss(app);
             handle_next_period(app);
         }
+        Message::PeekPreviousPeriod => {
+            dismiss_on_focus_loss(app);
+            handle_peek_previous_period(app);
+        }
+        Message::PeekNextPeriod => {
+            dismiss_on_focus_loss(app);
+            handle_peek_next_period(app);
+        }
+        Message::ReturnToSelectedDate => {
+            dismiss_on_focus_loss(app);
+            if app.peek_date.is_some() {
+                app.sync_views_to_selected_date();
+            }
+        }
         Message::Today => {
             dismiss_on_focus_loss(app);
             app.navigate_to_today();
         }
+        Message::JumpToNow => {
+            dismiss_on_focus_loss(app);
+            app.navigate_to_today();
+            // Timeline views also scroll so the current time indicator is visible
+            if matches!(app.current_view, CalendarView::Day | CalendarView::Week) {
+                return scroll_week_to_current_time();
+            }
+        }
         Message::SelectDay(year, month, day) => {
             dismiss_on_focus_loss(app);
             if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
@@ -489,6 +631,30 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::TimeTick => {
             // Timer tick to update the current time indicator
             // The view will re-render with the new time automatically
+            if app.pending_move_undo.as_ref().is_some_and(|undo| undo.is_expired()) {
+                app.pending_move_undo = None;
+            }
+            app.toasts.expire();
+            return app.save_event_cache();
+        }
+        Message::ReloadCachedEvents => {
+            debug!("Message::ReloadCachedEvents");
+            app.refresh_cached_events();
+        }
+        Message::CachedEventsReloaded(month_events, week_events) => {
+            debug!("Message::CachedEventsReloaded");
+            return app.apply_reloaded_events(month_events, week_events);
+        }
+        Message::ShowToast(text, severity, action) => {
+            app.toasts.push(text, severity, action);
+        }
+        Message::DismissToast(id) => {
+            app.toasts.dismiss(id);
+        }
+        Message::ToastActionPressed(id) => {
+            if let Some(action) = app.toasts.dismiss(id).and_then(|toast| toast.action) {
+                return Task::done(cosmic::Action::App(*action.message));
+            }
         }
         Message::ToggleSidebar => {
             app.show_sidebar = !app.show_sidebar;
@@ -505,10 +671,151 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 log::error!("Failed to toggle week numbers: {}", e);
             }
         }
+        Message::ToggleHighlightCurrentWeek => {
+            debug!("Message::ToggleHighlightCurrentWeek");
+            if let Err(e) = SettingsHandler::toggle_highlight_current_week(&mut app.settings) {
+                log::error!("Failed to toggle current week highlight: {}", e);
+            }
+        }
+        Message::ToggleRollingWeek => {
+            debug!("Message::ToggleRollingWeek");
+            if let Err(e) = SettingsHandler::toggle_rolling_week(&mut app.settings) {
+                log::error!("Failed to toggle rolling week: {}", e);
+            }
+            app.sync_views_to_selected_date();
+        }
+        Message::ToggleDimPastEvents => {
+            debug!("Message::ToggleDimPastEvents");
+            if let Err(e) = SettingsHandler::toggle_dim_past_events(&mut app.settings) {
+                log::error!("Failed to toggle past event dimming: {}", e);
+            }
+        }
+        Message::ToggleFreeDayHighlight => {
+            debug!("Message::ToggleFreeDayHighlight");
+            if let Err(e) = SettingsHandler::toggle_free_day_highlight(&mut app.settings) {
+                log::error!("Failed to toggle free day highlight: {}", e);
+            }
+        }
+        Message::ToggleHalfHourLines => {
+            debug!("Message::ToggleHalfHourLines");
+            if let Err(e) = SettingsHandler::toggle_half_hour_lines(&mut app.settings) {
+                log::error!("Failed to toggle half-hour lines: {}", e);
+            }
+        }
+        Message::ToggleEventCountBadges => {
+            debug!("Message::ToggleEventCountBadges");
+            if let Err(e) = SettingsHandler::toggle_event_count_badges(&mut app.settings) {
+                log::error!("Failed to toggle event count badges: {}", e);
+            }
+        }
+        Message::ToggleConfirmEventMoves => {
+            debug!("Message::ToggleConfirmEventMoves");
+            if let Err(e) = SettingsHandler::toggle_confirm_event_moves(&mut app.settings) {
+                log::error!("Failed to toggle confirm event moves: {}", e);
+            }
+        }
+        Message::SetWeekScrollStart(start) => {
+            debug!("Message::SetWeekScrollStart: {:?}", start);
+            if let Err(e) = SettingsHandler::set_week_scroll_start(&mut app.settings, start) {
+                log::error!("Failed to set week scroll start: {}", e);
+            }
+        }
+        Message::SetStartupView(view) => {
+            debug!("Message::SetStartupView: {:?}", view);
+            if let Err(e) = SettingsHandler::set_startup_view(&mut app.settings, view) {
+                log::error!("Failed to set startup view: {}", e);
+            }
+        }
+        Message::SetDragThreshold(pixels) => {
+            debug!("Message::SetDragThreshold: {}", pixels);
+            if let Err(e) = SettingsHandler::set_drag_threshold(&mut app.settings, pixels) {
+                log::error!("Failed to set drag threshold: {}", e);
+            }
+        }
+        Message::SetNextDayAfterHour(hour) => {
+            debug!("Message::SetNextDayAfterHour: {:?}", hour);
+            if let Err(e) = SettingsHandler::set_next_day_after_hour(&mut app.settings, hour) {
+                log::error!("Failed to set next day cutoff hour: {}", e);
+            }
+        }
+        Message::ToggleAllDaySection => {
+            if let Err(e) = SettingsHandler::toggle_all_day_section_collapsed(&mut app.settings) {
+                log::error!("Failed to toggle all-day section: {}", e);
+            }
+        }
+        Message::SetEventClickAction(action) => {
+            debug!("Message::SetEventClickAction: {:?}", action);
+            if let Err(e) = SettingsHandler::set_event_click_action(&mut app.settings, action) {
+                log::error!("Failed to set event click action: {}", e);
+            }
+        }
+        Message::SetWeekendDays(weekend) => {
+            debug!("Message::SetWeekendDays: {:?}", weekend);
+            app.locale.set_weekend_days(weekend.days());
+            if let Err(e) = SettingsHandler::set_weekend_days(&mut app.settings, weekend) {
+                log::error!("Failed to set weekend days: {}", e);
+            }
+        }
+        Message::SetCompactSensitivity(sensitivity) => {
+            debug!("Message::SetCompactSensitivity: {:?}", sensitivity);
+            if let Err(e) = SettingsHandler::set_compact_sensitivity(&mut app.settings, sensitivity) {
+                log::error!("Failed to set compact sensitivity: {}", e);
+            }
+        }
+        Message::SetEventOverlapLayout(layout) => {
+            debug!("Message::SetEventOverlapLayout: {:?}", layout);
+            if let Err(e) = SettingsHandler::set_event_overlap_layout(&mut app.settings, layout) {
+                log::error!("Failed to set event overlap layout: {}", e);
+            }
+        }
+        Message::SetEventSortOrder(order) => {
+            debug!("Message::SetEventSortOrder: {:?}", order);
+            if let Err(e) = SettingsHandler::set_event_sort_order(&mut app.settings, order) {
+                log::error!("Failed to set event sort order: {}", e);
+            }
+            app.refresh_cached_events();
+        }
+        Message::SetTimeSnap(snap) => {
+            debug!("Message::SetTimeSnap: {:?}", snap);
+            if let Err(e) = SettingsHandler::set_time_snap(&mut app.settings, snap) {
+                log::error!("Failed to set time snap: {}", e);
+            }
+        }
+        Message::ToggleRoundNewEventStart => {
+            debug!("Message::ToggleRoundNewEventStart");
+            if let Err(e) = SettingsHandler::toggle_round_new_event_start(&mut app.settings) {
+                log::error!("Failed to toggle new event rounding: {}", e);
+            }
+        }
+        Message::SetNewEventRounding(rounding) => {
+            debug!("Message::SetNewEventRounding: {:?}", rounding);
+            if let Err(e) = SettingsHandler::set_new_event_rounding(&mut app.settings, rounding) {
+                log::error!("Failed to set new event rounding: {}", e);
+            }
+        }
+        Message::SetTodayHighlight(highlight) => {
+            debug!("Message::SetTodayHighlight: {:?}", highlight);
+            if let Err(e) = SettingsHandler::set_today_highlight(&mut app.settings, highlight) {
+                log::error!("Failed to set today highlight: {}", e);
+            }
+        }
+        Message::SetSelectedHighlight(highlight) => {
+            debug!("Message::SetSelectedHighlight: {:?}", highlight);
+            if let Err(e) = SettingsHandler::set_selected_highlight(&mut app.settings, highlight) {
+                log::error!("Failed to set selected day highlight: {}", e);
+            }
+        }
+        Message::SetViewImageScale(scale) => {
+            debug!("Message::SetViewImageScale: {:?}", scale);
+            if let Err(e) = SettingsHandler::set_view_image_scale(&mut app.settings, scale) {
+                log::error!("Failed to set view image scale: {}", e);
+            }
+        }
         Message::WeekViewScroll(viewport) => {
             // Track scroll position via on_scroll callback (COSMIC Files pattern)
             // This stores the actual pixel offset so we can restore it precisely
             app.week_view_scroll_opt = Some(viewport.absolute_offset());
+            app.week_view_bounds = Some(viewport.bounds());
         }
         Message::RestoreWeekViewScroll => {
             // Restore scroll position from the saved restore point
@@ -540,6 +847,29 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 }
             }
         }
+        Message::ScrollTimelineTop => {
+            // Only works in Day or Week view - jump to midnight
+            if matches!(app.current_view, CalendarView::Day | CalendarView::Week) {
+                return scrollable::scroll_to(
+                    week_time_grid_id(),
+                    scrollable::AbsoluteOffset { x: 0.0, y: 0.0 },
+                );
+            }
+        }
+        Message::ScrollTimelineBottom => {
+            // Only works in Day or Week view - jump to the last hour of the day
+            if matches!(app.current_view, CalendarView::Day | CalendarView::Week) {
+                return scrollable::scroll_to(
+                    week_time_grid_id(),
+                    scrollable::AbsoluteOffset { x: 0.0, y: HOUR_ROW_HEIGHT * 23.0 },
+                );
+            }
+        }
+        Message::ScrollTimelineToHour(hour) => {
+            if matches!(app.current_view, CalendarView::Day | CalendarView::Week) {
+                return scroll_week_to_hour(hour);
+            }
+        }
 
         // === Calendar Management ===
         Message::ToggleCalendar(id) => {
@@ -552,9 +882,49 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             // Close dialogs when selecting a different calendar (with scroll restore if quick event)
             let task = close_quick_event_with_scroll_restore(app);
             app.selected_calendar_id = Some(id);
+            app.calendar_picker_open = false;
             app.update_selected_calendar_color();
+            if app.focus_mode {
+                app.refresh_cached_events();
+            }
             return task;
         }
+        Message::ToggleCalendarPicker => {
+            app.calendar_picker_open = !app.calendar_picker_open;
+        }
+        Message::ToggleFocusMode => {
+            app.focus_mode = !app.focus_mode;
+            info!("Focus mode set to {}", app.focus_mode);
+            app.refresh_cached_events();
+        }
+        Message::ToggleLegend => {
+            app.show_legend = !app.show_legend;
+        }
+        Message::ShowMonthStats => {
+            handle_show_month_stats(app);
+        }
+        Message::ToggleMonthYearPicker => {
+            app.month_year_picker_year = match app.month_year_picker_year {
+                Some(_) => None,
+                None => Some(match app.current_view {
+                    CalendarView::Year => app.year_state.year,
+                    _ => app.cache.current_state().year,
+                }),
+            };
+        }
+        Message::StepMonthYearPicker(delta) => {
+            if let Some(year) = app.month_year_picker_year.as_mut() {
+                *year += delta;
+            }
+        }
+        Message::SelectMonthYear(year, month) => {
+            debug!("Message::SelectMonthYear: {}-{:02}", year, month);
+            app.month_year_picker_year = None;
+            // Keep the day of month where possible, like period navigation
+            if let Some(date) = NaiveDate::from_ymd_opt(year, month, app.selected_date.day().min(28)) {
+                app.set_selected_date(date);
+            }
+        }
         Message::ToggleColorPicker(id) => {
             // Toggle: if already open for this calendar, close it; otherwise open it
             if app.active_dialog.color_picker_calendar_id() == Some(&id) {
@@ -569,6 +939,9 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::ChangeCalendarColor(id, color) => {
             handle_change_calendar_color(app, id, color);
         }
+        Message::ApplyPalette(palette) => {
+            handle_apply_palette(app, palette);
+        }
         Message::OpenNewCalendarDialog => {
             DialogManager::close(&mut app.active_dialog);
             handle_open_calendar_dialog_create(app);
@@ -612,6 +985,49 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 _ => {}
             }
         }
+        Message::CalendarDialogGroupChanged(group) => {
+            // Update calendar dialog group via active_dialog
+            match &mut app.active_dialog {
+                ActiveDialog::CalendarCreate { group: g, .. }
+                | ActiveDialog::CalendarEdit { group: g, .. } => {
+                    *g = group;
+                }
+                _ => {}
+            }
+        }
+        Message::CalendarDialogFocusGroup => {
+            return text_input::focus(calendar_dialog_group_input_id());
+        }
+        Message::CalendarDialogDurationChanged(duration) => {
+            // Update calendar dialog default event duration via active_dialog
+            match &mut app.active_dialog {
+                ActiveDialog::CalendarCreate { default_duration: d, .. }
+                | ActiveDialog::CalendarEdit { default_duration: d, .. } => {
+                    *d = duration;
+                }
+                _ => {}
+            }
+        }
+        Message::CalendarDialogSyncIntervalChanged(interval) => {
+            if let ActiveDialog::CalendarEdit { sync_interval, .. } = &mut app.active_dialog {
+                *sync_interval = interval;
+            }
+        }
+        Message::CalendarDialogHolidayOverlayToggled(enabled) => {
+            if let ActiveDialog::CalendarEdit { holiday_overlay, .. } = &mut app.active_dialog {
+                *holiday_overlay = enabled;
+            }
+        }
+        Message::CalendarDialogAutoExportPathChanged(path) => {
+            if let ActiveDialog::CalendarEdit { auto_export_path, .. } = &mut app.active_dialog {
+                *auto_export_path = path;
+            }
+        }
+        Message::ToggleCalendarGroup(group) => {
+            if let Err(e) = SettingsHandler::toggle_calendar_group_collapsed(&mut app.settings, &group) {
+                log::error!("Failed to toggle calendar group: {}", e);
+            }
+        }
         Message::ConfirmCalendarDialog => {
             handle_confirm_calendar_dialog(app);
         }
@@ -667,6 +1083,16 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::SelectionCancel => {
             handle_selection_cancel(app);
         }
+        Message::ModifiersChanged(modifiers) => {
+            app.keyboard_modifiers = modifiers;
+        }
+        Message::CreateOnPickedDates => {
+            handle_create_on_picked_dates(app);
+            #[allow(deprecated)]
+            if app.event_dialog.is_some() {
+                return text_input::focus(event_dialog_input_id(EventDialogField::Title));
+            }
+        }
 
         // === Time-Based Selection - Drag Selection for Timed Events ===
         Message::TimeSelectionStart(date, time) => {
@@ -679,18 +1105,15 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         }
         Message::TimeSelectionEnd => {
             handle_time_selection_end(app);
-            // Focus the quick event input if a quick event was started
-            if let Some((start_time, _end_time)) = app.active_dialog.quick_event_times() {
-                // Save the current scroll position BEFORE we scroll to show the input
-                // This will be used to restore when the quick event is canceled/committed
-                app.week_view_scroll_restore = app.week_view_scroll_opt;
-                // Chain: focus input, then scroll to the start time position
-                // The scroll ensures the input is visible after focusing
-                let focus_task = focus_quick_event_input();
-                let scroll_task = scroll_week_to_hour(start_time.hour());
-                return Task::batch([focus_task, scroll_task]);
-            } else if app.active_dialog.is_quick_event() {
-                return focus_quick_event_input();
+            return focus_timed_quick_event(app);
+        }
+        Message::QuickEventAtClickedTime => {
+            // Enter only creates when nothing else is open
+            #[allow(deprecated)]
+            let nothing_open = !app.active_dialog.is_open() && app.event_dialog.is_none();
+            if nothing_open {
+                handle_quick_event_at_clicked_time(app);
+                return focus_timed_quick_event(app);
             }
         }
 
@@ -711,11 +1134,25 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             // Schedule deferred scroll restore after UI updates
             return schedule_deferred_scroll_restore(app);
         }
+        Message::CreateQuickEventAnyway(result) => {
+            handle_create_quick_event_anyway(app, result);
+        }
         Message::CancelQuickEvent => {
             handle_cancel_quick_event(app);
             // Schedule deferred scroll restore after UI updates
             return schedule_deferred_scroll_restore(app);
         }
+        Message::BeginInlineRename(unique_id) => {
+            if handle_begin_inline_rename(app, &unique_id) {
+                return text_input::focus(inline_rename_input_id());
+            }
+        }
+        Message::InlineRenameChanged(text) => {
+            handle_inline_rename_changed(app, text);
+        }
+        Message::CommitInlineRename => {
+            handle_commit_inline_rename(app);
+        }
         Message::DeleteEvent(uid) => {
             handle_delete_event(app, uid);
         }
@@ -727,13 +1164,16 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 // Extract the occurrence date from the UID (if it's an occurrence)
                 let occurrence_date = extract_occurrence_date(&uid);
                 // Find the event to get its name and check if it's recurring
-                if let Ok((event, _calendar_id)) = crate::services::EventHandler::find_event(&app.calendar_manager, master_uid) {
+                if let Ok((event, calendar_id)) = crate::services::EventHandler::find_event(&app.calendar_manager, master_uid) {
+                    if refuse_read_only(app, &calendar_id) {
+                        return Task::none();
+                    }
                     let is_recurring = !matches!(event.repeat, crate::caldav::RepeatFrequency::Never);
                     DialogManager::open(
                         &mut app.active_dialog,
                         ActiveDialog::EventDelete {
                             event_uid: uid,
-                            event_name: event.summary,
+                            event_name: crate::components::event_title(&event.summary),
                             is_recurring,
                             occurrence_date,
                         },
@@ -803,6 +1243,69 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::SelectEvent(uid) => {
             handle_select_event(app, uid);
         }
+        Message::ConvertToRecurring(unique_id) => {
+            handle_convert_to_recurring(app, unique_id);
+        }
+        Message::ConvertSelectedToRecurring => {
+            if let Some(unique_id) = app.selected_event_uid.clone() {
+                handle_convert_to_recurring(app, unique_id);
+            } else {
+                debug!("ConvertSelectedToRecurring: No event selected");
+            }
+        }
+        Message::ConvertToRecurringRepeatChanged(repeat) => {
+            if let ActiveDialog::ConvertToRecurring { repeat: r, .. } = &mut app.active_dialog {
+                *r = repeat;
+            }
+        }
+        Message::ConfirmConvertToRecurring => {
+            handle_confirm_convert_to_recurring(app);
+        }
+        Message::ToggleEventAllDay(unique_id) => {
+            return handle_toggle_event_all_day(app, unique_id);
+        }
+        Message::ToggleSelectedEventAllDay => {
+            if let Some(unique_id) = app.selected_event_uid.clone() {
+                return handle_toggle_event_all_day(app, unique_id);
+            }
+            debug!("ToggleSelectedEventAllDay: No event selected");
+        }
+        Message::MakeTimedStartChanged(text) => {
+            if let ActiveDialog::MakeTimed { start, .. } = &mut app.active_dialog {
+                *start = text;
+            }
+        }
+        Message::ConfirmMakeTimed => {
+            handle_confirm_make_timed(app);
+        }
+        Message::CopyEventLink(uid) => {
+            return handle_copy_event_link(app, uid);
+        }
+        Message::CopySelectedEventLink => {
+            // Selected events are identified as calendar_id:uid
+            let uid = app
+                .selected_event_uid
+                .as_deref()
+                .and_then(|unique_id| unique_id.split_once(':'))
+                .map(|(_, uid)| uid.to_string());
+            if let Some(uid) = uid {
+                return handle_copy_event_link(app, uid);
+            }
+            debug!("CopySelectedEventLink: No event selected");
+        }
+        Message::SkipOccurrence(unique_id) => {
+            handle_skip_occurrence(app, unique_id);
+        }
+        Message::SkipSelectedOccurrence => {
+            if let Some(unique_id) = app.selected_event_uid.clone() {
+                handle_skip_occurrence(app, unique_id);
+            } else {
+                debug!("SkipSelectedOccurrence: No event selected");
+            }
+        }
+        Message::UndoSkipOccurrence(master_uid, date) => {
+            handle_undo_skip_occurrence(app, master_uid, date);
+        }
 
         // === Event Drag-and-Drop ===
         Message::DragEventStart(calendar_id, uid, date, summary, color) => {
@@ -811,19 +1314,40 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::DragEventUpdate(date) => {
             handle_drag_event_update(app, date);
         }
+        Message::DragEventOverAllDay(date, index) => {
+            app.event_drag_state.update_in_stack(date, index);
+        }
         Message::DragEventCursorMove(x, y) => {
             app.event_drag_state.update_cursor(x, y);
         }
+        Message::DragAutoScrollTick => {
+            let delta = app.drag_edge_scroll_delta();
+            if let (true, Some(offset), Some(bounds)) = (delta != 0.0, app.week_view_scroll_opt, app.week_view_bounds) {
+                let max_scroll = (HOUR_ROW_HEIGHT * 24.0 - bounds.height).max(0.0);
+                let new_y = (offset.y + delta).clamp(0.0, max_scroll);
+                if new_y != offset.y {
+                    let new_offset = scrollable::AbsoluteOffset { x: offset.x, y: new_y };
+                    // Track right away so ticks keep adding up before on_scroll reports back
+                    app.week_view_scroll_opt = Some(new_offset);
+                    return scrollable::scroll_to(week_time_grid_id(), new_offset);
+                }
+            }
+        }
         Message::DragEventEnd => {
             handle_drag_event_end(app);
         }
         Message::DragEventCancel => {
             handle_drag_event_cancel(app);
         }
+        Message::UndoEventMove => {
+            handle_undo_event_move(app);
+        }
 
         // === Event Management - Event Dialog ===
         Message::OpenNewEventDialog => {
-            handle_open_new_event_dialog(app);
+            if handle_open_new_event_dialog(app) {
+                return text_input::focus(event_dialog_input_id(EventDialogField::Title));
+            }
         }
         Message::OpenEditEventDialog(calendar_id, uid) => {
             // Cancel any drag operation that may have started from the first click of double-click
@@ -838,27 +1362,37 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 dialog.editing_field = if editing { Some(field) } else { None };
             }
         }
+        Message::EventDialogFocusNext => {
+            return focus_event_dialog_field(app, true);
+        }
+        Message::EventDialogFocusPrevious => {
+            return focus_event_dialog_field(app, false);
+        }
         Message::EventDialogTitleChanged(title) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.title = title;
             }
         }
         Message::EventDialogLocationChanged(location) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.location = location;
             }
         }
         Message::EventDialogAllDayToggled(all_day) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.all_day = all_day;
             }
         }
         Message::EventDialogStartDateInputChanged(input) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.start_date_input = input.clone();
                 // Try to parse the date
                 if let Ok(date) = chrono::NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
@@ -874,6 +1408,7 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::EventDialogStartDateChanged(date) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.start_date = date;
                 dialog.start_date_input = date.format("%Y-%m-%d").to_string();
                 dialog.start_date_calendar.set_selected_visible(date);
@@ -928,38 +1463,39 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::EventDialogStartTimeHourChanged(hour) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 let current = dialog.start_time.unwrap_or_else(|| chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap());
                 if let Some(new_time) = chrono::NaiveTime::from_hms_opt(hour, current.minute(), 0) {
                     dialog.start_time = Some(new_time);
                     dialog.start_time_input = new_time.format("%H:%M").to_string();
-                    // Auto-update end time to 1 hour after start time
-                    let end_hour = (new_time.hour() + 1) % 24;
-                    if let Some(end_time) = chrono::NaiveTime::from_hms_opt(end_hour, new_time.minute(), 0) {
-                        dialog.end_time = Some(end_time);
-                        dialog.end_time_input = end_time.format("%H:%M").to_string();
-                    }
+                    // Auto-update end time to the calendar's event duration after start time
+                    let duration = CalendarHandler::event_duration(&app.calendar_manager, &dialog.calendar_id);
+                    let end_time = new_time.overflowing_add_signed(duration).0;
+                    dialog.end_time = Some(end_time);
+                    dialog.end_time_input = end_time.format("%H:%M").to_string();
                 }
             }
         }
         Message::EventDialogStartTimeMinuteChanged(minute) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 let current = dialog.start_time.unwrap_or_else(|| chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap());
                 if let Some(new_time) = chrono::NaiveTime::from_hms_opt(current.hour(), minute, 0) {
                     dialog.start_time = Some(new_time);
                     dialog.start_time_input = new_time.format("%H:%M").to_string();
-                    // Auto-update end time to 1 hour after start time
-                    let end_hour = (new_time.hour() + 1) % 24;
-                    if let Some(end_time) = chrono::NaiveTime::from_hms_opt(end_hour, new_time.minute(), 0) {
-                        dialog.end_time = Some(end_time);
-                        dialog.end_time_input = end_time.format("%H:%M").to_string();
-                    }
+                    // Auto-update end time to the calendar's event duration after start time
+                    let duration = CalendarHandler::event_duration(&app.calendar_manager, &dialog.calendar_id);
+                    let end_time = new_time.overflowing_add_signed(duration).0;
+                    dialog.end_time = Some(end_time);
+                    dialog.end_time_input = end_time.format("%H:%M").to_string();
                 }
             }
         }
         Message::EventDialogEndDateInputChanged(input) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.end_date_input = input.clone();
                 // Try to parse the date
                 if let Ok(date) = chrono::NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
@@ -970,6 +1506,7 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::EventDialogEndDateChanged(date) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.end_date = date;
                 dialog.end_date_input = date.format("%Y-%m-%d").to_string();
                 dialog.end_date_calendar.set_selected_visible(date);
@@ -1018,6 +1555,7 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::EventDialogEndTimeHourChanged(hour) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 let current = dialog.end_time.unwrap_or_else(|| chrono::NaiveTime::from_hms_opt(10, 0, 0).unwrap());
                 if let Some(new_time) = chrono::NaiveTime::from_hms_opt(hour, current.minute(), 0) {
                     dialog.end_time = Some(new_time);
@@ -1028,6 +1566,7 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::EventDialogEndTimeMinuteChanged(minute) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 let current = dialog.end_time.unwrap_or_else(|| chrono::NaiveTime::from_hms_opt(10, 0, 0).unwrap());
                 if let Some(new_time) = chrono::NaiveTime::from_hms_opt(current.hour(), minute, 0) {
                     dialog.end_time = Some(new_time);
@@ -1038,30 +1577,42 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::EventDialogTravelTimeChanged(travel_time) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.travel_time = travel_time;
             }
         }
         Message::EventDialogRepeatChanged(repeat) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.repeat = repeat;
             }
         }
+        Message::EventDialogPriorityChanged(priority) => {
+            #[allow(deprecated)]
+            if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
+                dialog.priority = priority;
+            }
+        }
         Message::EventDialogCalendarChanged(calendar_id) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.calendar_id = calendar_id;
             }
         }
         Message::EventDialogInviteeInputChanged(input) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.invitee_input = input;
             }
         }
         Message::EventDialogAddInvitee => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 let email = dialog.invitee_input.trim().to_string();
                 if !email.is_empty() && !dialog.invitees.contains(&email) {
                     dialog.invitees.push(email);
@@ -1072,34 +1623,55 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::EventDialogRemoveInvitee(index) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 if index < dialog.invitees.len() {
                     dialog.invitees.remove(index);
                 }
             }
         }
-        Message::EventDialogAlertChanged(alert) => {
+        Message::EventDialogAddAlert(alert) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
-                dialog.alert = alert;
+                if alert != crate::caldav::AlertTime::None && !dialog.alerts.contains(&alert) {
+                    dialog.dirty = true;
+                    dialog.alerts.push(alert);
+                }
             }
         }
-        Message::EventDialogAlertSecondChanged(alert) => {
+        Message::EventDialogRemoveAlert(index) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
-                dialog.alert_second = alert;
+                if index < dialog.alerts.len() {
+                    dialog.dirty = true;
+                    dialog.alerts.remove(index);
+                }
             }
         }
         Message::EventDialogAddAttachment(path) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
+                if !dialog.attachments.contains(&path) {
+                    dialog.attachments.push(path);
+                }
+            }
+        }
+        Message::FileDropped(path) => {
+            // Dropped files become attachments while the event dialog is open
+            #[allow(deprecated)]
+            if let Some(ref mut dialog) = app.event_dialog {
+                let path = path.to_string_lossy().to_string();
                 if !dialog.attachments.contains(&path) {
                     dialog.attachments.push(path);
+                    dialog.dirty = true;
+                    debug!("Message::FileDropped: Attached dropped file ({} attachments)", dialog.attachments.len());
                 }
             }
         }
         Message::EventDialogRemoveAttachment(index) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 if index < dialog.attachments.len() {
                     dialog.attachments.remove(index);
                 }
@@ -1108,12 +1680,15 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::EventDialogUrlChanged(url) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                dialog.dirty = true;
                 dialog.url = url;
             }
         }
         Message::EventDialogNotesAction(action) => {
             #[allow(deprecated)]
             if let Some(ref mut dialog) = app.event_dialog {
+                // Cursor moves and selections don't change the notes
+                dialog.dirty |= action.is_edit();
                 dialog.notes_content.perform(action);
             }
         }
@@ -1123,6 +1698,60 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::CancelEventDialog => {
             handle_cancel_event_dialog(app);
         }
+        Message::DiscardEventDialog => {
+            handle_discard_event_dialog(app);
+        }
+        Message::KeepEditingEventDialog => {
+            handle_keep_editing_event_dialog(app);
+        }
+
+        // === Maintenance ===
+        Message::FindDuplicateEvents => {
+            handle_find_duplicate_events(app);
+        }
+        Message::ConfirmMergeDuplicates => {
+            handle_confirm_merge_duplicates(app);
+        }
+        Message::CopyWeekForward => {
+            handle_copy_week_forward(app);
+        }
+        Message::ConfirmCopyWeek => {
+            handle_confirm_copy_week(app);
+        }
+
+        // === Command Palette ===
+        Message::OpenCommandPalette => {
+            debug!("Message::OpenCommandPalette");
+            DialogManager::open(
+                &mut app.active_dialog,
+                ActiveDialog::CommandPalette { text: String::new() },
+            );
+            return text_input::focus(command_palette_input_id());
+        }
+        Message::CommandPaletteChanged(new_text) => {
+            if let ActiveDialog::CommandPalette { text } = &mut app.active_dialog {
+                *text = new_text;
+            }
+        }
+        Message::CommitCommandPalette => {
+            handle_commit_command_palette(app);
+        }
+
+        // === Day Notes ===
+        Message::EditDayNote(date) => {
+            return handle_edit_day_note(app, date);
+        }
+        Message::EditSelectedDayNote => {
+            return handle_edit_day_note(app, app.selected_date);
+        }
+        Message::DayNoteChanged(new_text) => {
+            if let ActiveDialog::DayNote { text, .. } = &mut app.active_dialog {
+                *text = new_text;
+            }
+        }
+        Message::SaveDayNote => {
+            handle_save_day_note(app);
+        }
 
         // === Mini Calendar ===
         Message::MiniCalendarPrevMonth => {
@@ -1134,7 +1763,9 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
 
         // === Menu Actions ===
         Message::NewEvent => {
-            handle_open_new_event_dialog(app);
+            if handle_open_new_event_dialog(app) {
+                return text_input::focus(event_dialog_input_id(EventDialogField::Title));
+            }
         }
         Message::ImportICal => {
             // Open file picker dialog using XDG portal (Flatpak-compatible)
@@ -1183,11 +1814,11 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                     ) {
                         Ok(_) => {
                             info!("Message::ExportICal: Exported to {:?}", export_path);
-                            // TODO: Show success notification
+                            app.toasts.push(fl!("toast-calendar-exported"), ToastSeverity::Success, None);
                         }
                         Err(e) => {
                             error!("Message::ExportICal: Export failed: {}", e);
-                            // TODO: Show error notification
+                            app.toasts.push(fl!("toast-export-failed"), ToastSeverity::Error, None);
                         }
                     }
                 } else {
@@ -1221,6 +1852,14 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::ImportFile(path) => {
             return import::handle_import_file(app, path);
         }
+        Message::ImportFromClipboard => {
+            debug!("Message::ImportFromClipboard: Reading clipboard");
+            return cosmic::iced::clipboard::read()
+                .map(|content| cosmic::Action::App(Message::ClipboardImportRead(content)));
+        }
+        Message::ClipboardImportRead(content) => {
+            return import::handle_clipboard_import(app, content);
+        }
         Message::ShowImportDialog(events, source_file_name) => {
             return import::handle_show_import_dialog(app, events, source_file_name);
         }
@@ -1245,6 +1884,9 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
                 *current_event = summary;
             }
         }
+        Message::ImportNextBatch => {
+            return import::handle_import_next_batch(app);
+        }
         Message::CancelImportProgress => {
             return import::handle_cancel_import_progress(app);
         }
@@ -1254,6 +1896,46 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
         Message::ExportCalendarToFile(calendar_id, path) => {
             return handle_export_calendar_to_file(app, calendar_id, path);
         }
+        Message::OpenExportRangeDialog => {
+            handle_open_export_range_dialog(app);
+        }
+        Message::ExportRangeStartChanged(text) => {
+            if let ActiveDialog::ExportRange { start, .. } = &mut app.active_dialog {
+                *start = text;
+            }
+        }
+        Message::ExportRangeEndChanged(text) => {
+            if let ActiveDialog::ExportRange { end, .. } = &mut app.active_dialog {
+                *end = text;
+            }
+        }
+        Message::ExportRangeToggleCalendar(calendar_id) => {
+            handle_export_range_toggle_calendar(app, calendar_id);
+        }
+        Message::ConfirmExportRange => {
+            return handle_confirm_export_range(app);
+        }
+        Message::ExportRangeToFile(path) => {
+            handle_export_range_to_file(app, path);
+        }
+        Message::ExportSelectedEvents => {
+            return handle_export_selected_events(app);
+        }
+        Message::ExportSelectedEventsToFile(path) => {
+            handle_export_selected_events_to_file(app, path);
+        }
+        Message::ExportViewImage => {
+            return handle_export_view_image(app);
+        }
+        Message::ExportViewImageToFile(path) => {
+            return handle_export_view_image_to_file(app, path);
+        }
+        Message::ViewImageCaptured(path, bounds, screenshot) => {
+            return handle_view_image_captured(app, path, bounds, screenshot);
+        }
+        Message::ViewImageSaved(result) => {
+            handle_view_image_saved(app, result);
+        }
 
         Message::ProcessUrl(url) => {
             return handle_process_url(app, url);
@@ -1287,6 +1969,27 @@ pub fn handle_message(app: &mut CosmicCalendar, message: Message) -> Task<Messag
             return handle_cancel_subscription(app);
         }
 
+        // === Sync ===
+        Message::SyncAll => {
+            info!("Message::SyncAll");
+            return sync::handle_sync_all(app);
+        }
+        Message::SyncDue => {
+            return sync::handle_sync_due(app);
+        }
+        Message::SyncFetched(fetched) => {
+            return sync::handle_sync_fetched(app, fetched);
+        }
+        Message::SyncConflictsDetected(conflicts) => {
+            return sync::handle_sync_conflicts_detected(app, conflicts);
+        }
+        Message::ResolveSyncConflict(resolution) => {
+            return sync::handle_resolve_sync_conflict(app, resolution);
+        }
+        Message::AutoExportDue => {
+            auto_export::handle_auto_export_due(app);
+        }
+
         // No-op for cancelled operations
         Message::None => {}
     }
@@ -0,0 +1,29 @@
+//! Statistics for the displayed month
+
+use log::debug;
+
+use crate::app::CosmicCalendar;
+use crate::dialogs::{ActiveDialog, DialogManager};
+use crate::models::MonthStats;
+
+/// Open the statistics dialog for the month shown in the month view
+/// Computed from the month's display event cache, so hidden calendars are left out
+pub fn handle_show_month_stats(app: &mut CosmicCalendar) {
+    let state = app.cache.current_state();
+    let calendars: Vec<(String, String)> = app
+        .calendar_manager
+        .sources()
+        .iter()
+        .map(|c| (c.info().id.clone(), c.info().name.clone()))
+        .collect();
+
+    let stats = MonthStats::from_events(state.year, state.month, &app.cached_month_events, &calendars);
+    debug!(
+        "handle_show_month_stats: {}-{:02} has {} events over {} calendars",
+        stats.year,
+        stats.month,
+        stats.total_events,
+        stats.calendars.len()
+    );
+    DialogManager::open(&mut app.active_dialog, ActiveDialog::MonthStats { stats });
+}
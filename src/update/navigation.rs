@@ -1,4 +1,4 @@
-//! Navigation-related message handlers (Previous/Next period, view changes)
+//! Navigation-related message handlers (Previous/Next period, peeking, view changes)
 
 use chrono::{Datelike, NaiveDate};
 use crate::app::CosmicCalendar;
@@ -12,32 +12,48 @@ pub enum NavigationDirection {
 
 /// Handle period navigation (previous or next) based on current view
 pub fn handle_period_navigation(app: &mut CosmicCalendar, direction: NavigationDirection) {
+    if let Some(date) = adjacent_period_date(app, app.selected_date, direction) {
+        app.set_selected_date(date);
+    }
+}
+
+/// Handle peek navigation: show the previous or next period while selected_date stays put
+/// Repeated peeks keep moving from the period currently shown
+pub fn handle_peek_navigation(app: &mut CosmicCalendar, direction: NavigationDirection) {
+    let from = app.peek_date.unwrap_or(app.selected_date);
+    if let Some(date) = adjacent_period_date(app, from, direction) {
+        app.peek_at(date);
+    }
+}
+
+/// Get the date one period before or after `from` in the current view
+fn adjacent_period_date(app: &CosmicCalendar, from: NaiveDate, direction: NavigationDirection) -> Option<NaiveDate> {
     let multiplier: i32 = match direction {
         NavigationDirection::Previous => -1,
         NavigationDirection::Next => 1,
     };
 
-    let new_date = match app.current_view {
+    match app.current_view {
         CalendarView::Year => {
             // Move by one year
-            navigate_by_year(app.selected_date, multiplier)
+            navigate_by_year(from, multiplier)
         }
         CalendarView::Month => {
             // Move by one month
-            navigate_by_month(app.selected_date, multiplier)
+            navigate_by_month(from, multiplier)
+        }
+        CalendarView::Week if app.settings.rolling_week => {
+            // Move the rolling week by 7 days from its anchor
+            Some(app.week_state.days[0] + chrono::Duration::days(7 * multiplier as i64))
         }
         CalendarView::Week => {
             // Move by one week
-            Some(app.selected_date + chrono::Duration::days(7 * multiplier as i64))
+            Some(from + chrono::Duration::days(7 * multiplier as i64))
         }
         CalendarView::Day => {
             // Move by one day
-            Some(app.selected_date + chrono::Duration::days(multiplier as i64))
+            Some(from + chrono::Duration::days(multiplier as i64))
         }
-    };
-
-    if let Some(date) = new_date {
-        app.set_selected_date(date);
     }
 }
 
@@ -69,3 +85,13 @@ pub fn handle_previous_period(app: &mut CosmicCalendar) {
 pub fn handle_next_period(app: &mut CosmicCalendar) {
     handle_period_navigation(app, NavigationDirection::Next);
 }
+
+/// Handle peeking at the previous period
+pub fn handle_peek_previous_period(app: &mut CosmicCalendar) {
+    handle_peek_navigation(app, NavigationDirection::Previous);
+}
+
+/// Handle peeking at the next period
+pub fn handle_peek_next_period(app: &mut CosmicCalendar) {
+    handle_peek_navigation(app, NavigationDirection::Next);
+}
@@ -8,6 +8,8 @@
 //! - **day**: Open event dialog with specific times (future)
 //!
 //! The core selection logic (start, update, cancel) is shared across all views.
+//! Holding Ctrl while releasing picks the selected dates instead, for creating
+//! one event on each picked date.
 
 mod day;
 mod month;
@@ -18,6 +20,7 @@ use log::debug;
 
 use crate::app::CosmicCalendar;
 use crate::dialogs::{DialogAction, DialogManager};
+use crate::services::CalendarHandler;
 use crate::views::CalendarView;
 
 /// Start a drag selection at the given date (mouse press on day cell)
@@ -45,6 +48,13 @@ pub fn handle_selection_end(app: &mut CosmicCalendar) {
         return;
     };
 
+    // Ctrl-click toggles a date, Ctrl-drag adds the range
+    if app.keyboard_modifiers.control() {
+        app.selection_state.pick(&range);
+        return;
+    }
+    app.selection_state.clear_picked();
+
     match app.current_view {
         CalendarView::Month => {
             month::handle_selection_end(app, range.start.date, range.end.date);
@@ -113,8 +123,11 @@ pub fn handle_time_selection_end(app: &mut CosmicCalendar) {
 
     if start_date == end_date && start_time == end_time {
         debug!("handle_time_selection_end: No drag detected (same cell), skipping quick event");
+        // Remember the slot so Enter can create an event there
+        app.clicked_time_slot = Some((start_date, start_time));
         return;
     }
+    app.clicked_time_slot = None;
 
     // Normalize: ensure start <= end
     let (start_time, end_time) = if start_time <= end_time {
@@ -123,6 +136,13 @@ pub fn handle_time_selection_end(app: &mut CosmicCalendar) {
         (end_time, start_time)
     };
 
+    // Points are slot starts; the selection includes the whole last slot (up to midnight)
+    let slot = chrono::Duration::minutes(i64::from(app.settings.time_snap.minutes()));
+    let end_time = match end_time.overflowing_add_signed(slot) {
+        (next, 0) => next,
+        _ => NaiveTime::from_hms_opt(23, 59, 0).unwrap(),
+    };
+
     debug!(
         "handle_time_selection_end: Creating quick timed event from {:?} to {:?}",
         start_time, end_time
@@ -138,3 +158,38 @@ pub fn handle_time_selection_end(app: &mut CosmicCalendar) {
         },
     );
 }
+
+/// Start a quick timed event at the last clicked time slot (Enter in week/day view)
+/// The event lasts the selected calendar's default duration, ending at midnight at the latest
+pub fn handle_quick_event_at_clicked_time(app: &mut CosmicCalendar) {
+    let Some((date, start_time)) = app.clicked_time_slot else {
+        return;
+    };
+
+    // Only while the clicked day is still on screen
+    let visible = match app.current_view {
+        CalendarView::Week => app.week_state.days.contains(&date),
+        CalendarView::Day => app.day_state.date == date,
+        CalendarView::Month | CalendarView::Year => false,
+    };
+    if !visible {
+        debug!("handle_quick_event_at_clicked_time: Clicked slot {} is not displayed", date);
+        return;
+    }
+
+    let calendar_id = app.selected_calendar_id.clone().unwrap_or_default();
+    let duration = CalendarHandler::event_duration(&app.calendar_manager, &calendar_id);
+    let end_time = match start_time.overflowing_add_signed(duration) {
+        (end, 0) => end,
+        _ => NaiveTime::from_hms_opt(23, 59, 0).unwrap(),
+    };
+
+    debug!(
+        "handle_quick_event_at_clicked_time: Starting quick event on {} from {:?} to {:?}",
+        date, start_time, end_time
+    );
+    DialogManager::handle_action(
+        &mut app.active_dialog,
+        DialogAction::StartQuickTimedEvent { date, start_time, end_time },
+    );
+}
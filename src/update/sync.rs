@@ -0,0 +1,152 @@
+//! Sync Update Handlers
+//!
+//! Handles manual and scheduled syncs, sync conflict queueing and resolution.
+//! Server events are fetched in a background task so a slow or unreachable
+//! server never blocks the UI.
+
+use crate::app::CosmicCalendar;
+use crate::calendars::SyncConflict;
+use crate::dialogs::{ActiveDialog, DialogManager};
+use crate::fl;
+use crate::message::Message;
+use crate::models::ToastSeverity;
+use crate::services::{ConflictResolution, FetchedSync, PendingSync, SyncHandler, SyncReport};
+use chrono::Utc;
+use cosmic::app::Task;
+use log::{debug, error, info, warn};
+
+/// Sync every enabled calendar now, regardless of its interval
+pub fn handle_sync_all(app: &mut CosmicCalendar) -> Task<Message> {
+    if app.sync_in_progress || app.is_importing() {
+        debug!("handle_sync_all: A sync or import is already running");
+        return Task::none();
+    }
+    let pending = SyncHandler::begin_sync_all(&mut app.calendar_manager);
+    fetch_in_background(app, pending, true)
+}
+
+/// Sync the calendars whose interval has elapsed (from the background scheduler)
+pub fn handle_sync_due(app: &mut CosmicCalendar) -> Task<Message> {
+    // Retried on the next tick once the running sync or import has finished
+    if app.sync_in_progress || app.is_importing() {
+        return Task::none();
+    }
+    let pending = SyncHandler::begin_sync_due(&mut app.calendar_manager, Utc::now());
+    if pending.report.total == 0 && pending.fetches.is_empty() {
+        return Task::none();
+    }
+    fetch_in_background(app, pending, false)
+}
+
+/// Fetch the server events of a started sync on a blocking thread
+fn fetch_in_background(app: &mut CosmicCalendar, pending: PendingSync, manual: bool) -> Task<Message> {
+    if pending.fetches.is_empty() {
+        let fetched = FetchedSync {
+            manual,
+            report: pending.report,
+            fetched: Vec::new(),
+        };
+        return handle_sync_fetched(app, fetched);
+    }
+
+    debug!("fetch_in_background: Fetching {} calendars", pending.fetches.len());
+    app.sync_in_progress = true;
+    let calendar_ids: Vec<String> = pending.fetches.iter().map(|(id, _)| id.clone()).collect();
+    let report = pending.report.clone();
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || pending.fetch(manual))
+                .await
+                .unwrap_or_else(|e| FetchedSync {
+                    manual,
+                    report,
+                    fetched: calendar_ids
+                        .into_iter()
+                        .map(|id| (id, Err(e.to_string())))
+                        .collect(),
+                })
+        },
+        |fetched| cosmic::Action::App(Message::SyncFetched(fetched)),
+    )
+}
+
+/// Merge fetched server events and report the finished sync
+pub fn handle_sync_fetched(app: &mut CosmicCalendar, fetched: FetchedSync) -> Task<Message> {
+    app.sync_in_progress = false;
+    let manual = fetched.manual;
+    let report = SyncHandler::finish_sync(&mut app.calendar_manager, fetched);
+
+    if manual {
+        if report.all_succeeded() {
+            app.toasts.push(fl!("toast-sync-complete"), ToastSeverity::Success, None);
+        } else {
+            app.toasts.push(
+                fl!("toast-sync-failed", count = (report.failed as i64)),
+                ToastSeverity::Error,
+                None,
+            );
+        }
+    }
+    finish_sync(app, &report)
+}
+
+/// Persist the sync times, show the synced events and queue any conflicts
+fn finish_sync(app: &mut CosmicCalendar, report: &SyncReport) -> Task<Message> {
+    debug!("finish_sync: {} succeeded, {} failed", report.succeeded, report.failed);
+    if let Err(e) = app.calendar_manager.save_config() {
+        error!("finish_sync: Failed to save sync times: {}", e);
+    }
+    app.refresh_cached_events();
+
+    let conflicts = SyncHandler::take_conflicts(&mut app.calendar_manager);
+    if conflicts.is_empty() {
+        Task::none()
+    } else {
+        Task::done(cosmic::Action::App(Message::SyncConflictsDetected(conflicts)))
+    }
+}
+
+/// Queue conflicts reported by a sync and show the first one
+pub fn handle_sync_conflicts_detected(app: &mut CosmicCalendar, conflicts: Vec<SyncConflict>) -> Task<Message> {
+    info!("handle_sync_conflicts_detected: {} conflicts", conflicts.len());
+    app.pending_sync_conflicts.extend(conflicts);
+    show_next_sync_conflict(app);
+    Task::none()
+}
+
+/// Apply the chosen resolution to the displayed conflict and move on to the next one
+pub fn handle_resolve_sync_conflict(app: &mut CosmicCalendar, resolution: ConflictResolution) -> Task<Message> {
+    let conflict = match &app.active_dialog {
+        ActiveDialog::SyncConflict { calendar_id, local, remote } => SyncConflict {
+            calendar_id: calendar_id.clone(),
+            local: local.clone(),
+            remote: remote.clone(),
+        },
+        _ => {
+            warn!("handle_resolve_sync_conflict: No sync conflict dialog open");
+            return Task::none();
+        }
+    };
+
+    if let Err(e) = SyncHandler::resolve_conflict(&mut app.calendar_manager, &conflict, resolution) {
+        error!("handle_resolve_sync_conflict: Failed to resolve uid={}: {}", conflict.local.uid, e);
+    }
+
+    app.refresh_cached_events();
+    DialogManager::close(&mut app.active_dialog);
+    show_next_sync_conflict(app);
+    Task::none()
+}
+
+/// Open the dialog for the next queued conflict, if any, unless another
+/// dialog is open (it is shown after that dialog closes instead)
+pub fn show_next_sync_conflict(app: &mut CosmicCalendar) {
+    if app.pending_sync_conflicts.is_empty() || app.active_dialog.is_open() {
+        return;
+    }
+    let SyncConflict { calendar_id, local, remote } = app.pending_sync_conflicts.remove(0);
+    DialogManager::open(
+        &mut app.active_dialog,
+        ActiveDialog::SyncConflict { calendar_id, local, remote },
+    );
+}
@@ -0,0 +1,123 @@
+//! Export of the current view as a PNG image
+//!
+//! The window is captured as rendered, so the image always matches the
+//! month/week/day layout on screen, then cropped to the main content area
+//! (leaving out the header bar and sidebar) and rescaled to the configured
+//! resolution. Encoding and writing the PNG runs in a background task.
+
+use chrono::Local;
+use cosmic::app::Task;
+use log::{debug, error, info, warn};
+use std::path::PathBuf;
+
+use crate::app::CosmicCalendar;
+use crate::fl;
+use crate::message::Message;
+use crate::models::ToastSeverity;
+use crate::services::ExportHandler;
+use crate::views::main_content_id;
+
+/// Open a file save dialog for exporting the current view as an image
+pub fn handle_export_view_image(app: &mut CosmicCalendar) -> Task<Message> {
+    debug!("handle_export_view_image: Exporting {:?} view", app.current_view);
+
+    // Suggested filename: Calendar-YYYY-MM-DD.png
+    let suggested_filename = format!("Calendar-{}.png", Local::now().format("%Y-%m-%d"));
+
+    Task::perform(
+        async move {
+            rfd::AsyncFileDialog::new()
+                .set_title("Export View as Image")
+                .set_file_name(&suggested_filename)
+                .add_filter("PNG image", &["png"])
+                .save_file()
+                .await
+                .map(|file_handle| file_handle.path().to_path_buf())
+        },
+        |result| match result {
+            Some(path) => cosmic::Action::App(Message::ExportViewImageToFile(path)),
+            None => {
+                debug!("View image export cancelled by user");
+                cosmic::Action::App(Message::None)
+            }
+        },
+    )
+}
+
+/// Look up the view bounds, then capture the main window so it can be written to the chosen path
+pub fn handle_export_view_image_to_file(app: &mut CosmicCalendar, path: PathBuf) -> Task<Message> {
+    let Some(window_id) = app.core.main_window_id() else {
+        warn!("handle_export_view_image_to_file: No main window to capture");
+        return Task::none();
+    };
+
+    cosmic::iced::widget::container::visible_bounds(main_content_id()).then(move |bounds| {
+        let path = path.clone();
+        cosmic::iced::window::screenshot(window_id)
+            .map(move |screenshot| cosmic::Action::App(Message::ViewImageCaptured(path.clone(), bounds, screenshot)))
+    })
+}
+
+/// Crop a finished window capture to the view and write it to disk in the background
+pub fn handle_view_image_captured(
+    app: &mut CosmicCalendar,
+    path: PathBuf,
+    bounds: Option<cosmic::iced::Rectangle>,
+    screenshot: cosmic::iced::window::Screenshot,
+) -> Task<Message> {
+    let scale = app.settings.view_image_scale.factor();
+
+    // Bounds are logical; the capture is in physical pixels
+    let screenshot = match bounds {
+        Some(bounds) => {
+            let factor = screenshot.scale_factor as f32;
+            let region = cosmic::iced::Rectangle {
+                x: (bounds.x * factor).round() as u32,
+                y: (bounds.y * factor).round() as u32,
+                width: (bounds.width * factor).round() as u32,
+                height: (bounds.height * factor).round() as u32,
+            };
+            screenshot.crop(region).unwrap_or_else(|e| {
+                warn!("handle_view_image_captured: Could not crop to the view ({:?}), saving the whole window", e);
+                screenshot
+            })
+        }
+        None => {
+            warn!("handle_view_image_captured: View bounds unknown, saving the whole window");
+            screenshot
+        }
+    };
+
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                ExportHandler::export_view_image(
+                    &path,
+                    &screenshot.bytes,
+                    screenshot.size.width,
+                    screenshot.size.height,
+                    screenshot.scale_factor as f32,
+                    scale,
+                )
+                .map_err(|e| e.to_string())
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()))
+        },
+        |result| cosmic::Action::App(Message::ViewImageSaved(result)),
+    )
+}
+
+/// Report the outcome of writing a view image
+pub fn handle_view_image_saved(app: &mut CosmicCalendar, result: Result<(), String>) {
+    match result {
+        Ok(()) => {
+            info!("handle_view_image_saved: Saved view image at {}x", app.settings.view_image_scale.factor());
+            app.toasts.push(fl!("toast-view-image-saved"), ToastSeverity::Success, None);
+        }
+        Err(e) => {
+            error!("handle_view_image_saved: Failed to save view image: {}", e);
+            app.toasts.push(fl!("toast-export-failed"), ToastSeverity::Error, None);
+        }
+    }
+}
@@ -10,10 +10,14 @@
 //! - HTTP-only URLs are rejected
 //! - User confirmation required before downloading from remote URLs
 
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use log::{debug, error, info, warn};
 use std::error::Error;
 use url::Url;
 
+/// Duration used for timed events created from a link without a usable end
+const DEFAULT_EVENT_DURATION_MINUTES: i64 = 60;
+
 /// URL action types for calendar operations
 #[derive(Debug, Clone)]
 pub enum UrlAction {
@@ -32,6 +36,13 @@ pub enum UrlAction {
     ViewEvent { uid: String },
 }
 
+/// Build the calendar://event link that opens an event (or one occurrence of it)
+/// The UID is percent-encoded so UIDs like "abc@example.com/1" survive the round trip
+pub fn event_link(uid: &str) -> String {
+    let encoded: String = url::form_urlencoded::byte_serialize(uid.as_bytes()).collect();
+    format!("calendar://event/{}", encoded)
+}
+
 /// Parse a URL and determine the action to take
 pub fn parse_url(url_str: &str) -> Result<UrlAction, Box<dyn Error>> {
     debug!("UrlHandler: Parsing URL: {}", url_str);
@@ -98,10 +109,7 @@ fn parse_calendar_url(url: &Url) -> Result<UrlAction, Box<dyn Error>> {
                 }
             }
 
-            debug!(
-                "UrlHandler: Create event - summary={:?}, start={:?}, end={:?}, location={:?}",
-                summary, start, end, location
-            );
+            debug!("UrlHandler: Create event - start={:?}, end={:?}", start, end);
 
             Ok(UrlAction::CreateEvent {
                 summary,
@@ -112,7 +120,11 @@ fn parse_calendar_url(url: &Url) -> Result<UrlAction, Box<dyn Error>> {
         }
         "event" => {
             // calendar://event/UID
-            let uid = url.path().trim_start_matches('/').to_string();
+            let path = url.path().trim_start_matches('/');
+            let uid: String = url::form_urlencoded::parse(path.as_bytes())
+                .map(|(key, _)| key.into_owned())
+                .next()
+                .unwrap_or_default();
             if uid.is_empty() {
                 return Err("Event UID is required".into());
             }
@@ -126,6 +138,83 @@ fn parse_calendar_url(url: &Url) -> Result<UrlAction, Box<dyn Error>> {
     }
 }
 
+/// Event dialog fields derived from a calendar://new link
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventPrefill {
+    pub title: String,
+    pub location: String,
+    pub all_day: bool,
+    pub start_date: NaiveDate,
+    pub start_time: Option<NaiveTime>,
+    pub end_date: NaiveDate,
+    pub end_time: Option<NaiveTime>,
+}
+
+impl EventPrefill {
+    /// Build dialog fields from calendar://new parameters.
+    /// A date-only start creates an all-day event. A missing or unreadable start falls back
+    /// to `fallback_start`, and a missing or invalid end to the default duration.
+    pub fn from_url_fields(
+        summary: Option<String>,
+        start: Option<String>,
+        end: Option<String>,
+        location: Option<String>,
+        fallback_start: NaiveDateTime,
+    ) -> Self {
+        let (start_date, start_time) = start
+            .as_deref()
+            .and_then(parse_url_datetime)
+            .unwrap_or((fallback_start.date(), Some(fallback_start.time())));
+        let parsed_end = end.as_deref().and_then(parse_url_datetime);
+
+        let (end_date, end_time) = match start_time {
+            // All-day: the end is inclusive and only its date matters
+            None => {
+                let end_date = parsed_end
+                    .map(|(date, _)| date)
+                    .filter(|date| *date >= start_date)
+                    .unwrap_or(start_date);
+                (end_date, None)
+            }
+            Some(time) => {
+                let start_dt = start_date.and_time(time);
+                let end_dt = parsed_end
+                    .and_then(|(date, time)| time.map(|t| date.and_time(t)))
+                    .filter(|end_dt| *end_dt > start_dt)
+                    .unwrap_or(start_dt + Duration::minutes(DEFAULT_EVENT_DURATION_MINUTES));
+                (end_dt.date(), Some(end_dt.time()))
+            }
+        };
+
+        Self {
+            title: summary.map(|s| s.trim().to_string()).unwrap_or_default(),
+            location: location.map(|s| s.trim().to_string()).unwrap_or_default(),
+            all_day: start_time.is_none(),
+            start_date,
+            start_time,
+            end_date,
+            end_time,
+        }
+    }
+}
+
+/// Parse a date or date-time link parameter
+/// Accepts "2025-12-02", "2025-12-02T10:00[:00]" and iCalendar style "20251202[T100000][Z]"
+fn parse_url_datetime(value: &str) -> Option<(NaiveDate, Option<NaiveTime>)> {
+    let value = value.trim().trim_end_matches('Z');
+
+    for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M", "%Y%m%dT%H%M%S"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(value, format) {
+            return Some((dt.date(), Some(dt.time())));
+        }
+    }
+
+    ["%Y-%m-%d", "%Y%m%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+        .map(|date| (date, None))
+}
+
 /// Download calendar data from a remote HTTPS URL
 pub async fn download_calendar(url: &str) -> Result<String, Box<dyn Error>> {
     info!("UrlHandler: Downloading calendar from {}", url);
@@ -237,6 +326,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_event_prefill_from_url() {
+        let fallback = NaiveDate::from_ymd_opt(2025, 12, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let UrlAction::CreateEvent { summary, start, end, location } =
+            parse_url("calendar://new?summary=Meeting&start=2025-12-02T10:00&location=Office").unwrap()
+        else {
+            panic!("Expected CreateEvent action");
+        };
+
+        // Timed start without an end gets the default duration
+        let prefill = EventPrefill::from_url_fields(summary, start, end, location, fallback);
+        assert_eq!(prefill.title, "Meeting");
+        assert_eq!(prefill.location, "Office");
+        assert!(!prefill.all_day);
+        assert_eq!(prefill.start_date, NaiveDate::from_ymd_opt(2025, 12, 2).unwrap());
+        assert_eq!(prefill.start_time, NaiveTime::from_hms_opt(10, 0, 0));
+        assert_eq!(prefill.end_date, prefill.start_date);
+        assert_eq!(prefill.end_time, NaiveTime::from_hms_opt(11, 0, 0));
+
+        // Date-only start is all-day, an end before the start is ignored
+        let prefill = EventPrefill::from_url_fields(
+            None,
+            Some("20251205".to_string()),
+            Some("2025-12-03".to_string()),
+            None,
+            fallback,
+        );
+        assert!(prefill.all_day);
+        assert_eq!(prefill.start_time, None);
+        assert_eq!(prefill.end_date, NaiveDate::from_ymd_opt(2025, 12, 5).unwrap());
+
+        // Missing or unreadable start falls back
+        let prefill = EventPrefill::from_url_fields(None, Some("soon".to_string()), None, None, fallback);
+        assert_eq!(prefill.title, "");
+        assert_eq!(prefill.start_date, fallback.date());
+        assert_eq!(prefill.start_time, Some(fallback.time()));
+        assert_eq!(prefill.end_time, NaiveTime::from_hms_opt(10, 0, 0));
+    }
+
     #[test]
     fn test_parse_calendar_view_event() {
         let result = parse_url("calendar://event/test-uid-123").unwrap();
@@ -248,6 +376,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_event_link_round_trip() {
+        let uid = "abc/123@example.com_20251202";
+        let link = event_link(uid);
+        assert_eq!(link, "calendar://event/abc%2F123%40example.com_20251202");
+
+        match parse_url(&link).unwrap() {
+            UrlAction::ViewEvent { uid: parsed } => assert_eq!(parsed, uid),
+            _ => panic!("Expected ViewEvent action"),
+        }
+    }
+
     #[test]
     fn test_reject_http_webcal() {
         // HTTP URLs should be rejected for security
@@ -1,25 +1,59 @@
-use cosmic::iced::Length;
-use cosmic::widget::{column, container, row, scrollable};
+use chrono::NaiveDate;
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, container, icon, row, scrollable, text};
 use cosmic::Element;
+use std::collections::HashMap;
 
-use crate::components::{render_time_grid, render_time_column_placeholder, DayColumn, render_day_header, DayHeaderConfig};
+use crate::components::{render_time_column_placeholder, render_day_header, DayHeaderConfig};
 use crate::styles::bordered_cell_style;
 use crate::locale::LocalePreferences;
 use crate::message::Message;
 use crate::models::DayState;
-use crate::ui_constants::{PADDING_SMALL, ALL_DAY_HEADER_HEIGHT};
+use crate::ui_constants::{PADDING_SMALL, ALL_DAY_HEADER_HEIGHT, ICON_DAY_NOTE, SPACING_SMALL};
+use crate::views::week::{render_day_all_day_section, render_day_time_grid, render_timeline_minimap};
+use crate::views::{week_time_grid_id, WeekViewEvents};
 
-pub fn render_day_view(day_state: &DayState, locale: &LocalePreferences) -> Element<'static, Message> {
-    let all_day_section = render_all_day_section(day_state);
+pub fn render_day_view<'a>(
+    day_state: &'a DayState,
+    locale: &'a LocalePreferences,
+    events: Option<WeekViewEvents<'a>>,
+) -> Element<'a, Message> {
+    let header_row = render_header_row(day_state, locale);
 
-    // Single day column for day view (never weekend-styled in day view)
-    let day_columns = vec![DayColumn::regular()];
-    let time_grid = render_time_grid(locale, &day_columns);
+    // Same all-day section and interactive grid as the week view, with a single day column
+    // (holidays from holiday overlay calendars are shaded and named in the all-day section)
+    let all_day_section = render_day_all_day_section(locale, day_state.date, events.as_ref());
+    let time_grid = render_day_time_grid(locale, day_state.date, events.as_ref());
+
+    // The day's note, if any, sits between the header and the timeline
+    let day_note = events
+        .as_ref()
+        .and_then(|ev| ev.day_notes.get(&day_state.date))
+        .map(|note| render_day_note(day_state.date, note));
 
     let content = column()
         .spacing(0)
+        .push(header_row)
         .push(all_day_section)
-        .push(scrollable(time_grid));
+        .push_maybe(day_note)
+        .push(
+            // Shares the week grid scroll ID so timeline scroll helpers work in day view too
+            scrollable(time_grid)
+                .id(week_time_grid_id())
+                .on_scroll(Message::WeekViewScroll)
+                .height(Length::Fill)
+        );
+
+    // Mini-map along the right edge for jumping to busy hours
+    let days = [day_state.date];
+    let minimap = match events {
+        Some(ref ev) => render_timeline_minimap(&days, ev.events_by_date),
+        None => render_timeline_minimap(&days, &HashMap::new()),
+    };
+    let content = row()
+        .spacing(0)
+        .push(container(content).width(Length::Fill))
+        .push(minimap);
 
     container(content)
         .width(Length::Fill)
@@ -27,8 +61,8 @@ pub fn render_day_view(day_state: &DayState, locale: &LocalePreferences) -> Elem
         .into()
 }
 
-/// Render the all-day events section at the top
-fn render_all_day_section(day_state: &DayState) -> Element<'static, Message> {
+/// Render the day header at the top
+fn render_header_row(day_state: &DayState, locale: &LocalePreferences) -> Element<'static, Message> {
     let mut header_row = row().spacing(0);
 
     // Time column placeholder
@@ -37,7 +71,7 @@ fn render_all_day_section(day_state: &DayState) -> Element<'static, Message> {
     // Create day header with larger size for single day view
     let day_header = render_day_header(DayHeaderConfig::day_view(
         day_state.day_text.clone(),
-        day_state.date_number.clone(),
+        locale.numerals.localize_digits(&day_state.date_number),
         day_state.is_today(),
     ));
 
@@ -52,3 +86,18 @@ fn render_all_day_section(day_state: &DayState) -> Element<'static, Message> {
     header_row.into()
 }
 
+/// Render the note attached to the day; pressing it opens the note editor
+fn render_day_note(date: NaiveDate, note: &str) -> Element<'_, Message> {
+    let content = row()
+        .spacing(SPACING_SMALL)
+        .align_y(Alignment::Center)
+        .push(icon::from_name(ICON_DAY_NOTE).size(16))
+        .push(text::caption(note));
+
+    button::custom(content)
+        .class(cosmic::theme::Button::Text)
+        .padding(PADDING_SMALL)
+        .width(Length::Fill)
+        .on_press(Message::EditDayNote(date))
+        .into()
+}
@@ -1,6 +1,6 @@
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
 use cosmic::iced::Length;
-use cosmic::widget::{column, divider};
+use cosmic::widget::{column, container, divider};
 use cosmic::Element;
 
 use crate::cache::CalendarCache;
@@ -10,6 +10,11 @@ use crate::message::Message;
 use crate::models::{WeekState, DayState, YearState};
 use crate::views::{self, CalendarView, MonthViewEvents, WeekViewEvents};
 
+/// Returns the container ID of the main content area (view image exports are cropped to it)
+pub fn main_content_id() -> cosmic::iced_core::id::Id {
+    cosmic::iced_core::id::Id::new("main_content")
+}
+
 /// Render the main content area (toolbar + calendar view)
 pub fn render_main_content<'a>(
     cache: &'a CalendarCache,
@@ -20,8 +25,11 @@ pub fn render_main_content<'a>(
     current_view: CalendarView,
     selected_date: Option<NaiveDate>,
     show_week_numbers: bool,
+    highlight_current_week: bool,
     month_events: Option<MonthViewEvents<'a>>,
     week_events: Option<WeekViewEvents<'a>>,
+    calendar_picker: components::CalendarPicker,
+    month_year_picker_year: Option<i32>,
 ) -> Element<'a, Message> {
     // Render toolbar - use appropriate text for each view
     // primary_text is bold (month/period), secondary_text is normal weight (year)
@@ -31,22 +39,39 @@ pub fn render_main_content<'a>(
         CalendarView::Day => (day_state.month_year_text.clone(), String::new()),
         CalendarView::Month => (cache.current_month_text(), cache.current_year_text()),
     };
-    let toolbar = components::render_toolbar(&primary_text, &secondary_text);
+
+    // Month and year titles open a picker for jumping to another month
+    let month_year_picker = match current_view {
+        CalendarView::Month => Some((cache.current_state().year, cache.current_state().month)),
+        CalendarView::Year => Some((year_state.year, selected_date.map_or(1, |d| d.month()))),
+        CalendarView::Week | CalendarView::Day => None,
+    }
+    .map(|(current_year, current_month)| components::MonthYearPicker {
+        current_year,
+        current_month,
+        browse_year: month_year_picker_year,
+    });
+    let toolbar = components::render_toolbar(&primary_text, &secondary_text, calendar_picker, month_year_picker);
 
     // Render current calendar view
     let calendar_view = match current_view {
-        CalendarView::Year => views::render_year_view(year_state, locale),
+        CalendarView::Year => views::render_year_view(year_state, locale, highlight_current_week),
         CalendarView::Month => views::render_month_view(cache.current_state(), selected_date, locale, show_week_numbers, month_events),
         CalendarView::Week => views::render_week_view(week_state, locale, week_events),
-        CalendarView::Day => views::render_day_view(day_state, locale),
+        CalendarView::Day => views::render_day_view(day_state, locale, week_events),
     };
 
-    column()
+    let content = column()
         .spacing(0)
         .push(toolbar)
         .push(divider::horizontal::default())
         .push(calendar_view)
         .width(Length::Fill)
+        .height(Length::Fill);
+
+    container(content)
+        .id(main_content_id())
+        .width(Length::Fill)
         .height(Length::Fill)
         .into()
 }
@@ -8,6 +8,7 @@ use cosmic::iced::Length;
 use cosmic::widget::{container, mouse_area};
 use cosmic::{widget, Element};
 
+use crate::caldav::ShowAs;
 use crate::components::color_picker::parse_color_safe;
 use crate::components::{span_border_radius_from_flags, ChipOpacity};
 use crate::message::Message;
@@ -46,11 +47,13 @@ pub fn render_compact_date_event_chip(
 /// Works for both single-day and multi-day date events.
 /// Includes click/drag handling for event selection and movement.
 /// Events on past dates are rendered with reduced opacity.
+/// Tentative events are lighter with an outline and free events are hollow.
 pub fn render_date_event_chip(
     calendar_id: String,
     uid: String,
     summary: String,
     color_hex: String,
+    show_as: ShowAs,
     show_text: bool,
     is_event_start: bool,
     is_event_end: bool,
@@ -59,6 +62,7 @@ pub fn render_date_event_chip(
     is_drag_active: bool,
     is_being_dragged: bool,
     event_date: NaiveDate,
+    dim_past: bool,
 ) -> Element<'static, Message> {
     let color = parse_color_safe(&color_hex);
 
@@ -81,10 +85,11 @@ pub fn render_date_event_chip(
 
     // Check if this event is in the past (before today)
     let today = Local::now().date_naive();
-    let is_past = event_date < today;
+    let is_past = dim_past && event_date < today;
 
     // Dim opacity when being dragged or for past events
     let opacity = ChipOpacity::from_state_with_past(is_selected, is_being_dragged, is_past);
+    let (fill, outline) = ChipOpacity::show_as_style(show_as);
 
     let chip = container(content)
         .padding([2, 4, 2, 4])
@@ -93,11 +98,11 @@ pub fn render_date_event_chip(
         .style(move |_theme: &cosmic::Theme| {
             container::Style {
                 background: Some(cosmic::iced::Background::Color(
-                    color.scale_alpha(opacity.background)
+                    color.scale_alpha(opacity.background * fill)
                 )),
                 border: cosmic::iced::Border {
-                    color: if is_selected { color } else { cosmic::iced::Color::TRANSPARENT },
-                    width: if is_selected { BORDER_WIDTH_HIGHLIGHT } else { 0.0 },
+                    color: if is_selected || outline > 0.0 { color } else { cosmic::iced::Color::TRANSPARENT },
+                    width: if is_selected { BORDER_WIDTH_HIGHLIGHT } else { outline },
                     radius: border_radius.into(),
                 },
                 text_color: Some(color.scale_alpha(opacity.text)),
@@ -0,0 +1,82 @@
+//! Free day runs for the month view
+//!
+//! Finds stretches of consecutive days without any events, so free windows
+//! (e.g. for planning a vacation) can be shaded as one band across the grid.
+
+use chrono::NaiveDate;
+use std::collections::{HashMap, HashSet};
+
+use crate::components::DisplayEvent;
+
+/// Shortest run of free days that gets shaded - a lone free day isn't a window
+pub const MIN_FREE_RUN_DAYS: usize = 2;
+
+/// Dates that belong to a run of at least `min_len` consecutive event-free days
+///
+/// `dates` are the grid's days in order; runs continue across week rows.
+pub fn free_day_runs(
+    dates: &[NaiveDate],
+    events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+    min_len: usize,
+) -> HashSet<NaiveDate> {
+    let is_free = |date: &NaiveDate| events_by_date.get(date).map_or(true, |events| events.is_empty());
+
+    let mut free = HashSet::new();
+    let mut run: Vec<NaiveDate> = Vec::new();
+    for date in dates {
+        if is_free(date) {
+            run.push(*date);
+            continue;
+        }
+        if run.len() >= min_len {
+            free.extend(run.iter().copied());
+        }
+        run.clear();
+    }
+    if run.len() >= min_len {
+        free.extend(run);
+    }
+
+    free
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::ShowAs;
+    use chrono::{Duration, NaiveTime};
+
+    fn busy() -> Vec<DisplayEvent> {
+        vec![DisplayEvent {
+            calendar_id: "work".to_string(),
+            uid: "busy".to_string(),
+            summary: "Busy".to_string(),
+            color: "#3B82F6".to_string(),
+            all_day: false,
+            start_time: NaiveTime::from_hms_opt(9, 0, 0),
+            end_time: NaiveTime::from_hms_opt(10, 0, 0),
+            show_as: ShowAs::Busy,
+            priority: None,
+            location: None,
+            notes_preview: None,
+            display_order: None,
+            span_start: None,
+            span_end: None,
+        }]
+    }
+
+    #[test]
+    fn test_free_day_runs_skip_lone_free_days() {
+        let first = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+        let dates: Vec<NaiveDate> = (0..7).map(|i| first + Duration::days(i)).collect();
+
+        // Busy on the 2nd and 4th day: the 3rd is a lone free day, days 5-7 a run
+        let events: HashMap<_, _> = [dates[1], dates[3]].into_iter().map(|d| (d, busy())).collect();
+        let free = free_day_runs(&dates, &events, MIN_FREE_RUN_DAYS);
+
+        assert!(!free.contains(&dates[0]));
+        assert!(!free.contains(&dates[2]));
+        assert!(free.contains(&dates[4]) && free.contains(&dates[5]) && free.contains(&dates[6]));
+        assert_eq!(free.len(), 3);
+    }
+}
@@ -5,10 +5,12 @@
 //! - `overlay`: Slot computation and date event overlay rendering
 //! - `events`: Date event chip rendering
 //! - `selection`: Quick event selection overlay
+//! - `free_days`: Runs of consecutive days without events
 
 mod header;
 mod overlay;
 mod events;
+mod free_days;
 mod selection;
 
 use chrono::{Datelike, NaiveDate};
@@ -18,17 +20,19 @@ use cosmic::widget::{column, container, row, responsive};
 use cosmic::{widget, Element};
 
 use crate::components::spacer::fill_spacer;
-use crate::components::{render_day_cell_with_events, DayCellConfig, DisplayEvent, should_use_compact};
+use crate::components::{render_day_cell_with_events, CompactThresholds, DayCellConfig, DisplayEvent, should_use_compact};
 use crate::dialogs::ActiveDialog;
 use crate::locale::LocalePreferences;
 use crate::message::Message;
 use crate::models::{CalendarDay, CalendarState};
 use crate::selection::SelectionState;
+use crate::settings::HighlightColor;
 use crate::ui_constants::{
     FONT_SIZE_SMALL, PADDING_MONTH_GRID, PADDING_SMALL,
     SPACING_TINY, WEEK_NUMBER_WIDTH,
 };
 
+use free_days::{free_day_runs, MIN_FREE_RUN_DAYS};
 use header::render_weekday_header;
 use overlay::{compute_week_event_slots, render_date_events_overlay, WEEKDAY_HEADER_HEIGHT};
 use selection::render_spanning_overlay;
@@ -55,6 +59,22 @@ pub struct MonthViewEvents<'a> {
     pub dragging_event_uid: Option<&'a str>,
     /// The current drop target date during drag (for highlighting target cell)
     pub drag_target_date: Option<NaiveDate>,
+    /// Cell size below which events collapse to compact indicators
+    pub compact_thresholds: CompactThresholds,
+    /// Show a total event count badge on cells that hide some events
+    pub show_event_count: bool,
+    /// Dim events that have already ended
+    pub dim_past_events: bool,
+    /// Color of the circle behind today's day number
+    pub today_highlight: HighlightColor,
+    /// Color of the selected day's border
+    pub selected_highlight: HighlightColor,
+    /// Shade runs of consecutive days without events
+    pub highlight_free_days: bool,
+    /// Free-text notes attached to dates (shown as an indicator on the cell)
+    pub day_notes: &'a std::collections::BTreeMap<NaiveDate, String>,
+    /// Holiday names from holiday overlay calendars (shaded cells)
+    pub holidays: &'a std::collections::HashMap<NaiveDate, String>,
 }
 
 pub fn render_month_view<'a>(
@@ -82,6 +102,39 @@ pub fn render_month_view<'a>(
     // Get week numbers for the month
     let week_numbers = calendar_state.week_numbers();
 
+    // Configured compact thresholds, shared by the day cells and the date event overlay
+    let compact_thresholds = events
+        .as_ref()
+        .map(|e| e.compact_thresholds)
+        .unwrap_or_default();
+    let show_event_count = events
+        .as_ref()
+        .map(|e| e.show_event_count)
+        .unwrap_or(false);
+    let dim_past_events = events
+        .as_ref()
+        .map(|e| e.dim_past_events)
+        .unwrap_or(true);
+    let (today_highlight, selected_highlight) = events
+        .as_ref()
+        .map(|e| (e.today_highlight, e.selected_highlight))
+        .unwrap_or((HighlightColor::Blue, HighlightColor::Accent));
+
+    // Days in runs of free days, computed across the whole grid so runs continue between rows
+    let free_days = events
+        .as_ref()
+        .filter(|e| e.highlight_free_days)
+        .map(|e| {
+            let dates: Vec<NaiveDate> = calendar_state
+                .weeks_full
+                .iter()
+                .flatten()
+                .filter_map(|d| NaiveDate::from_ymd_opt(d.year, d.month, d.day))
+                .collect();
+            free_day_runs(&dates, e.events_by_date, MIN_FREE_RUN_DAYS)
+        })
+        .unwrap_or_default();
+
     // Use pre-calculated weeks from CalendarState cache (with adjacent month days)
     for (week_index, week) in calendar_state.weeks_full.iter().enumerate() {
         // Compute slot assignments for date events in this week
@@ -104,7 +157,7 @@ pub fn render_month_view<'a>(
             let week_number = week_numbers.get(week_index).copied().unwrap_or(0);
             week_row = week_row.push(
                 container(
-                    widget::text(format!("{}", week_number))
+                    widget::text(locale.numerals.format_number(week_number))
                         .size(FONT_SIZE_SMALL)
                 )
                 .width(Length::Fixed(WEEK_NUMBER_WIDTH))
@@ -158,8 +211,8 @@ pub fn render_month_view<'a>(
             // Also check if there's an active multi-day quick event that includes this date
             let (is_in_selection, selection_active) = if let Some(cell_date) = cell_date {
                 events.as_ref().map(|e| {
-                    // Show highlight if: actively dragging OR in quick event date range
-                    let in_drag_selection = e.selection.contains(cell_date);
+                    // Show highlight if: actively dragging, picked with Ctrl OR in quick event date range
+                    let in_drag_selection = e.selection.contains(cell_date) || e.selection.is_picked(cell_date);
                     let in_quick_event_range = e.active_dialog.is_date_in_quick_event_range(cell_date);
                     let is_active = e.selection.is_active || e.active_dialog.is_multi_day_quick_event();
                     (in_drag_selection || in_quick_event_range, is_active)
@@ -198,8 +251,11 @@ pub fn render_month_view<'a>(
                 year,
                 month,
                 day,
+                numerals: locale.numerals,
                 is_today,
                 is_selected,
+                today_highlight,
+                selected_highlight,
                 is_weekend,
                 is_adjacent_month: !is_current_month,
                 events: day_events,
@@ -213,6 +269,14 @@ pub fn render_month_view<'a>(
                 event_drag_active,
                 dragging_event_uid,
                 is_drag_target,
+                compact_thresholds,
+                show_event_count,
+                dim_past_events,
+                is_free_day: cell_date.is_some_and(|d| free_days.contains(&d)),
+                has_day_note: cell_date
+                    .is_some_and(|d| events.as_ref().is_some_and(|e| e.day_notes.contains_key(&d))),
+                holiday: cell_date
+                    .and_then(|d| events.as_ref().and_then(|e| e.holidays.get(&d).cloned())),
             });
 
             week_row = week_row.push(
@@ -261,7 +325,7 @@ pub fn render_month_view<'a>(
             let cell_height = available_height / num_weeks;
 
             // Determine if we should use compact mode
-            let compact = should_use_compact(cell_width, cell_height);
+            let compact = should_use_compact(cell_width, cell_height, compact_thresholds);
 
             if let Some(overlay) = render_date_events_overlay(
                 &weeks,
@@ -271,6 +335,7 @@ pub fn render_month_view<'a>(
                 selected_uid.as_deref(),
                 event_drag_active,
                 dragging_uid.as_deref(),
+                dim_past_events,
             ) {
                 overlay
             } else {
@@ -9,6 +9,7 @@ use cosmic::iced::Length;
 use cosmic::widget::{column, container, row};
 use cosmic::Element;
 
+use crate::caldav::ShowAs;
 use crate::components::spacer::{fill_spacer, horizontal_spacer, spacer, vertical_spacer};
 use crate::components::DisplayEvent;
 use crate::message::Message;
@@ -37,6 +38,8 @@ pub struct DateEventSegment {
     pub summary: String,
     /// Event color (hex string)
     pub color: String,
+    /// Event availability (busy, tentative or free)
+    pub show_as: ShowAs,
     /// Week index (0-based)
     pub week_idx: usize,
     /// Slot index within the week (for vertical stacking)
@@ -48,6 +51,11 @@ pub struct DateEventSegment {
     /// Whether this is the first segment (shows text)
     /// For single-day events, this is always true
     pub is_first_segment: bool,
+    /// Whether the event started before this segment's first day (square left corner)
+    /// Also true for events that started before the first visible date of the grid
+    pub continues_before: bool,
+    /// Whether the event ends after this segment's last day (square right corner)
+    pub continues_after: bool,
     /// The event's start date (for drag operations)
     pub event_start_date: NaiveDate,
     /// The date of the last day this segment covers (used for past event dimming)
@@ -57,13 +65,25 @@ pub struct DateEventSegment {
 /// Result of computing slot assignments for a week.
 /// Contains both the event-to-slot mapping and per-day occupancy info.
 pub struct WeekSlotInfo {
-    /// Map of event unique ID (calendar_id:uid) -> slot index
+    /// Map of event occurrence key (see [`occurrence_key`]) -> slot index
     pub slots: HashMap<String, usize>,
     /// Occupied slots for each day (column) in the week: [day_0, day_1, ..., day_6]
     /// Each set contains the slot indices that are occupied by date events on that day
     pub day_occupied_slots: Vec<std::collections::HashSet<usize>>,
 }
 
+/// Key identifying one occurrence of a date event shown on `date`.
+/// Occurrences of a recurring event share its UID, so the key adds the
+/// occurrence's first day to keep them apart.
+fn occurrence_key(event: &DisplayEvent, date: NaiveDate) -> String {
+    let first_day = if event.is_multi_day() {
+        event.span_start.unwrap_or(date)
+    } else {
+        date
+    };
+    format!("{}@{}", event.unique_id(), first_day)
+}
+
 /// Compute slot assignments for all date events in a week using greedy interval scheduling.
 /// Returns both the event-to-slot mapping and per-day slot occupancy.
 /// Both single-day and multi-day date events get slots assigned.
@@ -89,19 +109,19 @@ pub fn compute_week_date_event_slots(
     let week_end = week_dates[week_dates.len() - 1];
 
     // Collect all date events that appear in this week
-    // Store: (start_col, end_col, unique_id) - column range within this week
+    // Store: (start_col, end_col, occurrence_key) - column range within this week
     let mut date_events: Vec<(usize, usize, String)> = Vec::new();
     let mut seen_ids: std::collections::HashSet<String> = std::collections::HashSet::new();
 
     for (col, date) in week_dates.iter().enumerate() {
         if let Some(day_events) = events_by_date.get(date) {
             for event in day_events {
-                let unique_id = event.unique_id();
-                if !event.all_day || seen_ids.contains(&unique_id) {
+                let key = occurrence_key(event, *date);
+                if !event.all_day || seen_ids.contains(&key) {
                     continue;
                 }
 
-                seen_ids.insert(unique_id.clone());
+                seen_ids.insert(key.clone());
 
                 // Determine column range within this week
                 let (start_col, end_col) = if event.is_multi_day() {
@@ -123,7 +143,7 @@ pub fn compute_week_date_event_slots(
                     (col, col)
                 };
 
-                date_events.push((start_col, end_col, unique_id));
+                date_events.push((start_col, end_col, key));
             }
         }
     }
@@ -183,7 +203,7 @@ pub fn collect_date_event_segments(
     events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
 ) -> Vec<DateEventSegment> {
     let mut segments: Vec<DateEventSegment> = Vec::new();
-    let mut global_seen: HashMap<String, bool> = HashMap::new(); // uid -> has_been_first
+    let mut global_seen: HashMap<String, bool> = HashMap::new(); // occurrence key -> has_been_first
 
     for (week_idx, week) in weeks.iter().enumerate() {
         // Get week date range
@@ -208,13 +228,14 @@ pub fn collect_date_event_segments(
         for (col, date) in week_dates.iter().enumerate() {
             if let Some(day_events) = events_by_date.get(date) {
                 for event in day_events {
-                    let unique_id = event.unique_id();
+                    // Each occurrence of a recurring event gets its own bar
+                    let key = occurrence_key(event, *date);
                     // Only process date events we haven't seen this week
-                    if !event.all_day || week_seen.contains(&unique_id) {
+                    if !event.all_day || week_seen.contains(&key) {
                         continue;
                     }
 
-                    week_seen.insert(unique_id);
+                    week_seen.insert(key.clone());
 
                     // Determine start/end columns for this event in this week
                     // Also capture the event's start date for drag operations
@@ -241,27 +262,33 @@ pub fn collect_date_event_segments(
                         (col, col, *date)
                     };
 
-                    // Determine if this is the first segment for this event
-                    let unique_id = event.unique_id();
-                    let is_first_segment = !global_seen.contains_key(&unique_id);
-                    global_seen.insert(unique_id.clone(), true);
+                    // Determine if this is the first segment for this occurrence
+                    let is_first_segment = !global_seen.contains_key(&key);
+                    global_seen.insert(key.clone(), true);
 
                     // Get slot for this event
-                    let slot = week_slot_info.slots.get(&unique_id).copied().unwrap_or(0);
+                    let slot = week_slot_info.slots.get(&key).copied().unwrap_or(0);
 
                     // Get the actual date for the segment's end column (used for past event dimming)
                     let segment_end_date = week_dates[end_col];
 
+                    // The bar is clipped where the event runs past this week's visible days
+                    let continues_before = event.span_start.is_some_and(|s| s < week_dates[start_col]);
+                    let continues_after = event.span_end.is_some_and(|e| e > segment_end_date);
+
                     segments.push(DateEventSegment {
                         calendar_id: event.calendar_id.clone(),
                         uid: event.uid.clone(),
-                        summary: event.summary.clone(),
+                        summary: event.display_summary(),
                         color: event.color.clone(),
+                        show_as: event.show_as,
                         week_idx,
                         slot,
                         start_col,
                         end_col,
                         is_first_segment,
+                        continues_before,
+                        continues_after,
                         event_start_date,
                         segment_end_date,
                     });
@@ -286,6 +313,8 @@ pub fn collect_date_event_segments(
 /// * `selected_event_uid` - Currently selected event UID for visual feedback
 /// * `event_drag_active` - Whether an event drag operation is currently active
 /// * `dragging_event_uid` - UID of the event currently being dragged
+/// * `dim_past_events` - Whether events on past dates are dimmed
+#[allow(clippy::too_many_arguments)]
 pub fn render_date_events_overlay<'a>(
     weeks: &[Vec<CalendarDay>],
     events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
@@ -294,6 +323,7 @@ pub fn render_date_events_overlay<'a>(
     selected_event_uid: Option<&str>,
     event_drag_active: bool,
     dragging_event_uid: Option<&str>,
+    dim_past_events: bool,
 ) -> Option<Element<'a, Message>> {
     let segments = collect_date_event_segments(weeks, events_by_date);
 
@@ -369,8 +399,8 @@ pub fn render_date_events_overlay<'a>(
                     let chip = if compact {
                         render_compact_date_event_chip(
                             seg.color.clone(),
-                            seg.start_col == 0,
-                            seg.end_col == 6,
+                            !seg.continues_before,
+                            !seg.continues_after,
                         )
                     } else {
                         render_date_event_chip(
@@ -378,14 +408,16 @@ pub fn render_date_events_overlay<'a>(
                             seg.uid.clone(),
                             seg.summary.clone(),
                             seg.color.clone(),
+                            seg.show_as,
                             seg.is_first_segment,
-                            seg.start_col == 0,
-                            seg.end_col == 6,
+                            !seg.continues_before,
+                            !seg.continues_after,
                             is_selected,
                             seg.event_start_date,
                             event_drag_active,
                             is_being_dragged,
                             seg.segment_end_date,
+                            dim_past_events,
                         )
                     };
 
@@ -434,3 +466,132 @@ pub fn render_date_events_overlay<'a>(
             .into()
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CalendarState;
+
+    fn all_day_event(uid: &str, start: NaiveDate, end: NaiveDate) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "cal".to_string(),
+            uid: uid.to_string(),
+            summary: String::new(),
+            color: "#3B82F6".to_string(),
+            all_day: true,
+            start_time: None,
+            end_time: None,
+            show_as: ShowAs::Busy,
+            priority: None,
+            location: None,
+            notes_preview: None,
+            display_order: None,
+            span_start: Some(start),
+            span_end: Some(end),
+        }
+    }
+
+    /// Add an all-day event to each day it spans
+    fn place(events_by_date: &mut HashMap<NaiveDate, Vec<DisplayEvent>>, event: DisplayEvent) {
+        let (Some(start), Some(end)) = (event.span_start, event.span_end) else {
+            return;
+        };
+        let mut date = start;
+        while date <= end {
+            events_by_date.entry(date).or_default().push(event.clone());
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_weekly_all_day_event_has_a_bar_each_week() {
+        // February 2027 starts on a Monday, so each grid week starts on a Monday
+        let weeks = CalendarState::new(2027, 2).weeks_full;
+        let day = |d| NaiveDate::from_ymd_opt(2027, 2, d).unwrap();
+
+        // Occurrences of one recurring event share its UID
+        let mut events_by_date: HashMap<NaiveDate, Vec<DisplayEvent>> = HashMap::new();
+        place(&mut events_by_date, all_day_event("trip", day(1), day(3)));
+        for monday in [1, 8, 15, 22] {
+            place(&mut events_by_date, all_day_event("course", day(monday), day(monday + 1)));
+        }
+
+        let segments = collect_date_event_segments(&weeks, &events_by_date);
+        let course: Vec<(usize, usize, usize, usize, bool)> = segments
+            .iter()
+            .filter(|s| s.uid == "course")
+            .map(|s| (s.week_idx, s.slot, s.start_col, s.end_col, s.is_first_segment))
+            .collect();
+
+        // Below the longer trip in the first week, on top afterwards, titled every week
+        assert_eq!(course, [
+            (0, 1, 0, 1, true),
+            (1, 0, 0, 1, true),
+            (2, 0, 0, 1, true),
+            (3, 0, 0, 1, true),
+        ]);
+    }
+
+    #[test]
+    fn test_recurring_occurrences_in_same_week_both_shown() {
+        let weeks = CalendarState::new(2027, 2).weeks_full;
+        let day = |d| NaiveDate::from_ymd_opt(2027, 2, d).unwrap();
+
+        // Sunday to Monday every week: week 1 holds the end of one occurrence and the start of the next
+        let mut events_by_date: HashMap<NaiveDate, Vec<DisplayEvent>> = HashMap::new();
+        place(&mut events_by_date, all_day_event("shift", day(7), day(8)));
+        place(&mut events_by_date, all_day_event("shift", day(14), day(15)));
+
+        let week_segments: Vec<(usize, usize, bool, bool)> = collect_date_event_segments(&weeks, &events_by_date)
+            .iter()
+            .filter(|s| s.week_idx == 1)
+            .map(|s| (s.start_col, s.end_col, s.is_first_segment, s.continues_after))
+            .collect();
+
+        assert_eq!(week_segments, [(0, 0, false, false), (6, 6, true, true)]);
+    }
+
+    #[test]
+    fn test_event_started_in_previous_month_is_clipped_to_grid() {
+        // February 2027 starts on a Monday, so the grid begins on Feb 1
+        let weeks = CalendarState::new(2027, 2).weeks_full;
+        let start = NaiveDate::from_ymd_opt(2027, 1, 29).unwrap();
+        let end = NaiveDate::from_ymd_opt(2027, 2, 3).unwrap();
+        let event = all_day_event("trip", start, end);
+
+        let mut events_by_date: HashMap<NaiveDate, Vec<DisplayEvent>> = HashMap::new();
+        let mut date = start;
+        while date <= end {
+            events_by_date.entry(date).or_default().push(event.clone());
+            date = date.succ_opt().unwrap();
+        }
+
+        let segments = collect_date_event_segments(&weeks, &events_by_date);
+
+        assert_eq!(segments.len(), 1);
+        let seg = &segments[0];
+        assert_eq!((seg.week_idx, seg.start_col, seg.end_col), (0, 0, 2));
+        // The visible part still shows the title but has a square left corner
+        assert!(seg.is_first_segment);
+        assert!(seg.continues_before);
+        assert!(!seg.continues_after);
+        assert_eq!(seg.event_start_date, start);
+    }
+
+    #[test]
+    fn test_single_day_event_in_first_column_is_rounded() {
+        let weeks = CalendarState::new(2027, 2).weeks_full;
+        let date = NaiveDate::from_ymd_opt(2027, 2, 8).unwrap();
+        let mut event = all_day_event("day", date, date);
+        event.span_start = None;
+        event.span_end = None;
+
+        let events_by_date = HashMap::from([(date, vec![event])]);
+        let segments = collect_date_event_segments(&weeks, &events_by_date);
+
+        assert_eq!(segments.len(), 1);
+        assert_eq!((segments[0].week_idx, segments[0].start_col), (1, 0));
+        assert!(!segments[0].continues_before);
+        assert!(!segments[0].continues_after);
+    }
+}
@@ -5,6 +5,7 @@ use cosmic::Element;
 use crate::calendars::CalendarSource;
 use crate::components::{render_calendar_list, render_mini_calendar};
 use crate::dialogs::ActiveDialog;
+use crate::locale::Numerals;
 use crate::message::Message;
 use crate::models::CalendarState;
 use crate::ui_constants::{SIDEBAR_WIDTH, SPACING_LARGE, PADDING_STANDARD};
@@ -15,11 +16,13 @@ pub fn render_sidebar<'a>(
     selected_day: Option<u32>,
     active_dialog: &'a ActiveDialog,
     selected_calendar_id: Option<&'a String>,
+    collapsed_groups: &[String],
+    numerals: Numerals,
 ) -> Element<'a, Message> {
-    let mini_calendar = render_mini_calendar(calendar_state, selected_day);
+    let mini_calendar = render_mini_calendar(calendar_state, selected_day, numerals);
 
     // Use the calendar list component
-    let calendars_section = render_calendar_list(calendars, active_dialog, selected_calendar_id);
+    let calendars_section = render_calendar_list(calendars, active_dialog, selected_calendar_id, collapsed_groups);
 
     // Scrollable top section with calendars
     let scrollable_content = scrollable(
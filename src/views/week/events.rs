@@ -5,21 +5,28 @@
 use chrono::{Local, NaiveDate, Timelike};
 use cosmic::iced::{Background, Border, Length};
 use cosmic::iced_widget::keyed::Column as KeyedColumn;
-use cosmic::widget::{container, mouse_area, row};
+use cosmic::iced_widget::Stack;
+use cosmic::widget::{column, container, mouse_area, row, tooltip};
 use cosmic::{widget, Element};
 use std::hash::{Hash, Hasher};
 use std::collections::hash_map::DefaultHasher;
 
-use crate::components::{parse_color_safe, ChipOpacity, DisplayEvent};
-use crate::components::spacer::vertical_spacer;
+use crate::components::{parse_color_safe, render_inline_rename_input, timed_span_border_radius, ChipOpacity, DisplayEvent, SpanPosition};
+use crate::components::spacer::{horizontal_spacer, vertical_spacer};
+use crate::fl;
 use crate::message::Message;
-use crate::ui_constants::{HOUR_ROW_HEIGHT, BORDER_RADIUS};
+use crate::settings::EventOverlapLayout;
+use crate::styles::overlay_sidebar_style;
+use crate::ui_constants::{HOUR_ROW_HEIGHT, BORDER_RADIUS, PADDING_MEDIUM, SPACING_SMALL};
 
-use super::utils::{event_time_range, PositionedEvent};
+use super::utils::{event_time_range, event_tooltip_lines, stack_column_events, PositionedEvent};
 
 /// Spacing between event blocks in pixels (vertical gap)
 const EVENT_BLOCK_SPACING: f32 = 2.0;
 
+/// Spacing between compact events sharing a stack
+const STACKED_EVENT_SPACING: f32 = 1.0;
+
 /// Hash a string to a u64 key for keyed columns
 fn hash_key(s: &str) -> u64 {
     let mut hasher = DefaultHasher::new();
@@ -30,19 +37,27 @@ fn hash_key(s: &str) -> u64 {
 /// Spacing between overlapping event columns in pixels (horizontal gap)
 const EVENT_COLUMN_SPACING: u16 = 4;
 
+/// Horizontal offset per overlap column in cascade layout, in pixels
+const CASCADE_OFFSET: f32 = 16.0;
+
 /// Render the events overlay layer with events positioned based on their time spans
-/// Uses a row of columns approach where each column renders its events independently
+/// Side-by-side layout uses a row of columns where each column renders its events independently;
+/// cascade layout stacks the columns on top of each other, each shifted a little to the right
+#[allow(clippy::too_many_arguments)]
 pub fn render_events_overlay_layer(
     date: NaiveDate,
     positioned_events: &[PositionedEvent],
     max_columns: usize,
     selected_event_uid: Option<&str>,
+    upcoming: Option<&(String, String)>,
+    rename: Option<(&str, &str, &str)>,
+    dim_past: bool,
+    overlap_layout: EventOverlapLayout,
 ) -> Element<'static, Message> {
-    // Each column renders its events independently with proper vertical positioning
-    // This ensures overlapping events appear side-by-side
-    let mut columns_row = row().spacing(EVENT_COLUMN_SPACING);
+    let cascade = overlap_layout == EventOverlapLayout::Cascade;
 
-    for col_idx in 0..max_columns {
+    // Each column renders its events independently with proper vertical positioning
+    let column_layers = (0..max_columns).map(|col_idx| {
         // Get all events for this column, sorted by start time
         let mut col_events: Vec<&PositionedEvent> = positioned_events.iter()
             .filter(|pe| pe.column == col_idx)
@@ -50,15 +65,34 @@ pub fn render_events_overlay_layer(
         col_events.sort_by_key(|pe| event_time_range(&pe.event).0);
 
         // Build this column's content with spacers and events
-        let col_content = render_column_events(date, &col_events, selected_event_uid);
+        let cascaded = cascade && col_idx > 0;
+        render_column_events(date, &col_events, selected_event_uid, upcoming, rename, dim_past, cascaded)
+    });
 
-        columns_row = columns_row.push(
-            container(col_content)
-                .width(Length::Fill)
-        );
+    if cascade {
+        // Later columns are drawn on top, offset so earlier titles stay visible
+        let layers: Vec<Element<'static, Message>> = column_layers
+            .enumerate()
+            .map(|(col_idx, col_content)| {
+                let layer = container(col_content).width(Length::Fill);
+                if col_idx == 0 {
+                    return layer.into();
+                }
+                row()
+                    .push(horizontal_spacer(col_idx as f32 * CASCADE_OFFSET))
+                    .push(layer)
+                    .into()
+            })
+            .collect();
+        return Stack::with_children(layers).width(Length::Fill).into();
     }
 
-    columns_row.into()
+    // Side by side: overlapping events share the width in equal columns
+    column_layers
+        .fold(row().spacing(EVENT_COLUMN_SPACING), |columns_row, col_content| {
+            columns_row.push(container(col_content).width(Length::Fill))
+        })
+        .into()
 }
 
 /// Render a single column of events with proper vertical spacing
@@ -67,56 +101,90 @@ fn render_column_events(
     date: NaiveDate,
     events: &[&PositionedEvent],
     selected_event_uid: Option<&str>,
+    upcoming: Option<&(String, String)>,
+    rename: Option<(&str, &str, &str)>,
+    dim_past: bool,
+    cascaded: bool,
 ) -> Element<'static, Message> {
     let mut keyed_children: Vec<(u64, Element<'static, Message>)> = Vec::new();
-    let mut current_mins: u32 = 0;
+    let mut current_px: f32 = 0.0;
 
     // Half spacing on each side of an event creates full spacing between consecutive events
     let half_spacing = EVENT_BLOCK_SPACING / 2.0;
 
-    for (idx, pe) in events.iter().enumerate() {
-        let (start_mins, end_mins) = event_time_range(&pe.event);
-        let event_key = hash_key(&pe.event.uid);
+    // Short events get a minimum height; ones that would then collide share a compact stack
+    let ranges: Vec<(u32, u32)> = events.iter().map(|pe| event_time_range(&pe.event)).collect();
+
+    for (idx, stack) in stack_column_events(&ranges).into_iter().enumerate() {
+        let stacked = &events[stack.events.clone()];
+        let first_uid = &stacked[0].event.uid;
 
-        // Add spacer to position this event correctly (includes top margin)
-        if start_mins > current_mins {
-            let spacer_height = ((start_mins - current_mins) as f32 / 60.0) * HOUR_ROW_HEIGHT;
+        // Add spacer to position this stack at its first event's start
+        if stack.top > current_px {
             keyed_children.push((
                 hash_key(&format!("spacer-pre-{}", idx)),
-                vertical_spacer(spacer_height).into()
+                vertical_spacer(stack.top - current_px).into()
             ));
         }
 
-        // Add top margin spacer for this event
+        // Add top margin spacer for this stack
         keyed_children.push((
-            hash_key(&format!("margin-top-{}", pe.event.uid)),
+            hash_key(&format!("margin-top-{}", first_uid)),
             vertical_spacer(half_spacing).into()
         ));
 
-        // Render the event (subtract full spacing from height for top + bottom margins)
-        let ev_height = ((end_mins - start_mins) as f32 / 60.0) * HOUR_ROW_HEIGHT - EVENT_BLOCK_SPACING;
-        let event_block = render_positioned_event_block(
-            date,
-            &pe.event,
-            ev_height.max(16.0), // Minimum height for visibility
-            selected_event_uid,
-        );
-        // Key the event block with its UID hash for proper reconciliation
-        keyed_children.push((event_key, event_block));
+        // Render the events (subtract full spacing from height for top + bottom margins)
+        let block_height = (stack.height - EVENT_BLOCK_SPACING).max(0.0);
+        if stacked.len() == 1 {
+            let event_block = render_positioned_event_block(
+                date,
+                &stacked[0].event,
+                block_height,
+                false,
+                selected_event_uid,
+                upcoming,
+                rename,
+                dim_past,
+                cascaded,
+            );
+            // Key the event block with its UID hash for proper reconciliation
+            keyed_children.push((hash_key(first_uid), event_block));
+        } else {
+            let count = stacked.len() as f32;
+            let row_height = ((block_height - STACKED_EVENT_SPACING * (count - 1.0)) / count).max(1.0);
+            let stack_column = stacked.iter().fold(
+                column().spacing(STACKED_EVENT_SPACING),
+                |col, pe| col.push(render_positioned_event_block(
+                    date,
+                    &pe.event,
+                    row_height,
+                    true,
+                    selected_event_uid,
+                    upcoming,
+                    rename,
+                    dim_past,
+                    cascaded,
+                )),
+            );
+            keyed_children.push((
+                hash_key(&format!("stack-{}", first_uid)),
+                container(stack_column).height(Length::Fixed(block_height)).into()
+            ));
+        }
 
         // Add bottom margin spacer
         keyed_children.push((
-            hash_key(&format!("margin-bottom-{}", pe.event.uid)),
+            hash_key(&format!("margin-bottom-{}", first_uid)),
             vertical_spacer(half_spacing).into()
         ));
 
-        current_mins = end_mins;
+        current_px = stack.top + stack.height;
     }
 
     // Fill remaining space to maintain column height
-    let total_mins = 24 * 60;
-    if current_mins < total_mins {
-        let remaining_height = ((total_mins - current_mins) as f32 / 60.0) * HOUR_ROW_HEIGHT;
+    let total_height = 24.0 * HOUR_ROW_HEIGHT;
+    if current_px < total_height {
+        let remaining_height = total_height - current_px;
         keyed_children.push((
             hash_key("spacer-remaining"),
             vertical_spacer(remaining_height).into()
@@ -129,22 +197,35 @@ fn render_column_events(
 }
 
 /// Render a positioned event block with the specified height
+/// Compact blocks (short events sharing a stack) use smaller text and padding;
+/// cascaded blocks sit on top of other events and get an opaque, outlined background
+#[allow(clippy::too_many_arguments)]
 fn render_positioned_event_block(
     date: NaiveDate,
     event: &DisplayEvent,
     height: f32,
+    compact: bool,
     selected_event_uid: Option<&str>,
+    upcoming: Option<&(String, String)>,
+    rename: Option<(&str, &str, &str)>,
+    dim_past: bool,
+    cascaded: bool,
 ) -> Element<'static, Message> {
     let color = parse_color_safe(&event.color);
     let calendar_id = event.calendar_id.clone();
     let uid = event.uid.clone();
     let unique_id = event.unique_id();
     let is_selected = selected_event_uid == Some(&unique_id);
+    let upcoming_badge = upcoming
+        .filter(|(upcoming_id, _)| *upcoming_id == unique_id)
+        .map(|(_, badge)| badge.clone());
 
     // Check if this event is in the past (considering time on today)
     let now = Local::now();
     let today = now.date_naive();
-    let is_past = if date < today {
+    let is_past = if !dim_past {
+        false
+    } else if date < today {
         // Past day - always dim
         true
     } else if date == today {
@@ -165,31 +246,71 @@ fn render_positioned_event_block(
     };
 
     let (bg_opacity, border_width) = ChipOpacity::timed_event_opacity(is_selected, is_past);
+    // Events underneath shouldn't show through a cascaded block
+    let bg_opacity = if cascaded && !is_past { 1.0 } else { bg_opacity };
+    // A thin outline in the window color separates a cascaded block from the one below
+    let separator_width = if cascaded { 1.0 } else { 0.0 };
+    let (fill, outline) = ChipOpacity::show_as_style(event.show_as);
+
+    // Segments of a multi-day event connect across day columns
+    let span_position = event.span_position_for_date(date);
+    let border_radius = timed_span_border_radius(span_position, BORDER_RADIUS[0]);
+
+    // Build the label with time and summary (continuation segments only show the summary)
+    let label = match (span_position, event.start_time) {
+        (SpanPosition::Single | SpanPosition::First, Some(t)) => {
+            format!("{:02}:{:02} {}", t.hour(), t.minute(), event.display_summary())
+        }
+        _ => event.display_summary(),
+    };
+
+    // The first segment of the event being renamed shows the input instead of its label
+    let rename_text = rename
+        .filter(|(cal, uid, _)| *cal == event.calendar_id && *uid == event.uid)
+        .filter(|_| matches!(span_position, SpanPosition::Single | SpanPosition::First))
+        .map(|(_, _, text)| text.to_string());
+
+    // Double-clicking the title text renames inline; the rest of the chip opens the editor.
+    // The rename area only covers the text so the empty part of the chip stays clickable.
+    let renaming = rename_text.is_some();
+    let title: Element<'static, Message> = match rename_text {
+        Some(text) => render_inline_rename_input(text),
+        None => mouse_area(widget::text(label).size(if compact { 9 } else { 10 }))
+            .on_double_click(Message::BeginInlineRename(unique_id.clone()))
+            .into(),
+    };
 
-    // Build the label with time and summary
-    let time_str = event.start_time
-        .map(|t| format!("{:02}:{:02}", t.hour(), t.minute()))
-        .unwrap_or_default();
-    let label = format!("{} {}", time_str, event.summary);
-
-    let chip = container(
-        widget::text(label.clone())
-            .size(10)
-    )
-    .padding([2, 6])
+    let mut chip_content = row()
+        .spacing(4)
+        .push(title);
+    if !renaming {
+        chip_content = chip_content.push(widget::horizontal_space());
+    }
+
+    if let Some(badge) = upcoming_badge {
+        chip_content = chip_content.push(render_upcoming_badge(badge));
+    }
+
+    let chip = container(chip_content)
+    .padding(if compact { [0, 4] } else { [2, 6] })
     .width(Length::Fill)
     .height(Length::Fixed(height))
     .style(move |theme: &cosmic::Theme| container::Style {
         background: Some(Background::Color(cosmic::iced::Color {
-            a: bg_opacity,
+            a: bg_opacity * fill,
             ..color
         })),
-        text_color: Some(cosmic::iced::Color::WHITE),
+        // Lighter and hollow chips use the calendar color for text so it stays readable
+        text_color: Some(if fill < 1.0 { color } else { cosmic::iced::Color::WHITE }),
         border: Border {
-            radius: BORDER_RADIUS.into(),
-            width: border_width,
+            radius: border_radius.into(),
+            width: border_width.max(outline).max(separator_width),
             color: if is_selected {
                 theme.cosmic().accent_color().into()
+            } else if outline > 0.0 {
+                color
+            } else if cascaded {
+                theme.cosmic().background.base.into()
             } else {
                 cosmic::iced::Color::TRANSPARENT
             },
@@ -197,13 +318,65 @@ fn render_positioned_event_block(
         ..Default::default()
     });
 
+    // Chips clip long titles, so hovering shows the full details
+    // The tooltip sits inside the mouse area, leaving click and drag handling unchanged
+    let chip = tooltip(chip, render_event_tooltip(event_tooltip_lines(event)), tooltip::Position::FollowCursor);
+
     // Get color hex for drag preview
     let color_hex = event.color.clone();
 
     mouse_area(chip)
-        .on_press(Message::DragEventStart(calendar_id.clone(), uid.clone(), date, event.summary.clone(), color_hex))
+        .on_press(Message::DragEventStart(calendar_id.clone(), uid.clone(), date, event.title(), color_hex))
         .on_release(Message::DragEventEnd)
         .on_double_click(Message::OpenEditEventDialog(calendar_id, uid))
         .on_enter(Message::DragEventUpdate(date))
         .into()
 }
+
+/// Render the hover tooltip of an event chip, with the title first
+fn render_event_tooltip(lines: Vec<String>) -> Element<'static, Message> {
+    let mut lines = lines.into_iter();
+    let title = widget::text::heading(lines.next().unwrap_or_default());
+    let details = lines.fold(column().spacing(SPACING_SMALL).push(title), |col, line| {
+        col.push(widget::text::caption(line))
+    });
+
+    container(details)
+        .padding(PADDING_MEDIUM)
+        .max_width(320)
+        .style(|theme: &cosmic::Theme| container::Style {
+            border: Border {
+                radius: BORDER_RADIUS.into(),
+                ..Default::default()
+            },
+            ..overlay_sidebar_style(theme)
+        })
+        .into()
+}
+
+/// Format the time until an event starts, e.g. "in 20 min" or "in 1 h 5 min"
+pub fn format_time_until(minutes: i64) -> String {
+    if minutes < 60 {
+        fl!("upcoming-in-minutes", minutes = minutes)
+    } else if minutes % 60 == 0 {
+        fl!("upcoming-in-hours", hours = (minutes / 60))
+    } else {
+        fl!("upcoming-in-hours-minutes", hours = (minutes / 60), minutes = (minutes % 60))
+    }
+}
+
+/// Render the small "in X min" badge shown on the next upcoming event
+fn render_upcoming_badge(badge: String) -> Element<'static, Message> {
+    container(widget::text(badge).size(9))
+        .padding([0, 4])
+        .style(|_theme: &cosmic::Theme| container::Style {
+            background: Some(Background::Color(cosmic::iced::Color::from_rgba(1.0, 1.0, 1.0, 0.25))),
+            text_color: Some(cosmic::iced::Color::WHITE),
+            border: Border {
+                radius: BORDER_RADIUS.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .into()
+}
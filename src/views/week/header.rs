@@ -5,7 +5,7 @@
 use chrono::{Datelike, Local, NaiveDate};
 use cosmic::iced::{alignment, Background, Border, Length};
 use cosmic::iced_widget::keyed::Column as KeyedColumn;
-use cosmic::widget::{column, container, mouse_area, row};
+use cosmic::widget::{button, column, container, mouse_area, row};
 use cosmic::{widget, Element};
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
@@ -13,17 +13,18 @@ use std::collections::hash_map::DefaultHasher;
 
 use crate::components::{parse_color_safe, ChipOpacity, DisplayEvent};
 use crate::components::spacer::fixed_spacer;
+use crate::fl;
 use crate::locale::LocalePreferences;
 use crate::localized_names;
 use crate::message::Message;
 use crate::models::WeekState;
-use crate::styles::{today_filled_style, weekend_background};
+use crate::styles::{holiday_background, today_filled_style};
 use crate::ui_constants::{
     PADDING_SMALL, FONT_SIZE_SMALL, FONT_SIZE_MEDIUM, COLOR_DAY_CELL_BORDER,
-    TIME_LABEL_WIDTH, BORDER_WIDTH_THIN, SPACING_TINY, BORDER_RADIUS,
+    TIME_LABEL_WIDTH, BORDER_WIDTH_THIN, SPACING_TINY, BORDER_RADIUS, ICON_COLLAPSED, ICON_EXPANDED,
 };
 
-use super::utils::{DAY_HEADER_HEIGHT, ALL_DAY_EVENT_HEIGHT, ALL_DAY_SPACING};
+use super::utils::{AllDayLayout, DAY_HEADER_HEIGHT, ALL_DAY_EVENT_HEIGHT, ALL_DAY_SPACING};
 
 /// Hash a string to a u64 key for keyed columns
 fn hash_key(s: &str) -> u64 {
@@ -33,27 +34,45 @@ fn hash_key(s: &str) -> u64 {
 }
 
 /// Render the header section with day names, dates, and all-day events
+/// Holidays are shaded and named at the top of their all-day cell
+/// With `show_week_number`, the ISO week number sits above the hour labels
 pub fn render_header_section<'a>(
     week_state: &'a WeekState,
     locale: &'a LocalePreferences,
     all_day_events: &HashMap<NaiveDate, Vec<DisplayEvent>>,
-    all_day_section_height: f32,
+    holidays: &HashMap<NaiveDate, String>,
+    all_day_layout: AllDayLayout,
     selected_event_uid: Option<&str>,
+    show_week_number: bool,
 ) -> Element<'a, Message> {
     let mut header_col = column().spacing(0);
 
     // Day headers row
     let mut day_headers = row().spacing(0);
 
-    // Time column placeholder for day headers
-    day_headers = day_headers.push(fixed_spacer(TIME_LABEL_WIDTH, DAY_HEADER_HEIGHT));
+    // Week number above the time column, like the month view's week number column
+    let week_number = week_state.days.first().filter(|_| show_week_number).map(|day| day.iso_week().week());
+    day_headers = day_headers.push(match week_number {
+        Some(week) => container(
+            widget::text(fl!("week-view-week-number", week = locale.numerals.format_number(week)))
+                .size(FONT_SIZE_SMALL)
+        )
+        .width(Length::Fixed(TIME_LABEL_WIDTH))
+        .height(Length::Fixed(DAY_HEADER_HEIGHT))
+        .padding(PADDING_SMALL)
+        .center_x(Length::Fixed(TIME_LABEL_WIDTH))
+        .align_y(alignment::Vertical::Center)
+        .into(),
+        None => fixed_spacer(TIME_LABEL_WIDTH, DAY_HEADER_HEIGHT),
+    });
 
     // Day headers
     for date in &week_state.days {
         let is_today = week_state.is_today(date);
         let is_weekend = locale.is_weekend(date.weekday());
+        let is_holiday = holidays.contains_key(date);
         let day_name = localized_names::get_weekday_short(date.weekday());
-        let day_number = format!("{}", date.day());
+        let day_number = locale.numerals.format_number(date.day());
 
         let day_header = render_day_header(&day_name, &day_number, is_today);
 
@@ -64,7 +83,7 @@ pub fn render_header_section<'a>(
                 .padding(PADDING_SMALL)
                 .center_x(Length::Fill)
                 .style(move |_theme: &cosmic::Theme| container::Style {
-                    background: weekend_background(is_weekend),
+                    background: holiday_background(is_holiday, is_weekend),
                     border: Border {
                         width: BORDER_WIDTH_THIN,
                         color: COLOR_DAY_CELL_BORDER,
@@ -78,7 +97,7 @@ pub fn render_header_section<'a>(
     header_col = header_col.push(day_headers);
 
     // All-day events section
-    let all_day_section = render_all_day_section(week_state, locale, all_day_events, all_day_section_height, selected_event_uid);
+    let all_day_section = render_all_day_section(&week_state.days, locale, all_day_events, holidays, all_day_layout, selected_event_uid);
     header_col = header_col.push(all_day_section);
 
     header_col.into()
@@ -105,24 +124,35 @@ fn render_day_header<'a>(day_name: &str, day_number: &str, is_today: bool) -> El
         .into()
 }
 
-/// Render the all-day events section
-fn render_all_day_section<'a>(
-    week_state: &'a WeekState,
+/// Render the all-day events section for the shown days
+/// Shared by the week view and the day view (a single day) so both behave the same
+pub fn render_all_day_section<'a>(
+    days: &[NaiveDate],
     locale: &'a LocalePreferences,
     all_day_events: &HashMap<NaiveDate, Vec<DisplayEvent>>,
-    height: f32,
+    holidays: &HashMap<NaiveDate, String>,
+    layout: AllDayLayout,
     selected_event_uid: Option<&str>,
 ) -> Element<'a, Message> {
+    let height = layout.height;
     let mut all_day_row = row().spacing(0);
 
-    // Time column with "all-day" label
+    // Time column holds the collapse toggle when the section is tall enough
+    let toggle: Element<'a, Message> = if layout.collapsible {
+        let icon = if layout.collapsed { ICON_COLLAPSED } else { ICON_EXPANDED };
+        button::icon(widget::icon::from_name(icon))
+            .extra_small()
+            .on_press(Message::ToggleAllDaySection)
+            .into()
+    } else {
+        widget::text("").size(FONT_SIZE_SMALL).into()
+    };
     all_day_row = all_day_row.push(
-        container(
-            widget::text("").size(FONT_SIZE_SMALL)
-        )
+        container(toggle)
         .width(Length::Fixed(TIME_LABEL_WIDTH))
         .height(Length::Fixed(height))
-        .padding(PADDING_SMALL)
+        .padding([2, 2])
+        .align_x(alignment::Horizontal::Center)
         .align_y(alignment::Vertical::Top)
         .style(|_theme: &cosmic::Theme| container::Style {
             border: Border {
@@ -135,19 +165,34 @@ fn render_all_day_section<'a>(
     );
 
     // All-day events for each day
-    for date in &week_state.days {
+    for date in days {
         let is_weekend = locale.is_weekend(date.weekday());
+        let holiday = holidays.get(date).cloned();
+        let is_holiday = holiday.is_some();
         let day_events = all_day_events.get(date).cloned().unwrap_or_default();
         let date_copy = *date;
 
-        let events_column = render_all_day_events_for_day(*date, &day_events, selected_event_uid);
+        let events_column = if layout.collapsed {
+            render_all_day_summary(day_events.len())
+        } else {
+            let events = render_all_day_events_for_day(*date, &day_events, selected_event_uid, layout.dim_past);
+            match holiday {
+                // The section is one row taller in weeks with holidays to fit the name
+                Some(name) => column()
+                    .spacing(ALL_DAY_SPACING)
+                    .push(render_holiday_label(name))
+                    .push(events)
+                    .into(),
+                None => events,
+            }
+        };
 
         let cell = container(events_column)
             .width(Length::Fill)
             .height(Length::Fixed(height))
             .padding([2, 2])
             .style(move |_theme: &cosmic::Theme| container::Style {
-                background: weekend_background(is_weekend),
+                background: holiday_background(is_holiday, is_weekend),
                 border: Border {
                     width: BORDER_WIDTH_THIN,
                     color: COLOR_DAY_CELL_BORDER,
@@ -166,15 +211,45 @@ fn render_all_day_section<'a>(
     all_day_row.into()
 }
 
+/// Render a holiday name in a day's all-day cell, one all-day row high
+fn render_holiday_label(name: String) -> Element<'static, Message> {
+    container(
+        widget::text(name)
+            .size(FONT_SIZE_SMALL)
+            .wrapping(cosmic::iced::widget::text::Wrapping::None),
+    )
+    .padding([2, 4])
+    .width(Length::Fill)
+    .height(Length::Fixed(ALL_DAY_EVENT_HEIGHT))
+    .into()
+}
+
+/// Render the collapsed "+N" summary for a day's all-day events
+/// Clicking it expands the section again
+fn render_all_day_summary(count: usize) -> Element<'static, Message> {
+    if count == 0 {
+        return widget::text("").into();
+    }
+
+    mouse_area(
+        container(widget::text(fl!("all-day-more", count = (count as i64))).size(FONT_SIZE_SMALL))
+            .width(Length::Fill)
+            .padding([0, 4]),
+    )
+    .on_press(Message::ToggleAllDaySection)
+    .into()
+}
+
 /// Render all-day events for a single day as a vertical stack with click and drag support
+/// Dropping a chip on another chip of the same day reorders the stack
 /// Uses KeyedColumn to ensure proper widget reconciliation when events change
-fn render_all_day_events_for_day(date: NaiveDate, events: &[DisplayEvent], selected_event_uid: Option<&str>) -> Element<'static, Message> {
+fn render_all_day_events_for_day(date: NaiveDate, events: &[DisplayEvent], selected_event_uid: Option<&str>, dim_past: bool) -> Element<'static, Message> {
     // Check if this date is in the past (all-day events are past at end of day)
     let today = Local::now().date_naive();
-    let is_past = date < today; // All-day events don't have time - check by day
+    let is_past = dim_past && date < today; // All-day events don't have time - check by day
 
     // Use KeyedColumn for proper diffing when events are added/removed
-    let keyed_children: Vec<(u64, Element<'static, Message>)> = events.iter().map(|event| {
+    let keyed_children: Vec<(u64, Element<'static, Message>)> = events.iter().enumerate().map(|(index, event)| {
         let color = parse_color_safe(&event.color);
         let calendar_id = event.calendar_id.clone();
         let uid = event.uid.clone();
@@ -184,9 +259,10 @@ fn render_all_day_events_for_day(date: NaiveDate, events: &[DisplayEvent], selec
 
         // Selection highlight with past event dimming
         let (bg_opacity, border_width) = ChipOpacity::timed_event_opacity(is_selected, is_past);
+        let (fill, outline) = ChipOpacity::show_as_style(event.show_as);
 
         let chip = container(
-            widget::text(event.summary.clone())
+            widget::text(event.display_summary())
                 .size(10)
         )
         .padding([2, 4])
@@ -194,15 +270,18 @@ fn render_all_day_events_for_day(date: NaiveDate, events: &[DisplayEvent], selec
         .height(Length::Fixed(ALL_DAY_EVENT_HEIGHT))
         .style(move |theme: &cosmic::Theme| container::Style {
             background: Some(Background::Color(cosmic::iced::Color {
-                a: bg_opacity,
+                a: bg_opacity * fill,
                 ..color
             })),
-            text_color: Some(cosmic::iced::Color::WHITE),
+            // Lighter and hollow chips use the calendar color for text so it stays readable
+            text_color: Some(if fill < 1.0 { color } else { cosmic::iced::Color::WHITE }),
             border: Border {
                 radius: BORDER_RADIUS.into(),
-                width: border_width,
+                width: border_width.max(outline),
                 color: if is_selected {
                     theme.cosmic().accent_color().into()
+                } else if outline > 0.0 {
+                    color
                 } else {
                     cosmic::iced::Color::TRANSPARENT
                 },
@@ -215,10 +294,11 @@ fn render_all_day_events_for_day(date: NaiveDate, events: &[DisplayEvent], selec
 
         // Wrap with mouse area for click and drag handling
         let clickable_chip: Element<'static, Message> = mouse_area(chip)
-            .on_press(Message::DragEventStart(calendar_id.clone(), uid.clone(), date, event.summary.clone(), color_hex))
+            .on_press(Message::DragEventStart(calendar_id.clone(), uid.clone(), date, event.title(), color_hex))
             .on_release(Message::DragEventEnd)
             .on_double_click(Message::OpenEditEventDialog(calendar_id, uid))
-            .on_enter(Message::DragEventUpdate(date))
+            // Hovering a chip while dragging targets its position, for reordering the stack
+            .on_enter(Message::DragEventOverAllDay(date, index))
             .into();
 
         // Use event UID hash as the key for proper reconciliation
@@ -0,0 +1,67 @@
+//! Timeline mini-map for the week and day views
+//!
+//! A narrow strip beside the time grid with one cell per hour. Hours that
+//! contain timed events show a marker in the event's calendar color, and
+//! clicking any hour scrolls the timeline there.
+
+use chrono::NaiveDate;
+use cosmic::iced::{Background, Border, Length};
+use cosmic::widget::{column, container, mouse_area};
+use cosmic::Element;
+use std::collections::HashMap;
+
+use crate::components::spacer::fill_spacer;
+use crate::components::{parse_color_safe, DisplayEvent};
+use crate::message::Message;
+use crate::ui_constants::BORDER_RADIUS;
+
+use super::utils::hourly_event_markers;
+
+/// Width of the mini-map strip in pixels
+const MINIMAP_WIDTH: f32 = 12.0;
+
+/// Render the mini-map for the displayed days
+pub fn render_timeline_minimap(
+    days: &[NaiveDate],
+    events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+) -> Element<'static, Message> {
+    let timed: Vec<&DisplayEvent> = days
+        .iter()
+        .filter_map(|day| events_by_date.get(day))
+        .flatten()
+        .filter(|e| !e.all_day)
+        .collect();
+
+    let mut strip = column().spacing(0).width(Length::Fixed(MINIMAP_WIDTH)).height(Length::Fill);
+
+    for (hour, marker) in hourly_event_markers(&timed).into_iter().enumerate() {
+        // Busier hours get a stronger marker
+        let marker_style = marker.map(|(color, count)| {
+            let alpha = if count > 1 { 0.9 } else { 0.5 };
+            cosmic::iced::Color { a: alpha, ..parse_color_safe(&color) }
+        });
+
+        let cell = container(
+            container(fill_spacer())
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .style(move |_theme: &cosmic::Theme| container::Style {
+                    background: marker_style.map(Background::Color),
+                    border: Border {
+                        radius: BORDER_RADIUS.into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+        )
+        .padding([1, 2])
+        .width(Length::Fill)
+        .height(Length::FillPortion(1));
+
+        strip = strip.push(
+            mouse_area(cell).on_press(Message::ScrollTimelineToHour(hour as u32)),
+        );
+    }
+
+    strip.into()
+}
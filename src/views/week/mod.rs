@@ -8,11 +8,13 @@
 //! - [`time_grid`] - Time labels column and hour cell grid
 //! - [`events`] - Timed event chip rendering and positioning
 //! - [`time_indicator`] - Current time line and dot
+//! - [`minimap`] - Hour strip with event markers for quick scrolling
 //! - [`quick_event`] - Inline event creation input
 //! - [`utils`] - Shared types and utility functions
 
 mod events;
 mod header;
+mod minimap;
 mod quick_event;
 mod time_grid;
 mod time_indicator;
@@ -21,7 +23,7 @@ mod utils;
 use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
 use cosmic::iced::widget::stack;
 use cosmic::iced::Length;
-use cosmic::widget::{column, container, scrollable};
+use cosmic::widget::{column, container, row, scrollable};
 use cosmic::Element;
 use std::collections::HashMap;
 
@@ -31,17 +33,16 @@ use crate::locale::LocalePreferences;
 use crate::message::Message;
 use crate::models::WeekState;
 use crate::selection::SelectionState;
+use crate::settings::{EventOverlapLayout, TimeSnap};
 use crate::ui_constants::HOUR_ROW_HEIGHT;
 
-use events::render_events_overlay_layer;
-use header::render_header_section;
+use events::{format_time_until, render_events_overlay_layer};
+use header::{render_all_day_section, render_header_section};
+pub use minimap::render_timeline_minimap;
 use quick_event::render_quick_event_input_layer;
 use time_grid::{render_hour_grid_background, render_time_labels_column};
 use time_indicator::render_time_indicator_layer;
-use utils::{
-    calculate_event_columns, calculate_max_all_day_slots, separate_events,
-    ALL_DAY_EVENT_HEIGHT, ALL_DAY_MIN_HEIGHT, ALL_DAY_SPACING,
-};
+use utils::{calculate_event_columns, next_upcoming_event, separate_events, AllDayLayout};
 
 /// Returns the scrollable ID for the week view time grid
 pub fn week_time_grid_id() -> cosmic::iced_core::id::Id {
@@ -60,6 +61,22 @@ pub struct WeekViewEvents<'a> {
     pub active_dialog: &'a ActiveDialog,
     /// Selected calendar color (for quick event styling)
     pub calendar_color: &'a str,
+    /// Whether the all-day section is collapsed to a summary row
+    pub all_day_collapsed: bool,
+    /// Dim events that have already ended
+    pub dim_past_events: bool,
+    /// How overlapping timed events are laid out
+    pub overlap_layout: EventOverlapLayout,
+    /// Increment that time selections snap to
+    pub time_snap: TimeSnap,
+    /// Draw fainter gridlines at the half hours
+    pub half_hour_lines: bool,
+    /// Free-text notes attached to dates (shown under the day view header)
+    pub day_notes: &'a std::collections::BTreeMap<NaiveDate, String>,
+    /// Holiday names from holiday overlay calendars (shaded days)
+    pub holidays: &'a HashMap<NaiveDate, String>,
+    /// Show the ISO week number above the hour labels (follows "Show Week Numbers")
+    pub show_week_number: bool,
 }
 
 /// Render the week view with events
@@ -77,6 +94,11 @@ pub fn render_week_view<'a>(
     // Extract active dialog and calendar color for quick event input
     let active_dialog = events.as_ref().map(|e| e.active_dialog);
     let calendar_color = events.as_ref().map(|e| e.calendar_color);
+    let dim_past_events = events.as_ref().map_or(true, |e| e.dim_past_events);
+    let overlap_layout = events.as_ref().map_or(EventOverlapLayout::default(), |e| e.overlap_layout);
+    let time_snap = events.as_ref().map_or(TimeSnap::default(), |e| e.time_snap);
+    let half_hour_lines = events.as_ref().map_or(true, |e| e.half_hour_lines);
+    let show_week_number = events.as_ref().is_some_and(|e| e.show_week_number);
 
     // Separate events into all-day and timed
     let (all_day_events, timed_events) = if let Some(ref ev) = events {
@@ -85,15 +107,21 @@ pub fn render_week_view<'a>(
         (HashMap::new(), HashMap::new())
     };
 
-    // Calculate how many rows we need for all-day events
-    let max_all_day_slots = calculate_max_all_day_slots(&all_day_events);
-    let all_day_section_height = ALL_DAY_MIN_HEIGHT + (max_all_day_slots as f32 * (ALL_DAY_EVENT_HEIGHT + ALL_DAY_SPACING));
+    let no_holidays = HashMap::new();
+    let holidays = events.as_ref().map_or(&no_holidays, |e| e.holidays);
 
     // Day headers with all-day events section
-    let header_section = render_header_section(week_state, locale, &all_day_events, all_day_section_height, selected_event_uid);
+    let all_day_layout = AllDayLayout::for_days(
+        &week_state.days,
+        &all_day_events,
+        holidays,
+        events.as_ref().is_some_and(|e| e.all_day_collapsed),
+        dim_past_events,
+    );
+    let header_section = render_header_section(week_state, locale, &all_day_events, holidays, all_day_layout, selected_event_uid, show_week_number);
 
     // Time grid with timed events
-    let time_grid = render_time_grid_with_events(locale, week_state, &timed_events, selected_event_uid, selection, active_dialog, calendar_color);
+    let time_grid = render_time_grid_with_events(locale, &week_state.days, true, &timed_events, selected_event_uid, selection, active_dialog, calendar_color, dim_past_events, overlap_layout, time_snap, half_hour_lines);
 
     let content = column()
         .spacing(0)
@@ -105,21 +133,98 @@ pub fn render_week_view<'a>(
                 .height(Length::Fill)
         );
 
+    // Mini-map along the right edge for jumping to busy hours
+    // (outside the grid so day headers stay aligned with their columns)
+    let minimap = match events {
+        Some(ref ev) => render_timeline_minimap(&week_state.days, ev.events_by_date),
+        None => render_timeline_minimap(&week_state.days, &HashMap::new()),
+    };
+    let content = row()
+        .spacing(0)
+        .push(container(content).width(Length::Fill))
+        .push(minimap);
+
     container(content)
         .width(Length::Fill)
         .height(Length::Fill)
         .into()
 }
 
+/// Render the all-day section for a single day (used by the day view)
+///
+/// The same section as the week view's, so spanning, overflow, collapsing
+/// and holiday names behave the same in both views.
+pub fn render_day_all_day_section<'a>(
+    locale: &'a LocalePreferences,
+    date: NaiveDate,
+    events: Option<&WeekViewEvents<'a>>,
+) -> Element<'a, Message> {
+    let days = [date];
+    let all_day_events = if let Some(ev) = events {
+        separate_events(ev.events_by_date, &days).0
+    } else {
+        HashMap::new()
+    };
+    let no_holidays = HashMap::new();
+    let holidays = events.map_or(&no_holidays, |e| e.holidays);
+
+    let layout = AllDayLayout::for_days(
+        &days,
+        &all_day_events,
+        holidays,
+        events.is_some_and(|e| e.all_day_collapsed),
+        events.map_or(true, |e| e.dim_past_events),
+    );
+    render_all_day_section(&days, locale, &all_day_events, holidays, layout, events.and_then(|e| e.selected_event_uid))
+}
+
+/// Render the interactive time grid for a single day (used by the day view)
+///
+/// Shares the week view's hour cells, event overlay, quick event input and
+/// current time indicator so both views behave the same.
+pub fn render_day_time_grid<'a>(
+    locale: &'a LocalePreferences,
+    date: NaiveDate,
+    events: Option<&WeekViewEvents<'a>>,
+) -> Element<'a, Message> {
+    let days = [date];
+    let timed_events = if let Some(ev) = events {
+        separate_events(ev.events_by_date, &days).1
+    } else {
+        HashMap::new()
+    };
+
+    render_time_grid_with_events(
+        locale,
+        &days,
+        false, // Day view is never weekend-styled
+        &timed_events,
+        events.and_then(|e| e.selected_event_uid),
+        events.map(|e| e.selection),
+        events.map(|e| e.active_dialog),
+        events.map(|e| e.calendar_color),
+        events.map_or(true, |e| e.dim_past_events),
+        events.map_or(EventOverlapLayout::default(), |e| e.overlap_layout),
+        events.map_or(TimeSnap::default(), |e| e.time_snap),
+        events.map_or(true, |e| e.half_hour_lines),
+    )
+}
+
 /// Render the time grid with timed events spanning their full duration
+#[allow(clippy::too_many_arguments)]
 fn render_time_grid_with_events<'a>(
     locale: &'a LocalePreferences,
-    week_state: &'a WeekState,
+    days: &[NaiveDate],
+    style_weekends: bool,
     timed_events: &HashMap<NaiveDate, Vec<DisplayEvent>>,
     selected_event_uid: Option<&'a str>,
     selection: Option<&'a SelectionState>,
     active_dialog: Option<&'a ActiveDialog>,
     calendar_color: Option<&'a str>,
+    dim_past_events: bool,
+    overlap_layout: EventOverlapLayout,
+    time_snap: TimeSnap,
+    half_hour_lines: bool,
 ) -> Element<'a, Message> {
     // Get current time for the "now" indicator
     let now = chrono::Local::now();
@@ -127,8 +232,8 @@ fn render_time_grid_with_events<'a>(
     let current_hour = now.hour();
     let current_minute = now.minute();
 
-    // Check if today is in the current week
-    let today_column_index = week_state.days.iter().position(|d| *d == today);
+    // Check if today is among the displayed days
+    let today_column_index = days.iter().position(|d| *d == today);
 
     // Check if there's an active timed quick event to display
     let quick_event_data = active_dialog.and_then(|dialog| {
@@ -139,6 +244,9 @@ fn render_time_grid_with_events<'a>(
         }
     });
 
+    // Event being renamed inline, if any (calendar_id, uid, text)
+    let rename = active_dialog.and_then(|dialog| dialog.event_rename_data());
+
     // Build the grid as a row: time labels column + day columns
     let mut main_row = cosmic::widget::row().spacing(0);
 
@@ -147,8 +255,8 @@ fn render_time_grid_with_events<'a>(
     main_row = main_row.push(time_labels);
 
     // Day columns with events
-    for (day_idx, date) in week_state.days.iter().enumerate() {
-        let is_weekend = locale.is_weekend(date.weekday());
+    for (day_idx, date) in days.iter().enumerate() {
+        let is_weekend = style_weekends && locale.is_weekend(date.weekday());
         let is_today_column = today_column_index == Some(day_idx);
         let day_events = timed_events.get(date).cloned().unwrap_or_default();
 
@@ -172,6 +280,11 @@ fn render_time_grid_with_events<'a>(
             selected_event_uid,
             selection,
             day_quick_event,
+            rename,
+            dim_past_events,
+            overlap_layout,
+            time_snap,
+            half_hour_lines,
         );
 
         main_row = main_row.push(day_column);
@@ -181,6 +294,7 @@ fn render_time_grid_with_events<'a>(
 }
 
 /// Render a single day column with events spanning their full duration using stack overlay
+#[allow(clippy::too_many_arguments)]
 fn render_day_column_with_events(
     date: NaiveDate,
     events: &[DisplayEvent],
@@ -192,9 +306,14 @@ fn render_day_column_with_events(
     selected_event_uid: Option<&str>,
     selection: Option<&SelectionState>,
     quick_event: Option<(NaiveTime, NaiveTime, &str, &str)>, // (start_time, end_time, text, color)
+    rename: Option<(&str, &str, &str)>, // (calendar_id, uid, text)
+    dim_past_events: bool,
+    overlap_layout: EventOverlapLayout,
+    time_snap: TimeSnap,
+    half_hour_lines: bool,
 ) -> Element<'static, Message> {
     // Build the base hour grid (background layer) - without time indicator
-    let hour_grid = render_hour_grid_background(date, is_weekend, selection);
+    let hour_grid = render_hour_grid_background(date, is_weekend, selection, time_snap.minutes(), half_hour_lines);
 
     // Build the time indicator layer (rendered on top of events)
     let time_indicator_layer = if today_in_week {
@@ -226,8 +345,17 @@ fn render_day_column_with_events(
     let positioned_events = calculate_event_columns(events);
     let max_columns = positioned_events.iter().map(|p| p.total_columns).max().unwrap_or(1).max(1);
 
+    // Badge the next upcoming event today with the time until it starts
+    let upcoming = if is_today {
+        NaiveTime::from_hms_opt(current_hour, current_minute, 0)
+            .and_then(|now| next_upcoming_event(events, now))
+            .map(|(unique_id, minutes)| (unique_id, format_time_until(minutes)))
+    } else {
+        None
+    };
+
     // Build the events overlay layer
-    let events_layer = render_events_overlay_layer(date, &positioned_events, max_columns, selected_event_uid);
+    let events_layer = render_events_overlay_layer(date, &positioned_events, max_columns, selected_event_uid, upcoming.as_ref(), rename, dim_past_events, overlap_layout);
 
     // Stack order: grid (bottom) -> events -> time indicator -> quick event (top)
     // Time indicator must be above events so it's always visible
@@ -3,19 +3,23 @@
 //! Contains the time labels column and hour cell grid background.
 
 use chrono::{NaiveDate, NaiveTime};
+use cosmic::iced::widget::stack;
 use cosmic::iced::{alignment, Border, Length};
 use cosmic::widget::{column, container, mouse_area};
 use cosmic::{widget, Element};
 
+use crate::components::spacer::vertical_spacer;
 use crate::locale::LocalePreferences;
 use crate::message::Message;
 use crate::selection::SelectionState;
 use crate::styles::weekend_background;
 use crate::ui_constants::{
-    PADDING_SMALL, FONT_SIZE_SMALL, COLOR_DAY_CELL_BORDER,
+    PADDING_SMALL, FONT_SIZE_SMALL, COLOR_DAY_CELL_BORDER, COLOR_HALF_HOUR_LINE,
     HOUR_ROW_HEIGHT, TIME_LABEL_WIDTH, BORDER_WIDTH_THIN, COLOR_CURRENT_TIME,
 };
 
+use super::utils::hour_slot_starts;
+
 /// Render the time labels column (left side)
 pub fn render_time_labels_column<'a>(
     locale: &'a LocalePreferences,
@@ -57,17 +61,22 @@ pub fn render_time_labels_column<'a>(
 }
 
 /// Render the hour grid background (lines only, no events or time indicator) with clickable time slots
+/// Each hour is divided into slots of `snap_minutes` so selections start at snapped times
+/// With `half_hour_lines`, a fainter line is drawn across the middle of each hour
 pub fn render_hour_grid_background(
     date: NaiveDate,
     is_weekend: bool,
     selection: Option<&SelectionState>,
+    snap_minutes: u32,
+    half_hour_lines: bool,
 ) -> Element<'static, Message> {
     let mut hour_cells = column().spacing(0);
 
+    // Only an active selection is highlighted
+    let selection = selection.filter(|s| s.is_active);
+
     for hour in 0..24u32 {
-        // Check if this hour cell is within the current selection
-        let is_selected = selection.map(|s| s.is_active && s.contains_time(date, hour)).unwrap_or(false);
-        let cell = render_clickable_hour_cell(date, hour, is_weekend, is_selected);
+        let cell = render_clickable_hour_cell(date, hour, is_weekend, selection, snap_minutes, half_hour_lines);
         hour_cells = hour_cells.push(cell);
     }
 
@@ -75,28 +84,38 @@ pub fn render_hour_grid_background(
 }
 
 /// Render a clickable hour cell (for creating new events and drag targets)
-fn render_clickable_hour_cell(date: NaiveDate, hour: u32, is_weekend: bool, is_selected: bool) -> Element<'static, Message> {
-    // Create the time for this hour cell
-    let start_time = NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
-    let _end_time = NaiveTime::from_hms_opt(hour, 59, 59).unwrap_or_else(|| {
-        NaiveTime::from_hms_opt(23, 59, 59).unwrap()
-    });
-
-    let cell = container(widget::text(""))
+fn render_clickable_hour_cell(
+    date: NaiveDate,
+    hour: u32,
+    is_weekend: bool,
+    selection: Option<&SelectionState>,
+    snap_minutes: u32,
+    half_hour_line: bool,
+) -> Element<'static, Message> {
+    // Stack one invisible target per snap slot, so the press height maps to a snapped time
+    let slot_starts = hour_slot_starts(hour, snap_minutes);
+    let slot_minutes = 60 / slot_starts.len() as u32;
+    let slot_height = HOUR_ROW_HEIGHT / slot_starts.len() as f32;
+    let mut slots = column().spacing(0);
+    for start_time in slot_starts {
+        let is_selected = selection.is_some_and(|s| s.contains_time(date, start_time, slot_minutes));
+        slots = slots.push(render_time_slot(date, start_time, slot_height, is_selected));
+    }
+
+    // The line sits above the slots (and their selection tint) but takes no input,
+    // so presses still reach the slot underneath
+    let content: Element<'static, Message> = if half_hour_line {
+        stack![slots, render_half_hour_line()].into()
+    } else {
+        slots.into()
+    };
+
+    container(content)
         .width(Length::Fill)
         .height(Length::Fixed(HOUR_ROW_HEIGHT))
-        .style(move |theme: &cosmic::Theme| {
-            let background = if is_selected {
-                // Use theme accent color for selection (consistent with month view)
-                let accent = theme.cosmic().accent_color();
-                Some(cosmic::iced::Background::Color(cosmic::iced::Color::from_rgba(
-                    accent.red, accent.green, accent.blue, 0.2
-                )))
-            } else {
-                weekend_background(is_weekend)
-            };
+        .style(move |_theme: &cosmic::Theme| {
             container::Style {
-                background,
+                background: weekend_background(is_weekend),
                 border: Border {
                     width: BORDER_WIDTH_THIN,
                     color: COLOR_DAY_CELL_BORDER,
@@ -104,13 +123,52 @@ fn render_clickable_hour_cell(date: NaiveDate, hour: u32, is_weekend: bool, is_s
                 },
                 ..Default::default()
             }
+        })
+        .into()
+}
+
+/// Render the faint divider across the middle of an hour cell
+fn render_half_hour_line() -> Element<'static, Message> {
+    let line = container(widget::text(""))
+        .width(Length::Fill)
+        .height(Length::Fixed(BORDER_WIDTH_THIN))
+        .style(|_theme: &cosmic::Theme| container::Style {
+            background: Some(COLOR_HALF_HOUR_LINE.into()),
+            ..Default::default()
+        });
+
+    column()
+        .spacing(0)
+        .push(vertical_spacer((HOUR_ROW_HEIGHT - BORDER_WIDTH_THIN) / 2.0))
+        .push(line)
+        .into()
+}
+
+/// Render the drag target for one snap slot starting at `start_time`
+/// Selected slots are tinted, so the selection shows at slot precision
+fn render_time_slot(date: NaiveDate, start_time: NaiveTime, height: f32, is_selected: bool) -> Element<'static, Message> {
+    let slot = container(widget::text(""))
+        .width(Length::Fill)
+        .height(Length::Fixed(height))
+        .style(move |theme: &cosmic::Theme| {
+            // Use theme accent color for selection (consistent with month view)
+            let background = is_selected.then(|| {
+                let accent = theme.cosmic().accent_color();
+                cosmic::iced::Background::Color(cosmic::iced::Color::from_rgba(
+                    accent.red, accent.green, accent.blue, 0.2
+                ))
+            });
+            container::Style {
+                background,
+                ..Default::default()
+            }
         });
 
     // Press: start time selection for creating timed events
     // Release: end time selection
     // on_enter: update time selection (for drag selection)
     // Double-click: open new event dialog
-    mouse_area(cell)
+    mouse_area(slot)
         .on_press(Message::TimeSelectionStart(date, start_time))
         .on_release(Message::TimeSelectionEnd)
         .on_double_click(Message::OpenNewEventDialog)
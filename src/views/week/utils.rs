@@ -5,7 +5,7 @@
 use chrono::{NaiveDate, NaiveTime, Timelike};
 use std::collections::HashMap;
 
-use crate::components::DisplayEvent;
+use crate::components::{end_of_day, DisplayEvent};
 use crate::ui_constants::HOUR_ROW_HEIGHT;
 
 /// Represents an event with its calculated column position for overlap handling
@@ -28,6 +28,55 @@ pub const ALL_DAY_EVENT_HEIGHT: f32 = 22.0;
 /// Spacing between all-day events
 pub const ALL_DAY_SPACING: f32 = 2.0;
 
+/// Minimum height of a timed event block so its label stays readable
+pub const MIN_EVENT_BLOCK_HEIGHT: f32 = 20.0;
+
+/// Consecutive events of one column drawn as a single block
+/// A stack holds more than one event when short events would collide
+/// once the minimum height is applied; they are then shown compactly
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventStack {
+    /// Index range into the column's events (sorted by start time)
+    pub events: std::ops::Range<usize>,
+    /// Top of the stack in pixels from midnight (the first event's true start)
+    pub top: f32,
+    /// Height of the stack in pixels
+    pub height: f32,
+}
+
+/// Group a column's time ranges (sorted by start) into stacks
+/// Each stack starts at its first event's true position and is at least
+/// `MIN_EVENT_BLOCK_HEIGHT` tall; events starting inside that area join it
+pub fn stack_column_events(ranges: &[(u32, u32)]) -> Vec<EventStack> {
+    let to_px = |mins: u32| (mins as f32 / 60.0) * HOUR_ROW_HEIGHT;
+    let mut stacks: Vec<EventStack> = Vec::new();
+
+    for (idx, &(start, end)) in ranges.iter().enumerate() {
+        let top = to_px(start);
+        let bottom = to_px(end).max(top + MIN_EVENT_BLOCK_HEIGHT);
+
+        match stacks.last_mut() {
+            Some(stack) if top < stack.top + stack.height => {
+                stack.events.end = idx + 1;
+                stack.height = stack.height.max(bottom - stack.top);
+            }
+            _ => stacks.push(EventStack {
+                events: idx..idx + 1,
+                top,
+                height: bottom - top,
+            }),
+        }
+    }
+
+    // A stack may not run past midnight
+    let day_height = 24.0 * HOUR_ROW_HEIGHT;
+    for stack in &mut stacks {
+        stack.height = stack.height.min(day_height - stack.top).max(0.0);
+    }
+
+    stacks
+}
+
 /// Separate events into all-day and timed categories
 pub fn separate_events(
     events_by_date: &HashMap<NaiveDate, Vec<DisplayEvent>>,
@@ -56,14 +105,61 @@ pub fn calculate_max_all_day_slots(all_day_events: &HashMap<NaiveDate, Vec<Displ
     all_day_events.values().map(|v| v.len()).max().unwrap_or(0)
 }
 
+/// Size and collapse state of the all-day section
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AllDayLayout {
+    /// Section height in pixels
+    pub height: f32,
+    /// Whether the section has enough rows to offer a collapse toggle
+    pub collapsible: bool,
+    /// Whether the section is collapsed to a "+N" summary per day
+    pub collapsed: bool,
+    /// Whether events on past days are dimmed
+    pub dim_past: bool,
+}
+
+impl AllDayLayout {
+    /// Lay out the all-day section for the shown days (a week, or the single
+    /// day of the day view) from their all-day events
+    /// The section can only be collapsed once it holds more than one row
+    pub fn for_days(
+        days: &[NaiveDate],
+        all_day_events: &HashMap<NaiveDate, Vec<DisplayEvent>>,
+        holidays: &HashMap<NaiveDate, String>,
+        collapse: bool,
+        dim_past: bool,
+    ) -> Self {
+        let max_slots = calculate_max_all_day_slots(all_day_events);
+        let collapsible = max_slots > 1;
+        let collapsed = collapsible && collapse;
+        // Holiday names take a row of their own above the events
+        let holiday_rows = usize::from(days.iter().any(|d| holidays.contains_key(d)));
+        let height = if collapsed {
+            ALL_DAY_MIN_HEIGHT
+        } else {
+            ALL_DAY_MIN_HEIGHT + ((max_slots + holiday_rows) as f32 * (ALL_DAY_EVENT_HEIGHT + ALL_DAY_SPACING))
+        };
+
+        Self {
+            height,
+            collapsible,
+            collapsed,
+            dim_past,
+        }
+    }
+}
+
 /// Get the time range of an event in minutes from midnight
 pub fn event_time_range(event: &DisplayEvent) -> (u32, u32) {
     let start = event.start_time
         .map(|t| t.hour() * 60 + t.minute())
         .unwrap_or(0);
-    let end = event.end_time
-        .map(|t| t.hour() * 60 + t.minute())
-        .unwrap_or(start + 60); // Default 1 hour if no end time
+    let end = match event.end_time {
+        // Segments continuing into the next day fill the column to midnight
+        Some(t) if t == end_of_day() => 24 * 60,
+        Some(t) => t.hour() * 60 + t.minute(),
+        None => start + 60, // Default 1 hour if no end time
+    };
 
     // Ensure end is after start
     let end = if end <= start { start + 30 } else { end };
@@ -148,14 +244,246 @@ pub fn calculate_event_columns(events: &[DisplayEvent]) -> Vec<PositionedEvent>
     positioned
 }
 
+/// Markers for the timeline mini-map, one per hour of the day
+/// Each busy hour holds the color of its earliest event and how many events touch it
+pub fn hourly_event_markers(events: &[&DisplayEvent]) -> [Option<(String, usize)>; 24] {
+    let mut sorted: Vec<&DisplayEvent> = events.to_vec();
+    sorted.sort_by_key(|e| event_time_range(e).0);
+
+    let mut markers: [Option<(String, usize)>; 24] = std::array::from_fn(|_| None);
+    for event in sorted {
+        let (start, end) = event_time_range(event);
+        // An event ending on the hour doesn't touch the next one
+        let last_hour = ((end.saturating_sub(1)) / 60).min(23);
+        for hour in (start / 60).min(23)..=last_hour {
+            match &mut markers[hour as usize] {
+                Some((_, count)) => *count += 1,
+                slot => *slot = Some((event.color.clone(), 1)),
+            }
+        }
+    }
+    markers
+}
+
+/// Find the next timed event starting after `now`
+/// Returns the event's unique ID and the minutes until it starts
+pub fn next_upcoming_event(events: &[DisplayEvent], now: NaiveTime) -> Option<(String, i64)> {
+    events
+        .iter()
+        .filter(|e| !e.all_day)
+        .filter_map(|e| e.start_time.filter(|start| *start > now).map(|start| (e, start)))
+        .min_by_key(|(_, start)| *start)
+        .map(|(e, start)| (e.unique_id(), (start - now).num_minutes().max(1)))
+}
+
 /// Calculate the height for a time span in pixels
 #[allow(dead_code)]
 pub fn time_span_to_height(start_mins: u32, end_mins: u32) -> f32 {
     ((end_mins - start_mins) as f32 / 60.0) * HOUR_ROW_HEIGHT
 }
 
+/// Start times of the clickable slots an hour cell is divided into
+/// Each slot is `snap_minutes` long, so a press at a given height maps to its snapped time
+pub fn hour_slot_starts(hour: u32, snap_minutes: u32) -> Vec<NaiveTime> {
+    let snap_minutes = snap_minutes.clamp(1, 60);
+    (0..60)
+        .step_by(snap_minutes as usize)
+        .filter_map(|minute| NaiveTime::from_hms_opt(hour, minute, 0))
+        .collect()
+}
+
+/// Lines shown in an event chip's hover tooltip: the full title, when it
+/// happens, then the location and start of the notes when the event has them
+pub fn event_tooltip_lines(event: &DisplayEvent) -> Vec<String> {
+    let mut lines = vec![event.display_summary()];
+
+    // Segments of a multi-day event only know their own times, so show the dates
+    match (event.span_start, event.span_end, event.start_time, event.end_time) {
+        (Some(first), Some(last), _, _) if first != last => {
+            lines.push(format!("{} – {}", first.format("%a %b %-d"), last.format("%a %b %-d")));
+        }
+        (_, _, Some(start), Some(end)) => {
+            lines.push(format!("{} – {}", start.format("%H:%M"), end.format("%H:%M")));
+        }
+        _ => {}
+    }
+
+    lines.extend(event.location.iter().filter(|l| !l.trim().is_empty()).cloned());
+    lines.extend(event.notes_preview.iter().cloned());
+    lines
+}
+
 /// Calculate the vertical offset for a time in pixels
 #[allow(dead_code)]
 pub fn time_to_offset(hour: u32, minute: u32) -> f32 {
     (hour as f32 + minute as f32 / 60.0) * HOUR_ROW_HEIGHT
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caldav::ShowAs;
+
+    fn timed_event(uid: &str, start_hour: u32) -> DisplayEvent {
+        DisplayEvent {
+            calendar_id: "cal".to_string(),
+            uid: uid.to_string(),
+            summary: String::new(),
+            color: "#3B82F6".to_string(),
+            all_day: false,
+            start_time: NaiveTime::from_hms_opt(start_hour, 0, 0),
+            end_time: NaiveTime::from_hms_opt(start_hour + 1, 0, 0),
+            show_as: ShowAs::Busy,
+            priority: None,
+            location: None,
+            notes_preview: None,
+            display_order: None,
+            span_start: None,
+            span_end: None,
+        }
+    }
+
+    #[test]
+    fn test_next_upcoming_event_picks_nearest_future_start() {
+        let events = vec![timed_event("past", 8), timed_event("later", 14), timed_event("next", 11)];
+        let now = NaiveTime::from_hms_opt(10, 40, 0).unwrap();
+
+        let (unique_id, minutes) = next_upcoming_event(&events, now).unwrap();
+
+        assert_eq!(unique_id, "cal:next");
+        assert_eq!(minutes, 20);
+    }
+
+    #[test]
+    fn test_event_tooltip_lines() {
+        let mut event = timed_event("standup", 9);
+        event.summary = "Standup".to_string();
+        assert_eq!(event_tooltip_lines(&event), vec!["Standup", "09:00 – 10:00"]);
+
+        event.location = Some("Room 4".to_string());
+        event.notes_preview = DisplayEvent::preview_notes(Some("Agenda:\n  - updates"));
+        assert_eq!(
+            event_tooltip_lines(&event),
+            vec!["Standup", "09:00 – 10:00", "Room 4", "Agenda: - updates"]
+        );
+
+        // Long notes are cut short
+        let preview = DisplayEvent::preview_notes(Some(&"word ".repeat(100))).unwrap();
+        assert!(preview.ends_with('…'));
+        assert!(preview.chars().count() <= 121);
+        assert_eq!(DisplayEvent::preview_notes(Some("  \n ")), None);
+    }
+
+    #[test]
+    fn test_untitled_event_placeholder() {
+        let mut event = timed_event("untitled", 9);
+        let placeholder = crate::fl!("event-no-title");
+        assert_eq!(event.display_summary(), placeholder);
+        assert_eq!(event_tooltip_lines(&event)[0], placeholder);
+
+        event.summary = "  ".to_string();
+        event.priority = Some(1);
+        assert_eq!(event.display_summary(), format!("! {}", placeholder));
+
+        event.summary = "Standup".to_string();
+        assert_eq!(event.title(), "Standup");
+    }
+
+    #[test]
+    fn test_event_time_range_runs_to_midnight_for_continuing_segment() {
+        let mut event = timed_event("span", 22);
+        event.end_time = Some(end_of_day());
+
+        assert_eq!(event_time_range(&event), (22 * 60, 24 * 60));
+    }
+
+    #[test]
+    fn test_stack_column_events_groups_short_events() {
+        // Three 10-minute events back to back, then a regular one later
+        let ranges = [(9 * 60, 9 * 60 + 10), (9 * 60 + 10, 9 * 60 + 20), (9 * 60 + 20, 9 * 60 + 30), (11 * 60, 12 * 60)];
+        let stacks = stack_column_events(&ranges);
+
+        assert_eq!(stacks.len(), 2);
+        assert_eq!(stacks[0].events, 0..3);
+        assert_eq!(stacks[0].top, 9.0 * HOUR_ROW_HEIGHT);
+        // The stack ends where the last event's minimum-height block ends
+        let expected = (20.0 / 60.0) * HOUR_ROW_HEIGHT + MIN_EVENT_BLOCK_HEIGHT;
+        assert!((stacks[0].height - expected).abs() < 0.01);
+        assert_eq!(stacks[1].events, 3..4);
+        assert_eq!(stacks[1].height, HOUR_ROW_HEIGHT);
+
+        // A lone short event keeps its start but grows to the minimum height
+        let stacks = stack_column_events(&[(8 * 60, 8 * 60 + 5)]);
+        assert_eq!(stacks[0].top, 8.0 * HOUR_ROW_HEIGHT);
+        assert_eq!(stacks[0].height, MIN_EVENT_BLOCK_HEIGHT);
+    }
+
+    #[test]
+    fn test_hour_slot_starts_follow_snap() {
+        let quarters = hour_slot_starts(9, 15);
+        assert_eq!(
+            quarters,
+            vec![
+                NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(9, 15, 0).unwrap(),
+                NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+                NaiveTime::from_hms_opt(9, 45, 0).unwrap(),
+            ]
+        );
+        assert_eq!(hour_slot_starts(23, 5).len(), 12);
+        assert_eq!(hour_slot_starts(0, 30)[1], NaiveTime::from_hms_opt(0, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn test_hourly_event_markers() {
+        let mut long = timed_event("long", 9);
+        long.end_time = NaiveTime::from_hms_opt(11, 0, 0);
+        long.color = "#FF0000".to_string();
+        let events = vec![timed_event("short", 10)];
+        let refs: Vec<&DisplayEvent> = events.iter().chain(std::iter::once(&long)).collect();
+
+        let markers = hourly_event_markers(&refs);
+
+        assert_eq!(markers[9], Some(("#FF0000".to_string(), 1)));
+        assert_eq!(markers[10], Some(("#FF0000".to_string(), 2)));
+        assert!(markers[8].is_none());
+        assert!(markers[11].is_none());
+    }
+
+    #[test]
+    fn test_all_day_layout_shared_by_week_and_day() {
+        let monday = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+        let week: Vec<NaiveDate> = (0..7).map(|i| monday + chrono::Duration::days(i)).collect();
+        let mut trip = timed_event("trip", 0);
+        trip.all_day = true;
+        let mut events_by_date = HashMap::new();
+        events_by_date.insert(monday, vec![trip.clone(), timed_event("standup", 9)]);
+        events_by_date.insert(week[2], vec![trip.clone(), trip.clone(), trip]);
+        let holidays = HashMap::from([(week[2], "Holiday".to_string())]);
+
+        // Both views pick the all-day events out the same way
+        let (week_all_day, _) = separate_events(&events_by_date, &week);
+        let (day_all_day, day_timed) = separate_events(&events_by_date, &[monday]);
+        assert_eq!(day_all_day[&monday].len(), 1);
+        assert_eq!(day_timed[&monday].len(), 1);
+
+        // Three rows plus the holiday name in the week; one quiet row on Monday alone
+        let week_layout = AllDayLayout::for_days(&week, &week_all_day, &holidays, true, true);
+        assert!(week_layout.collapsible && week_layout.collapsed);
+        assert_eq!(week_layout.height, ALL_DAY_MIN_HEIGHT);
+        let expanded = AllDayLayout::for_days(&week, &week_all_day, &holidays, false, true);
+        assert_eq!(expanded.height, ALL_DAY_MIN_HEIGHT + 4.0 * (ALL_DAY_EVENT_HEIGHT + ALL_DAY_SPACING));
+
+        let day_layout = AllDayLayout::for_days(&[monday], &day_all_day, &holidays, true, true);
+        assert!(!day_layout.collapsible && !day_layout.collapsed);
+        assert_eq!(day_layout.height, ALL_DAY_MIN_HEIGHT + ALL_DAY_EVENT_HEIGHT + ALL_DAY_SPACING);
+    }
+
+    #[test]
+    fn test_next_upcoming_event_none_once_started() {
+        let events = vec![timed_event("started", 10)];
+        let now = NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+
+        assert!(next_upcoming_event(&events, now).is_none());
+    }
+}
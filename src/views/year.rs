@@ -1,8 +1,9 @@
+use chrono::{Datelike, IsoWeek, NaiveDate};
 use cosmic::iced::{alignment, Border, Length, Size};
 use cosmic::widget::{column, container, row, scrollable, responsive};
 use cosmic::{widget, Element};
 
-use crate::locale::LocalePreferences;
+use crate::locale::{LocalePreferences, Numerals};
 use crate::localized_names;
 use crate::message::Message;
 use crate::models::YearState;
@@ -14,15 +15,20 @@ use crate::ui_constants::{
 // Minimum size for month boxes - ensures all content is readable
 const MIN_MONTH_BOX_SIZE: f32 = 220.0;
 
-pub fn render_year_view(year_state: &YearState, _locale: &LocalePreferences) -> Element<'static, Message> {
+pub fn render_year_view(
+    year_state: &YearState,
+    locale: &LocalePreferences,
+    highlight_current_week: bool,
+) -> Element<'static, Message> {
     // Clone data needed for the closure
     let months = year_state.months.clone();
     let today = year_state.today;
     let year = year_state.year;
+    let numerals = locale.numerals;
 
     responsive(move |size: Size| {
         let (num_columns, box_size) = calculate_layout(size.width, size.height);
-        render_year_grid(&months, today, year, num_columns, box_size)
+        render_year_grid(&months, today, year, num_columns, box_size, highlight_current_week, numerals)
     })
     .into()
 }
@@ -67,7 +73,16 @@ fn render_year_grid(
     year: i32,
     num_columns: usize,
     box_size: Option<f32>,
+    highlight_current_week: bool,
+    numerals: Numerals,
 ) -> Element<'static, Message> {
+    // ISO week containing today, outlined in whichever months show it
+    let current_week = if highlight_current_week {
+        NaiveDate::from_ymd_opt(today.0, today.1, today.2).map(|d| d.iso_week())
+    } else {
+        None
+    };
+
     let actual_box_size = box_size.unwrap_or(MIN_MONTH_BOX_SIZE);
 
     let mut year_layout = column()
@@ -87,6 +102,8 @@ fn render_year_grid(
                     year,
                     month_index + 1,
                     actual_box_size,
+                    current_week,
+                    numerals,
                 );
                 month_row = month_row.push(month_calendar);
                 month_index += 1;
@@ -123,6 +140,8 @@ fn render_mini_month(
     year: i32,
     month: usize,
     box_size: f32,
+    current_week: Option<IsoWeek>,
+    numerals: Numerals,
 ) -> Element<'static, Message> {
     let mut mini_calendar = column()
         .spacing(SPACING_SMALL)
@@ -152,15 +171,22 @@ fn render_mini_month(
     }
     mini_calendar = mini_calendar.push(header_row);
 
-    // Day grid
+    // Day grid (rows run Monday-Sunday, so each row is one ISO week)
     for week in &month_state.weeks {
+        let is_current_week = current_week.is_some_and(|current| {
+            week.iter().flatten().any(|day| {
+                NaiveDate::from_ymd_opt(year, month as u32, *day)
+                    .is_some_and(|date| date.iso_week() == current)
+            })
+        });
+
         let mut week_row = row().spacing(SPACING_XXS);
         for day_opt in week {
             if let Some(day) = day_opt {
                 let is_today = today == (year, month as u32, *day);
 
                 let day_container = if is_today {
-                    container(widget::text(format!("{}", day)).size(FONT_SIZE_SMALL))
+                    container(widget::text(numerals.format_number(*day)).size(FONT_SIZE_SMALL))
                         .width(Length::Fill)
                         .padding(PADDING_TINY)
                         .center_x(Length::Fill)
@@ -179,7 +205,7 @@ fn render_mini_month(
                             }
                         })
                 } else {
-                    container(widget::text(format!("{}", day)).size(FONT_SIZE_SMALL))
+                    container(widget::text(numerals.format_number(*day)).size(FONT_SIZE_SMALL))
                         .width(Length::Fill)
                         .padding(PADDING_TINY)
                         .center_x(Length::Fill)
@@ -195,7 +221,25 @@ fn render_mini_month(
                 );
             }
         }
-        mini_calendar = mini_calendar.push(week_row);
+
+        if is_current_week {
+            // Faint outline around the current week's row
+            mini_calendar = mini_calendar.push(
+                container(week_row).style(|theme: &cosmic::Theme| {
+                    let accent: cosmic::iced::Color = theme.cosmic().accent_color().into();
+                    container::Style {
+                        border: Border {
+                            width: BORDER_WIDTH_THIN,
+                            color: accent.scale_alpha(0.4),
+                            radius: BORDER_RADIUS.into(),
+                        },
+                        ..Default::default()
+                    }
+                })
+            );
+        } else {
+            mini_calendar = mini_calendar.push(week_row);
+        }
     }
 
     container(mini_calendar)